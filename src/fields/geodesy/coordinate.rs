@@ -0,0 +1,89 @@
+//! `LatLon` is a struct representing a geographic coordinate
+//!
+//! A `LatLon` holds a latitude in [−90°, 90°] and a longitude, which is wrapped
+//! into (−180°, 180°]. Latitudes are geodetic when used with an ellipsoid and
+//! geocentric when used with a sphere.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geodesy::coordinate::LatLon;
+//!
+//! let sydney = LatLon::from_degrees(-33.8688f64, 151.2093).unwrap();
+//! let wrapped = LatLon::from_degrees(10.0f64, 190.0).unwrap();
+//!
+//! assert_eq!(sydney.latitude().degrees(), -33.8688);
+//! assert!((wrapped.longitude().degrees() + 170.0).abs() < 1e-12);
+//! assert!(LatLon::from_degrees(91.0f64, 0.0).is_err());
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a coordinate from a latitude and longitude angle.
+//! - `from_degrees`: Constructs a coordinate from a latitude and longitude in degrees.
+//! - `latitude`: Gets the latitude.
+//! - `longitude`: Gets the longitude.
+
+use crate::fields::geodesy::error::GeodesyError;
+use crate::types::Angle;
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLon<T> {
+    latitude: Angle<T>,
+    longitude: Angle<T>,
+}
+
+impl<T> LatLon<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to create a coordinate, wrapping the longitude into (−180°, 180°].
+    pub fn new(latitude: Angle<T>, longitude: Angle<T>) -> Result<Self, GeodesyError> {
+        if latitude.radians().is_nan() || latitude.radians().abs() > T::FRAC_PI_2() {
+            return Err(GeodesyError::new("Latitude must lie in [-90°, 90°]"));
+        }
+        if !longitude.radians().is_finite() {
+            return Err(GeodesyError::new("Longitude must be finite"));
+        }
+        Ok(LatLon {
+            latitude,
+            longitude: longitude.normalized_signed(),
+        })
+    }
+
+    // Function to create a coordinate from a latitude and longitude in degrees.
+    pub fn from_degrees(latitude: T, longitude: T) -> Result<Self, GeodesyError> {
+        LatLon::new(
+            Angle::from_degrees(latitude),
+            Angle::from_degrees(longitude),
+        )
+    }
+
+    // Function to get the latitude.
+    pub fn latitude(&self) -> Angle<T> {
+        self.latitude
+    }
+
+    // Function to get the longitude.
+    pub fn longitude(&self) -> Angle<T> {
+        self.longitude
+    }
+}
+
+#[cfg(test)]
+mod geodesy_coordinate_tests {
+    use super::*;
+
+    #[test]
+    fn lat_lon_new_test() {
+        let point = LatLon::from_degrees(45.0, -540.0).unwrap();
+
+        assert_eq!(point.latitude().degrees(), 45.0);
+        assert!((point.longitude().degrees() - 180.0).abs() < 1e-12);
+        assert!(LatLon::from_degrees(90.0, 0.0).is_ok());
+        assert!(LatLon::from_degrees(-90.5, 0.0).is_err());
+        assert!(LatLon::from_degrees(f64::NAN, 0.0).is_err());
+        assert!(LatLon::from_degrees(0.0, f64::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! This module provides conversions between geodetic and ECEF coordinates.
+//!
+//! It is part of the `geodesy` module. Earth-centred Earth-fixed (ECEF)
+//! coordinates are Cartesian, in metres, with the origin at the centre of the
+//! ellipsoid. The x axis points to latitude 0° longitude 0°, the y axis to
+//! longitude 90° east, and the z axis to the north pole. Geodetic coordinates
+//! are a `LatLon` and a height above the ellipsoid.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geodesy::coordinate::LatLon;
+//! use crabmath::fields::geodesy::ecef;
+//! use crabmath::fields::geodesy::ellipsoid::Ellipsoid;
+//! use crabmath::types::Length;
+//!
+//! let wgs84 = Ellipsoid::wgs84();
+//! let point = LatLon::from_degrees(0.0f64, 90.0).unwrap();
+//!
+//! let position = ecef::to_ecef(point, Length::from_meters(100.0), wgs84);
+//! assert!(position.x.abs() < 1e-6);
+//! assert!((position.y - 6378237.0).abs() < 1e-6);
+//!
+//! let (back, height) = ecef::from_ecef(position, wgs84);
+//! assert!((back.longitude().degrees() - 90.0).abs() < 1e-12);
+//! assert!((height.meters() - 100.0).abs() < 1e-6);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_ecef`: Converts ECEF coordinates to a geodetic coordinate and height.
+//! - `to_ecef`: Converts a geodetic coordinate and height to ECEF coordinates.
+
+use crate::fields::geodesy::coordinate::LatLon;
+use crate::fields::geodesy::ellipsoid::Ellipsoid;
+use crate::fields::geometry::point::Point3;
+use crate::types::{Angle, Length};
+use num_traits::{Float, FloatConst};
+
+const MAX_ITERATIONS: usize = 10;
+
+// Function to convert a geodetic coordinate and height above the ellipsoid to ECEF.
+pub fn to_ecef<T>(point: LatLon<T>, height: Length<T>, ellipsoid: Ellipsoid<T>) -> Point3<T>
+where
+    T: Float + FloatConst,
+{
+    let a = ellipsoid.semi_major_axis().meters();
+    let e_sq = ellipsoid.eccentricity_squared();
+    let h = height.meters();
+    let (sin_lat, cos_lat) = point.latitude().sin_cos();
+    let (sin_lon, cos_lon) = point.longitude().sin_cos();
+    // The radius of curvature in the prime vertical.
+    let n = a / (T::one() - e_sq * sin_lat * sin_lat).sqrt();
+    Point3::new(
+        (n + h) * cos_lat * cos_lon,
+        (n + h) * cos_lat * sin_lon,
+        (n * (T::one() - e_sq) + h) * sin_lat,
+    )
+}
+
+// Function to convert ECEF coordinates to a geodetic coordinate and height above the ellipsoid.
+//
+// The latitude is found by fixed-point iteration, which gains about two digits
+// per step on the Earth, and the height formula stays accurate at the poles.
+pub fn from_ecef<T>(position: Point3<T>, ellipsoid: Ellipsoid<T>) -> (LatLon<T>, Length<T>)
+where
+    T: Float + FloatConst,
+{
+    let a = ellipsoid.semi_major_axis().meters();
+    let e_sq = ellipsoid.eccentricity_squared();
+    let p = position.x.hypot(position.y);
+    let z = position.z;
+    let prime_vertical = |latitude: T| a / (T::one() - e_sq * latitude.sin().powi(2)).sqrt();
+
+    let mut latitude = z.atan2(p * (T::one() - e_sq));
+    for _ in 0..MAX_ITERATIONS {
+        let next = (z + e_sq * prime_vertical(latitude) * latitude.sin()).atan2(p);
+        let converged = (next - latitude).abs() <= T::epsilon();
+        latitude = next;
+        if converged {
+            break;
+        }
+    }
+    let (sin_lat, cos_lat) = latitude.sin_cos();
+    let height = p * cos_lat + z * sin_lat - a * a / prime_vertical(latitude);
+    let point = LatLon::new(
+        Angle::from_radians(latitude),
+        Angle::from_radians(position.y.atan2(position.x)),
+    )
+    .unwrap();
+    (point, Length::from_meters(height))
+}
+
+#[cfg(test)]
+mod geodesy_ecef_tests {
+    use super::*;
+
+    #[test]
+    fn to_ecef_test() {
+        let wgs84 = Ellipsoid::<f64>::wgs84();
+        let zero = Length::zero();
+
+        let origin = to_ecef(LatLon::from_degrees(0.0, 0.0).unwrap(), zero, wgs84);
+        assert_eq!(origin, Point3::new(6378137.0, 0.0, 0.0));
+
+        let pole = to_ecef(
+            LatLon::from_degrees(90.0, 0.0).unwrap(),
+            Length::from_meters(10.0),
+            wgs84,
+        );
+        assert!(pole.x.abs() < 1e-9);
+        assert!((pole.z - wgs84.semi_minor_axis().meters() - 10.0).abs() < 1e-6);
+
+        // A published reference point: 45°N 45°E at a height of 1000 m.
+        let point = to_ecef(
+            LatLon::from_degrees(45.0, 45.0).unwrap(),
+            Length::from_meters(1000.0),
+            wgs84,
+        );
+        assert!((point.x - 3194919.145).abs() < 1e-3);
+        assert!((point.y - 3194919.145).abs() < 1e-3);
+        assert!((point.z - 4488055.516).abs() < 1e-3);
+    }
+
+    #[test]
+    fn from_ecef_round_trip_test() {
+        let wgs84 = Ellipsoid::<f64>::wgs84();
+        for latitude in (-90i32..=90).step_by(15) {
+            for longitude in (-165..=180).step_by(45) {
+                for height in [-400.0, 0.0, 8848.0, 4.0e5] {
+                    let point = LatLon::from_degrees(latitude as f64, longitude as f64).unwrap();
+                    let (back, back_height) =
+                        from_ecef(to_ecef(point, Length::from_meters(height), wgs84), wgs84);
+
+                    assert!((back.latitude() - point.latitude()).radians().abs() < 1e-12);
+                    if latitude.abs() != 90 {
+                        let dlon = (back.longitude() - point.longitude()).normalized_signed();
+                        assert!(dlon.radians().abs() < 1e-12);
+                    }
+                    assert!((back_height.meters() - height).abs() < 1e-6);
+                }
+            }
+        }
+    }
+}
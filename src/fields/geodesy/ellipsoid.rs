@@ -0,0 +1,126 @@
+//! `Ellipsoid` is a struct representing a reference ellipsoid of revolution
+//!
+//! An ellipsoid is defined by its semi-major (equatorial) axis and its
+//! flattening. The WGS-84 ellipsoid used by GPS and the nearly identical GRS-80
+//! ellipsoid are provided, and a sphere is an ellipsoid with zero flattening.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geodesy::ellipsoid::Ellipsoid;
+//! use crabmath::types::quantity::unit::LengthUnit;
+//!
+//! let wgs84 = Ellipsoid::<f64>::wgs84();
+//!
+//! assert_eq!(wgs84.semi_major_axis().meters(), 6378137.0);
+//! assert!((wgs84.semi_minor_axis().meters() - 6356752.314245179).abs() < 1e-6);
+//! assert!((wgs84.eccentricity_squared() - 0.00669437999014).abs() < 1e-14);
+//! assert!((wgs84.semi_major_axis().to(LengthUnit::Mile) - 3963.190592).abs() < 1e-6);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs an ellipsoid from its semi-major axis and flattening.
+//! - `wgs84`: Constructs the WGS-84 ellipsoid.
+//! - `grs80`: Constructs the GRS-80 ellipsoid.
+//! - `sphere`: Constructs a sphere of a given radius.
+//! - `semi_major_axis`: Gets the equatorial radius.
+//! - `semi_minor_axis`: Gets the polar radius.
+//! - `flattening`: Gets the flattening.
+//! - `eccentricity_squared`: Gets the square of the first eccentricity.
+
+use crate::fields::geodesy::error::GeodesyError;
+use crate::types::Length;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid<T> {
+    semi_major_axis: T,
+    flattening: T,
+}
+
+impl<T> Ellipsoid<T>
+where
+    T: Float,
+{
+    // Function to create an ellipsoid from its equatorial radius and flattening.
+    pub fn new(semi_major_axis: Length<T>, flattening: T) -> Result<Self, GeodesyError> {
+        if !(semi_major_axis.meters() > T::zero() && semi_major_axis.meters().is_finite()) {
+            return Err(GeodesyError::new("Semi-major axis must be positive"));
+        }
+        if !(flattening >= T::zero() && flattening < T::one()) {
+            return Err(GeodesyError::new("Flattening must lie in [0, 1)"));
+        }
+        Ok(Ellipsoid {
+            semi_major_axis: semi_major_axis.meters(),
+            flattening,
+        })
+    }
+
+    // Function to create the WGS-84 ellipsoid.
+    pub fn wgs84() -> Self {
+        Ellipsoid {
+            semi_major_axis: T::from(6378137.0).unwrap(),
+            flattening: T::one() / T::from(298.257223563).unwrap(),
+        }
+    }
+
+    // Function to create the GRS-80 ellipsoid.
+    pub fn grs80() -> Self {
+        Ellipsoid {
+            semi_major_axis: T::from(6378137.0).unwrap(),
+            flattening: T::one() / T::from(298.257222101).unwrap(),
+        }
+    }
+
+    // Function to create a sphere, an ellipsoid with no flattening.
+    pub fn sphere(radius: Length<T>) -> Result<Self, GeodesyError> {
+        Ellipsoid::new(radius, T::zero())
+    }
+
+    // Function to get the equatorial radius.
+    pub fn semi_major_axis(&self) -> Length<T> {
+        Length::from_meters(self.semi_major_axis)
+    }
+
+    // Function to get the polar radius.
+    pub fn semi_minor_axis(&self) -> Length<T> {
+        Length::from_meters(self.semi_major_axis * (T::one() - self.flattening))
+    }
+
+    // Function to get the flattening.
+    pub fn flattening(&self) -> T {
+        self.flattening
+    }
+
+    // Function to get the square of the first eccentricity.
+    pub fn eccentricity_squared(&self) -> T {
+        self.flattening * (T::from(2).unwrap() - self.flattening)
+    }
+}
+
+#[cfg(test)]
+mod geodesy_ellipsoid_tests {
+    use super::*;
+
+    #[test]
+    fn ellipsoid_new_test() {
+        let sphere = Ellipsoid::sphere(Length::from_meters(6371000.0)).unwrap();
+
+        assert_eq!(sphere.semi_minor_axis(), sphere.semi_major_axis());
+        assert_eq!(sphere.eccentricity_squared(), 0.0);
+        assert!(Ellipsoid::new(Length::from_meters(0.0), 0.0).is_err());
+        assert!(Ellipsoid::new(Length::from_meters(1.0), 1.0).is_err());
+        assert!(Ellipsoid::new(Length::from_meters(1.0), -0.1).is_err());
+    }
+
+    #[test]
+    fn ellipsoid_reference_test() {
+        let wgs84 = Ellipsoid::<f64>::wgs84();
+        let grs80 = Ellipsoid::<f64>::grs80();
+
+        assert!((wgs84.semi_minor_axis().meters() - 6356752.3142).abs() < 1e-4);
+        assert!((grs80.semi_minor_axis().meters() - 6356752.3141).abs() < 1e-4);
+        assert!((grs80.eccentricity_squared() - 0.00669438002290).abs() < 1e-14);
+    }
+}
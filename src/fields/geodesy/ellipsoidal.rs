@@ -0,0 +1,351 @@
+//! This module provides geodesics on an ellipsoid using Vincenty's formulae.
+//!
+//! It is part of the `geodesy` module. The inverse problem finds the distance and
+//! bearings between two points. The direct problem finds the point reached from a
+//! start point, a bearing and a distance. Both are accurate to well under a
+//! millimetre on the Earth. The inverse iteration can fail to converge for points
+//! that are nearly antipodal, which is reported as a `GeodesyError`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geodesy::coordinate::LatLon;
+//! use crabmath::fields::geodesy::ellipsoid::Ellipsoid;
+//! use crabmath::fields::geodesy::ellipsoidal;
+//! use crabmath::types::quantity::unit::LengthUnit;
+//!
+//! let wgs84 = Ellipsoid::wgs84();
+//! let jfk = LatLon::from_degrees(40.6413f64, -73.7781).unwrap();
+//! let lhr = LatLon::from_degrees(51.4700f64, -0.4543).unwrap();
+//!
+//! let geodesic = ellipsoidal::solve_inverse(jfk, lhr, wgs84).unwrap();
+//! assert!((geodesic.distance.to(LengthUnit::Kilometer) - 5554.0).abs() < 1.0);
+//!
+//! let (end, _) = ellipsoidal::solve_direct(jfk, geodesic.initial_bearing, geodesic.distance, wgs84);
+//! assert!((end.latitude().degrees() - 51.47).abs() < 1e-9);
+//! ```
+//!
+//! # Functions
+//!
+//! - `get_distance_vincenty`: Computes the geodesic distance between two points.
+//! - `solve_direct`: Finds the destination and final bearing from a start, bearing and distance.
+//! - `solve_inverse`: Finds the distance and bearings between two points.
+
+use crate::fields::geodesy::coordinate::LatLon;
+use crate::fields::geodesy::ellipsoid::Ellipsoid;
+use crate::fields::geodesy::error::GeodesyError;
+use crate::types::{Angle, Length};
+use num_traits::{Float, FloatConst};
+use std::f64::consts::PI;
+
+const MAX_ITERATIONS: usize = 200;
+const CONVERGENCE: f64 = 1e-12;
+
+// The solution of the inverse geodesic problem.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodesic<T> {
+    pub distance: Length<T>,
+    pub initial_bearing: Angle<T>,
+    pub final_bearing: Angle<T>,
+}
+
+// Function to get the geodesic distance between two points on an ellipsoid.
+pub fn get_distance_vincenty<T>(
+    from: LatLon<T>,
+    to: LatLon<T>,
+    ellipsoid: Ellipsoid<T>,
+) -> Result<Length<T>, GeodesyError>
+where
+    T: Float + FloatConst,
+{
+    solve_inverse(from, to, ellipsoid).map(|geodesic| geodesic.distance)
+}
+
+// Function to get the distance and the initial and final bearings between two points.
+//
+// Coincident points give a zero distance and bearings of north.
+pub fn solve_inverse<T>(
+    from: LatLon<T>,
+    to: LatLon<T>,
+    ellipsoid: Ellipsoid<T>,
+) -> Result<Geodesic<T>, GeodesyError>
+where
+    T: Float + FloatConst,
+{
+    let (a, b, f) = axes(ellipsoid);
+    let lon_difference = (to.longitude() - from.longitude()).normalized_signed();
+    let l = to_f64(lon_difference.radians());
+    let (sin_u1, cos_u1) = reduced_latitude(from.latitude(), f);
+    let (sin_u2, cos_u2) = reduced_latitude(to.latitude(), f);
+
+    let mut lambda = l;
+    let mut iterations = 0;
+    let (sin_sigma, cos_sigma, sigma, cos_sq_alpha, cos_2sigma_m, sin_lambda, cos_lambda) = loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == 0.0 {
+            let north = Angle::zero();
+            return Ok(Geodesic {
+                distance: Length::zero(),
+                initial_bearing: north,
+                final_bearing: north,
+            });
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        // Both points on the equator give cos²α = 0.
+        let cos_2sigma_m = if cos_sq_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            0.0
+        };
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+        iterations += 1;
+        if lambda.abs() > PI || iterations > MAX_ITERATIONS {
+            return Err(GeodesyError::new(
+                "Vincenty's formula failed to converge for nearly antipodal points",
+            ));
+        }
+        if (lambda - previous).abs() < CONVERGENCE {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            break (
+                sin_sigma,
+                cos_sigma,
+                sigma,
+                cos_sq_alpha,
+                cos_2sigma_m,
+                sin_lambda,
+                cos_lambda,
+            );
+        }
+    };
+
+    let (big_a, big_b) = series_coefficients(cos_sq_alpha, a, b);
+    let delta_sigma = delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+    let distance = b * big_a * (sigma - delta_sigma);
+    let initial = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+    let fin = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+    Ok(Geodesic {
+        distance: Length::from_meters(from_f64(distance)),
+        initial_bearing: Angle::from_radians(from_f64(initial)).normalized(),
+        final_bearing: Angle::from_radians(from_f64(fin)).normalized(),
+    })
+}
+
+// Function to get the point reached by travelling `distance` from `start` along `bearing`,
+// together with the bearing on arrival.
+pub fn solve_direct<T>(
+    start: LatLon<T>,
+    bearing: Angle<T>,
+    distance: Length<T>,
+    ellipsoid: Ellipsoid<T>,
+) -> (LatLon<T>, Angle<T>)
+where
+    T: Float + FloatConst,
+{
+    let (a, b, f) = axes(ellipsoid);
+    let s = to_f64(distance.meters());
+    let (sin_alpha1, cos_alpha1) = to_f64(bearing.radians()).sin_cos();
+    let (sin_u1, cos_u1) = reduced_latitude(start.latitude(), f);
+
+    let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+    let (big_a, big_b) = series_coefficients(cos_sq_alpha, a, b);
+
+    let mut sigma = s / (b * big_a);
+    for _ in 0..MAX_ITERATIONS {
+        let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let previous = sigma;
+        sigma = s / (b * big_a) + delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m);
+        if (sigma - previous).abs() < CONVERGENCE {
+            break;
+        }
+    }
+
+    let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+    let (sin_sigma, cos_sigma) = sigma.sin_cos();
+    let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let latitude = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((1.0 - f) * (sin_alpha * sin_alpha + x * x).sqrt());
+    let lambda =
+        (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+    let l = lambda
+        - (1.0 - c)
+            * f
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma
+                    * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+    let final_bearing = sin_alpha.atan2(-x);
+
+    let end = LatLon::new(
+        Angle::from_radians(from_f64(latitude)),
+        start.longitude() + Angle::from_radians(from_f64(l)),
+    )
+    .unwrap();
+    (
+        end,
+        Angle::from_radians(from_f64(final_bearing)).normalized(),
+    )
+}
+
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap()
+}
+
+fn from_f64<T: Float>(value: f64) -> T {
+    T::from(value).unwrap()
+}
+
+// Gets the semi-major axis, semi-minor axis and flattening.
+fn axes<T: Float>(ellipsoid: Ellipsoid<T>) -> (f64, f64, f64) {
+    (
+        to_f64(ellipsoid.semi_major_axis().meters()),
+        to_f64(ellipsoid.semi_minor_axis().meters()),
+        to_f64(ellipsoid.flattening()),
+    )
+}
+
+// Gets the sine and cosine of the reduced latitude, tan U = (1 - f) tan φ.
+fn reduced_latitude<T: Float>(latitude: Angle<T>, f: f64) -> (f64, f64) {
+    let (sin, cos) = to_f64(latitude.radians()).sin_cos();
+    let u = ((1.0 - f) * sin).atan2(cos);
+    u.sin_cos()
+}
+
+// Gets Vincenty's A and B series coefficients.
+fn series_coefficients(cos_sq_alpha: f64, a: f64, b: f64) -> (f64, f64) {
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+    (big_a, big_b)
+}
+
+fn delta_sigma(big_b: f64, sin_sigma: f64, cos_sigma: f64, cos_2sigma_m: f64) -> f64 {
+    let cos_sq_2sigma_m = cos_2sigma_m * cos_2sigma_m;
+    big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_sq_2sigma_m)
+                    - big_b / 6.0
+                        * cos_2sigma_m
+                        * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                        * (-3.0 + 4.0 * cos_sq_2sigma_m)))
+}
+
+#[cfg(test)]
+mod geodesy_ellipsoidal_tests {
+    use super::*;
+
+    fn flinders_peak() -> LatLon<f64> {
+        LatLon::new(
+            Angle::from_dms(-37.0, 57.0, 3.7203),
+            Angle::from_dms(144.0, 25.0, 29.5244),
+        )
+        .unwrap()
+    }
+
+    fn buninyong() -> LatLon<f64> {
+        LatLon::new(
+            Angle::from_dms(-37.0, 39.0, 10.1561),
+            Angle::from_dms(143.0, 55.0, 35.3839),
+        )
+        .unwrap()
+    }
+
+    fn assert_seconds(angle: Angle<f64>, expected: Angle<f64>, seconds: f64) {
+        let difference = (angle - expected).normalized_signed().degrees() * 3600.0;
+        assert!(difference.abs() < seconds, "off by {}\"", difference);
+    }
+
+    #[test]
+    fn solve_inverse_test() {
+        // Geoscience Australia's worked example on GRS-80.
+        let geodesic = solve_inverse(flinders_peak(), buninyong(), Ellipsoid::grs80()).unwrap();
+
+        assert!((geodesic.distance.meters() - 54972.271).abs() < 1e-3);
+        assert_seconds(
+            geodesic.initial_bearing,
+            Angle::from_dms(306.0, 52.0, 5.37),
+            0.01,
+        );
+        assert_seconds(
+            geodesic.final_bearing,
+            Angle::from_dms(127.0, 10.0, 25.07) + Angle::straight(),
+            0.01,
+        );
+    }
+
+    #[test]
+    fn solve_inverse_special_test() {
+        let wgs84 = Ellipsoid::<f64>::wgs84();
+        let point = |lat: f64, lon: f64| LatLon::from_degrees(lat, lon).unwrap();
+
+        // A quarter of the equator and a quarter of a meridian.
+        let equator = get_distance_vincenty(point(0.0, 0.0), point(0.0, 90.0), wgs84).unwrap();
+        assert!((equator.meters() - 6378137.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-5);
+        let meridian = get_distance_vincenty(point(0.0, 0.0), point(90.0, 0.0), wgs84).unwrap();
+        assert!((meridian.meters() - 10001965.729).abs() < 1e-3);
+
+        assert_eq!(
+            get_distance_vincenty(point(10.0, 10.0), point(10.0, 10.0), wgs84)
+                .unwrap()
+                .meters(),
+            0.0
+        );
+        assert!(solve_inverse(point(0.0, 0.0), point(0.5, 179.7), wgs84).is_err());
+    }
+
+    #[test]
+    fn solve_direct_test() {
+        let (end, final_bearing) = solve_direct(
+            flinders_peak(),
+            Angle::from_dms(306.0, 52.0, 5.37),
+            Length::from_meters(54972.271),
+            Ellipsoid::grs80(),
+        );
+
+        assert_seconds(end.latitude(), buninyong().latitude(), 1e-4);
+        assert_seconds(end.longitude(), buninyong().longitude(), 1e-4);
+        assert_seconds(final_bearing, Angle::from_dms(307.0, 10.0, 25.07), 0.01);
+    }
+
+    #[test]
+    fn solve_direct_round_trip_test() {
+        let wgs84 = Ellipsoid::<f64>::wgs84();
+        let mut state = 7u64;
+        let mut random = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for _ in 0..200 {
+            let start =
+                LatLon::from_degrees(random() * 170.0 - 85.0, random() * 360.0 - 180.0).unwrap();
+            let bearing = Angle::from_degrees(random() * 360.0);
+            let distance = Length::from_meters(random() * 1.5e7);
+            let (end, _) = solve_direct(start, bearing, distance, wgs84);
+            let geodesic = solve_inverse(start, end, wgs84).unwrap();
+            assert!((geodesic.distance - distance).abs().meters() < 1e-4);
+            assert_seconds(geodesic.initial_bearing, bearing, 1e-3);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct GeodesyError {
+    details: String,
+}
+
+impl GeodesyError {
+    pub fn new(msg: &str) -> GeodesyError {
+        GeodesyError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for GeodesyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for GeodesyError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
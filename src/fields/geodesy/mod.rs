@@ -0,0 +1,23 @@
+//! This module contains calculations on the surface of the Earth.
+//!
+//! Planar formulas from `geometry` treat latitude and longitude as flat
+//! coordinates, which distorts distances and areas. This module works on a sphere
+//! or an ellipsoid such as WGS-84 instead. Distances are `Length`s, areas are
+//! `Area`s, and latitudes, longitudes and bearings are `Angle`s. Bearings are
+//! measured clockwise from north in [0°, 360°).
+//!
+//! # Modules
+//!
+//! - `coordinate`: Contains the `LatLon` geographic coordinate type.
+//! - `ecef`: Contains conversions between geodetic and Earth-centred Earth-fixed coordinates.
+//! - `ellipsoid`: Contains the `Ellipsoid` reference surface type.
+//! - `ellipsoidal`: Contains Vincenty's direct and inverse geodesic solutions on an ellipsoid.
+//! - `error`: Contains the error type returned by fallible geodesy operations.
+//! - `spherical`: Contains haversine distances, bearings, destinations and areas on a sphere.
+
+pub mod coordinate;
+pub mod ecef;
+pub mod ellipsoid;
+pub mod ellipsoidal;
+pub mod error;
+pub mod spherical;
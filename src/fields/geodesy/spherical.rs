@@ -0,0 +1,303 @@
+//! This module provides distances, bearings, destinations and areas on a sphere.
+//!
+//! It is part of the `geodesy` module. Paths follow great circles, the shortest
+//! routes on a sphere. Treating the Earth as a sphere of mean radius
+//! `EARTH_RADIUS` gives errors up to about 0.5%. Use the `ellipsoidal` module
+//! where that matters.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geodesy::coordinate::LatLon;
+//! use crabmath::fields::geodesy::spherical;
+//! use crabmath::types::quantity::unit::LengthUnit;
+//!
+//! let london = LatLon::from_degrees(51.5074f64, -0.1278).unwrap();
+//! let paris = LatLon::from_degrees(48.8566f64, 2.3522).unwrap();
+//!
+//! let distance = spherical::get_distance_haversine(london, paris, spherical::earth_radius());
+//! let bearing = spherical::get_initial_bearing(london, paris);
+//!
+//! assert!((distance.to(LengthUnit::Kilometer) - 343.56).abs() < 0.01);
+//! assert!((bearing.degrees() - 148.12).abs() < 0.01);
+//! ```
+//!
+//! # Functions
+//!
+//! - `earth_radius`: Gets the mean radius of the Earth.
+//! - `get_area_polygon`: Computes the area of a polygon with great circle edges.
+//! - `get_destination`: Computes the point reached by travelling along a bearing.
+//! - `get_distance_haversine`: Computes the great circle distance between two points.
+//! - `get_final_bearing`: Computes the bearing on arrival at the end of a great circle path.
+//! - `get_initial_bearing`: Computes the bearing at the start of a great circle path.
+
+use crate::fields::geodesy::coordinate::LatLon;
+use crate::fields::geometry::vector::Vector3;
+use crate::types::{Angle, Area, Length};
+use num_traits::{Float, FloatConst};
+
+// The IUGG mean radius of the Earth in metres.
+pub const EARTH_RADIUS: f64 = 6371008.8;
+
+// Function to get the mean radius of the Earth.
+pub fn earth_radius<T: Float>() -> Length<T> {
+    Length::from_meters(T::from(EARTH_RADIUS).unwrap())
+}
+
+// Function to get the great circle distance between two points with the haversine formula.
+pub fn get_distance_haversine<T>(from: LatLon<T>, to: LatLon<T>, radius: Length<T>) -> Length<T>
+where
+    T: Float + FloatConst,
+{
+    let two = T::from(2).unwrap();
+    let (lat1, lat2) = (from.latitude().radians(), to.latitude().radians());
+    let half_dlat = (lat2 - lat1) / two;
+    let half_dlon = (to.longitude() - from.longitude()).radians() / two;
+    let h = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+    // Rounding can push h just past 1 for antipodal points.
+    let h = h.min(T::one());
+    radius * (two * h.sqrt().atan2((T::one() - h).sqrt()))
+}
+
+// Function to get the bearing at the start of the great circle path from `from` to `to`.
+//
+// Coincident points have no defined bearing and give north.
+pub fn get_initial_bearing<T>(from: LatLon<T>, to: LatLon<T>) -> Angle<T>
+where
+    T: Float + FloatConst,
+{
+    let (lat1, lat2) = (from.latitude().radians(), to.latitude().radians());
+    let dlon = (to.longitude() - from.longitude()).radians();
+    let y = dlon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+    Angle::from_radians(y.atan2(x)).normalized()
+}
+
+// Function to get the bearing on arrival at `to` along the great circle path from `from`.
+pub fn get_final_bearing<T>(from: LatLon<T>, to: LatLon<T>) -> Angle<T>
+where
+    T: Float + FloatConst,
+{
+    (get_initial_bearing(to, from) + Angle::straight()).normalized()
+}
+
+// Function to get the point reached by travelling `distance` from `start` along `bearing`.
+pub fn get_destination<T>(
+    start: LatLon<T>,
+    bearing: Angle<T>,
+    distance: Length<T>,
+    radius: Length<T>,
+) -> LatLon<T>
+where
+    T: Float + FloatConst,
+{
+    let delta = distance / radius;
+    let lat1 = start.latitude().radians();
+    let (sin_bearing, cos_bearing) = bearing.sin_cos();
+    let sin_lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * cos_bearing)
+        .max(-T::one())
+        .min(T::one());
+    let lat2 = sin_lat2.asin();
+    let dlon = (sin_bearing * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * sin_lat2);
+    LatLon::new(
+        Angle::from_radians(lat2),
+        start.longitude() + Angle::from_radians(dlon),
+    )
+    .unwrap()
+}
+
+// Function to get area of a polygon on a sphere from its vertices.
+//
+// Edges are great circle arcs. A ring splits the sphere into two regions, and the
+// smaller one is measured, so the vertex order does not matter and rings around
+// a pole are handled. The area is the spherical excess of the polygon times the
+// square of the radius, summed over a fan of triangles from the first vertex.
+pub fn get_area_polygon<T>(vertices: &[LatLon<T>], radius: Length<T>) -> Area<T>
+where
+    T: Float + FloatConst,
+{
+    if vertices.len() < 3 {
+        return Area::zero();
+    }
+    let unit = |point: &LatLon<T>| {
+        let (sin_lat, cos_lat) = point.latitude().sin_cos();
+        let (sin_lon, cos_lon) = point.longitude().sin_cos();
+        Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat)
+    };
+    let a = unit(&vertices[0]);
+    let two = T::from(2).unwrap();
+    let excess = vertices[1..].windows(2).fold(T::zero(), |sum, pair| {
+        let (b, c) = (unit(&pair[0]), unit(&pair[1]));
+        // The signed excess of triangle abc, from Eriksson's formula for tan(E / 2).
+        let triple = a.dot(b.cross(c));
+        let denominator = T::one() + a.dot(b) + b.dot(c) + c.dot(a);
+        sum + two * triple.atan2(denominator)
+    });
+    // The signed sum is the area to the left of the ring, up to a multiple of 4π.
+    let sphere = two * T::TAU();
+    let left = ((excess % sphere) + sphere) % sphere;
+    radius * radius * left.min(sphere - left)
+}
+
+#[cfg(test)]
+mod geodesy_spherical_tests {
+    use super::*;
+    use crate::types::quantity::unit::LengthUnit;
+
+    fn point(latitude: f64, longitude: f64) -> LatLon<f64> {
+        LatLon::from_degrees(latitude, longitude).unwrap()
+    }
+
+    fn assert_degrees(angle: Angle<f64>, degrees: f64, tolerance: f64) {
+        assert!(
+            (angle.degrees() - degrees).abs() < tolerance,
+            "{} != {}",
+            angle.degrees(),
+            degrees
+        );
+    }
+
+    #[test]
+    fn get_distance_haversine_test() {
+        // Land's End to John o' Groats on a 6371 km sphere is 968.9 km.
+        let lands_end = LatLon::new(
+            Angle::from_dms(50.0, 3.0, 59.0),
+            Angle::from_dms(-5.0, 42.0, 53.0),
+        )
+        .unwrap();
+        let john_o_groats = LatLon::new(
+            Angle::from_dms(58.0, 38.0, 38.0),
+            Angle::from_dms(-3.0, 4.0, 12.0),
+        )
+        .unwrap();
+        let radius = Length::new(6371.0, LengthUnit::Kilometer);
+        let distance = get_distance_haversine(lands_end, john_o_groats, radius);
+        assert!((distance.to(LengthUnit::Kilometer) - 968.9).abs() < 0.05);
+
+        // A quarter of the equator and half of a meridian.
+        let quarter = get_distance_haversine(point(0.0, 0.0), point(0.0, 90.0), radius);
+        let half = get_distance_haversine(point(90.0, 0.0), point(-90.0, 0.0), radius);
+        assert!(
+            (quarter.to(LengthUnit::Kilometer) - 6371.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-6
+        );
+        assert!((half.to(LengthUnit::Kilometer) - 6371.0 * std::f64::consts::PI).abs() < 1e-6);
+        assert_eq!(
+            get_distance_haversine(point(10.0, 20.0), point(10.0, 20.0), radius).meters(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn get_bearing_test() {
+        let lands_end = LatLon::new(
+            Angle::from_dms(50.0, 3.0, 59.0),
+            Angle::from_dms(-5.0, 42.0, 53.0),
+        )
+        .unwrap();
+        let john_o_groats = LatLon::new(
+            Angle::from_dms(58.0, 38.0, 38.0),
+            Angle::from_dms(-3.0, 4.0, 12.0),
+        )
+        .unwrap();
+
+        let initial = get_initial_bearing(lands_end, john_o_groats);
+        let fin = get_final_bearing(lands_end, john_o_groats);
+        assert_degrees(
+            initial,
+            Angle::from_dms(9.0, 7.0, 11.0).degrees(),
+            1.0 / 3600.0,
+        );
+        assert_degrees(
+            fin,
+            Angle::from_dms(11.0, 16.0, 31.0).degrees(),
+            1.0 / 3600.0,
+        );
+
+        assert_degrees(
+            get_initial_bearing(point(0.0, 0.0), point(0.0, -10.0)),
+            270.0,
+            1e-12,
+        );
+        assert_degrees(
+            get_initial_bearing(point(0.0, 0.0), point(-10.0, 0.0)),
+            180.0,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn get_destination_test() {
+        // 124.8 km from 53°19'14"N 1°43'47"W on a bearing of 96°01'18".
+        let start = LatLon::new(
+            Angle::from_dms(53.0, 19.0, 14.0),
+            Angle::from_dms(-1.0, 43.0, 47.0),
+        )
+        .unwrap();
+        let bearing = Angle::from_dms(96.0, 1.0, 18.0);
+        let radius = Length::new(6371.0, LengthUnit::Kilometer);
+        let end = get_destination(
+            start,
+            bearing,
+            Length::new(124.8, LengthUnit::Kilometer),
+            radius,
+        );
+
+        assert_degrees(
+            end.latitude(),
+            Angle::from_dms(53.0, 11.0, 18.0).degrees(),
+            1.0 / 3600.0,
+        );
+        assert_degrees(
+            end.longitude(),
+            Angle::from_dms(0.0, 8.0, 0.0).degrees(),
+            1.0 / 3600.0,
+        );
+        assert_degrees(
+            get_final_bearing(start, end),
+            Angle::from_dms(97.0, 30.0, 52.0).degrees(),
+            1.0 / 3600.0,
+        );
+
+        let back = get_destination(
+            end,
+            get_final_bearing(start, end) + Angle::straight(),
+            Length::new(124.8, LengthUnit::Kilometer),
+            radius,
+        );
+        assert_degrees(back.latitude(), start.latitude().degrees(), 1e-9);
+        assert_degrees(back.longitude(), start.longitude().degrees(), 1e-9);
+    }
+
+    #[test]
+    fn get_area_polygon_test() {
+        let radius = Length::from_meters(1.0);
+        let pi = std::f64::consts::PI;
+
+        // One octant of the sphere, in either winding.
+        let octant = [point(0.0, 0.0), point(0.0, 90.0), point(90.0, 0.0)];
+        assert!((get_area_polygon(&octant, radius).square_meters() - pi / 2.0).abs() < 1e-12);
+        let reversed: Vec<_> = octant.iter().rev().copied().collect();
+        assert!((get_area_polygon(&reversed, radius).square_meters() - pi / 2.0).abs() < 1e-12);
+
+        // A square around the north pole is four pole-centred triangles.
+        let square = [
+            point(80.0, 0.0),
+            point(80.0, 90.0),
+            point(80.0, 180.0),
+            point(80.0, -90.0),
+        ];
+        let triangle = [point(90.0, 0.0), point(80.0, 0.0), point(80.0, 90.0)];
+        let expected = 4.0 * get_area_polygon(&triangle, radius).square_meters();
+        assert!((get_area_polygon(&square, radius).square_meters() - expected).abs() < 1e-12);
+
+        let earth = get_area_polygon(&octant, earth_radius());
+        assert!(
+            (earth.to(crate::types::quantity::unit::AreaUnit::Square(
+                LengthUnit::Kilometer
+            )) - 63_758_000.0)
+                .abs()
+                < 1000.0
+        );
+        assert_eq!(get_area_polygon(&octant[..2], radius), Area::zero());
+    }
+}
@@ -0,0 +1,331 @@
+//! This module provides circles in the plane.
+//!
+//! It is part of the `analytic` module. A `Circle` is given by its centre and
+//! radius. It can be constructed through three points or fitted to any number
+//! of measured points. The fit minimises the algebraic distance
+//! `Σ (|p − c|² − r²)²` (the Kåsa method), which reduces to a 2 × 2 linear
+//! system once the points are centred. It is exact for points on a circle and
+//! accurate when the points cover a good part of it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::analytic::circle::Circle;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let circle = Circle::through(
+//!     Point2::new(1.0, 0.0),
+//!     Point2::new(-1.0, 0.0),
+//!     Point2::new(0.0, 1.0),
+//! )
+//! .unwrap();
+//! assert_eq!(circle.center(), Point2::new(0.0, 0.0));
+//! assert_eq!(circle.radius(), 1.0);
+//!
+//! // Two tangents reach the circle from an outside point.
+//! let tangents = circle.tangents_through(Point2::new(2.0, 0.0));
+//! assert_eq!(tangents.len(), 2);
+//! for tangent in tangents {
+//!     assert!((tangent.distance(circle.center()) - 1.0f64).abs() < 1e-12);
+//! }
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a circle from its centre and radius.
+//! - `through`: Constructs the circle through three points.
+//! - `fit`: Fits a circle to three or more points by least squares.
+//! - `center` and `radius`: Get the centre and radius.
+//! - `area` and `circumference`: Compute the area and circumference.
+//! - `point_at`: Gets the point of the circle at an angle.
+//! - `contains_point` and `power`: Locate a point relative to the circle.
+//! - `tangent_at`: Gets the tangent line at a point of the circle.
+//! - `tangents_through`: Gets the tangent lines through a point.
+//! - `line_intersection`: Intersects the circle with a line.
+
+use crate::fields::geometry::analytic::line::Line2;
+use crate::fields::geometry::area::get_area_circle;
+use crate::fields::geometry::circumference::get_circumference;
+use crate::fields::geometry::delaunay::circumcenter;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::predicates::{orientation, Orientation};
+use crate::types::Angle;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T> {
+    center: Point2<T>,
+    radius: T,
+}
+
+impl<T> Circle<T>
+where
+    T: Float,
+{
+    // Function to create a circle from its centre and radius.
+    pub fn new(center: Point2<T>, radius: T) -> Result<Self, GeometryError> {
+        if radius < T::zero() || !radius.is_finite() {
+            return Err(GeometryError::new(
+                "Circle radius must be finite and not negative",
+            ));
+        }
+        Ok(Circle { center, radius })
+    }
+
+    // Function to create the circle through three points.
+    pub fn through(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Result<Self, GeometryError> {
+        if orientation(a, b, c) == Orientation::Collinear {
+            return Err(GeometryError::new(
+                "A circle needs three points that are not collinear",
+            ));
+        }
+        let center = circumcenter(a, b, c);
+        Circle::new(center, center.distance(a))
+    }
+
+    // Function to fit a circle to three or more points by algebraic least squares.
+    pub fn fit(points: &[Point2<T>]) -> Result<Self, GeometryError> {
+        if points.len() < 3 {
+            return Err(GeometryError::new(
+                "A circle fit needs at least three points",
+            ));
+        }
+        let n = T::from(points.len()).unwrap();
+        let mean = points
+            .iter()
+            .fold(Point2::origin().to_vector(), |sum, p| sum + p.to_vector())
+            / n;
+
+        // With centred coordinates (u, v) and z = u² + v², the circle
+        // z + D·u + E·v + F = 0 solves a 2 × 2 system for D and E.
+        let (mut suu, mut suv, mut svv) = (T::zero(), T::zero(), T::zero());
+        let (mut szu, mut szv, mut sz) = (T::zero(), T::zero(), T::zero());
+        for p in points {
+            let (u, v) = (p.x - mean.x, p.y - mean.y);
+            let z = u * u + v * v;
+            suu = suu + u * u;
+            suv = suv + u * v;
+            svv = svv + v * v;
+            szu = szu + z * u;
+            szv = szv + z * v;
+            sz = sz + z;
+        }
+        let determinant = suu * svv - suv * suv;
+        if determinant <= T::epsilon() * (suu * svv) {
+            return Err(GeometryError::new(
+                "A circle fit needs points that are not collinear",
+            ));
+        }
+        let d = (suv * szv - svv * szu) / determinant;
+        let e = (suv * szu - suu * szv) / determinant;
+        let f = -sz / n;
+        let two = T::one() + T::one();
+        let (cu, cv) = (-d / two, -e / two);
+        Circle::new(
+            Point2::new(mean.x + cu, mean.y + cv),
+            (cu * cu + cv * cv - f).sqrt(),
+        )
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point2<T> {
+        self.center
+    }
+
+    // Function to get the radius.
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        get_area_circle(self.radius)
+    }
+
+    // Function to get the circumference.
+    pub fn circumference(&self) -> T {
+        get_circumference(self.radius)
+    }
+
+    // Function to get the point of the circle at an angle from the positive x axis.
+    pub fn point_at(&self, angle: Angle<T>) -> Point2<T> {
+        let (sin, cos) = angle.sin_cos();
+        Point2::new(
+            self.center.x + self.radius * cos,
+            self.center.y + self.radius * sin,
+        )
+    }
+
+    // Function to check whether a point lies in the closed disc.
+    pub fn contains_point(&self, point: Point2<T>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    // Function to get the power of a point: negative inside, zero on and positive outside.
+    //
+    // For an outside point it is the squared length of the tangents to the circle.
+    pub fn power(&self, point: Point2<T>) -> T {
+        self.center.distance_squared(point) - self.radius * self.radius
+    }
+
+    // Function to get the tangent line at the point of the circle in the direction of a point.
+    //
+    // The tangent runs counter-clockwise around the circle.
+    pub fn tangent_at(&self, point: Point2<T>) -> Result<Line2<T>, GeometryError> {
+        let radial = (point - self.center)
+            .normalize()
+            .ok_or_else(|| GeometryError::new("The centre has no tangent direction"))?;
+        Line2::from_point_direction(self.center + radial * self.radius, radial.perpendicular())
+    }
+
+    // Function to get the tangent lines through a point.
+    //
+    // Returns two lines for a point outside the circle, one for a point on it and
+    // none for a point inside.
+    pub fn tangents_through(&self, point: Point2<T>) -> Vec<Line2<T>> {
+        let distance = self.center.distance(point);
+        let scale = T::one() + self.radius + distance;
+        if (distance - self.radius).abs() <= T::epsilon().sqrt() * scale {
+            return self.tangent_at(point).into_iter().collect();
+        }
+        if distance < self.radius {
+            return Vec::new();
+        }
+        // The touching points are seen from the centre at ±acos(r / d) from the point.
+        let toward = (point - self.center) / distance;
+        let cos = self.radius / distance;
+        let sin = (T::one() - cos * cos).sqrt();
+        [sin, -sin]
+            .into_iter()
+            .filter_map(|sin| {
+                let radial = toward * cos + toward.perpendicular() * sin;
+                Line2::through(point, self.center + radial * self.radius).ok()
+            })
+            .collect()
+    }
+
+    // Function to get the points where a line meets the circle, ordered along the line.
+    pub fn line_intersection(&self, line: &Line2<T>) -> Vec<Point2<T>> {
+        let foot = line.project(self.center);
+        let distance = line.distance(self.center);
+        let scale = T::one() + self.radius;
+        if (distance - self.radius).abs() <= T::epsilon().sqrt() * scale {
+            return vec![foot];
+        }
+        if distance > self.radius {
+            return Vec::new();
+        }
+        let half = (self.radius * self.radius - distance * distance).sqrt();
+        vec![
+            foot - line.direction() * half,
+            foot + line.direction() * half,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod geometry_circle_tests {
+    use super::*;
+
+    fn assert_close(p: Point2<f64>, q: Point2<f64>, tolerance: f64) {
+        assert!(p.distance(q) < tolerance, "{p} != {q}");
+    }
+
+    #[test]
+    fn circle_construction_test() {
+        let circle = Circle::through(
+            Point2::new(0.0, 0.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(0.0, 8.0),
+        )
+        .unwrap();
+        assert_eq!(circle.center(), Point2::new(3.0, 4.0));
+        assert_eq!(circle.radius(), 5.0);
+        assert!((circle.area() - 25.0 * std::f64::consts::PI).abs() < 1e-12);
+        assert!((circle.circumference() - 10.0 * std::f64::consts::PI).abs() < 1e-12);
+        assert_close(
+            circle.point_at(Angle::from_degrees(90.0)),
+            Point2::new(3.0, 9.0),
+            1e-12,
+        );
+
+        assert!(Circle::new(Point2::new(0.0, 0.0), -1.0).is_err());
+        assert!(Circle::through(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 2.0),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn circle_fit_test() {
+        let center = Point2::new(-2.0, 7.0);
+        let exact: Vec<_> = (0..7)
+            .map(|i| Point2::new(-2.0 + 3.0 * (i as f64).cos(), 7.0 + 3.0 * (i as f64).sin()))
+            .collect();
+        let circle = Circle::fit(&exact).unwrap();
+        assert_close(circle.center(), center, 1e-12);
+        assert!((circle.radius() - 3.0).abs() < 1e-12);
+
+        // Points alternately pushed in and out by 0.01 still fit the circle closely.
+        let noisy: Vec<_> = (0..40)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 20.0;
+                let r = if i % 2 == 0 { 3.01 } else { 2.99 };
+                Point2::new(-2.0 + r * angle.cos(), 7.0 + r * angle.sin())
+            })
+            .collect();
+        let circle = Circle::fit(&noisy).unwrap();
+        assert_close(circle.center(), center, 1e-3);
+        assert!((circle.radius() - 3.0).abs() < 1e-3);
+
+        assert!(Circle::fit(&exact[..2]).is_err());
+        let collinear: Vec<_> = (0..5)
+            .map(|i| Point2::new(i as f64, 2.0 * i as f64))
+            .collect();
+        assert!(Circle::fit(&collinear).is_err());
+    }
+
+    #[test]
+    fn circle_tangents_test() {
+        let circle = Circle::new(Point2::new(1.0, 1.0), 1.0).unwrap();
+        assert_eq!(circle.power(Point2::new(1.0, 3.0)), 3.0);
+        assert!(circle.contains_point(Point2::new(1.5, 1.5)));
+
+        let tangent = circle.tangent_at(Point2::new(5.0, 1.0)).unwrap();
+        assert_eq!(tangent.coefficients(), (-1.0, 0.0, 2.0));
+        assert!(circle.tangent_at(circle.center()).is_err());
+
+        let outside = Point2::new(1.0, 3.0);
+        let tangents = circle.tangents_through(outside);
+        assert_eq!(tangents.len(), 2);
+        for tangent in &tangents {
+            assert!(tangent.distance(outside) < 1e-12);
+            assert!((tangent.distance(circle.center()) - 1.0).abs() < 1e-12);
+            // The tangent length agrees with the power of the point.
+            let touch = tangent.project(circle.center());
+            assert!((touch.distance_squared(outside) - 3.0).abs() < 1e-12);
+        }
+        assert_eq!(circle.tangents_through(Point2::new(2.0, 1.0)).len(), 1);
+        assert!(circle.tangents_through(Point2::new(1.2, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn circle_line_intersection_test() {
+        let circle = Circle::new(Point2::new(0.0, 0.0), 5.0).unwrap();
+        let horizontal = Line2::new(0.0, 1.0, -3.0).unwrap();
+        assert_eq!(
+            circle.line_intersection(&horizontal),
+            vec![Point2::new(-4.0, 3.0), Point2::new(4.0, 3.0)]
+        );
+        let touching = Line2::new(1.0, 0.0, -5.0).unwrap();
+        assert_eq!(
+            circle.line_intersection(&touching),
+            vec![Point2::new(5.0, 0.0)]
+        );
+        let missing = Line2::new(1.0, 1.0, -10.0).unwrap();
+        assert!(circle.line_intersection(&missing).is_empty());
+    }
+}
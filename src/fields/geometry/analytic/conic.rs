@@ -0,0 +1,945 @@
+//! This module provides general conic sections.
+//!
+//! It is part of the `analytic` module. A `Conic` is the set of points with
+//! `a·x² + b·xy + c·y² + d·x + e·y + f = 0`. Turning the axes by the angle that
+//! removes the `xy` term and moving the origin to the centre (or, for a
+//! parabola, the vertex) brings the equation into one of the canonical forms
+//!
+//! - ellipse `x²/a² + y²/b² = 1`, with the circle as the case `a = b`,
+//! - hyperbola `x²/a² − y²/b² = 1`,
+//! - parabola `y² = 4p·x`,
+//!
+//! or into a degenerate form: a single point, two crossing lines, two parallel
+//! lines, one double line, or no real points at all. `classify` reports the
+//! type and `canonical` the centre or vertex, the axis angle and the lengths.
+//! Foci, directrices and the eccentricity follow from the canonical form.
+//!
+//! Zero tests during classification are relative to the size of the terms
+//! involved, so a conic far from the origin is classified like the same conic at
+//! the origin. `fit` finds the conic through five points, or the least-squares
+//! conic for more, as the eigenvector of the smallest eigenvalue of the scatter
+//! matrix of `(x², xy, y², x, y, 1)` after centring and scaling the points.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::analytic::conic::{CanonicalForm, Conic, ConicType};
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! // x²/25 + y²/9 = 1, shifted to centre (1, 2).
+//! let ellipse = Conic::new(9.0, 0.0, 25.0, -18.0, -100.0, -116.0).unwrap();
+//! assert_eq!(ellipse.classify(), ConicType::Ellipse);
+//! assert_eq!(ellipse.eccentricity(), Some(0.8));
+//! assert_eq!(ellipse.foci(), vec![Point2::new(5.0, 2.0), Point2::new(-3.0, 2.0)]);
+//!
+//! match ellipse.canonical() {
+//!     Some(CanonicalForm::Ellipse { center, semi_major, semi_minor, .. }) => {
+//!         assert_eq!(center, Point2::new(1.0, 2.0));
+//!         assert_eq!((semi_major, semi_minor), (5.0, 3.0));
+//!     }
+//!     _ => unreachable!(),
+//! }
+//!
+//! // y = x² has its focus a quarter above the vertex.
+//! let parabola = Conic::new(1.0, 0.0, 0.0, 0.0, -1.0, 0.0).unwrap();
+//! assert_eq!(parabola.classify(), ConicType::Parabola);
+//! let focus = parabola.foci()[0];
+//! assert!(focus.distance(Point2::new(0.0, 0.25)) < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a conic from its six coefficients.
+//! - `from_circle`: Constructs the conic of a circle.
+//! - `fit`: Fits a conic to five or more points.
+//! - `coefficients`: Gets the coefficients `[a, b, c, d, e, f]`.
+//! - `evaluate` and `gradient`: Evaluate the equation and its gradient at a point.
+//! - `discriminant`: Computes `b² − 4ac`.
+//! - `classify`: Determines the type of the conic.
+//! - `center`: Gets the centre of a central conic.
+//! - `canonical`: Reduces a non-degenerate conic to canonical form.
+//! - `eccentricity`, `foci` and `directrices`: Get the focal properties.
+//! - `tangent_at`: Gets the tangent line at a point of the conic.
+//! - `tangents_through`: Gets the tangent lines through a point.
+//! - `line_intersection`: Intersects the conic with a line.
+
+use crate::fields::geometry::analytic::circle::Circle;
+use crate::fields::geometry::analytic::line::Line2;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::vector::Vector2;
+use crate::types::Angle;
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic<T> {
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+    e: T,
+    f: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConicType {
+    Ellipse,
+    Circle,
+    Parabola,
+    Hyperbola,
+    // No real points, such as x² + y² + 1 = 0 or x² + 1 = 0.
+    Empty,
+    Point,
+    IntersectingLines,
+    ParallelLines,
+    CoincidentLines,
+}
+
+// Canonical forms of the non-degenerate conics.
+//
+// `angle` is the direction of the major (or transverse) axis in (−π/2, π/2], and
+// for a parabola the direction in which it opens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CanonicalForm<T> {
+    Ellipse {
+        center: Point2<T>,
+        angle: Angle<T>,
+        semi_major: T,
+        semi_minor: T,
+    },
+    Hyperbola {
+        center: Point2<T>,
+        angle: Angle<T>,
+        semi_transverse: T,
+        semi_conjugate: T,
+    },
+    Parabola {
+        vertex: Point2<T>,
+        angle: Angle<T>,
+        focal_length: T,
+    },
+}
+
+impl ConicType {
+    // Function to check whether the type is one of the degenerate cases.
+    pub fn is_degenerate(&self) -> bool {
+        !matches!(
+            self,
+            ConicType::Ellipse | ConicType::Circle | ConicType::Parabola | ConicType::Hyperbola
+        )
+    }
+}
+
+impl<T> Conic<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to create the conic a·x² + b·xy + c·y² + d·x + e·y + f = 0.
+    pub fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Result<Self, GeometryError> {
+        if [a, b, c, d, e, f].iter().any(|v| !v.is_finite()) {
+            return Err(GeometryError::new("Conic coefficients must be finite"));
+        }
+        if a == T::zero() && b == T::zero() && c == T::zero() {
+            return Err(GeometryError::new(
+                "A conic needs a non-zero quadratic coefficient",
+            ));
+        }
+        Ok(Conic { a, b, c, d, e, f })
+    }
+
+    // Function to create the conic of a circle, (x − cx)² + (y − cy)² − r² = 0.
+    pub fn from_circle(circle: &Circle<T>) -> Self {
+        let (center, radius) = (circle.center(), circle.radius());
+        let two = T::one() + T::one();
+        Conic {
+            a: T::one(),
+            b: T::zero(),
+            c: T::one(),
+            d: -two * center.x,
+            e: -two * center.y,
+            f: center.x * center.x + center.y * center.y - radius * radius,
+        }
+    }
+
+    // Function to fit a conic to five or more points.
+    //
+    // Five points in general position give the conic through all of them. The
+    // coefficients are scaled to unit length, with a + c not negative.
+    pub fn fit(points: &[Point2<T>]) -> Result<Self, GeometryError> {
+        if points.len() < 5 {
+            return Err(GeometryError::new("A conic fit needs at least five points"));
+        }
+        // Centre the points and scale them to a mean distance of √2 from the origin.
+        let n = T::from(points.len()).unwrap();
+        let (mx, my) = points.iter().fold((T::zero(), T::zero()), |(x, y), p| {
+            (x + p.x / n, y + p.y / n)
+        });
+        let spread = points
+            .iter()
+            .fold(T::zero(), |sum, p| sum + (p.x - mx).hypot(p.y - my) / n);
+        if spread == T::zero() || !spread.is_finite() {
+            return Err(GeometryError::new("A conic fit needs distinct points"));
+        }
+        let s = T::SQRT_2() / spread;
+
+        let mut scatter = [[T::zero(); 6]; 6];
+        for p in points {
+            let (u, v) = (s * (p.x - mx), s * (p.y - my));
+            let row = [u * u, u * v, v * v, u, v, T::one()];
+            for (i, scatter_row) in scatter.iter_mut().enumerate() {
+                for (j, entry) in scatter_row.iter_mut().enumerate() {
+                    *entry = *entry + row[i] * row[j];
+                }
+            }
+        }
+        let (values, vectors) = symmetric_eigen(scatter);
+        let mut order = [0, 1, 2, 3, 4, 5];
+        order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap());
+        let largest = values[order[5]];
+        if values[order[1]] <= T::epsilon().sqrt() * largest {
+            return Err(GeometryError::new(
+                "The points do not determine a unique conic",
+            ));
+        }
+        let [a, b, c, d, e, f]: [T; 6] = std::array::from_fn(|i| vectors[i][order[0]]);
+
+        // Substitute u = s·(x − mx) and v = s·(y − my) back into the equation.
+        let two = T::one() + T::one();
+        let s2 = s * s;
+        let coefficients = [
+            a * s2,
+            b * s2,
+            c * s2,
+            -two * a * s2 * mx - b * s2 * my + d * s,
+            -b * s2 * mx - two * c * s2 * my + e * s,
+            a * s2 * mx * mx + b * s2 * mx * my + c * s2 * my * my - d * s * mx - e * s * my + f,
+        ];
+        let norm = coefficients
+            .iter()
+            .fold(T::zero(), |sum, &v| sum + v * v)
+            .sqrt();
+        let sign = if coefficients[0] + coefficients[2] < T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        let [a, b, c, d, e, f] = coefficients.map(|v| sign * v / norm);
+        Conic::new(a, b, c, d, e, f)
+    }
+
+    // Function to get the coefficients [a, b, c, d, e, f].
+    pub fn coefficients(&self) -> [T; 6] {
+        [self.a, self.b, self.c, self.d, self.e, self.f]
+    }
+
+    // Function to evaluate the left-hand side of the equation at a point.
+    pub fn evaluate(&self, point: Point2<T>) -> T {
+        let (x, y) = (point.x, point.y);
+        self.a * x * x + self.b * x * y + self.c * y * y + self.d * x + self.e * y + self.f
+    }
+
+    // Function to get the gradient of the equation at a point.
+    pub fn gradient(&self, point: Point2<T>) -> Vector2<T> {
+        let two = T::one() + T::one();
+        Vector2::new(
+            two * self.a * point.x + self.b * point.y + self.d,
+            self.b * point.x + two * self.c * point.y + self.e,
+        )
+    }
+
+    // Function to get the discriminant b² − 4ac: negative for ellipses, zero for
+    // parabolas and positive for hyperbolas.
+    pub fn discriminant(&self) -> T {
+        self.b * self.b - T::from(4).unwrap() * self.a * self.c
+    }
+
+    // Function to determine the type of the conic.
+    pub fn classify(&self) -> ConicType {
+        self.reduce().0
+    }
+
+    // Function to get the centre of symmetry, or None when there is no single one.
+    pub fn center(&self) -> Option<Point2<T>> {
+        let two = T::one() + T::one();
+        let determinant = T::from(4).unwrap() * self.a * self.c - self.b * self.b;
+        let scale = (self.a.abs() + self.b.abs() + self.c.abs()).powi(2);
+        if determinant.abs() <= tolerance::<T>() * scale {
+            return None;
+        }
+        Some(Point2::new(
+            (self.b * self.e - two * self.c * self.d) / determinant,
+            (self.b * self.d - two * self.a * self.e) / determinant,
+        ))
+    }
+
+    // Function to get the canonical form, or None for degenerate conics.
+    pub fn canonical(&self) -> Option<CanonicalForm<T>> {
+        self.reduce().1
+    }
+
+    // Function to get the eccentricity: 0 for circles, below 1 for ellipses, 1 for
+    // parabolas and above 1 for hyperbolas.
+    pub fn eccentricity(&self) -> Option<T> {
+        match self.canonical()? {
+            CanonicalForm::Ellipse {
+                semi_major,
+                semi_minor,
+                ..
+            } => Some((semi_major * semi_major - semi_minor * semi_minor).sqrt() / semi_major),
+            CanonicalForm::Hyperbola {
+                semi_transverse,
+                semi_conjugate,
+                ..
+            } => Some(semi_transverse.hypot(semi_conjugate) / semi_transverse),
+            CanonicalForm::Parabola { .. } => Some(T::one()),
+        }
+    }
+
+    // Function to get the foci: two for ellipses and hyperbolas, one for parabolas
+    // and for circles (at the centre), and none for degenerate conics.
+    pub fn foci(&self) -> Vec<Point2<T>> {
+        match self.canonical() {
+            Some(CanonicalForm::Ellipse {
+                center,
+                angle,
+                semi_major,
+                semi_minor,
+            }) => {
+                if semi_major == semi_minor {
+                    return vec![center];
+                }
+                let c = (semi_major * semi_major - semi_minor * semi_minor).sqrt();
+                let axis = axis(angle);
+                vec![center + axis * c, center - axis * c]
+            }
+            Some(CanonicalForm::Hyperbola {
+                center,
+                angle,
+                semi_transverse,
+                semi_conjugate,
+            }) => {
+                let c = semi_transverse.hypot(semi_conjugate);
+                let axis = axis(angle);
+                vec![center + axis * c, center - axis * c]
+            }
+            Some(CanonicalForm::Parabola {
+                vertex,
+                angle,
+                focal_length,
+            }) => vec![vertex + axis(angle) * focal_length],
+            None => Vec::new(),
+        }
+    }
+
+    // Function to get the directrices, each belonging to the focus at the same index.
+    //
+    // Circles and degenerate conics have none.
+    pub fn directrices(&self) -> Vec<Line2<T>> {
+        let (center, angle, distance) = match self.canonical() {
+            Some(CanonicalForm::Parabola {
+                vertex,
+                angle,
+                focal_length,
+            }) => {
+                let axis = axis(angle);
+                return Line2::from_point_direction(
+                    vertex - axis * focal_length,
+                    axis.perpendicular(),
+                )
+                .into_iter()
+                .collect();
+            }
+            Some(CanonicalForm::Ellipse {
+                center,
+                angle,
+                semi_major,
+                ..
+            })
+            | Some(CanonicalForm::Hyperbola {
+                center,
+                angle,
+                semi_transverse: semi_major,
+                ..
+            }) => {
+                let eccentricity = self.eccentricity().unwrap();
+                if eccentricity <= T::zero() {
+                    return Vec::new();
+                }
+                (center, angle, semi_major / eccentricity)
+            }
+            None => return Vec::new(),
+        };
+        let axis = axis(angle);
+        [distance, -distance]
+            .into_iter()
+            .filter_map(|t| {
+                Line2::from_point_direction(center + axis * t, axis.perpendicular()).ok()
+            })
+            .collect()
+    }
+
+    // Function to get the tangent line at a point of the conic.
+    //
+    // The line is normal to the gradient. Fails for points off the conic and for
+    // singular points, such as the crossing of two lines.
+    pub fn tangent_at(&self, point: Point2<T>) -> Result<Line2<T>, GeometryError> {
+        if !self.is_on(point) {
+            return Err(GeometryError::new("The point does not lie on the conic"));
+        }
+        let gradient = self.gradient(point);
+        Line2::new(gradient.x, gradient.y, -gradient.dot(point.to_vector()))
+            .map_err(|_| GeometryError::new("The conic has no tangent at a singular point"))
+    }
+
+    // Function to get the tangent lines through a point.
+    //
+    // A point on the conic has one tangent. From other points the tangents touch
+    // the conic where the polar line of the point meets it, so there are two from
+    // outside an ellipse and none from inside.
+    pub fn tangents_through(&self, point: Point2<T>) -> Vec<Line2<T>> {
+        if self.is_on(point) {
+            return self.tangent_at(point).into_iter().collect();
+        }
+        let two = T::one() + T::one();
+        let (x, y) = (point.x, point.y);
+        let polar = match Line2::new(
+            self.a * x + self.b * y / two + self.d / two,
+            self.b * x / two + self.c * y + self.e / two,
+            self.d * x / two + self.e * y / two + self.f,
+        ) {
+            Ok(polar) => polar,
+            Err(_) => return Vec::new(),
+        };
+        self.line_intersection(&polar)
+            .into_iter()
+            .filter_map(|touch| Line2::through(point, touch).ok())
+            .collect()
+    }
+
+    // Function to get the points where a line meets the conic, ordered along the line.
+    //
+    // A line that lies entirely on a degenerate conic gives no points.
+    pub fn line_intersection(&self, line: &Line2<T>) -> Vec<Point2<T>> {
+        let (origin, direction) = (line.origin(), line.direction());
+        let quadratic = self.a * direction.x * direction.x
+            + self.b * direction.x * direction.y
+            + self.c * direction.y * direction.y;
+        let linear = self.gradient(origin).dot(direction);
+        let constant = self.evaluate(origin);
+        let tolerance = tolerance::<T>();
+
+        if quadratic.abs() <= tolerance * (self.a.abs() + self.b.abs() + self.c.abs()) {
+            if linear == T::zero() {
+                return Vec::new();
+            }
+            return vec![line.point_at(-constant / linear)];
+        }
+        let two = T::one() + T::one();
+        let four = two * two;
+        let discriminant = linear * linear - four * quadratic * constant;
+        let scale = linear * linear + (four * quadratic * constant).abs();
+        if discriminant.abs() <= tolerance * scale {
+            return vec![line.point_at(-linear / (two * quadratic))];
+        }
+        if discriminant < T::zero() {
+            return Vec::new();
+        }
+        // Avoid cancellation by computing the larger root first.
+        let q = -(linear + linear.signum() * discriminant.sqrt()) / two;
+        let (mut t1, mut t2) = (q / quadratic, constant / q);
+        if t1 > t2 {
+            std::mem::swap(&mut t1, &mut t2);
+        }
+        vec![line.point_at(t1), line.point_at(t2)]
+    }
+
+    // Function to check whether a point satisfies the equation up to rounding.
+    fn is_on(&self, point: Point2<T>) -> bool {
+        let (x, y) = (point.x, point.y);
+        let terms = [
+            self.a * x * x,
+            self.b * x * y,
+            self.c * y * y,
+            self.d * x,
+            self.e * y,
+            self.f,
+        ];
+        let scale = terms.iter().fold(T::zero(), |sum, t| sum + t.abs());
+        self.evaluate(point).abs() <= tolerance::<T>() * scale
+    }
+
+    // Function to classify the conic and reduce it to canonical form.
+    //
+    // The axes are turned by θ so that the quadratic part becomes λ1·X² + λ2·Y²
+    // and the linear part d'·X + e'·Y. The remaining cases then depend only on
+    // which of these terms vanish and on their signs.
+    fn reduce(&self) -> (ConicType, Option<CanonicalForm<T>>) {
+        let two = T::one() + T::one();
+        let four = two * two;
+        let tolerance = tolerance::<T>();
+        // Axis-aligned conics keep their axes, so their reduction is exact.
+        let theta = if self.b == T::zero() {
+            T::zero()
+        } else {
+            self.b.atan2(self.a - self.c) / two
+        };
+        let (sin, cos) = theta.sin_cos();
+        let lambda1 = self.a * cos * cos + self.b * sin * cos + self.c * sin * sin;
+        let lambda2 = self.a * sin * sin - self.b * sin * cos + self.c * cos * cos;
+        let d1 = self.d * cos + self.e * sin;
+        let e1 = -self.d * sin + self.e * cos;
+        let f = self.f;
+        let u = Vector2::new(cos, sin);
+        let v = u.perpendicular();
+
+        let size = lambda1.abs().max(lambda2.abs());
+        let zero1 = lambda1.abs() <= tolerance * size;
+        let zero2 = lambda2.abs() <= tolerance * size;
+
+        if !zero1 && !zero2 {
+            // λ1·(X − X0)² + λ2·(Y − Y0)² + f0 = 0.
+            let (x0, y0) = (-d1 / (two * lambda1), -e1 / (two * lambda2));
+            let (shift1, shift2) = (d1 * d1 / (four * lambda1), e1 * e1 / (four * lambda2));
+            let f0 = f - shift1 - shift2;
+            let vanishes = f0.abs() <= tolerance * (f.abs() + shift1.abs() + shift2.abs());
+            let center = Point2::from(u * x0 + v * y0);
+            let same_sign = (lambda1 > T::zero()) == (lambda2 > T::zero());
+            if same_sign {
+                if vanishes {
+                    return (ConicType::Point, None);
+                }
+                if (f0 > T::zero()) == (lambda1 > T::zero()) {
+                    return (ConicType::Empty, None);
+                }
+                let is_circle = (lambda1 - lambda2).abs() <= tolerance * size;
+                // A circle gets equal axes, so its eccentricity is zero like its type says.
+                let (r1, r2) = if is_circle {
+                    let radius = (-two * f0 / (lambda1 + lambda2)).sqrt();
+                    (radius, radius)
+                } else {
+                    ((-f0 / lambda1).sqrt(), (-f0 / lambda2).sqrt())
+                };
+                let kind = if is_circle {
+                    ConicType::Circle
+                } else {
+                    ConicType::Ellipse
+                };
+                let (semi_major, semi_minor, angle) = if r1 >= r2 {
+                    (r1, r2, theta)
+                } else {
+                    (r2, r1, theta + T::FRAC_PI_2())
+                };
+                let form = CanonicalForm::Ellipse {
+                    center,
+                    angle: axis_angle(angle),
+                    semi_major,
+                    semi_minor,
+                };
+                return (kind, Some(form));
+            }
+            if vanishes {
+                return (ConicType::IntersectingLines, None);
+            }
+            let (r1, r2) = ((-f0 / lambda1).abs().sqrt(), (-f0 / lambda2).abs().sqrt());
+            let (semi_transverse, semi_conjugate, angle) = if -f0 / lambda1 > T::zero() {
+                (r1, r2, theta)
+            } else {
+                (r2, r1, theta + T::FRAC_PI_2())
+            };
+            let form = CanonicalForm::Hyperbola {
+                center,
+                angle: axis_angle(angle),
+                semi_transverse,
+                semi_conjugate,
+            };
+            return (ConicType::Hyperbola, Some(form));
+        }
+
+        // One eigenvalue vanishes. Write the equation as λ·s² + ds·s + dt·t + f = 0
+        // with s across the axis and t along it.
+        let (lambda, ds, dt, across, along) = if zero2 {
+            (lambda1, d1, e1, u, v)
+        } else {
+            (lambda2, e1, d1, v, u)
+        };
+        let s0 = -ds / (two * lambda);
+        let shift = ds * ds / (four * lambda);
+        if dt.abs() <= tolerance * lambda.abs().max(ds.abs()) {
+            // λ·(s − s0)² + (f − shift) = 0.
+            let f0 = f - shift;
+            if f0.abs() <= tolerance * (f.abs() + shift.abs()) {
+                return (ConicType::CoincidentLines, None);
+            }
+            if (f0 > T::zero()) == (lambda > T::zero()) {
+                return (ConicType::Empty, None);
+            }
+            return (ConicType::ParallelLines, None);
+        }
+        // λ·(s − s0)² = −dt·(t − t0).
+        let t0 = -(f - shift) / dt;
+        let vertex = Point2::from(across * s0 + along * t0);
+        let opening = if -dt / lambda > T::zero() {
+            along
+        } else {
+            -along
+        };
+        let form = CanonicalForm::Parabola {
+            vertex,
+            angle: Angle::from_radians(opening.y.atan2(opening.x)),
+            focal_length: (dt / lambda).abs() / four,
+        };
+        (ConicType::Parabola, Some(form))
+    }
+}
+
+// Relative tolerance for deciding that a quantity vanishes.
+fn tolerance<T: Float>() -> T {
+    T::epsilon().sqrt()
+}
+
+// Function to get the unit vector at an angle.
+fn axis<T: Float>(angle: Angle<T>) -> Vector2<T> {
+    let (sin, cos) = angle.sin_cos();
+    Vector2::new(cos, sin)
+}
+
+// Function to wrap the direction of an undirected axis into (−π/2, π/2].
+fn axis_angle<T: Float + FloatConst>(radians: T) -> Angle<T> {
+    let mut radians = radians;
+    while radians > T::FRAC_PI_2() {
+        radians = radians - T::PI();
+    }
+    while radians <= -T::FRAC_PI_2() {
+        radians = radians + T::PI();
+    }
+    Angle::from_radians(radians)
+}
+
+// Function to get the eigenvalues and eigenvectors of a symmetric matrix with the
+// cyclic Jacobi method. The eigenvectors are the columns of the second result.
+fn symmetric_eigen<T: Float, const N: usize>(matrix: [[T; N]; N]) -> ([T; N], [[T; N]; N]) {
+    let mut m = matrix;
+    let mut vectors = [[T::zero(); N]; N];
+    for (i, row) in vectors.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    let two = T::one() + T::one();
+    for _ in 0..64 {
+        let mut off = T::zero();
+        let mut total = T::zero();
+        for (i, row) in m.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                total = total + value * value;
+                if i != j {
+                    off = off + value * value;
+                }
+            }
+        }
+        if off <= T::epsilon() * T::epsilon() * total {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if m[p][q] == T::zero() {
+                    continue;
+                }
+                let theta = (m[q][q] - m[p][p]) / (two * m[p][q]);
+                let sign = if theta >= T::zero() {
+                    T::one()
+                } else {
+                    -T::one()
+                };
+                let t = sign / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                for row in m.iter_mut().chain(vectors.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (m[p], m[q]);
+                for (k, (&x, &y)) in row_p.iter().zip(row_q.iter()).enumerate() {
+                    m[p][k] = c * x - s * y;
+                    m[q][k] = s * x + c * y;
+                }
+            }
+        }
+    }
+    (std::array::from_fn(|i| m[i][i]), vectors)
+}
+
+#[cfg(test)]
+mod geometry_conic_tests {
+    use super::*;
+
+    fn assert_close(p: Point2<f64>, q: Point2<f64>, tolerance: f64) {
+        assert!(p.distance(q) < tolerance, "{p} != {q}");
+    }
+
+    // Function to turn a conic by an angle about the origin and then move it.
+    fn moved(conic: &Conic<f64>, degrees: f64, dx: f64, dy: f64) -> Conic<f64> {
+        let [a, b, c, d, e, f] = conic.coefficients();
+        let (s, k) = degrees.to_radians().sin_cos();
+        // A point p of the new conic maps back to R⁻¹(p − t) on the old one.
+        let (a1, b1, c1) = (
+            a * k * k - b * k * s + c * s * s,
+            2.0 * (a - c) * k * s + b * (k * k - s * s),
+            a * s * s + b * k * s + c * k * k,
+        );
+        let (d1, e1) = (d * k - e * s, d * s + e * k);
+        Conic::new(
+            a1,
+            b1,
+            c1,
+            d1 - 2.0 * a1 * dx - b1 * dy,
+            e1 - b1 * dx - 2.0 * c1 * dy,
+            a1 * dx * dx + b1 * dx * dy + c1 * dy * dy - d1 * dx - e1 * dy + f,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn conic_classify_test() {
+        let cases = [
+            ([1.0, 0.0, 4.0, 0.0, 0.0, -4.0], ConicType::Ellipse),
+            ([1.0, 0.0, 1.0, 0.0, 0.0, -4.0], ConicType::Circle),
+            ([0.0, 0.0, 1.0, -4.0, 0.0, 0.0], ConicType::Parabola),
+            ([1.0, 0.0, -1.0, 0.0, 0.0, -1.0], ConicType::Hyperbola),
+            ([0.0, 1.0, 0.0, 0.0, 0.0, -1.0], ConicType::Hyperbola),
+            ([1.0, 0.0, 1.0, 0.0, 0.0, 1.0], ConicType::Empty),
+            ([1.0, 0.0, 1.0, 0.0, 0.0, 0.0], ConicType::Point),
+            (
+                [1.0, 0.0, -1.0, 0.0, 0.0, 0.0],
+                ConicType::IntersectingLines,
+            ),
+            ([1.0, 0.0, 0.0, 0.0, 0.0, -1.0], ConicType::ParallelLines),
+            ([1.0, 0.0, 0.0, 0.0, 0.0, 0.0], ConicType::CoincidentLines),
+            ([1.0, 0.0, 0.0, 0.0, 0.0, 1.0], ConicType::Empty),
+        ];
+        for ([a, b, c, d, e, f], kind) in cases {
+            let conic = Conic::new(a, b, c, d, e, f).unwrap();
+            assert_eq!(conic.classify(), kind);
+            assert_eq!(kind.is_degenerate(), conic.canonical().is_none());
+            // Turning and moving the conic far from the origin keeps its type.
+            assert_eq!(moved(&conic, 37.0, 300.0, -150.0).classify(), kind);
+        }
+        assert!(Conic::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0).is_err());
+        assert_eq!(
+            Conic::new(1.0, 0.0, 4.0, 0.0, 0.0, -4.0)
+                .unwrap()
+                .discriminant(),
+            -16.0
+        );
+    }
+
+    #[test]
+    fn conic_canonical_test() {
+        // x²/16 + y²/4 = 1, turned by 30° and moved to (2, −1).
+        let base = Conic::new(1.0, 0.0, 4.0, 0.0, 0.0, -16.0).unwrap();
+        let ellipse = moved(&base, 30.0, 2.0, -1.0);
+        assert_close(ellipse.center().unwrap(), Point2::new(2.0, -1.0), 1e-12);
+        match ellipse.canonical().unwrap() {
+            CanonicalForm::Ellipse {
+                center,
+                angle,
+                semi_major,
+                semi_minor,
+            } => {
+                assert_close(center, Point2::new(2.0, -1.0), 1e-12);
+                assert!((angle.degrees() - 30.0).abs() < 1e-9);
+                assert!((semi_major - 4.0).abs() < 1e-12);
+                assert!((semi_minor - 2.0).abs() < 1e-12);
+            }
+            form => panic!("unexpected {form:?}"),
+        }
+
+        // x² − y²/3 = 1 turned by 100°: the transverse axis points at −80°.
+        let base = Conic::new(3.0, 0.0, -1.0, 0.0, 0.0, -3.0).unwrap();
+        match moved(&base, 100.0, 0.0, 0.0).canonical().unwrap() {
+            CanonicalForm::Hyperbola {
+                angle,
+                semi_transverse,
+                semi_conjugate,
+                ..
+            } => {
+                assert!((angle.degrees() + 80.0).abs() < 1e-9);
+                assert!((semi_transverse - 1.0).abs() < 1e-12);
+                assert!((semi_conjugate - 3.0f64.sqrt()).abs() < 1e-12);
+            }
+            form => panic!("unexpected {form:?}"),
+        }
+
+        // y² = 8x opens along +x; turned by 90° it opens along +y.
+        let base = Conic::new(0.0, 0.0, 1.0, -8.0, 0.0, 0.0).unwrap();
+        match moved(&base, 90.0, 1.0, 1.0).canonical().unwrap() {
+            CanonicalForm::Parabola {
+                vertex,
+                angle,
+                focal_length,
+            } => {
+                assert_close(vertex, Point2::new(1.0, 1.0), 1e-12);
+                assert!((angle.degrees() - 90.0).abs() < 1e-9);
+                assert!((focal_length - 2.0).abs() < 1e-12);
+            }
+            form => panic!("unexpected {form:?}"),
+        }
+        assert!(Conic::new(1.0, 0.0, -1.0, 0.0, 0.0, 0.0)
+            .unwrap()
+            .center()
+            .is_some());
+        assert!(base.center().is_none());
+    }
+
+    #[test]
+    fn conic_focal_properties_test() {
+        // The focal definitions hold at every point of the curves.
+        let ellipse = moved(
+            &Conic::new(9.0, 0.0, 25.0, 0.0, 0.0, -225.0).unwrap(),
+            20.0,
+            3.0,
+            4.0,
+        );
+        let hyperbola = moved(
+            &Conic::new(16.0, 0.0, -9.0, 0.0, 0.0, -144.0).unwrap(),
+            -50.0,
+            -1.0,
+            2.0,
+        );
+        let parabola = moved(
+            &Conic::new(1.0, 0.0, 0.0, 0.0, -4.0, 0.0).unwrap(),
+            75.0,
+            5.0,
+            0.0,
+        );
+
+        assert!((ellipse.eccentricity().unwrap() - 0.8).abs() < 1e-12);
+        assert!((hyperbola.eccentricity().unwrap() - 5.0 / 3.0).abs() < 1e-12);
+        assert_eq!(parabola.eccentricity(), Some(1.0));
+
+        for conic in [ellipse, hyperbola, parabola] {
+            let foci = conic.foci();
+            let directrices = conic.directrices();
+            assert_eq!(foci.len(), directrices.len());
+            let eccentricity = conic.eccentricity().unwrap();
+            let line = Line2::through(foci[0], foci[0] + Vector2::new(0.3, 1.0)).unwrap();
+            for point in conic.line_intersection(&line) {
+                assert!(conic.evaluate(point).abs() < 1e-9);
+                for (focus, directrix) in foci.iter().zip(&directrices) {
+                    let ratio = point.distance(*focus) / directrix.distance(point);
+                    assert!((ratio - eccentricity).abs() < 1e-9);
+                }
+            }
+        }
+
+        let circle = Conic::from_circle(&Circle::new(Point2::new(1.0, 1.0), 2.0).unwrap());
+        assert_eq!(circle.classify(), ConicType::Circle);
+        assert_eq!(circle.eccentricity(), Some(0.0));
+        assert_eq!(circle.foci(), vec![Point2::new(1.0, 1.0)]);
+        assert!(circle.directrices().is_empty());
+        assert!(Conic::new(1.0, 0.0, 1.0, 0.0, 0.0, 1.0)
+            .unwrap()
+            .foci()
+            .is_empty());
+    }
+
+    #[test]
+    fn conic_tangents_test() {
+        // x²/4 + y² = 1.
+        let ellipse = Conic::new(1.0, 0.0, 4.0, 0.0, 0.0, -4.0).unwrap();
+        let tangent = ellipse.tangent_at(Point2::new(0.0, 1.0)).unwrap();
+        assert_eq!(tangent.coefficients(), (0.0, 1.0, -1.0));
+        assert!(ellipse.tangent_at(Point2::new(1.0, 1.0)).is_err());
+
+        let outside = Point2::new(4.0, 1.0);
+        let tangents = ellipse.tangents_through(outside);
+        assert_eq!(tangents.len(), 2);
+        for tangent in &tangents {
+            assert!(tangent.distance(outside) < 1e-12);
+            // A tangent meets the conic exactly once.
+            assert_eq!(ellipse.line_intersection(tangent).len(), 1);
+        }
+        assert!(ellipse.tangents_through(Point2::new(0.5, 0.0)).is_empty());
+        assert_eq!(ellipse.tangents_through(Point2::new(2.0, 0.0)).len(), 1);
+
+        // Crossing lines have no tangent at their crossing.
+        let cross = Conic::new(1.0, 0.0, -1.0, 0.0, 0.0, 0.0).unwrap();
+        assert!(cross.tangent_at(Point2::new(0.0, 0.0)).is_err());
+
+        // y = x² meets y = 2x + 3 at x = −1 and x = 3.
+        let parabola = Conic::new(1.0, 0.0, 0.0, 0.0, -1.0, 0.0).unwrap();
+        let line = Line2::through(Point2::new(-1.0, 1.0), Point2::new(3.0, 9.0)).unwrap();
+        let points = parabola.line_intersection(&line);
+        assert_eq!(points.len(), 2);
+        assert_close(points[0], Point2::new(-1.0, 1.0), 1e-12);
+        assert_close(points[1], Point2::new(3.0, 9.0), 1e-12);
+        // The axis of the parabola meets it only once.
+        let axis = Line2::new(1.0, 0.0, 0.0).unwrap();
+        assert_eq!(parabola.line_intersection(&axis).len(), 1);
+    }
+
+    #[test]
+    fn conic_fit_test() {
+        let ellipse = moved(
+            &Conic::new(1.0, 0.0, 4.0, 0.0, 0.0, -16.0).unwrap(),
+            30.0,
+            50.0,
+            20.0,
+        );
+        let points: Vec<_> = (0..5)
+            .map(|i| {
+                let t = i as f64 * 1.2;
+                let (s, k) = 30f64.to_radians().sin_cos();
+                let (x, y) = (4.0 * t.cos(), 2.0 * t.sin());
+                Point2::new(50.0 + x * k - y * s, 20.0 + x * s + y * k)
+            })
+            .collect();
+        for &p in &points {
+            assert!(ellipse.evaluate(p).abs() < 1e-9);
+        }
+
+        let fitted = Conic::fit(&points).unwrap();
+        assert_eq!(fitted.classify(), ConicType::Ellipse);
+        let [a, ..] = ellipse.coefficients();
+        let [fa, ..] = fitted.coefficients();
+        for (x, y) in ellipse.coefficients().iter().zip(fitted.coefficients()) {
+            assert!((x / a - y / fa).abs() < 1e-6);
+        }
+
+        // A hyperbola through many points, with its foci recovered.
+        let hyperbola = Conic::new(1.0, 0.0, -1.0, 0.0, 0.0, -1.0).unwrap();
+        let points: Vec<_> = (-5..=5)
+            .flat_map(|i| {
+                let t = i as f64 * 0.3;
+                [
+                    Point2::new(t.cosh(), t.sinh()),
+                    Point2::new(-t.cosh(), t.sinh()),
+                ]
+            })
+            .collect();
+        let fitted = Conic::fit(&points).unwrap();
+        assert_eq!(fitted.classify(), ConicType::Hyperbola);
+        let foci = fitted.foci();
+        assert_close(foci[0], Point2::new(2.0f64.sqrt(), 0.0), 1e-9);
+        assert_close(foci[1], Point2::new(-(2.0f64.sqrt()), 0.0), 1e-9);
+        assert!(hyperbola.evaluate(points[3]).abs() < 1e-12);
+
+        assert!(Conic::fit(&points[..4]).is_err());
+        let collinear: Vec<_> = (0..6).map(|i| Point2::new(i as f64, 1.0)).collect();
+        assert!(Conic::fit(&collinear).is_err());
+
+        // A fitted circle has zero eccentricity and a single focus at its centre.
+        let points: Vec<_> = (0..7)
+            .map(|i| {
+                let t = i as f64 * 0.9;
+                Point2::new(3.0 + 2.0 * t.cos(), -1.0 + 2.0 * t.sin())
+            })
+            .collect();
+        let fitted = Conic::fit(&points).unwrap();
+        assert_eq!(fitted.classify(), ConicType::Circle);
+        assert_eq!(fitted.eccentricity(), Some(0.0));
+        let foci = fitted.foci();
+        assert_eq!(foci.len(), 1);
+        assert_close(foci[0], Point2::new(3.0, -1.0), 1e-9);
+        assert!(fitted.directrices().is_empty());
+    }
+}
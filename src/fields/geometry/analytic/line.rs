@@ -0,0 +1,353 @@
+//! This module provides infinite lines in the plane and in space.
+//!
+//! It is part of the `analytic` module. A `Line2` is stored in implicit form
+//! `a·x + b·y + c = 0`, scaled so that `(a, b)` is the unit normal. The signed
+//! distance of a point is then simply `a·x + b·y + c`. The same line is also
+//! available in parametric form `p(t) = origin + t·direction`, where `origin` is
+//! the point of the line closest to the coordinate origin and `direction` is the
+//! normal turned a quarter turn clockwise.
+//!
+//! A `Line3` is stored in parametric form with a unit direction, since a line in
+//! space has no single implicit equation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::analytic::line::{Line2, Line3};
+//! use crabmath::fields::geometry::point::{Point2, Point3};
+//!
+//! let diagonal = Line2::through(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)).unwrap();
+//! let vertical = Line2::new(1.0, 0.0, -3.0).unwrap();
+//!
+//! assert_eq!(vertical.signed_distance(Point2::new(5.0, 1.0)), 2.0);
+//! assert_eq!(diagonal.intersection(&vertical), Some(Point2::new(3.0, 3.0)));
+//! assert_eq!(vertical.project(Point2::new(0.0, 4.0)), Point2::new(3.0, 4.0));
+//!
+//! let axis = Line3::through(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 2.0)).unwrap();
+//! assert_eq!(axis.distance(Point3::new(3.0, 4.0, 7.0)), 5.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `Line2::new`: Constructs a line from the coefficients of `a·x + b·y + c = 0`.
+//! - `Line2::through`: Constructs the line through two points.
+//! - `Line2::from_point_direction`: Constructs the line through a point along a direction.
+//! - `coefficients`, `normal`, `direction` and `origin`: Get the implicit and parametric forms.
+//! - `point_at` and `parameter_of`: Convert between parameters and points.
+//! - `signed_distance` and `distance`: Measure the distance of a point.
+//! - `project` and `reflect`: Project a point onto the line or mirror it.
+//! - `intersection`, `is_parallel` and `angle_to`: Relate two lines.
+//! - `parallel_through` and `perpendicular_through`: Construct related lines.
+//! - `Line3::new` and `Line3::through`: Construct a line in space.
+//! - `closest_point`, `distance` and `closest_points`: Distance queries in space.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::{Point2, Point3};
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use crate::types::Angle;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line2<T> {
+    a: T,
+    b: T,
+    c: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line3<T> {
+    origin: Point3<T>,
+    direction: Vector3<T>,
+}
+
+impl<T> Line2<T>
+where
+    T: Float,
+{
+    // Function to create a line from the coefficients of a·x + b·y + c = 0.
+    pub fn new(a: T, b: T, c: T) -> Result<Self, GeometryError> {
+        let norm = a.hypot(b);
+        if norm == T::zero() || !norm.is_finite() || !c.is_finite() {
+            return Err(GeometryError::new(
+                "Line coefficients a and b must be finite and not both zero",
+            ));
+        }
+        Ok(Line2 {
+            a: a / norm,
+            b: b / norm,
+            c: c / norm,
+        })
+    }
+
+    // Function to create the line through two points, directed from p to q.
+    pub fn through(p: Point2<T>, q: Point2<T>) -> Result<Self, GeometryError> {
+        if p == q {
+            return Err(GeometryError::new("A line needs two distinct points"));
+        }
+        Line2::from_point_direction(p, q - p)
+    }
+
+    // Function to create the line through a point along a direction.
+    pub fn from_point_direction(
+        point: Point2<T>,
+        direction: Vector2<T>,
+    ) -> Result<Self, GeometryError> {
+        // The normal is the direction turned a quarter turn counter-clockwise.
+        let normal = direction.perpendicular();
+        Line2::new(
+            normal.x,
+            normal.y,
+            -(normal.x * point.x + normal.y * point.y),
+        )
+    }
+
+    // Function to get the coefficients (a, b, c), with (a, b) a unit vector.
+    pub fn coefficients(&self) -> (T, T, T) {
+        (self.a, self.b, self.c)
+    }
+
+    // Function to get the unit normal, pointing to the side of positive distances.
+    pub fn normal(&self) -> Vector2<T> {
+        Vector2::new(self.a, self.b)
+    }
+
+    // Function to get the unit direction of the line.
+    pub fn direction(&self) -> Vector2<T> {
+        Vector2::new(self.b, -self.a)
+    }
+
+    // Function to get the point of the line closest to the coordinate origin.
+    pub fn origin(&self) -> Point2<T> {
+        Point2::new(-self.c * self.a, -self.c * self.b)
+    }
+
+    // Function to get the point at parameter t of the parametric form.
+    pub fn point_at(&self, t: T) -> Point2<T> {
+        self.origin() + self.direction() * t
+    }
+
+    // Function to get the parameter of the projection of a point.
+    pub fn parameter_of(&self, point: Point2<T>) -> T {
+        (point - self.origin()).dot(self.direction())
+    }
+
+    // Function to get the signed distance of a point, positive on the side of the normal.
+    pub fn signed_distance(&self, point: Point2<T>) -> T {
+        self.a * point.x + self.b * point.y + self.c
+    }
+
+    // Function to get the distance of a point from the line.
+    pub fn distance(&self, point: Point2<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+
+    // Function to get the point of the line closest to a point.
+    pub fn project(&self, point: Point2<T>) -> Point2<T> {
+        point - self.normal() * self.signed_distance(point)
+    }
+
+    // Function to mirror a point in the line.
+    pub fn reflect(&self, point: Point2<T>) -> Point2<T> {
+        let two = T::one() + T::one();
+        point - self.normal() * (two * self.signed_distance(point))
+    }
+
+    // Function to check whether two lines are parallel (or the same line).
+    pub fn is_parallel(&self, other: &Line2<T>) -> bool {
+        self.normal().cross(other.normal()).abs() <= T::epsilon()
+    }
+
+    // Function to get the point where two lines cross, or None for parallel lines.
+    pub fn intersection(&self, other: &Line2<T>) -> Option<Point2<T>> {
+        if self.is_parallel(other) {
+            return None;
+        }
+        let determinant = self.a * other.b - self.b * other.a;
+        Some(Point2::new(
+            (self.b * other.c - self.c * other.b) / determinant,
+            (self.c * other.a - self.a * other.c) / determinant,
+        ))
+    }
+
+    // Function to get the angle from this line's direction to the other's, in (−π, π].
+    pub fn angle_to(&self, other: &Line2<T>) -> Angle<T> {
+        let (u, v) = (self.direction(), other.direction());
+        Angle::from_radians(u.cross(v).atan2(u.dot(v)))
+    }
+
+    // Function to get the line through a point parallel to this one.
+    pub fn parallel_through(&self, point: Point2<T>) -> Line2<T> {
+        Line2 {
+            a: self.a,
+            b: self.b,
+            c: -(self.a * point.x + self.b * point.y),
+        }
+    }
+
+    // Function to get the line through a point perpendicular to this one.
+    pub fn perpendicular_through(&self, point: Point2<T>) -> Line2<T> {
+        Line2 {
+            a: self.b,
+            b: -self.a,
+            c: -(self.b * point.x - self.a * point.y),
+        }
+    }
+}
+
+impl<T> Line3<T>
+where
+    T: Float,
+{
+    // Function to create a line through a point along a direction.
+    pub fn new(origin: Point3<T>, direction: Vector3<T>) -> Result<Self, GeometryError> {
+        let direction = direction
+            .normalize()
+            .ok_or_else(|| GeometryError::new("Line direction must not be zero"))?;
+        Ok(Line3 { origin, direction })
+    }
+
+    // Function to create the line through two points, directed from p to q.
+    pub fn through(p: Point3<T>, q: Point3<T>) -> Result<Self, GeometryError> {
+        if p == q {
+            return Err(GeometryError::new("A line needs two distinct points"));
+        }
+        Line3::new(p, q - p)
+    }
+
+    // Function to get the point the line was constructed through.
+    pub fn origin(&self) -> Point3<T> {
+        self.origin
+    }
+
+    // Function to get the unit direction of the line.
+    pub fn direction(&self) -> Vector3<T> {
+        self.direction
+    }
+
+    // Function to get the point at parameter t.
+    pub fn point_at(&self, t: T) -> Point3<T> {
+        self.origin + self.direction * t
+    }
+
+    // Function to get the parameter of the projection of a point.
+    pub fn parameter_of(&self, point: Point3<T>) -> T {
+        (point - self.origin).dot(self.direction)
+    }
+
+    // Function to get the point of the line closest to a point.
+    pub fn closest_point(&self, point: Point3<T>) -> Point3<T> {
+        self.point_at(self.parameter_of(point))
+    }
+
+    // Function to get the distance of a point from the line.
+    pub fn distance(&self, point: Point3<T>) -> T {
+        (point - self.origin).cross(self.direction).norm()
+    }
+
+    // Function to get the closest pair of points of two lines, or None for parallel lines.
+    //
+    // The first point lies on this line and the second on the other one. They
+    // coincide when the lines intersect.
+    pub fn closest_points(&self, other: &Line3<T>) -> Option<(Point3<T>, Point3<T>)> {
+        let (u, v) = (self.direction, other.direction);
+        let w = self.origin - other.origin;
+        let b = u.dot(v);
+        let denominator = T::one() - b * b;
+        if denominator <= T::epsilon() {
+            return None;
+        }
+        let (d, e) = (u.dot(w), v.dot(w));
+        let s = (b * e - d) / denominator;
+        let t = (e - b * d) / denominator;
+        Some((self.point_at(s), other.point_at(t)))
+    }
+
+    // Function to get the shortest distance between two lines.
+    pub fn distance_to_line(&self, other: &Line3<T>) -> T {
+        match self.closest_points(other) {
+            Some((p, q)) => p.distance(q),
+            None => other.distance(self.origin),
+        }
+    }
+}
+
+#[cfg(test)]
+mod geometry_line_tests {
+    use super::*;
+
+    fn assert_close(p: Point2<f64>, q: Point2<f64>) {
+        assert!(p.distance(q) < 1e-12, "{p} != {q}");
+    }
+
+    #[test]
+    fn line2_forms_test() {
+        let line = Line2::through(Point2::new(1.0, 1.0), Point2::new(4.0, 5.0)).unwrap();
+        let (a, b, c) = line.coefficients();
+        assert!((a * a + b * b - 1.0).abs() < 1e-15);
+        assert!((4.0 * a + 5.0 * b + c).abs() < 1e-12);
+        assert_eq!(line.direction(), Vector2::new(0.6, 0.8));
+        assert_eq!(line.normal(), Vector2::new(-0.8, 0.6));
+
+        // The parametric form runs through the origin point at unit speed.
+        let origin = line.origin();
+        assert!(line.distance(origin) < 1e-12);
+        assert!(origin.to_vector().dot(line.direction()).abs() < 1e-12);
+        assert_close(
+            line.point_at(line.parameter_of(Point2::new(4.0, 5.0))),
+            Point2::new(4.0, 5.0),
+        );
+
+        assert!(Line2::new(0.0, 0.0, 1.0).is_err());
+        assert!(Line2::through(Point2::new(1.0, 1.0), Point2::new(1.0, 1.0)).is_err());
+    }
+
+    #[test]
+    fn line2_queries_test() {
+        let line = Line2::new(0.0, 2.0, -2.0).unwrap();
+        assert_eq!(line.signed_distance(Point2::new(7.0, 4.0)), 3.0);
+        assert_eq!(line.signed_distance(Point2::new(7.0, -1.0)), -2.0);
+        assert_eq!(line.project(Point2::new(7.0, 4.0)), Point2::new(7.0, 1.0));
+        assert_eq!(line.reflect(Point2::new(7.0, 4.0)), Point2::new(7.0, -2.0));
+
+        let parallel = line.parallel_through(Point2::new(0.0, 5.0));
+        assert!(line.is_parallel(&parallel));
+        assert_eq!(line.intersection(&parallel), None);
+        assert_eq!(parallel.distance(Point2::new(3.0, 1.0)), 4.0);
+
+        let perpendicular = line.perpendicular_through(Point2::new(2.0, 9.0));
+        assert_close(
+            line.intersection(&perpendicular).unwrap(),
+            Point2::new(2.0, 1.0),
+        );
+        assert!((line.angle_to(&perpendicular).degrees().abs() - 90.0).abs() < 1e-12);
+
+        let diagonal = Line2::through(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)).unwrap();
+        assert!((line.angle_to(&diagonal).degrees() - 45.0).abs() < 1e-12);
+        assert!((diagonal.angle_to(&line).degrees() + 45.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line3_test() {
+        let x_axis = Line3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0)).unwrap();
+        let skew = Line3::through(Point3::new(1.0, 0.0, 3.0), Point3::new(1.0, 1.0, 3.0)).unwrap();
+
+        assert_eq!(x_axis.direction(), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            x_axis.closest_point(Point3::new(4.0, 2.0, 1.0)),
+            Point3::new(4.0, 0.0, 0.0)
+        );
+        assert_eq!(x_axis.distance(Point3::new(4.0, 3.0, 4.0)), 5.0);
+
+        let (p, q) = x_axis.closest_points(&skew).unwrap();
+        assert_eq!(p, Point3::new(1.0, 0.0, 0.0));
+        assert_eq!(q, Point3::new(1.0, 0.0, 3.0));
+        assert_eq!(x_axis.distance_to_line(&skew), 3.0);
+
+        let parallel =
+            Line3::new(Point3::new(0.0, 1.0, 1.0), Vector3::new(-1.0, 0.0, 0.0)).unwrap();
+        assert_eq!(x_axis.closest_points(&parallel), None);
+        assert!((x_axis.distance_to_line(&parallel) - 2.0f64.sqrt()).abs() < 1e-12);
+
+        assert!(Line3::new(Point3::new(0.0, 0.0, 0.0), Vector3::zero()).is_err());
+    }
+}
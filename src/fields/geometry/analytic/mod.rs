@@ -0,0 +1,19 @@
+//! This module contains coordinate geometry of lines, planes, circles and conics.
+//!
+//! It is part of the `geometry` module. Where the `area` and `circumference`
+//! modules evaluate formulas for a shape of given size, the types here describe
+//! a shape by its equation at a position in the plane or in space. They support
+//! distances, projections, intersections and tangents, and conics can be
+//! classified, reduced to canonical form and fitted to measured points.
+//!
+//! # Modules
+//!
+//! - `circle`: Contains the `Circle` type with tangents, intersections and least-squares fitting.
+//! - `conic`: Contains general conics with classification, canonical form, foci and fitting.
+//! - `line`: Contains the implicit 2D line `Line2` and the parametric 3D line `Line3`.
+//! - `plane`: Contains the `Plane` type with distances, projections and intersections.
+
+pub mod circle;
+pub mod conic;
+pub mod line;
+pub mod plane;
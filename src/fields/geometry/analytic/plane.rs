@@ -0,0 +1,252 @@
+//! This module provides planes in space.
+//!
+//! It is part of the `analytic` module. A `Plane` is stored in Hessian normal
+//! form `n·p = d`, where `n` is a unit normal and `d` the signed distance of the
+//! plane from the origin. The signed distance of a point is `n·p − d`, positive
+//! on the side the normal points to.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::analytic::line::Line3;
+//! use crabmath::fields::geometry::analytic::plane::Plane;
+//! use crabmath::fields::geometry::point::Point3;
+//!
+//! let floor = Plane::through(
+//!     Point3::new(0.0, 0.0, 1.0),
+//!     Point3::new(1.0, 0.0, 1.0),
+//!     Point3::new(0.0, 1.0, 1.0),
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(floor.signed_distance(Point3::new(5.0, 5.0, 4.0)), 3.0);
+//! assert_eq!(floor.project(Point3::new(5.0, 5.0, 4.0)), Point3::new(5.0, 5.0, 1.0));
+//!
+//! let ray = Line3::through(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 2.0)).unwrap();
+//! let hit = floor.line_intersection(&ray).unwrap();
+//! assert!(hit.distance(Point3::new(0.5, 0.5, 1.0)) < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a plane from a normal and its offset from the origin.
+//! - `from_point_normal`: Constructs the plane through a point with a given normal.
+//! - `through`: Constructs the plane through three points.
+//! - `normal` and `offset`: Get the Hessian normal form.
+//! - `signed_distance` and `distance`: Measure the distance of a point.
+//! - `project` and `reflect`: Project a point onto the plane or mirror it.
+//! - `line_intersection`: Intersects the plane with a line.
+//! - `plane_intersection`: Intersects two planes in a line.
+//! - `intersection_of_three`: Finds the common point of three planes.
+//! - `angle_to`: Gets the dihedral angle between two planes.
+
+use crate::fields::geometry::analytic::line::Line3;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point3;
+use crate::fields::geometry::vector::Vector3;
+use crate::types::Angle;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<T> {
+    normal: Vector3<T>,
+    offset: T,
+}
+
+impl<T> Plane<T>
+where
+    T: Float,
+{
+    // Function to create the plane n·p = offset; the normal is scaled to unit length.
+    pub fn new(normal: Vector3<T>, offset: T) -> Result<Self, GeometryError> {
+        let length = normal.norm();
+        if length == T::zero() || !length.is_finite() || !offset.is_finite() {
+            return Err(GeometryError::new(
+                "Plane normal must be finite and not zero",
+            ));
+        }
+        Ok(Plane {
+            normal: normal / length,
+            offset: offset / length,
+        })
+    }
+
+    // Function to create the plane through a point with a given normal.
+    pub fn from_point_normal(point: Point3<T>, normal: Vector3<T>) -> Result<Self, GeometryError> {
+        Plane::new(normal, normal.dot(point.to_vector()))
+    }
+
+    // Function to create the plane through three points.
+    //
+    // The normal follows the right-hand rule, so the points run counter-clockwise
+    // seen from the positive side.
+    pub fn through(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Result<Self, GeometryError> {
+        let normal = (b - a).cross(c - a);
+        if normal.norm() <= T::zero() {
+            return Err(GeometryError::new(
+                "A plane needs three non-collinear points",
+            ));
+        }
+        Plane::from_point_normal(a, normal)
+    }
+
+    // Function to get the unit normal.
+    pub fn normal(&self) -> Vector3<T> {
+        self.normal
+    }
+
+    // Function to get the signed distance of the plane from the origin.
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+
+    // Function to get the signed distance of a point, positive on the side of the normal.
+    pub fn signed_distance(&self, point: Point3<T>) -> T {
+        self.normal.dot(point.to_vector()) - self.offset
+    }
+
+    // Function to get the distance of a point from the plane.
+    pub fn distance(&self, point: Point3<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+
+    // Function to get the point of the plane closest to a point.
+    pub fn project(&self, point: Point3<T>) -> Point3<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    // Function to mirror a point in the plane.
+    pub fn reflect(&self, point: Point3<T>) -> Point3<T> {
+        let two = T::one() + T::one();
+        point - self.normal * (two * self.signed_distance(point))
+    }
+
+    // Function to get the point where a line meets the plane.
+    //
+    // Returns None when the line is parallel to the plane, including when it lies in it.
+    pub fn line_intersection(&self, line: &Line3<T>) -> Option<Point3<T>> {
+        let rate = self.normal.dot(line.direction());
+        if rate.abs() <= T::epsilon() {
+            return None;
+        }
+        Some(line.point_at(-self.signed_distance(line.origin()) / rate))
+    }
+
+    // Function to get the line where two planes meet, or None for parallel planes.
+    //
+    // The line runs along n1 × n2 through its point closest to the origin.
+    pub fn plane_intersection(&self, other: &Plane<T>) -> Option<Line3<T>> {
+        let direction = self.normal.cross(other.normal);
+        let denominator = direction.norm_squared();
+        if denominator <= T::epsilon() {
+            return None;
+        }
+        let point = (other.normal.cross(direction) * self.offset
+            + direction.cross(self.normal) * other.offset)
+            / denominator;
+        Line3::new(Point3::from(point), direction).ok()
+    }
+
+    // Function to get the single point shared by three planes, if there is one.
+    pub fn intersection_of_three(a: &Plane<T>, b: &Plane<T>, c: &Plane<T>) -> Option<Point3<T>> {
+        let determinant = a.normal.dot(b.normal.cross(c.normal));
+        if determinant.abs() <= T::epsilon() {
+            return None;
+        }
+        let point = (b.normal.cross(c.normal) * a.offset
+            + c.normal.cross(a.normal) * b.offset
+            + a.normal.cross(b.normal) * c.offset)
+            / determinant;
+        Some(Point3::from(point))
+    }
+
+    // Function to get the dihedral angle between two planes, in [0, π/2].
+    pub fn angle_to(&self, other: &Plane<T>) -> Angle<T> {
+        let cross = self.normal.cross(other.normal).norm();
+        Angle::from_radians(cross.atan2(self.normal.dot(other.normal).abs()))
+    }
+}
+
+#[cfg(test)]
+mod geometry_plane_tests {
+    use super::*;
+
+    fn assert_close(p: Point3<f64>, q: Point3<f64>) {
+        assert!(p.distance(q) < 1e-12, "{p} != {q}");
+    }
+
+    #[test]
+    fn plane_construction_test() {
+        let plane = Plane::new(Vector3::new(0.0, 3.0, 4.0), 10.0).unwrap();
+        assert_eq!(plane.normal(), Vector3::new(0.0, 0.6, 0.8));
+        assert_eq!(plane.offset(), 2.0);
+
+        let through = Plane::through(
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        )
+        .unwrap();
+        assert!((through.offset() - 1.0 / 3.0f64.sqrt()).abs() < 1e-12);
+        assert!(through.normal().x > 0.0);
+        assert!(through.distance(Point3::new(1.0, 0.0, 0.0)) < 1e-12);
+
+        assert!(Plane::new(Vector3::zero(), 1.0).is_err());
+        assert!(Plane::through(
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn plane_queries_test() {
+        let plane =
+            Plane::from_point_normal(Point3::new(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, -1.0))
+                .unwrap();
+        let point = Point3::new(1.0, 2.0, 5.0);
+        assert_eq!(plane.signed_distance(point), -3.0);
+        assert_eq!(plane.distance(point), 3.0);
+        assert_eq!(plane.project(point), Point3::new(1.0, 2.0, 2.0));
+        assert_eq!(plane.reflect(point), Point3::new(1.0, 2.0, -1.0));
+
+        let tilted = Plane::new(Vector3::new(1.0, 0.0, 1.0), 0.0).unwrap();
+        assert!((plane.angle_to(&tilted).degrees() - 45.0).abs() < 1e-12);
+        assert_eq!(plane.angle_to(&plane).degrees(), 0.0);
+    }
+
+    #[test]
+    fn plane_intersections_test() {
+        let x = Plane::new(Vector3::new(1.0, 0.0, 0.0), 1.0).unwrap();
+        let y = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0).unwrap();
+        let z = Plane::new(Vector3::new(0.0, 0.0, 1.0), 3.0).unwrap();
+
+        let line = x.plane_intersection(&y).unwrap();
+        assert_close(line.origin(), Point3::new(1.0, 2.0, 0.0));
+        assert_eq!(line.direction(), Vector3::new(0.0, 0.0, 1.0));
+        assert_close(
+            z.line_intersection(&line).unwrap(),
+            Point3::new(1.0, 2.0, 3.0),
+        );
+        assert_close(
+            Plane::intersection_of_three(&x, &y, &z).unwrap(),
+            Point3::new(1.0, 2.0, 3.0),
+        );
+
+        // A general pair: the line lies in both planes.
+        let a = Plane::new(Vector3::new(1.0, 2.0, -1.0), 3.0).unwrap();
+        let b = Plane::new(Vector3::new(-2.0, 1.0, 4.0), 1.0).unwrap();
+        let line = a.plane_intersection(&b).unwrap();
+        for t in [-2.0, 0.0, 3.5] {
+            assert!(a.distance(line.point_at(t)) < 1e-12);
+            assert!(b.distance(line.point_at(t)) < 1e-12);
+        }
+
+        let shifted = Plane::new(Vector3::new(2.0, 0.0, 0.0), 8.0).unwrap();
+        assert!(x.plane_intersection(&shifted).is_none());
+        assert!(Plane::intersection_of_three(&x, &y, &shifted).is_none());
+        let in_x = Line3::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 1.0)).unwrap();
+        assert!(x.line_intersection(&in_x).is_none());
+    }
+}
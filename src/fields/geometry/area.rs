@@ -7,10 +7,17 @@
 //!
 //! ```rust
 //! use crabmath::fields::geometry::area;
+//! use crabmath::fields::geometry::point::Point2;
 //!
 //! let area_circle = area::get_area_circle(12.0);
 //! let area_parallelogram = area::get_area_parallelogram(15.5, 5.5);
+//! let area_polygon = area::get_area_polygon(&[
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(4.0, 3.0),
+//! ]);
 //! let area_rectangle = area::get_area_rectangle(3.5, 3.5);
+//! let area_regular_polygon = area::get_area_regular_polygon(4, 3.5f64);
 //! let area_square = area::get_area_square(3.5);
 //! let area_trapezoid = area::get_area_trapezoid(15.5, 15.5, 10.0);
 //! let area_triangle = area::get_area_triangle(15.5, 30.5);
@@ -18,7 +25,9 @@
 //!
 //! assert_eq!(area_circle, 452.3893421169302);
 //! assert_eq!(area_parallelogram, 85.25);
+//! assert_eq!(area_polygon, 6.0);
 //! assert_eq!(area_rectangle, 12.25);
+//! assert!((area_regular_polygon - 12.25).abs() < 1e-12);
 //! assert_eq!(area_square, 12.25);
 //! assert_eq!(area_trapezoid, 155.0);
 //! assert_eq!(area_triangle, 236.375);
@@ -27,18 +36,36 @@
 //!
 //! # Functions
 //!
+//! - `get_area_bezier_path`: Computes the area enclosed by a closed sequence of Bézier curves.
 //! - `get_area_circle`: Computes the area of a circle.
+//! - `get_area_multipolygon`: Computes the area of a set of polygons with holes.
 //! - `get_area_parallelogram`: Computes the area of a parallelogram.
+//! - `get_area_polygon`: Computes the area of a simple polygon from its vertices.
 //! - `get_area_rectangle`: Computes the area of a rectangle.
+//! - `get_area_regular_polygon`: Computes the area of a regular polygon.
 //! - `get_area_square`: Computes the area of a square.
 //! - `get_area_trapezoid`: Computes the area of a trapezoid.
 //! - `get_area_triangle` : Computes the area of a triangle.
 //! - `get_area_triangle_right` : Computes the area of a right triangle.
 
-use num_traits::{Num, NumCast};
+use crate::fields::geometry::curves::bezier::BezierCurve;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::PolygonWithHoles;
+use num_traits::{Float, Num, NumCast};
+
+// Function to get area enclosed by a closed path of Bézier curves.
+//
+// Each curve should start where the previous one ends and the last should end where the
+// first starts. The path may wind either way; self-intersecting paths give the net area.
+pub fn get_area_bezier_path<T: Float>(curves: &[BezierCurve<T>]) -> T {
+    curves
+        .iter()
+        .fold(T::zero(), |sum, curve| sum + curve.signed_area())
+        .abs()
+}
 
 // Function to get area of a circle
-pub fn get_area_circle<T: Num>(radius: T) -> T
+pub fn get_area_circle<T>(radius: T) -> T
 where
     T: Num + NumCast,
 {
@@ -47,18 +74,65 @@ where
     T::from(area_f64).unwrap()
 }
 
+// Function to get area of a multipolygon, the total area of its parts minus their holes.
+//
+// The parts are expected not to overlap, as returned by the `clipping` operations.
+pub fn get_area_multipolygon<T>(polygons: &[PolygonWithHoles<T>]) -> T
+where
+    T: Num + NumCast + Copy,
+{
+    polygons.iter().fold(T::zero(), |sum, polygon| {
+        let exterior = get_area_polygon(polygon.exterior().vertices());
+        polygon.holes().iter().fold(sum + exterior, |sum, hole| {
+            sum - get_area_polygon(hole.vertices())
+        })
+    })
+}
+
 // Function to get area of a parallelogram
 pub fn get_area_parallelogram<T: Num>(base: T, height: T) -> T {
     base * height
 }
 
+// Function to get area of a simple polygon from its vertices (shoelace formula).
+//
+// The vertices may be in either winding order and the polygon is implicitly closed.
+pub fn get_area_polygon<T>(vertices: &[Point2<T>]) -> T
+where
+    T: Num + NumCast + Copy,
+{
+    let n = vertices.len();
+    let mut twice_area_f64 = 0f64;
+    for i in 0..n {
+        let current = vertices[i];
+        let next = vertices[(i + 1) % n];
+        let x1 = T::to_f64(&current.x).unwrap();
+        let y1 = T::to_f64(&current.y).unwrap();
+        let x2 = T::to_f64(&next.x).unwrap();
+        let y2 = T::to_f64(&next.y).unwrap();
+        twice_area_f64 += x1 * y2 - x2 * y1;
+    }
+    T::from(0.5f64 * twice_area_f64.abs()).unwrap()
+}
+
 // Function to get area of a rectangle
 pub fn get_area_rectangle<T: Num>(length: T, width: T) -> T {
     length * width
 }
 
+// Function to get area of a regular polygon with `sides` sides of equal length.
+pub fn get_area_regular_polygon<T>(sides: usize, side: T) -> T
+where
+    T: Num + NumCast,
+{
+    let n = sides as f64;
+    let side_f64 = T::to_f64(&side).unwrap();
+    let area_f64 = n * side_f64.powi(2) / (4f64 * (std::f64::consts::PI / n).tan());
+    T::from(area_f64).unwrap()
+}
+
 // Function to get area of a square.
-pub fn get_area_square<T: Num>(side: T) -> T
+pub fn get_area_square<T>(side: T) -> T
 where
     T: Num + NumCast,
 {
@@ -67,7 +141,7 @@ where
 }
 
 // Function to get area of a trapezoid.
-pub fn get_area_trapezoid<T: Num>(base1: T, base2: T, height: T) -> T
+pub fn get_area_trapezoid<T>(base1: T, base2: T, height: T) -> T
 where
     T: Num + NumCast,
 {
@@ -79,7 +153,7 @@ where
 }
 
 // Function to get area of a triangle.
-pub fn get_area_triangle<T: Num>(base: T, height: T) -> T
+pub fn get_area_triangle<T>(base: T, height: T) -> T
 where
     T: Num + NumCast,
 {
@@ -89,7 +163,7 @@ where
 }
 
 // Function to get area of a right triangle.
-pub fn get_area_triangle_right<T: Num>(adjacent: T, opposite: T) -> T
+pub fn get_area_triangle_right<T>(adjacent: T, opposite: T) -> T
 where
     T: Num + NumCast,
 {
@@ -101,6 +175,34 @@ where
 #[cfg(test)]
 mod geometry_area_tests {
     use super::*;
+    use crate::fields::geometry::polygon::Polygon;
+
+    #[test]
+    fn get_area_bezier_path_test() {
+        // A rounded slot: two half circles of radius 1 joined by straight sides of length 2.
+        let k = 4.0 * (2f64.sqrt() - 1.0) / 3.0;
+        let p = Point2::new;
+        let path = vec![
+            BezierCurve::line(p(0.0, -1.0), p(2.0, -1.0)),
+            BezierCurve::cubic(p(2.0, -1.0), p(2.0 + k, -1.0), p(3.0, -k), p(3.0, 0.0)),
+            BezierCurve::cubic(p(3.0, 0.0), p(3.0, k), p(2.0 + k, 1.0), p(2.0, 1.0)),
+            BezierCurve::line(p(2.0, 1.0), p(0.0, 1.0)),
+            BezierCurve::cubic(p(0.0, 1.0), p(-k, 1.0), p(-1.0, k), p(-1.0, 0.0)),
+            BezierCurve::cubic(p(-1.0, 0.0), p(-1.0, -k), p(-k, -1.0), p(0.0, -1.0)),
+        ];
+        let result = get_area_bezier_path(&path);
+        assert!((result - (4.0 + std::f64::consts::PI)).abs() < 1e-3);
+
+        let reversed: Vec<BezierCurve<f64>> = path.iter().rev().map(|c| c.reversed()).collect();
+        assert!((get_area_bezier_path(&reversed) - result).abs() < 1e-12);
+
+        // Straight segments give the polygon area.
+        let square = [p(0.0, 0.0), p(3.0, 0.0), p(3.0, 3.0), p(0.0, 3.0)];
+        let edges: Vec<BezierCurve<f64>> = (0..4)
+            .map(|i| BezierCurve::line(square[i], square[(i + 1) % 4]))
+            .collect();
+        assert_eq!(get_area_bezier_path(&edges), get_area_polygon(&square));
+    }
 
     #[test]
     fn get_area_circle_test() {
@@ -111,6 +213,25 @@ mod geometry_area_tests {
         assert_eq!(result, 452);
     }
 
+    #[test]
+    fn get_area_multipolygon_test() {
+        let square = |x: f64, y: f64, side: f64| {
+            Polygon::new(vec![
+                Point2::new(x, y),
+                Point2::new(x + side, y),
+                Point2::new(x + side, y + side),
+                Point2::new(x, y + side),
+            ])
+        };
+        let polygons = vec![
+            PolygonWithHoles::new(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 1.0)]),
+            PolygonWithHoles::from(square(10.0, 0.0, 2.0)),
+        ];
+
+        assert_eq!(get_area_multipolygon(&polygons), 19.0);
+        assert_eq!(get_area_multipolygon::<f64>(&[]), 0.0);
+    }
+
     #[test]
     fn get_area_parallelogram_test() {
         let result = get_area_parallelogram(15.5, 5.5);
@@ -120,6 +241,33 @@ mod geometry_area_tests {
         assert_eq!(result, 75);
     }
 
+    #[test]
+    fn get_area_polygon_test() {
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(3.5, 0.0),
+            Point2::new(3.5, 3.5),
+            Point2::new(0.0, 3.5),
+        ];
+        assert_eq!(get_area_polygon(&square), get_area_square(3.5));
+
+        let clockwise: Vec<_> = square.iter().rev().copied().collect();
+        assert_eq!(get_area_polygon(&clockwise), 12.25);
+
+        let l_shape = [
+            Point2::new(0, 0),
+            Point2::new(4, 0),
+            Point2::new(4, 1),
+            Point2::new(1, 1),
+            Point2::new(1, 3),
+            Point2::new(0, 3),
+        ];
+        assert_eq!(get_area_polygon(&l_shape), 6);
+
+        let degenerate: [Point2<f64>; 2] = [Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)];
+        assert_eq!(get_area_polygon(&degenerate), 0.0);
+    }
+
     #[test]
     fn get_area_rectangle_test() {
         let result = get_area_rectangle(3.5, 3.5);
@@ -129,6 +277,15 @@ mod geometry_area_tests {
         assert_eq!(result, 9);
     }
 
+    #[test]
+    fn get_area_regular_polygon_test() {
+        let result = get_area_regular_polygon(6, 2.0);
+        assert!((result - 6.0 * 3f64.sqrt()).abs() < 1e-12);
+
+        let result = get_area_regular_polygon(6, 2);
+        assert_eq!(result, 10);
+    }
+
     #[test]
     fn get_area_square_test() {
         let result = get_area_square(3.5);
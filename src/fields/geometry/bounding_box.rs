@@ -0,0 +1,216 @@
+//! This module provides an axis-aligned bounding box type.
+//!
+//! It is part of the `geometry` module. A `BoundingBox` is the closed rectangle
+//! between a minimum and a maximum corner. Boxes are used to bound shapes and as
+//! the keys of the `spatial` indexes. A box with equal corners is a single point.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let a = BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+//! let b = BoundingBox::new(Point2::new(3.0, 1.0), Point2::new(1.0, 3.0));
+//!
+//! assert_eq!(b.min(), Point2::new(1.0, 1.0));
+//! assert!(a.intersects(&b));
+//! assert_eq!(a.intersection(&b).unwrap().area(), 1.0);
+//! assert_eq!(a.union(&b).area(), 9.0);
+//! assert_eq!(a.distance_to_point(Point2::new(5.0, 6.0)), 5.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs the box spanned by two corners.
+//! - `from_point`: Constructs the box of a single point.
+//! - `from_points`: Constructs the smallest box containing a set of points.
+//! - `min` and `max`: Get the corners.
+//! - `width`, `height`, `area` and `center`: Measure the box.
+//! - `contains_point` and `contains`: Check containment of a point or a box.
+//! - `intersects` and `intersection`: Check and compute the overlap of two boxes.
+//! - `union`: Computes the smallest box containing two boxes.
+//! - `expand_to`: Grows the box to contain a point.
+//! - `distance_squared_to_point` and `distance_to_point`: Measure the gap to a point.
+
+use crate::fields::geometry::point::Point2;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox<T> {
+    min: Point2<T>,
+    max: Point2<T>,
+}
+
+impl<T> BoundingBox<T>
+where
+    T: Float,
+{
+    // Function to create the box spanned by two opposite corners, in any order.
+    pub fn new(a: Point2<T>, b: Point2<T>) -> Self {
+        BoundingBox {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    // Function to create the box of a single point.
+    pub fn from_point(point: Point2<T>) -> Self {
+        BoundingBox {
+            min: point,
+            max: point,
+        }
+    }
+
+    // Function to create the smallest box containing the points, or None if there are none.
+    pub fn from_points(points: &[Point2<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut bounds = BoundingBox::from_point(*first);
+        for &point in rest {
+            bounds.expand_to(point);
+        }
+        Some(bounds)
+    }
+
+    // Function to get the corner with the smallest coordinates.
+    pub fn min(&self) -> Point2<T> {
+        self.min
+    }
+
+    // Function to get the corner with the largest coordinates.
+    pub fn max(&self) -> Point2<T> {
+        self.max
+    }
+
+    // Function to get the extent along x.
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+
+    // Function to get the extent along y.
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point2<T> {
+        self.min.midpoint(self.max)
+    }
+
+    // Function to check whether a point lies in the box, including its boundary.
+    pub fn contains_point(&self, point: Point2<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+
+    // Function to check whether another box lies entirely in this one.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    // Function to check whether two boxes share at least one point.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    // Function to get the overlap of two boxes, or None if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| BoundingBox {
+            min: Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        })
+    }
+
+    // Function to get the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        BoundingBox {
+            min: Point2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    // Function to grow the box so that it contains a point.
+    pub fn expand_to(&mut self, point: Point2<T>) {
+        *self = self.union(&BoundingBox::from_point(point));
+    }
+
+    // Function to get the squared distance from a point to the box, zero inside it.
+    pub fn distance_squared_to_point(&self, point: Point2<T>) -> T {
+        let gap = |v: T, lo: T, hi: T| (lo - v).max(v - hi).max(T::zero());
+        let dx = gap(point.x, self.min.x, self.max.x);
+        let dy = gap(point.y, self.min.y, self.max.y);
+        dx * dx + dy * dy
+    }
+
+    // Function to get the distance from a point to the box, zero inside it.
+    pub fn distance_to_point(&self, point: Point2<T>) -> T {
+        self.distance_squared_to_point(point).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod geometry_bounding_box_tests {
+    use super::*;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn bounding_box_new_test() {
+        let bounds = BoundingBox::new(p(3.0, -1.0), p(1.0, 2.0));
+        assert_eq!(bounds.min(), p(1.0, -1.0));
+        assert_eq!(bounds.max(), p(3.0, 2.0));
+        assert_eq!(bounds.width(), 2.0);
+        assert_eq!(bounds.height(), 3.0);
+        assert_eq!(bounds.area(), 6.0);
+        assert_eq!(bounds.center(), p(2.0, 0.5));
+
+        let points = [p(0.0, 5.0), p(-2.0, 1.0), p(4.0, 3.0)];
+        let bounds = BoundingBox::from_points(&points).unwrap();
+        assert_eq!(bounds, BoundingBox::new(p(-2.0, 1.0), p(4.0, 5.0)));
+        assert_eq!(BoundingBox::<f64>::from_points(&[]), None);
+    }
+
+    #[test]
+    fn bounding_box_relations_test() {
+        let a = BoundingBox::new(p(0.0, 0.0), p(4.0, 4.0));
+        let b = BoundingBox::new(p(1.0, 1.0), p(2.0, 2.0));
+        let c = BoundingBox::new(p(4.0, 4.0), p(5.0, 5.0));
+        let d = BoundingBox::new(p(6.0, 0.0), p(7.0, 1.0));
+
+        assert!(a.contains(&b));
+        assert!(!b.contains(&a));
+        assert!(a.contains_point(p(4.0, 0.0)));
+        assert!(a.intersects(&c));
+        assert_eq!(
+            a.intersection(&c),
+            Some(BoundingBox::from_point(p(4.0, 4.0)))
+        );
+        assert!(!a.intersects(&d));
+        assert_eq!(a.intersection(&d), None);
+        assert_eq!(a.union(&d), BoundingBox::new(p(0.0, 0.0), p(7.0, 4.0)));
+    }
+
+    #[test]
+    fn bounding_box_distance_test() {
+        let bounds = BoundingBox::new(p(0.0, 0.0), p(2.0, 2.0));
+        assert_eq!(bounds.distance_to_point(p(1.0, 1.0)), 0.0);
+        assert_eq!(bounds.distance_to_point(p(1.0, 5.0)), 3.0);
+        assert_eq!(bounds.distance_squared_to_point(p(-1.0, -1.0)), 2.0);
+
+        let mut grown = BoundingBox::from_point(p(1.0, 1.0));
+        grown.expand_to(p(-1.0, 3.0));
+        assert_eq!(grown, BoundingBox::new(p(-1.0, 1.0), p(1.0, 3.0)));
+    }
+}
@@ -10,7 +10,7 @@
 //!
 //! let circumference = circumference::get_circumference(15.5);
 //!
-//! assert_eq!(circumference, 97.389372261283583);
+//! assert_eq!(circumference, 97.38937226128358);
 //! ```
 //!
 //! # Functions
@@ -20,7 +20,7 @@
 use num_traits::{Num, NumCast};
 
 // Function to get circumference of a circle.
-pub fn get_circumference<T: Num>(radius: T) -> T
+pub fn get_circumference<T>(radius: T) -> T
 where
     T: Num + NumCast,
 {
@@ -35,7 +35,7 @@ mod geometry_circumference_tests {
     #[test]
     fn get_circumference_test() {
         let result = get_circumference(15.5);
-        assert_eq!(result, 97.389372261283583);
+        assert_eq!(result, 97.38937226128358);
 
         let result = get_circumference(15);
         assert_eq!(result, 94);
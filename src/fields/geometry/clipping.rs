@@ -0,0 +1,734 @@
+//! This module provides polygon clipping and boolean operations.
+//!
+//! It is part of the `geometry` module. `sutherland_hodgman` clips a polygon
+//! against a convex clip window and returns a single polygon.
+//!
+//! The general operations (`union`, `intersection`, `difference` and `xor`) work
+//! on multipolygons: slices of `PolygonWithHoles` whose parts do not overlap.
+//! They follow the edge-overlay approach of the Martinez–Rueda algorithm. Every
+//! edge of both inputs is split where it meets the other input. Each piece is
+//! then kept or dropped depending on whether it lies inside, outside or on the
+//! boundary of the other input, and the kept pieces are joined into rings.
+//! Crossings and vertices that rounding leaves within a few ulps of a vertex are
+//! snapped onto it, and edges are split at vertices lying on them, so that the
+//! pieces of both inputs meet at identical endpoints.
+//! Counter-clockwise rings become exteriors and clockwise rings become holes of
+//! the exterior that encloses them. The result is again a multipolygon, so
+//! operations can be chained. Its area agrees with `area::get_area_multipolygon`
+//! and satisfies area(A ∪ B) = area(A) + area(B) − area(A ∩ B) up to rounding.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::area::get_area_multipolygon;
+//! use crabmath::fields::geometry::clipping::{difference, intersection, union, xor};
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+//!
+//! let square = |x: f64, y: f64| {
+//!     PolygonWithHoles::from(Polygon::new(vec![
+//!         Point2::new(x, y),
+//!         Point2::new(x + 2.0, y),
+//!         Point2::new(x + 2.0, y + 2.0),
+//!         Point2::new(x, y + 2.0),
+//!     ]))
+//! };
+//! let a = [square(0.0, 0.0)];
+//! let b = [square(1.0, 1.0)];
+//!
+//! assert_eq!(get_area_multipolygon(&union(&a, &b)), 7.0);
+//! assert_eq!(get_area_multipolygon(&intersection(&a, &b)), 1.0);
+//! assert_eq!(get_area_multipolygon(&difference(&a, &b)), 3.0);
+//! assert_eq!(get_area_multipolygon(&xor(&a, &b)), 6.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `sutherland_hodgman`: Clips a polygon against a convex clip window.
+//! - `boolean_operation`: Applies a `BooleanOperation` to two multipolygons.
+//! - `union`: Computes the region covered by either multipolygon.
+//! - `intersection`: Computes the region covered by both multipolygons.
+//! - `difference`: Computes the region covered by the first multipolygon but not the second.
+//! - `xor`: Computes the region covered by exactly one of the multipolygons.
+//! - `union_all`: Computes the region covered by any of several multipolygons.
+
+use crate::fields::geometry::containment::{
+    point_in_polygon_winding, point_in_polygon_with_holes, Location,
+};
+use crate::fields::geometry::intersection::{segment_intersection, SegmentIntersection};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::predicates::{orientation, Orientation};
+use num_traits::Float;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperation {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+// Function to clip a polygon against a convex clip window.
+//
+// The clip window may wind either way. Parts of the subject on the window boundary
+// are kept. A concave subject clipped into several pieces comes back as one polygon
+// whose pieces are joined along the window boundary.
+pub fn sutherland_hodgman<T: Float>(subject: &Polygon<T>, clip: &Polygon<T>) -> Polygon<T> {
+    let window = if clip.signed_area() < T::zero() {
+        clip.reversed()
+    } else {
+        clip.clone()
+    };
+    let mut output = subject.vertices().to_vec();
+    for (c1, c2) in window.edges() {
+        if output.is_empty() {
+            break;
+        }
+        let input = std::mem::take(&mut output);
+        let inside = |p: Point2<T>| orientation(c1, c2, p) != Orientation::Clockwise;
+        for i in 0..input.len() {
+            let current = input[i];
+            let previous = input[(i + input.len() - 1) % input.len()];
+            match (inside(previous), inside(current)) {
+                (true, true) => output.push(current),
+                (true, false) => output.push(line_crossing(previous, current, c1, c2)),
+                (false, true) => {
+                    output.push(line_crossing(previous, current, c1, c2));
+                    output.push(current);
+                }
+                (false, false) => {}
+            }
+        }
+    }
+    Polygon::new(output)
+}
+
+// Function to apply a boolean operation to two multipolygons.
+pub fn boolean_operation<T: Float>(
+    a: &[PolygonWithHoles<T>],
+    b: &[PolygonWithHoles<T>],
+    operation: BooleanOperation,
+) -> Vec<PolygonWithHoles<T>> {
+    let a_edges = oriented_edges(a);
+    let b_edges = oriented_edges(b);
+    let (a_pieces, b_pieces) = split_edges(&a_edges, &b_edges);
+
+    let a_keys: HashSet<(Key, Key)> = a_pieces.iter().map(|&(p, q)| (key(p), key(q))).collect();
+    let b_keys: HashSet<(Key, Key)> = b_pieces.iter().map(|&(p, q)| (key(p), key(q))).collect();
+
+    let mut selected = Vec::new();
+    for &(p, q) in &a_pieces {
+        let shared = if b_keys.contains(&(key(p), key(q))) {
+            Some(true)
+        } else if b_keys.contains(&(key(q), key(p))) {
+            Some(false)
+        } else {
+            None
+        };
+        let keep = match (shared, operation) {
+            (Some(same), BooleanOperation::Union | BooleanOperation::Intersection) => {
+                same.then_some(false)
+            }
+            (Some(same), BooleanOperation::Difference) => (!same).then_some(false),
+            (Some(_), BooleanOperation::Xor) => None,
+            (None, _) => match (locate(p.midpoint(q), b), operation) {
+                (Location::Inside, BooleanOperation::Intersection) => Some(false),
+                (Location::Inside, BooleanOperation::Xor) => Some(true),
+                (Location::Inside, _) => None,
+                (_, BooleanOperation::Intersection) => None,
+                _ => Some(false),
+            },
+        };
+        if let Some(reverse) = keep {
+            selected.push(if reverse { (q, p) } else { (p, q) });
+        }
+    }
+    for &(p, q) in &b_pieces {
+        // Shared pieces were decided together with the matching piece of `a`.
+        if a_keys.contains(&(key(p), key(q))) || a_keys.contains(&(key(q), key(p))) {
+            continue;
+        }
+        let keep = match (locate(p.midpoint(q), a), operation) {
+            (Location::Inside, BooleanOperation::Union) => None,
+            (Location::Inside, BooleanOperation::Intersection) => Some(false),
+            (Location::Inside, _) => Some(true),
+            (_, BooleanOperation::Union | BooleanOperation::Xor) => Some(false),
+            _ => None,
+        };
+        if let Some(reverse) = keep {
+            selected.push(if reverse { (q, p) } else { (p, q) });
+        }
+    }
+    assemble(&join_rings(&selected))
+}
+
+// Function to get the union of two multipolygons.
+pub fn union<T: Float>(
+    a: &[PolygonWithHoles<T>],
+    b: &[PolygonWithHoles<T>],
+) -> Vec<PolygonWithHoles<T>> {
+    boolean_operation(a, b, BooleanOperation::Union)
+}
+
+// Function to get the intersection of two multipolygons.
+pub fn intersection<T: Float>(
+    a: &[PolygonWithHoles<T>],
+    b: &[PolygonWithHoles<T>],
+) -> Vec<PolygonWithHoles<T>> {
+    boolean_operation(a, b, BooleanOperation::Intersection)
+}
+
+// Function to get the part of `a` not covered by `b`.
+pub fn difference<T: Float>(
+    a: &[PolygonWithHoles<T>],
+    b: &[PolygonWithHoles<T>],
+) -> Vec<PolygonWithHoles<T>> {
+    boolean_operation(a, b, BooleanOperation::Difference)
+}
+
+// Function to get the symmetric difference of two multipolygons.
+pub fn xor<T: Float>(
+    a: &[PolygonWithHoles<T>],
+    b: &[PolygonWithHoles<T>],
+) -> Vec<PolygonWithHoles<T>> {
+    boolean_operation(a, b, BooleanOperation::Xor)
+}
+
+// Function to get the union of several multipolygons.
+//
+// The parts are merged pairwise, so each boolean operation works on inputs of
+// similar size.
+pub fn union_all<T: Float>(parts: Vec<Vec<PolygonWithHoles<T>>>) -> Vec<PolygonWithHoles<T>> {
+    let mut parts = parts;
+    while parts.len() > 1 {
+        let mut merged = Vec::with_capacity(parts.len().div_ceil(2));
+        let mut iter = parts.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => merged.push(union(&a, &b)),
+                None => merged.push(a),
+            }
+        }
+        parts = merged;
+    }
+    parts.pop().unwrap_or_default()
+}
+
+// Exact bit patterns of a point, used to match coincident points.
+pub(crate) type Key = (u64, u64);
+
+pub(crate) fn key<T: Float>(point: Point2<T>) -> Key {
+    // Adding zero turns -0.0 into 0.0 so both spell the same point.
+    let bits = |v: T| (v.to_f64().unwrap() + 0f64).to_bits();
+    (bits(point.x), bits(point.y))
+}
+
+// Where segment pq crosses the infinite line through c1 and c2.
+fn line_crossing<T: Float>(p: Point2<T>, q: Point2<T>, c1: Point2<T>, c2: Point2<T>) -> Point2<T> {
+    let direction = c2 - c1;
+    let t = (c1 - p).cross(direction) / (q - p).cross(direction);
+    p + (q - p).scale(t)
+}
+
+fn locate<T: Float>(point: Point2<T>, polygons: &[PolygonWithHoles<T>]) -> Location {
+    let mut location = Location::Outside;
+    for polygon in polygons {
+        match point_in_polygon_with_holes(point, polygon) {
+            Location::Inside => return Location::Inside,
+            Location::Boundary => location = Location::Boundary,
+            Location::Outside => {}
+        }
+    }
+    location
+}
+
+// The edges of every ring, wound so that the interior lies to their left.
+fn oriented_edges<T: Float>(polygons: &[PolygonWithHoles<T>]) -> Vec<(Point2<T>, Point2<T>)> {
+    let mut edges = Vec::new();
+    for polygon in polygons {
+        let rings = std::iter::once((polygon.exterior(), true))
+            .chain(polygon.holes().iter().map(|hole| (hole, false)));
+        for (ring, exterior) in rings {
+            let counter_clockwise = ring.signed_area() > T::zero();
+            if counter_clockwise == exterior {
+                edges.extend(ring.edges().filter(|(p, q)| p != q));
+            } else {
+                edges.extend(ring.reversed().edges().filter(|(p, q)| p != q));
+            }
+        }
+    }
+    edges
+}
+
+type Edges<T> = Vec<(Point2<T>, Point2<T>)>;
+
+// How far, in units of machine epsilon times the largest coordinate, a computed
+// intersection may lie from a vertex and still be taken as that vertex. Rounding
+// puts a crossing a few ulps from where it belongs; without snapping, a crossing
+// next to a vertex leaves the two inputs with endpoints that do not match, and
+// the rings that are joined from them get zero-width spikes.
+const SNAP_ULPS: f64 = 64.0;
+
+// Splits the edges of both inputs wherever they meet an edge of the other input.
+//
+// Vertices of `b` and computed crossings are first snapped to a vertex or an
+// earlier crossing within the snapping distance. Every edge is then also split
+// at the vertices and crossings lying on it within that distance, so that both
+// inputs share their endpoints wherever their boundaries meet.
+fn split_edges<T: Float>(
+    a: &[(Point2<T>, Point2<T>)],
+    b: &[(Point2<T>, Point2<T>)],
+) -> (Edges<T>, Edges<T>) {
+    let scale = a
+        .iter()
+        .chain(b)
+        .flat_map(|&(p, q)| [p.x, p.y, q.x, q.y])
+        .fold(T::zero(), |largest, v| largest.max(v.abs()));
+    let tolerance = scale * T::epsilon() * T::from(SNAP_ULPS).unwrap();
+    let mut points: Vec<Point2<T>> = a.iter().map(|&(p, _)| p).collect();
+    let mut snap = |point: Point2<T>| {
+        let nearest = points.iter().copied().min_by(|&u, &v| {
+            (u - point)
+                .norm_squared()
+                .partial_cmp(&(v - point).norm_squared())
+                .unwrap()
+        });
+        match nearest {
+            Some(nearest) if (nearest - point).norm() <= tolerance => nearest,
+            _ => {
+                points.push(point);
+                point
+            }
+        }
+    };
+    let b: Edges<T> = b
+        .iter()
+        .map(|&(p, q)| (snap(p), snap(q)))
+        .filter(|(p, q)| p != q)
+        .collect();
+
+    let mut a_cuts: Vec<Vec<Point2<T>>> = vec![Vec::new(); a.len()];
+    let mut b_cuts: Vec<Vec<Point2<T>>> = vec![Vec::new(); b.len()];
+    for (i, &(p1, p2)) in a.iter().enumerate() {
+        for (j, &(q1, q2)) in b.iter().enumerate() {
+            match segment_intersection(p1, p2, q1, q2) {
+                SegmentIntersection::None => {}
+                SegmentIntersection::Point(point) => {
+                    let point = snap(point);
+                    a_cuts[i].push(point);
+                    b_cuts[j].push(point);
+                }
+                SegmentIntersection::Overlap(start, end) => {
+                    a_cuts[i].extend([start, end]);
+                    b_cuts[j].extend([start, end]);
+                }
+            }
+        }
+    }
+    for (edges, cuts) in [(a, &mut a_cuts), (&b[..], &mut b_cuts)] {
+        for (&(p, q), cuts) in edges.iter().zip(cuts.iter_mut()) {
+            cuts.extend(
+                points
+                    .iter()
+                    .copied()
+                    .filter(|&point| near_interior(point, p, q, tolerance)),
+            );
+        }
+    }
+    (split(a, a_cuts), split(&b, b_cuts))
+}
+
+// Whether a point lies within the given distance of segment pq, between its ends.
+fn near_interior<T: Float>(point: Point2<T>, p: Point2<T>, q: Point2<T>, distance: T) -> bool {
+    let direction = q - p;
+    let length_squared = direction.norm_squared();
+    let along = (point - p).dot(direction);
+    if point == p || point == q || along <= T::zero() || along >= length_squared {
+        return false;
+    }
+    let across = direction.cross(point - p);
+    across * across <= distance * distance * length_squared
+}
+
+fn split<T: Float>(edges: &[(Point2<T>, Point2<T>)], cuts: Vec<Vec<Point2<T>>>) -> Edges<T> {
+    let mut pieces = Vec::new();
+    for (&(p, q), mut points) in edges.iter().zip(cuts) {
+        let direction = q - p;
+        points.retain(|&point| point != p && point != q);
+        points.sort_by(|&u, &v| {
+            (u - p)
+                .dot(direction)
+                .partial_cmp(&(v - p).dot(direction))
+                .unwrap()
+        });
+        points.dedup();
+        let mut start = p;
+        for point in points.into_iter().chain(std::iter::once(q)) {
+            pieces.push((start, point));
+            start = point;
+        }
+    }
+    pieces
+}
+
+// Joins directed edges into closed rings. At a vertex with several outgoing edges
+// the sharpest left turn is taken, which keeps rings that touch at a vertex apart.
+fn join_rings<T: Float>(edges: &[(Point2<T>, Point2<T>)]) -> Vec<Polygon<T>> {
+    let mut outgoing: HashMap<Key, Vec<usize>> = HashMap::new();
+    for (index, &(p, _)) in edges.iter().enumerate() {
+        outgoing.entry(key(p)).or_default().push(index);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let (p, q) = edges[current];
+            ring.push(p);
+            let incoming = q - p;
+            let candidates = outgoing.get(&key(q)).map(Vec::as_slice).unwrap_or(&[]);
+            let next = candidates
+                .iter()
+                .copied()
+                .filter(|&index| !used[index] || index == first)
+                .max_by(|&u, &v| {
+                    let turn = |index: usize| {
+                        let out = edges[index].1 - edges[index].0;
+                        let (cross, dot) = (incoming.cross(out), incoming.dot(out));
+                        // Doubling straight back is the last resort.
+                        if cross.is_zero() && dot < T::zero() {
+                            -T::infinity()
+                        } else {
+                            cross.atan2(dot)
+                        }
+                    };
+                    turn(u).partial_cmp(&turn(v)).unwrap()
+                });
+            match next {
+                Some(index) if index != first => current = index,
+                _ => break,
+            }
+        }
+        let ring = remove_collinear(ring);
+        if ring.len() >= 3 {
+            rings.push(Polygon::new(ring));
+        }
+    }
+    rings
+}
+
+// Drops vertices that lie on the straight line through their neighbours.
+fn remove_collinear<T: Float>(mut ring: Vec<Point2<T>>) -> Vec<Point2<T>> {
+    loop {
+        let n = ring.len();
+        if n < 3 {
+            return ring;
+        }
+        let redundant = (0..n).find(|&i| {
+            let (previous, current, next) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            orientation(previous, current, next) == Orientation::Collinear
+                && (current - previous).dot(next - current) > T::zero()
+        });
+        match redundant {
+            Some(i) => {
+                ring.remove(i);
+            }
+            None => return ring,
+        }
+    }
+}
+
+// Groups counter-clockwise exteriors with the clockwise holes they enclose.
+fn assemble<T: Float>(rings: &[Polygon<T>]) -> Vec<PolygonWithHoles<T>> {
+    let (exteriors, holes): (Vec<&Polygon<T>>, Vec<&Polygon<T>>) = rings
+        .iter()
+        .filter(|ring| !ring.signed_area().is_zero())
+        .partition(|ring| ring.signed_area() > T::zero());
+    let mut grouped: Vec<Vec<Polygon<T>>> = vec![Vec::new(); exteriors.len()];
+    for hole in holes {
+        let owner = (0..exteriors.len())
+            .filter(|&i| encloses(exteriors[i], hole))
+            .min_by(|&i, &j| {
+                exteriors[i]
+                    .area()
+                    .partial_cmp(&exteriors[j].area())
+                    .unwrap()
+            });
+        if let Some(owner) = owner {
+            grouped[owner].push(hole.clone());
+        }
+    }
+    exteriors
+        .into_iter()
+        .zip(grouped)
+        .map(|(exterior, holes)| PolygonWithHoles::new(exterior.clone(), holes))
+        .collect()
+}
+
+// Whether a hole ring lies inside an exterior ring, judged by the first vertex or
+// edge midpoint of the hole that is not on the exterior boundary.
+fn encloses<T: Float>(exterior: &Polygon<T>, hole: &Polygon<T>) -> bool {
+    let vertices = hole.vertices().iter().copied();
+    let midpoints = hole.edges().map(|(p, q)| p.midpoint(q));
+    vertices
+        .chain(midpoints)
+        .map(|point| point_in_polygon_winding(point, exterior))
+        .find(|&location| location != Location::Boundary)
+        .is_some_and(|location| location == Location::Inside)
+}
+
+#[cfg(test)]
+mod geometry_clipping_tests {
+    use super::*;
+    use crate::fields::geometry::area::get_area_multipolygon;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+        Polygon::new(vec![p(x0, y0), p(x1, y0), p(x1, y1), p(x0, y1)])
+    }
+
+    fn shape(polygon: Polygon<f64>) -> Vec<PolygonWithHoles<f64>> {
+        vec![PolygonWithHoles::from(polygon)]
+    }
+
+    fn regular(n: usize, cx: f64, cy: f64, radius: f64, phase: f64) -> Polygon<f64> {
+        Polygon::new(
+            (0..n)
+                .map(|i| {
+                    let angle = phase + 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                    p(cx + radius * angle.cos(), cy + radius * angle.sin())
+                })
+                .collect(),
+        )
+    }
+
+    // Checks inclusion-exclusion and the relations between the four operations.
+    fn assert_consistent(a: &[PolygonWithHoles<f64>], b: &[PolygonWithHoles<f64>]) {
+        let area_a = get_area_multipolygon(a);
+        let area_b = get_area_multipolygon(b);
+        let union_area = get_area_multipolygon(&union(a, b));
+        let intersection_area = get_area_multipolygon(&intersection(a, b));
+        let difference_area = get_area_multipolygon(&difference(a, b));
+        let xor_area = get_area_multipolygon(&xor(a, b));
+        let tolerance = 1e-9 * (area_a + area_b).max(1.0);
+
+        assert!((union_area - (area_a + area_b - intersection_area)).abs() < tolerance);
+        assert!((difference_area - (area_a - intersection_area)).abs() < tolerance);
+        assert!((xor_area - (union_area - intersection_area)).abs() < tolerance);
+    }
+
+    #[test]
+    fn sutherland_hodgman_test() {
+        let subject = rectangle(0.0, 0.0, 4.0, 4.0);
+        let window = regular(4, 4.0, 4.0, 2.0, 0.0);
+
+        let clipped = sutherland_hodgman(&subject, &window);
+        assert!((clipped.area() - 2.0).abs() < 1e-12);
+        assert!((sutherland_hodgman(&subject, &window.reversed()).area() - 2.0).abs() < 1e-12);
+
+        let inside = rectangle(1.0, 1.0, 2.0, 2.0);
+        assert_eq!(sutherland_hodgman(&inside, &subject), inside);
+        assert!(sutherland_hodgman(&rectangle(5.0, 5.0, 6.0, 6.0), &subject).is_empty());
+    }
+
+    #[test]
+    fn boolean_overlapping_squares_test() {
+        let a = shape(rectangle(0.0, 0.0, 2.0, 2.0));
+        let b = shape(rectangle(1.0, 1.0, 3.0, 3.0));
+
+        let union_result = union(&a, &b);
+        assert_eq!(union_result.len(), 1);
+        assert_eq!(union_result[0].exterior().len(), 8);
+        assert_eq!(get_area_multipolygon(&union_result), 7.0);
+
+        let intersection_result = intersection(&a, &b);
+        assert_eq!(intersection_result.len(), 1);
+        assert_eq!(intersection_result[0].exterior().len(), 4);
+
+        // The symmetric difference is two L shapes that touch at two corners.
+        assert_eq!(get_area_multipolygon(&xor(&a, &b)), 6.0);
+        assert_consistent(&a, &b);
+    }
+
+    #[test]
+    fn boolean_disjoint_and_nested_test() {
+        let outer = shape(rectangle(0.0, 0.0, 10.0, 10.0));
+        let inner = shape(rectangle(3.0, 3.0, 5.0, 5.0));
+        let far = shape(rectangle(20.0, 0.0, 21.0, 1.0));
+
+        assert_eq!(union(&outer, &far).len(), 2);
+        assert!(intersection(&outer, &far).is_empty());
+        assert_eq!(get_area_multipolygon(&difference(&outer, &far)), 100.0);
+
+        // Cutting a square out of a larger one leaves a hole.
+        let frame = difference(&outer, &inner);
+        assert_eq!(frame.len(), 1);
+        assert_eq!(frame[0].holes().len(), 1);
+        assert_eq!(get_area_multipolygon(&frame), 96.0);
+        assert!(difference(&inner, &outer).is_empty());
+        assert_eq!(get_area_multipolygon(&union(&outer, &inner)), 100.0);
+        assert_consistent(&outer, &inner);
+        assert_consistent(&outer, &far);
+    }
+
+    #[test]
+    fn boolean_shared_edges_test() {
+        // Squares sharing an edge merge into a rectangle.
+        let left = shape(rectangle(0.0, 0.0, 1.0, 1.0));
+        let right = shape(rectangle(1.0, 0.0, 2.0, 1.0));
+        let merged = union(&left, &right);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].exterior().len(), 4);
+        assert_eq!(get_area_multipolygon(&merged), 2.0);
+        assert!(intersection(&left, &right).is_empty());
+        assert_consistent(&left, &right);
+
+        // Identical inputs.
+        assert_eq!(get_area_multipolygon(&union(&left, &left)), 1.0);
+        assert_eq!(get_area_multipolygon(&intersection(&left, &left)), 1.0);
+        assert!(difference(&left, &left).is_empty());
+        assert!(xor(&left, &left).is_empty());
+
+        // Squares touching at a corner stay separate.
+        let corner = shape(rectangle(1.0, 1.0, 2.0, 2.0));
+        assert_eq!(union(&left, &corner).len(), 2);
+        assert_consistent(&left, &corner);
+    }
+
+    #[test]
+    fn boolean_concave_test() {
+        // A comb with three teeth against a bar crossing all of them.
+        let comb = shape(Polygon::new(vec![
+            p(0.0, 0.0),
+            p(5.0, 0.0),
+            p(5.0, 3.0),
+            p(4.0, 3.0),
+            p(4.0, 1.0),
+            p(3.0, 1.0),
+            p(3.0, 3.0),
+            p(2.0, 3.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 3.0),
+            p(0.0, 3.0),
+        ]));
+        let bar = shape(rectangle(-1.0, 2.0, 6.0, 2.5));
+
+        let pieces = intersection(&comb, &bar);
+        assert_eq!(pieces.len(), 3);
+        assert!((get_area_multipolygon(&pieces) - 1.5).abs() < 1e-12);
+
+        let joined = union(&comb, &bar);
+        assert_eq!(joined.len(), 1);
+        assert_eq!(joined[0].holes().len(), 2);
+        assert_consistent(&comb, &bar);
+    }
+
+    #[test]
+    fn boolean_holes_test() {
+        let donut = vec![PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 6.0, 6.0),
+            vec![rectangle(2.0, 2.0, 4.0, 4.0)],
+        )];
+        let plug = shape(rectangle(1.0, 1.0, 5.0, 5.0));
+        let filler = shape(rectangle(2.0, 2.0, 4.0, 4.0));
+
+        assert_eq!(get_area_multipolygon(&intersection(&donut, &plug)), 12.0);
+        assert_eq!(get_area_multipolygon(&union(&donut, &plug)), 36.0);
+        assert_eq!(get_area_multipolygon(&difference(&plug, &donut)), 4.0);
+
+        let filled = union(&donut, &filler);
+        assert_eq!(filled.len(), 1);
+        assert!(filled[0].holes().is_empty());
+        assert_eq!(get_area_multipolygon(&filled), 36.0);
+        assert_consistent(&donut, &plug);
+        assert_consistent(&donut, &filler);
+    }
+
+    #[test]
+    fn union_all_test() {
+        // A row of overlapping squares merges into one strip.
+        let parts: Vec<_> = (0..5)
+            .map(|i| shape(rectangle(i as f64, 0.0, i as f64 + 2.0, 2.0)))
+            .collect();
+        let merged = union_all(parts);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(get_area_multipolygon(&merged), 12.0);
+        assert!(union_all::<f64>(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn boolean_chained_union_test() {
+        // Bands along two edges of a hexagon cross at the shared vertex, which the
+        // first union rounds to a nearby point. The second union adds the corner
+        // patch at the exact vertex and must still join everything into one ring.
+        let d = 0.9428798155689877;
+        let (a, v, b) = (
+            p(1.1892279714223508, 0.7699898607360196),
+            p(1.8940147854513296, 0.4557999111320754),
+            p(3.063633158131395, 0.8563809206797348),
+        );
+        let band = |from: Point2<f64>, to: Point2<f64>| {
+            let normal = (to - from).normalize().unwrap().perpendicular() * d;
+            shape(Polygon::new(vec![
+                from - normal,
+                to - normal,
+                to + normal,
+                from + normal,
+            ]))
+        };
+        let (n1, n2) = (
+            -(v - a).normalize().unwrap().perpendicular(),
+            -(b - v).normalize().unwrap().perpendicular(),
+        );
+        let half_cos = ((n1 + n2).norm() / 2.0).min(1.0);
+        let miter = v + (n1 + n2).normalize().unwrap() * (d / half_cos);
+        let patch = shape(Polygon::new(vec![v, v + n1 * d, miter, v + n2 * d]));
+
+        let bands = union(&band(a, v), &band(v, b));
+        assert_eq!(bands.len(), 1);
+        let joined = union(&bands, &patch);
+        assert_eq!(joined.len(), 1);
+        assert!(joined[0].holes().is_empty());
+        // No zero-width spike where a rounded copy of the vertex meets the exact one.
+        let vertices = joined[0].exterior().vertices();
+        for (i, &u) in vertices.iter().enumerate() {
+            assert!(vertices[i + 1..].iter().all(|&w| (u - w).norm() > 1e-9));
+        }
+        assert_consistent(&bands, &patch);
+        let overlap = get_area_multipolygon(&intersection(&bands, &patch));
+        let expected = get_area_multipolygon(&bands) + get_area_multipolygon(&patch) - overlap;
+        assert!((get_area_multipolygon(&joined) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn boolean_random_polygons_test() {
+        // Irregular polygons in general position, including chained operations.
+        for k in 0..20 {
+            let shift = k as f64 * 0.173;
+            let a = shape(regular(7 + k % 5, 0.0, 0.0, 2.0, 0.1 * k as f64));
+            let b = shape(regular(
+                5 + k % 3,
+                1.0 + shift,
+                0.5,
+                1.5 + 0.05 * k as f64,
+                0.3,
+            ));
+            let c = shape(regular(11, 0.5, -0.5 - shift, 1.0, 0.0));
+            assert_consistent(&a, &b);
+
+            let ab = union(&a, &b);
+            assert_consistent(&ab, &c);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! This module provides a three dimensional axis-aligned bounding box type.
+//!
+//! It is part of the `collision` module. An `Aabb3` is the closed box between a
+//! minimum and a maximum corner, and is the 3D counterpart of `BoundingBox`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::collision::aabb::Aabb3;
+//! use crabmath::fields::geometry::point::Point3;
+//!
+//! let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+//! let b = Aabb3::new(Point3::new(3.0, 1.0, 1.0), Point3::new(1.0, 3.0, 4.0));
+//!
+//! assert_eq!(b.min(), Point3::new(1.0, 1.0, 1.0));
+//! assert!(a.intersects(&b));
+//! assert_eq!(a.union(&b).volume(), 36.0);
+//! assert_eq!(a.distance_to_point(Point3::new(5.0, 6.0, 2.0)), 5.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs the box spanned by two corners.
+//! - `from_points`: Constructs the smallest box containing a set of points.
+//! - `min` and `max`: Get the corners.
+//! - `center` and `half_extents`: Get the centre and half the size along each axis.
+//! - `volume` and `surface_area`: Measure the box.
+//! - `contains_point`: Checks whether a point lies in the box.
+//! - `intersects`: Checks whether two boxes overlap.
+//! - `union`: Computes the smallest box containing two boxes.
+//! - `closest_point`: Finds the point of the box nearest a point.
+//! - `distance_squared_to_point` and `distance_to_point`: Measure the gap to a point.
+
+use crate::fields::geometry::point::Point3;
+use crate::fields::geometry::vector::Vector3;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb3<T> {
+    min: Point3<T>,
+    max: Point3<T>,
+}
+
+impl<T> Aabb3<T>
+where
+    T: Float,
+{
+    // Function to create the box spanned by two opposite corners, in any order.
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        Aabb3 {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    // Function to create the smallest box containing a set of points, or None if there are none.
+    pub fn from_points(points: &[Point3<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(
+            rest.iter()
+                .fold(Aabb3::new(*first, *first), |aabb, &point| {
+                    aabb.union(&Aabb3::new(point, point))
+                }),
+        )
+    }
+
+    // Function to get the minimum corner.
+    pub fn min(&self) -> Point3<T> {
+        self.min
+    }
+
+    // Function to get the maximum corner.
+    pub fn max(&self) -> Point3<T> {
+        self.max
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point3<T> {
+        self.min.midpoint(self.max)
+    }
+
+    // Function to get half the size of the box along each axis.
+    pub fn half_extents(&self) -> Vector3<T> {
+        (self.max - self.min) / T::from(2).unwrap()
+    }
+
+    // Function to get the volume.
+    pub fn volume(&self) -> T {
+        let size = self.max - self.min;
+        size.x * size.y * size.z
+    }
+
+    // Function to get the surface area.
+    pub fn surface_area(&self) -> T {
+        let size = self.max - self.min;
+        T::from(2).unwrap() * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    // Function to check whether a point lies in the box, boundary included.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.distance_squared_to_point(point) == T::zero()
+    }
+
+    // Function to check whether two boxes overlap, touching included.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    // Function to get the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Aabb3 {
+            min: Point3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+
+    // Function to get the point of the box nearest a point.
+    pub fn closest_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+            point.z.max(self.min.z).min(self.max.z),
+        )
+    }
+
+    // Function to get the squared distance from a point to the box, zero inside it.
+    pub fn distance_squared_to_point(&self, point: Point3<T>) -> T {
+        point.distance_squared(self.closest_point(point))
+    }
+
+    // Function to get the distance from a point to the box, zero inside it.
+    pub fn distance_to_point(&self, point: Point3<T>) -> T {
+        self.distance_squared_to_point(point).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod geometry_collision_aabb_tests {
+    use super::*;
+
+    #[test]
+    fn aabb3_measure_test() {
+        let aabb = Aabb3::new(Point3::new(1.0, 4.0, 0.0), Point3::new(3.0, 0.0, 6.0));
+
+        assert_eq!(aabb.min(), Point3::new(1.0, 0.0, 0.0));
+        assert_eq!(aabb.max(), Point3::new(3.0, 4.0, 6.0));
+        assert_eq!(aabb.center(), Point3::new(2.0, 2.0, 3.0));
+        assert_eq!(aabb.half_extents(), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(aabb.volume(), 48.0);
+        assert_eq!(aabb.surface_area(), 88.0);
+
+        let points = [
+            Point3::new(1.0, 2.0, 3.0),
+            Point3::new(-1.0, 5.0, 0.0),
+            Point3::new(0.0, 0.0, 4.0),
+        ];
+        let bounds = Aabb3::from_points(&points).unwrap();
+        assert_eq!(bounds.min(), Point3::new(-1.0, 0.0, 0.0));
+        assert_eq!(bounds.max(), Point3::new(1.0, 5.0, 4.0));
+        assert!(Aabb3::<f64>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn aabb3_query_test() {
+        let aabb = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+
+        assert!(aabb.contains_point(Point3::new(1.0, 0.5, 0.0)));
+        assert!(!aabb.contains_point(Point3::new(1.0, 0.5, -0.1)));
+        assert_eq!(
+            aabb.closest_point(Point3::new(2.0, 0.5, -1.0)),
+            Point3::new(1.0, 0.5, 0.0)
+        );
+        assert_eq!(
+            aabb.distance_squared_to_point(Point3::new(2.0, 0.5, -1.0)),
+            2.0
+        );
+
+        let touching = Aabb3::new(Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0));
+        let apart = Aabb3::new(Point3::new(0.0, 0.0, 1.5), Point3::new(1.0, 1.0, 2.0));
+        assert!(aabb.intersects(&touching));
+        assert!(!aabb.intersects(&apart));
+        assert!(!apart.intersects(&aabb));
+    }
+}
@@ -0,0 +1,267 @@
+//! This module provides the bounding circle type and the minimum enclosing circle.
+//!
+//! It is part of the `collision` module. A `BoundingCircle` is a closed disc given
+//! by its centre and radius. `from_points` finds the smallest circle containing
+//! a set of points with Welzl's algorithm, in its incremental form. The points
+//! are visited in a shuffled order, which gives expected linear time. The shuffle
+//! uses a fixed seed, so the result does not change between runs.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::collision::bounding_circle::BoundingCircle;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let points = [
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(2.0, 1.0),
+//!     Point2::new(1.0, -1.0),
+//! ];
+//! let circle = BoundingCircle::from_points(&points).unwrap();
+//!
+//! assert_eq!(circle.center(), Point2::new(2.0, 0.0));
+//! assert_eq!(circle.radius(), 2.0);
+//! assert!(circle.intersects(&BoundingCircle::new(Point2::new(5.0, 0.0), 1.5)));
+//! assert!(!circle.intersects_box(&BoundingBox::new(Point2::new(4.0, 2.0), Point2::new(5.0, 3.0))));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a circle from its centre and radius.
+//! - `from_points`: Computes the minimum enclosing circle of a set of points.
+//! - `center` and `radius`: Get the centre and radius.
+//! - `area`: Computes the area.
+//! - `bounding_box`: Gets the smallest box containing the circle.
+//! - `contains_point`: Checks whether a point lies in the circle.
+//! - `intersects`: Checks whether two circles overlap.
+//! - `intersects_box`: Checks whether the circle overlaps an axis-aligned box.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::point::Point2;
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingCircle<T> {
+    center: Point2<T>,
+    radius: T,
+}
+
+impl<T> BoundingCircle<T>
+where
+    T: Float,
+{
+    // Function to create a circle from its centre and radius.
+    pub fn new(center: Point2<T>, radius: T) -> Self {
+        BoundingCircle {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    // Function to get the smallest circle containing a set of points, or None if there are none.
+    pub fn from_points(points: &[Point2<T>]) -> Option<Self> {
+        let points = shuffled(points);
+        let mut circle = BoundingCircle::new(*points.first()?, T::zero());
+        for i in 1..points.len() {
+            if circle.encloses(points[i]) {
+                continue;
+            }
+            // points[i] lies on the boundary of the circle of the first i + 1 points.
+            circle = BoundingCircle::new(points[i], T::zero());
+            for j in 0..i {
+                if circle.encloses(points[j]) {
+                    continue;
+                }
+                circle = diameter_circle(points[i], points[j]);
+                for k in 0..j {
+                    if !circle.encloses(points[k]) {
+                        circle = boundary_circle(points[i], points[j], points[k]);
+                    }
+                }
+            }
+        }
+        Some(circle)
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point2<T> {
+        self.center
+    }
+
+    // Function to get the radius.
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    // Function to get the smallest box containing the circle.
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        let (x, y, r) = (self.center.x, self.center.y, self.radius);
+        BoundingBox::new(Point2::new(x - r, y - r), Point2::new(x + r, y + r))
+    }
+
+    // Function to check whether a point lies in the circle, boundary included.
+    pub fn contains_point(&self, point: Point2<T>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    // Function to check whether two circles overlap, touching included.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let reach = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= reach * reach
+    }
+
+    // Function to check whether the circle overlaps an axis-aligned box, touching included.
+    pub fn intersects_box(&self, bounds: &BoundingBox<T>) -> bool {
+        bounds.distance_squared_to_point(self.center) <= self.radius * self.radius
+    }
+
+    // Checks containment with a little slack for the rounding of constructed circles.
+    fn encloses(&self, point: Point2<T>) -> bool {
+        let slack = T::from(1e-12).unwrap();
+        self.center.distance(point) <= self.radius + slack * self.radius.max(T::one())
+    }
+}
+
+impl<T> BoundingCircle<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        T::PI() * self.radius * self.radius
+    }
+}
+
+// Orders the points with a seeded Fisher–Yates shuffle.
+pub(crate) fn shuffled<P: Copy>(points: &[P]) -> Vec<P> {
+    let mut points = points.to_vec();
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..points.len()).rev() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        points.swap(i, (state >> 33) as usize % (i + 1));
+    }
+    points
+}
+
+fn diameter_circle<T: Float>(a: Point2<T>, b: Point2<T>) -> BoundingCircle<T> {
+    BoundingCircle::new(a.midpoint(b), a.distance(b) / T::from(2).unwrap())
+}
+
+// Gets the smallest circle with three points on its boundary, or a diameter circle when they are collinear.
+fn boundary_circle<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> BoundingCircle<T> {
+    let (u, v) = (b - a, c - a);
+    let d = T::from(2).unwrap() * u.cross(v);
+    if d == T::zero() {
+        return [diameter_circle(b, c), diameter_circle(a, c)]
+            .into_iter()
+            .fold(diameter_circle(a, b), |best, circle| {
+                if circle.radius > best.radius {
+                    circle
+                } else {
+                    best
+                }
+            });
+    }
+    let (uu, vv) = (u.norm_squared(), v.norm_squared());
+    let center = Point2::new(
+        a.x + (v.y * uu - u.y * vv) / d,
+        a.y + (u.x * vv - v.x * uu) / d,
+    );
+    BoundingCircle::new(center, center.distance(a))
+}
+
+#[cfg(test)]
+mod geometry_collision_bounding_circle_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn bounding_circle_from_points_test() {
+        let triangle = [
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 3f64.sqrt()),
+        ];
+        let circle = BoundingCircle::from_points(&triangle).unwrap();
+        assert_close(circle.center().x, 1.0);
+        assert_close(circle.center().y, 1.0 / 3f64.sqrt());
+        assert_close(circle.radius(), 2.0 / 3f64.sqrt());
+
+        // An obtuse triangle is bounded by the circle on its longest side.
+        let obtuse = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(2.0, 0.5),
+        ];
+        let circle = BoundingCircle::from_points(&obtuse).unwrap();
+        assert_close(circle.radius(), 2.0);
+
+        let collinear = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(3.0, 3.0),
+        ];
+        let circle = BoundingCircle::from_points(&collinear).unwrap();
+        assert_close(circle.radius(), 18f64.sqrt() / 2.0);
+
+        assert_eq!(
+            BoundingCircle::from_points(&[Point2::new(1.0, 2.0)]).unwrap(),
+            BoundingCircle::new(Point2::new(1.0, 2.0), 0.0)
+        );
+        assert!(BoundingCircle::<f64>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn bounding_circle_random_test() {
+        let mut state: u64 = 7;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let points: Vec<_> = (0..500)
+            .map(|_| Point2::new(next() * 10.0, next() * 4.0))
+            .collect();
+        let circle = BoundingCircle::from_points(&points).unwrap();
+
+        assert!(points
+            .iter()
+            .all(|&p| circle.center().distance(p) <= circle.radius() + 1e-9));
+        // The minimum circle touches at least two of the points.
+        let touching = points
+            .iter()
+            .filter(|&&p| (circle.center().distance(p) - circle.radius()).abs() < 1e-9)
+            .count();
+        assert!(touching >= 2);
+    }
+
+    #[test]
+    fn bounding_circle_intersects_test() {
+        let circle = BoundingCircle::new(Point2::new(0.0, 0.0), -2.0);
+        assert_eq!(circle.radius(), 2.0);
+        assert_close(circle.area(), 4.0 * std::f64::consts::PI);
+        assert_eq!(circle.bounding_box().max(), Point2::new(2.0, 2.0));
+
+        assert!(circle.contains_point(Point2::new(0.0, 2.0)));
+        assert!(!circle.contains_point(Point2::new(1.5, 1.5)));
+        assert!(circle.intersects(&BoundingCircle::new(Point2::new(3.0, 4.0), 3.0)));
+        assert!(!circle.intersects(&BoundingCircle::new(Point2::new(3.0, 4.0), 2.9)));
+
+        let corner = BoundingBox::new(Point2::new(1.5, 1.5), Point2::new(3.0, 3.0));
+        let side = BoundingBox::new(Point2::new(1.9, -1.0), Point2::new(3.0, 1.0));
+        assert!(!circle.intersects_box(&corner));
+        assert!(circle.intersects_box(&side));
+        assert!(circle.intersects_box(&BoundingBox::new(
+            Point2::new(-9.0, -9.0),
+            Point2::new(9.0, 9.0)
+        )));
+    }
+}
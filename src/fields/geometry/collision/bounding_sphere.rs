@@ -0,0 +1,321 @@
+//! This module provides the bounding sphere type and the minimum enclosing sphere.
+//!
+//! It is part of the `collision` module. A `BoundingSphere` is a closed ball given
+//! by its centre and radius, the 3D counterpart of `BoundingCircle`.
+//! `from_points` finds the smallest sphere containing a set of points with the
+//! incremental form of Welzl's algorithm, visiting the points in a shuffled order
+//! with a fixed seed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::collision::aabb::Aabb3;
+//! use crabmath::fields::geometry::collision::bounding_sphere::BoundingSphere;
+//! use crabmath::fields::geometry::point::Point3;
+//!
+//! let points = [
+//!     Point3::new(-1.0, 0.0, 0.0),
+//!     Point3::new(1.0, 0.0, 0.0),
+//!     Point3::new(0.0, 0.5, 0.5),
+//!     Point3::new(0.0, 0.0, -0.8),
+//! ];
+//! let sphere = BoundingSphere::from_points(&points).unwrap();
+//!
+//! assert_eq!(sphere.center(), Point3::new(0.0, 0.0, 0.0));
+//! assert_eq!(sphere.radius(), 1.0);
+//! assert!(sphere.intersects_box(&Aabb3::new(Point3::new(0.5, 0.5, 0.5), Point3::new(2.0, 2.0, 2.0))));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a sphere from its centre and radius.
+//! - `from_points`: Computes the minimum enclosing sphere of a set of points.
+//! - `center` and `radius`: Get the centre and radius.
+//! - `volume` and `surface_area`: Measure the sphere.
+//! - `bounding_box`: Gets the smallest box containing the sphere.
+//! - `contains_point`: Checks whether a point lies in the sphere.
+//! - `intersects`: Checks whether two spheres overlap.
+//! - `intersects_box`: Checks whether the sphere overlaps an axis-aligned box.
+
+use crate::fields::geometry::collision::aabb::Aabb3;
+use crate::fields::geometry::collision::bounding_circle::shuffled;
+use crate::fields::geometry::point::Point3;
+use crate::fields::geometry::vector::Vector3;
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingSphere<T> {
+    center: Point3<T>,
+    radius: T,
+}
+
+impl<T> BoundingSphere<T>
+where
+    T: Float,
+{
+    // Function to create a sphere from its centre and radius.
+    pub fn new(center: Point3<T>, radius: T) -> Self {
+        BoundingSphere {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    // Function to get the smallest sphere containing a set of points, or None if there are none.
+    pub fn from_points(points: &[Point3<T>]) -> Option<Self> {
+        let points = shuffled(points);
+        let mut sphere = BoundingSphere::new(*points.first()?, T::zero());
+        for i in 1..points.len() {
+            if sphere.encloses(points[i]) {
+                continue;
+            }
+            sphere = BoundingSphere::new(points[i], T::zero());
+            for j in 0..i {
+                if sphere.encloses(points[j]) {
+                    continue;
+                }
+                sphere = diameter_sphere(points[i], points[j]);
+                for k in 0..j {
+                    if sphere.encloses(points[k]) {
+                        continue;
+                    }
+                    sphere = circle_sphere(points[i], points[j], points[k]);
+                    for l in 0..k {
+                        if !sphere.encloses(points[l]) {
+                            sphere = boundary_sphere(points[i], points[j], points[k], points[l]);
+                        }
+                    }
+                }
+            }
+        }
+        Some(sphere)
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point3<T> {
+        self.center
+    }
+
+    // Function to get the radius.
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    // Function to get the smallest box containing the sphere.
+    pub fn bounding_box(&self) -> Aabb3<T> {
+        let reach = Vector3::new(self.radius, self.radius, self.radius);
+        Aabb3::new(self.center - reach, self.center + reach)
+    }
+
+    // Function to check whether a point lies in the sphere, boundary included.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    // Function to check whether two spheres overlap, touching included.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let reach = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= reach * reach
+    }
+
+    // Function to check whether the sphere overlaps an axis-aligned box, touching included.
+    pub fn intersects_box(&self, bounds: &Aabb3<T>) -> bool {
+        bounds.distance_squared_to_point(self.center) <= self.radius * self.radius
+    }
+
+    // Checks containment with a little slack for the rounding of constructed spheres.
+    fn encloses(&self, point: Point3<T>) -> bool {
+        let slack = T::from(1e-12).unwrap();
+        self.center.distance(point) <= self.radius + slack * self.radius.max(T::one())
+    }
+}
+
+impl<T> BoundingSphere<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to get the volume.
+    pub fn volume(&self) -> T {
+        T::from(4).unwrap() / T::from(3).unwrap() * T::PI() * self.radius.powi(3)
+    }
+
+    // Function to get the surface area.
+    pub fn surface_area(&self) -> T {
+        T::from(4).unwrap() * T::PI() * self.radius * self.radius
+    }
+}
+
+fn diameter_sphere<T: Float>(a: Point3<T>, b: Point3<T>) -> BoundingSphere<T> {
+    BoundingSphere::new(a.midpoint(b), a.distance(b) / T::from(2).unwrap())
+}
+
+// Gets the smallest sphere with three points on its boundary, centred in their plane.
+//
+// Collinear points give the diameter sphere of the farthest pair.
+fn circle_sphere<T: Float>(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> BoundingSphere<T> {
+    let (u, v) = (b - a, c - a);
+    let w = u.cross(v);
+    let ww = w.norm_squared();
+    if ww == T::zero() {
+        return largest([
+            diameter_sphere(a, b),
+            diameter_sphere(a, c),
+            diameter_sphere(b, c),
+        ]);
+    }
+    let offset = (v.cross(w) * u.norm_squared() + w.cross(u) * v.norm_squared())
+        / (T::from(2).unwrap() * ww);
+    BoundingSphere::new(a + offset, offset.norm())
+}
+
+// Gets the sphere with four points on its boundary.
+//
+// Coplanar points give the smallest of the three point spheres that contains all four.
+fn boundary_sphere<T: Float>(
+    a: Point3<T>,
+    b: Point3<T>,
+    c: Point3<T>,
+    d: Point3<T>,
+) -> BoundingSphere<T> {
+    let (u, v, w) = (b - a, c - a, d - a);
+    let det = u.dot(v.cross(w));
+    if det == T::zero() {
+        let candidates = [
+            circle_sphere(a, b, c),
+            circle_sphere(a, b, d),
+            circle_sphere(a, c, d),
+            circle_sphere(b, c, d),
+        ];
+        return candidates
+            .into_iter()
+            .filter(|sphere| [a, b, c, d].iter().all(|&p| sphere.encloses(p)))
+            .reduce(|best, sphere| {
+                if sphere.radius < best.radius {
+                    sphere
+                } else {
+                    best
+                }
+            })
+            .unwrap_or_else(|| largest(candidates));
+    }
+    let offset = (v.cross(w) * u.norm_squared()
+        + w.cross(u) * v.norm_squared()
+        + u.cross(v) * w.norm_squared())
+        / (T::from(2).unwrap() * det);
+    BoundingSphere::new(a + offset, offset.norm())
+}
+
+fn largest<T: Float, const N: usize>(spheres: [BoundingSphere<T>; N]) -> BoundingSphere<T> {
+    spheres
+        .into_iter()
+        .reduce(|best, sphere| {
+            if sphere.radius > best.radius {
+                sphere
+            } else {
+                best
+            }
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod geometry_collision_bounding_sphere_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn bounding_sphere_from_points_test() {
+        // A regular tetrahedron with edge 2√2 has circumradius √3.
+        let tetrahedron = [
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(1.0, -1.0, -1.0),
+            Point3::new(-1.0, 1.0, -1.0),
+            Point3::new(-1.0, -1.0, 1.0),
+        ];
+        let sphere = BoundingSphere::from_points(&tetrahedron).unwrap();
+        assert_close(sphere.center().distance(Point3::origin()), 0.0);
+        assert_close(sphere.radius(), 3f64.sqrt());
+
+        // An equilateral triangle is bounded by its circumcircle.
+        let triangle = [
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(2.0, 0.0, 1.0),
+            Point3::new(1.0, 3f64.sqrt(), 1.0),
+        ];
+        let sphere = BoundingSphere::from_points(&triangle).unwrap();
+        assert_close(sphere.center().z, 1.0);
+        assert_close(sphere.radius(), 2.0 / 3f64.sqrt());
+
+        // Coplanar points on a circle.
+        let square = [
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(-1.0, 0.0, 0.0),
+            Point3::new(0.0, -1.0, 0.0),
+            Point3::new(0.5, 0.5, 0.0),
+        ];
+        assert_close(BoundingSphere::from_points(&square).unwrap().radius(), 1.0);
+
+        let collinear = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0),
+        ];
+        assert_close(
+            BoundingSphere::from_points(&collinear).unwrap().radius(),
+            3f64.sqrt(),
+        );
+        assert!(BoundingSphere::<f64>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn bounding_sphere_random_test() {
+        let mut state: u64 = 11;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let points: Vec<_> = (0..400)
+            .map(|_| Point3::new(next() * 6.0, next() * 3.0, next()))
+            .collect();
+        let sphere = BoundingSphere::from_points(&points).unwrap();
+
+        assert!(points
+            .iter()
+            .all(|&p| sphere.center().distance(p) <= sphere.radius() + 1e-9));
+        let touching = points
+            .iter()
+            .filter(|&&p| (sphere.center().distance(p) - sphere.radius()).abs() < 1e-9)
+            .count();
+        assert!(touching >= 2);
+        // No sphere is smaller than half the widest pair of points.
+        let widest = points
+            .iter()
+            .flat_map(|&p| points.iter().map(move |&q| p.distance(q)))
+            .fold(0.0, f64::max);
+        assert!(sphere.radius() >= widest / 2.0 - 1e-12);
+    }
+
+    #[test]
+    fn bounding_sphere_intersects_test() {
+        let sphere = BoundingSphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+        assert_close(sphere.volume(), 4.0 / 3.0 * std::f64::consts::PI);
+        assert_close(sphere.surface_area(), 4.0 * std::f64::consts::PI);
+        assert_eq!(sphere.bounding_box().min(), Point3::new(-1.0, -1.0, -1.0));
+
+        assert!(sphere.contains_point(Point3::new(0.0, 0.6, 0.8)));
+        assert!(!sphere.contains_point(Point3::new(0.6, 0.6, 0.6)));
+        assert!(sphere.intersects(&BoundingSphere::new(Point3::new(2.0, 2.0, 1.0), 2.0)));
+        assert!(!sphere.intersects(&BoundingSphere::new(Point3::new(2.0, 2.0, 1.0), 1.9)));
+
+        let corner = Aabb3::new(Point3::new(0.6, 0.6, 0.6), Point3::new(1.0, 1.0, 1.0));
+        assert!(!sphere.intersects_box(&corner));
+        let face = Aabb3::new(Point3::new(0.9, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0));
+        assert!(sphere.intersects_box(&face));
+    }
+}
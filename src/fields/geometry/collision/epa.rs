@@ -0,0 +1,372 @@
+//! This module provides the expanding polytope algorithm (EPA) for penetration depth.
+//!
+//! It is part of the `collision` module. Once GJK finds that two convex shapes
+//! overlap, EPA grows its simplex into a polygon, or a polytope in 3D, inside the
+//! Minkowski difference. Each step adds the support point beyond the edge or face
+//! nearest the origin, until that feature lies on the boundary of the
+//! difference. Its distance from the origin is the penetration depth and its
+//! normal the direction in which to push the shapes apart: moving the second
+//! shape by `normal * depth`, or the first by the opposite, leaves them touching.
+//!
+//! Polygons and polyhedra are solved exactly. For curved shapes the polytope only
+//! approaches the boundary and the search stops after a fixed number of steps, so
+//! the depth of a deep contact is approximate, typically to a few thousandths of
+//! the shapes' size.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::collision::epa;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::vector::Vector2;
+//!
+//! let a = BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+//! let b = BoundingBox::new(Point2::new(1.5, 0.5), Point2::new(4.0, 1.5));
+//!
+//! let contact = epa::penetration_2d(&a, &b).unwrap();
+//! assert_eq!(contact.normal, Vector2::new(1.0, 0.0));
+//! assert_eq!(contact.depth, 0.5);
+//!
+//! let apart = BoundingBox::new(Point2::new(3.0, 0.0), Point2::new(4.0, 1.0));
+//! assert!(epa::penetration_2d(&a, &apart).is_none());
+//! ```
+//!
+//! # Functions
+//!
+//! - `penetration_2d`: Computes the penetration of two convex planar shapes.
+//! - `penetration_3d`: Computes the penetration of two convex solids.
+
+use crate::fields::geometry::collision::gjk::{simplex_2d, simplex_3d, support_2d, support_3d};
+use crate::fields::geometry::collision::support::{Support2, Support3};
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use num_traits::Float;
+
+// Curved shapes stop improving after this many steps.
+const MAX_ITERATIONS: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration2<T> {
+    // The unit direction from the first shape into the second.
+    pub normal: Vector2<T>,
+    pub depth: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration3<T> {
+    // The unit direction from the first shape into the second.
+    pub normal: Vector3<T>,
+    pub depth: T,
+}
+
+// Function to get the penetration of two convex planar shapes, or None if they do not overlap.
+//
+// Shapes that only touch, or whose difference is flat, give a depth of zero.
+pub fn penetration_2d<T, A, B>(a: &A, b: &B) -> Option<Penetration2<T>>
+where
+    T: Float,
+    A: Support2<T> + ?Sized,
+    B: Support2<T> + ?Sized,
+{
+    let mut polygon = simplex_2d(a, b)?;
+    let x = Vector2::new(T::one(), T::zero());
+    let y = Vector2::new(T::zero(), T::one());
+    // Widen a simplex that stopped on the boundary into a triangle.
+    for direction in [x, y, -x, -y] {
+        if polygon.len() == 3 {
+            break;
+        }
+        let direction = match polygon.len() {
+            1 => direction,
+            _ => (polygon[1] - polygon[0]).perpendicular() * direction.dot(x + y),
+        };
+        let point = support_2d(a, b, direction);
+        let spans = match polygon.len() {
+            1 => point != polygon[0],
+            _ => (polygon[1] - polygon[0]).cross(point - polygon[0]) != T::zero(),
+        };
+        if spans {
+            polygon.push(point);
+        }
+    }
+    if polygon.len() < 3 {
+        return Some(Penetration2 {
+            normal: x,
+            depth: T::zero(),
+        });
+    }
+    if (polygon[1] - polygon[0]).cross(polygon[2] - polygon[0]) < T::zero() {
+        polygon.swap(1, 2);
+    }
+
+    let mut best = Penetration2 {
+        normal: x,
+        depth: T::infinity(),
+    };
+    for _ in 0..MAX_ITERATIONS {
+        // The nearest edge; its outward normal points right of the edge for a counter-clockwise polygon.
+        let mut nearest = None;
+        for i in 0..polygon.len() {
+            let edge = polygon[(i + 1) % polygon.len()] - polygon[i];
+            if let Some(normal) = Vector2::new(edge.y, -edge.x).normalize() {
+                let distance = normal.dot(polygon[i]);
+                if nearest.is_none_or(|(_, _, nearest)| distance < nearest) {
+                    nearest = Some((i, normal, distance));
+                }
+            }
+        }
+        let (index, normal, distance) = nearest?;
+        best = Penetration2 {
+            normal,
+            depth: distance.max(T::zero()),
+        };
+        let point = support_2d(a, b, normal);
+        if point.dot(normal) - distance <= tolerance(distance) {
+            return Some(best);
+        }
+        polygon.insert(index + 1, point);
+    }
+    Some(best)
+}
+
+// Function to get the penetration of two convex solids, or None if they do not overlap.
+//
+// Solids that only touch, or whose difference is flat, give a depth of zero.
+pub fn penetration_3d<T, A, B>(a: &A, b: &B) -> Option<Penetration3<T>>
+where
+    T: Float,
+    A: Support3<T> + ?Sized,
+    B: Support3<T> + ?Sized,
+{
+    let mut vertices = simplex_3d(a, b)?;
+    let axes = [
+        Vector3::new(T::one(), T::zero(), T::zero()),
+        Vector3::new(T::zero(), T::one(), T::zero()),
+        Vector3::new(T::zero(), T::zero(), T::one()),
+    ];
+    // Widen a simplex that stopped on the boundary into a tetrahedron.
+    let directions = axes.iter().flat_map(|&axis| [axis, -axis]);
+    for direction in directions {
+        if vertices.len() == 4 {
+            break;
+        }
+        let direction = match vertices.len() {
+            1 => direction,
+            2 => (vertices[1] - vertices[0]).cross(direction),
+            _ => (vertices[1] - vertices[0])
+                .cross(vertices[2] - vertices[0])
+                .scale(direction.dot(axes[0] + axes[1] + axes[2])),
+        };
+        if direction.norm_squared() == T::zero() {
+            continue;
+        }
+        let point = support_3d(a, b, direction);
+        let spans = match vertices.len() {
+            1 => point != vertices[0],
+            2 => {
+                (vertices[1] - vertices[0])
+                    .cross(point - vertices[0])
+                    .norm_squared()
+                    != T::zero()
+            }
+            _ => {
+                (vertices[1] - vertices[0])
+                    .cross(vertices[2] - vertices[0])
+                    .dot(point - vertices[0])
+                    != T::zero()
+            }
+        };
+        if spans {
+            vertices.push(point);
+        }
+    }
+    if vertices.len() < 4 {
+        return Some(Penetration3 {
+            normal: axes[0],
+            depth: T::zero(),
+        });
+    }
+
+    // Orient the faces of the tetrahedron outward.
+    let center = (vertices[0] + vertices[1] + vertices[2] + vertices[3]) / T::from(4).unwrap();
+    let mut faces: Vec<[usize; 3]> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .map(|[i, j, k]| {
+            let normal = (vertices[j] - vertices[i]).cross(vertices[k] - vertices[i]);
+            if normal.dot(vertices[i] - center) < T::zero() {
+                [i, k, j]
+            } else {
+                [i, j, k]
+            }
+        })
+        .collect();
+
+    let mut best = Penetration3 {
+        normal: axes[0],
+        depth: T::infinity(),
+    };
+    for _ in 0..MAX_ITERATIONS {
+        let mut nearest = None;
+        for &[i, j, k] in &faces {
+            let normal = (vertices[j] - vertices[i]).cross(vertices[k] - vertices[i]);
+            if let Some(normal) = normal.normalize() {
+                let distance = normal.dot(vertices[i]);
+                if nearest.is_none_or(|(_, nearest)| distance < nearest) {
+                    nearest = Some((normal, distance));
+                }
+            }
+        }
+        let (normal, distance) = nearest?;
+        best = Penetration3 {
+            normal,
+            depth: distance.max(T::zero()),
+        };
+        let point = support_3d(a, b, normal);
+        if point.dot(normal) - distance <= tolerance(distance) {
+            return Some(best);
+        }
+
+        // Remove the faces the new point sees and close the hole with faces to the point.
+        let index = vertices.len();
+        vertices.push(point);
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|&[i, j, k]| {
+            let normal = (vertices[j] - vertices[i]).cross(vertices[k] - vertices[i]);
+            let visible = normal.dot(point - vertices[i]) > T::zero();
+            if visible {
+                for (u, v) in [(i, j), (j, k), (k, i)] {
+                    match horizon.iter().position(|&edge| edge == (v, u)) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push((u, v)),
+                    }
+                }
+            }
+            !visible
+        });
+        if horizon.is_empty() {
+            return Some(best);
+        }
+        faces.extend(horizon.into_iter().map(|(u, v)| [u, v, index]));
+    }
+    Some(best)
+}
+
+// The gap allowed between a feature and the boundary of the difference.
+fn tolerance<T: Float>(distance: T) -> T {
+    T::epsilon().sqrt() * distance.abs().max(T::one())
+}
+
+#[cfg(test)]
+mod geometry_collision_epa_tests {
+    use super::*;
+    use crate::fields::geometry::bounding_box::BoundingBox;
+    use crate::fields::geometry::collision::aabb::Aabb3;
+    use crate::fields::geometry::collision::bounding_circle::BoundingCircle;
+    use crate::fields::geometry::collision::bounding_sphere::BoundingSphere;
+    use crate::fields::geometry::collision::gjk::intersects_3d;
+    use crate::fields::geometry::collision::obb::{Obb2, Obb3};
+    use crate::fields::geometry::point::{Point2, Point3};
+    use crate::fields::geometry::polygon::Polygon;
+    use crate::types::Angle;
+
+    fn assert_close(a: f64, b: f64, tolerance: f64) {
+        assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn penetration_2d_test() {
+        let a = BoundingCircle::new(Point2::new(0.0, 0.0), 1.0);
+        let b = BoundingCircle::new(Point2::new(1.2, 1.6), 1.5);
+        let contact = penetration_2d(&a, &b).unwrap();
+        assert_close(contact.depth, 0.5, 1e-6);
+        assert_close(contact.normal.x, 0.6, 1e-3);
+        assert_close(contact.normal.y, 0.8, 1e-3);
+
+        let triangle = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        ]);
+        let square = Obb2::new(Point2::new(0.0, -0.4), Angle::zero(), 1.0, 0.5);
+        let contact = penetration_2d(&triangle, &square).unwrap();
+        assert_close(contact.depth, 0.1, 1e-12);
+        assert_close(contact.normal.y, -1.0, 1e-12);
+
+        let far = BoundingCircle::new(Point2::new(3.0, 3.0), 1.0);
+        assert!(penetration_2d(&triangle, &far).is_none());
+
+        // Touching boxes have no depth.
+        let left = BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+        let right = BoundingBox::new(Point2::new(1.0, 0.0), Point2::new(2.0, 1.0));
+        if let Some(contact) = penetration_2d(&left, &right) {
+            assert_close(contact.depth, 0.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn penetration_3d_test() {
+        let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+        let b = Aabb3::new(Point3::new(0.5, 0.5, 1.7), Point3::new(1.5, 1.5, 3.0));
+        let contact = penetration_3d(&a, &b).unwrap();
+        assert_close(contact.depth, 0.3, 1e-12);
+        assert_close(contact.normal.z, 1.0, 1e-12);
+
+        let sphere = BoundingSphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+        let other = BoundingSphere::new(Point3::new(1.0, 1.0, 1.0), 1.0);
+        let contact = penetration_3d(&sphere, &other).unwrap();
+        assert_close(contact.depth, 2.0 - 3f64.sqrt(), 1e-5);
+        assert_close(contact.normal.x, 1.0 / 3f64.sqrt(), 1e-2);
+
+        let turned = Obb3::new(
+            Point3::new(0.0, 0.0, 0.0),
+            [
+                Vector3::new(1.0, 1.0, 0.0) / 2f64.sqrt(),
+                Vector3::new(-1.0, 1.0, 0.0) / 2f64.sqrt(),
+                Vector3::new(0.0, 0.0, 1.0),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let wall = Aabb3::new(Point3::new(1.2, -5.0, -5.0), Point3::new(3.0, 5.0, 5.0));
+        let contact = penetration_3d(&turned, &wall).unwrap();
+        assert_close(contact.depth, 2f64.sqrt() - 1.2, 1e-12);
+        assert_close(contact.normal.x, 1.0, 1e-12);
+
+        let points = [
+            Point3::new(5.0, 0.0, 0.0),
+            Point3::new(6.0, 0.0, 0.0),
+            Point3::new(5.0, 1.0, 1.0),
+        ];
+        assert!(penetration_3d(&turned, &points[..]).is_none());
+    }
+
+    #[test]
+    fn penetration_random_test() {
+        let mut state: u64 = 3;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for _ in 0..200 {
+            let a = Point3::new(next() * 4.0, next() * 4.0, next() * 4.0);
+            let b = Point3::new(next() * 4.0, next() * 4.0, next() * 4.0);
+            let (ra, rb) = (0.5 + next(), 0.5 + next());
+            let gap = a.distance(b) - ra - rb;
+            let first = BoundingSphere::new(a, ra);
+            let second = Aabb3::new(b, b);
+            let cube = BoundingSphere::new(b, rb);
+            match penetration_3d(&first, &cube) {
+                Some(contact) => {
+                    assert!(gap < 1e-9);
+                    assert_close(contact.depth, -gap, 1e-2);
+                }
+                None => assert!(gap > -1e-9),
+            }
+            assert_eq!(intersects_3d(&first, &second), a.distance(b) <= ra);
+        }
+    }
+}
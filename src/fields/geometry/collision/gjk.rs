@@ -0,0 +1,364 @@
+//! This module provides the Gilbert–Johnson–Keerthi (GJK) intersection test.
+//!
+//! It is part of the `collision` module. Two convex shapes overlap exactly when
+//! their Minkowski difference, every point of one minus every point of the
+//! other, contains the origin. GJK searches that difference through the shapes'
+//! support points. It keeps a simplex of at most three points in 2D, or four in
+//! 3D, and moves it towards the origin until it either encloses the origin or
+//! finds a direction in which the difference stays short of it.
+//!
+//! Shapes that only touch lie on the edge of the test, and rounding can report
+//! them either way. Curved shapes closer than rounding are treated as overlapping.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::collision::bounding_circle::BoundingCircle;
+//! use crabmath::fields::geometry::collision::gjk;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::Polygon;
+//!
+//! let triangle = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(0.0, 4.0),
+//! ]);
+//!
+//! assert!(gjk::intersects_2d(&triangle, &BoundingCircle::new(Point2::new(2.5, 2.5), 0.8)));
+//! assert!(!gjk::intersects_2d(&triangle, &BoundingCircle::new(Point2::new(2.5, 2.5), 0.6)));
+//! ```
+//!
+//! # Functions
+//!
+//! - `intersects_2d`: Checks whether two convex planar shapes overlap.
+//! - `intersects_3d`: Checks whether two convex solids overlap.
+
+use crate::fields::geometry::collision::support::{Support2, Support3};
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use num_traits::Float;
+
+// The search stops after this many steps, which only shapes touching within rounding reach.
+const MAX_ITERATIONS: usize = 64;
+
+// Function to check whether two convex planar shapes overlap.
+pub fn intersects_2d<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Float,
+    A: Support2<T> + ?Sized,
+    B: Support2<T> + ?Sized,
+{
+    simplex_2d(a, b).is_some()
+}
+
+// Function to check whether two convex solids overlap.
+pub fn intersects_3d<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Float,
+    A: Support3<T> + ?Sized,
+    B: Support3<T> + ?Sized,
+{
+    simplex_3d(a, b).is_some()
+}
+
+// Gets the support point of the Minkowski difference a − b.
+pub(crate) fn support_2d<T, A, B>(a: &A, b: &B, direction: Vector2<T>) -> Vector2<T>
+where
+    T: Float,
+    A: Support2<T> + ?Sized,
+    B: Support2<T> + ?Sized,
+{
+    a.support(direction) - b.support(-direction)
+}
+
+// Gets the support point of the Minkowski difference a − b.
+pub(crate) fn support_3d<T, A, B>(a: &A, b: &B, direction: Vector3<T>) -> Vector3<T>
+where
+    T: Float,
+    A: Support3<T> + ?Sized,
+    B: Support3<T> + ?Sized,
+{
+    a.support(direction) - b.support(-direction)
+}
+
+// Gets a simplex of the Minkowski difference that contains the origin, or None if the shapes are apart.
+//
+// The simplex may have fewer than three points when the origin lies on its boundary.
+pub(crate) fn simplex_2d<T, A, B>(a: &A, b: &B) -> Option<Vec<Vector2<T>>>
+where
+    T: Float,
+    A: Support2<T> + ?Sized,
+    B: Support2<T> + ?Sized,
+{
+    let first = support_2d(a, b, Vector2::new(T::one(), T::zero()));
+    let mut simplex = vec![first];
+    let mut direction = -first;
+    for _ in 0..MAX_ITERATIONS {
+        if direction.norm_squared() == T::zero() {
+            return Some(simplex);
+        }
+        let point = support_2d(a, b, direction);
+        if point.dot(direction) < T::zero() {
+            return None;
+        }
+        simplex.push(point);
+        if reduce_2d(&mut simplex, &mut direction) {
+            return Some(simplex);
+        }
+    }
+    Some(simplex)
+}
+
+// Gets a simplex of the Minkowski difference that contains the origin, or None if the shapes are apart.
+//
+// The simplex may have fewer than four points when the origin lies on its boundary.
+pub(crate) fn simplex_3d<T, A, B>(a: &A, b: &B) -> Option<Vec<Vector3<T>>>
+where
+    T: Float,
+    A: Support3<T> + ?Sized,
+    B: Support3<T> + ?Sized,
+{
+    let first = support_3d(a, b, Vector3::new(T::one(), T::zero(), T::zero()));
+    let mut simplex = vec![first];
+    let mut direction = -first;
+    for _ in 0..MAX_ITERATIONS {
+        if direction.norm_squared() == T::zero() {
+            return Some(simplex);
+        }
+        let point = support_3d(a, b, direction);
+        if point.dot(direction) < T::zero() {
+            return None;
+        }
+        simplex.push(point);
+        if reduce_3d(&mut simplex, &mut direction) {
+            return Some(simplex);
+        }
+    }
+    Some(simplex)
+}
+
+// Keeps the feature of the simplex nearest the origin and points the direction at the origin.
+//
+// The newest point is last. Returns true once the simplex contains the origin.
+fn reduce_2d<T: Float>(simplex: &mut Vec<Vector2<T>>, direction: &mut Vector2<T>) -> bool {
+    let a = simplex[simplex.len() - 1];
+    let ao = -a;
+    if simplex.len() == 2 {
+        return reduce_segment_2d(simplex[0], a, simplex, direction);
+    }
+    let (c, b) = (simplex[0], simplex[1]);
+    let (ab, ac) = (b - a, c - a);
+    if ab.cross(ac) == T::zero() {
+        return reduce_segment_2d(b, a, simplex, direction);
+    }
+    let ab_out = toward(ab.perpendicular(), -ac);
+    let ac_out = toward(ac.perpendicular(), -ab);
+    if ab_out.dot(ao) > T::zero() {
+        *simplex = vec![b, a];
+        *direction = ab_out;
+        false
+    } else if ac_out.dot(ao) > T::zero() {
+        *simplex = vec![c, a];
+        *direction = ac_out;
+        false
+    } else {
+        true
+    }
+}
+
+fn reduce_segment_2d<T: Float>(
+    b: Vector2<T>,
+    a: Vector2<T>,
+    simplex: &mut Vec<Vector2<T>>,
+    direction: &mut Vector2<T>,
+) -> bool {
+    let (ab, ao) = (b - a, -a);
+    if ab.dot(ao) <= T::zero() {
+        *simplex = vec![a];
+        *direction = ao;
+        return false;
+    }
+    *simplex = vec![b, a];
+    *direction = toward(ab.perpendicular(), ao);
+    // The origin lies on the segment.
+    direction.dot(ao) == T::zero()
+}
+
+// Keeps the feature of the simplex nearest the origin and points the direction at the origin.
+//
+// The newest point is last. Returns true once the simplex contains the origin.
+fn reduce_3d<T: Float>(simplex: &mut Vec<Vector3<T>>, direction: &mut Vector3<T>) -> bool {
+    let a = simplex[simplex.len() - 1];
+    let ao = -a;
+    match simplex.len() {
+        2 => {
+            reduce_segment(simplex[0], a, simplex, direction);
+            direction.norm_squared() == T::zero()
+        }
+        3 => reduce_triangle(simplex[0], simplex[1], a, simplex, direction),
+        _ => {
+            let (d, c, b) = (simplex[0], simplex[1], simplex[2]);
+            if (b - a).dot((c - a).cross(d - a)) == T::zero() {
+                return reduce_triangle(c, b, a, simplex, direction);
+            }
+            // The origin lies beyond the face opposite a, or the search would have stopped.
+            for (p, q, opposite) in [(b, c, d), (c, d, b), (d, b, c)] {
+                let normal = toward_3d((p - a).cross(q - a), a - opposite);
+                if normal.dot(ao) > T::zero() {
+                    return reduce_triangle(q, p, a, simplex, direction);
+                }
+            }
+            true
+        }
+    }
+}
+
+fn reduce_segment<T: Float>(
+    b: Vector3<T>,
+    a: Vector3<T>,
+    simplex: &mut Vec<Vector3<T>>,
+    direction: &mut Vector3<T>,
+) {
+    let (ab, ao) = (b - a, -a);
+    if ab.dot(ao) > T::zero() {
+        *simplex = vec![b, a];
+        *direction = ab.cross(ao).cross(ab);
+    } else {
+        *simplex = vec![a];
+        *direction = ao;
+    }
+}
+
+fn reduce_triangle<T: Float>(
+    c: Vector3<T>,
+    b: Vector3<T>,
+    a: Vector3<T>,
+    simplex: &mut Vec<Vector3<T>>,
+    direction: &mut Vector3<T>,
+) -> bool {
+    let (ab, ac, ao) = (b - a, c - a, -a);
+    let normal = ab.cross(ac);
+    if normal.norm_squared() == T::zero() {
+        reduce_segment(b, a, simplex, direction);
+        return direction.norm_squared() == T::zero();
+    }
+    if normal.cross(ac).dot(ao) > T::zero() {
+        if ac.dot(ao) > T::zero() {
+            *simplex = vec![c, a];
+            *direction = ac.cross(ao).cross(ac);
+        } else {
+            reduce_segment(b, a, simplex, direction);
+        }
+    } else if ab.cross(normal).dot(ao) > T::zero() {
+        reduce_segment(b, a, simplex, direction);
+    } else if normal.dot(ao) >= T::zero() {
+        *simplex = vec![c, b, a];
+        *direction = normal;
+    } else {
+        *simplex = vec![b, c, a];
+        *direction = -normal;
+    }
+    // The origin lies on the triangle or its edge.
+    direction.norm_squared() == T::zero() || (simplex.len() == 3 && normal.dot(ao) == T::zero())
+}
+
+// Flips a vector, if needed, so that it does not point against a reference.
+fn toward<T: Float>(vector: Vector2<T>, reference: Vector2<T>) -> Vector2<T> {
+    if vector.dot(reference) < T::zero() {
+        -vector
+    } else {
+        vector
+    }
+}
+
+fn toward_3d<T: Float>(vector: Vector3<T>, reference: Vector3<T>) -> Vector3<T> {
+    if vector.dot(reference) < T::zero() {
+        -vector
+    } else {
+        vector
+    }
+}
+
+#[cfg(test)]
+mod geometry_collision_gjk_tests {
+    use super::*;
+    use crate::fields::geometry::collision::aabb::Aabb3;
+    use crate::fields::geometry::collision::bounding_circle::BoundingCircle;
+    use crate::fields::geometry::collision::obb::{Obb2, Obb3};
+    use crate::fields::geometry::point::{Point2, Point3};
+    use crate::types::Angle;
+
+    fn random() -> impl FnMut() -> f64 {
+        let mut state: u64 = 5;
+        move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    #[test]
+    fn intersects_2d_test() {
+        let mut next = random();
+        for _ in 0..300 {
+            let a = Obb2::new(
+                Point2::new(next() * 4.0, next() * 4.0),
+                Angle::from_degrees(next() * 180.0),
+                0.2 + next(),
+                0.2 + next(),
+            );
+            let b = Obb2::new(
+                Point2::new(next() * 4.0, next() * 4.0),
+                Angle::from_degrees(next() * 180.0),
+                0.2 + next(),
+                0.2 + next(),
+            );
+            assert_eq!(intersects_2d(&a, &b), a.intersects(&b));
+
+            let c = BoundingCircle::new(Point2::new(next() * 4.0, next() * 4.0), 0.2 + next());
+            assert_eq!(intersects_2d(&a, &c), a.intersects_circle(&c));
+        }
+
+        let points = [
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(0.0, 2.0),
+        ];
+        let inside = [Point2::new(0.5, 0.5)];
+        let outside = [Point2::new(1.5, 1.5)];
+        assert!(intersects_2d(&points[..], &inside[..]));
+        assert!(!intersects_2d(&points[..], &outside[..]));
+    }
+
+    #[test]
+    fn intersects_3d_test() {
+        let mut next = random();
+        let mut hits = 0;
+        for _ in 0..300 {
+            let mut boxes = [0, 1].map(|_| {
+                let (sin, cos) = (next() * 3.0).sin_cos();
+                let (tilt_sin, tilt_cos) = (next() * 3.0).sin_cos();
+                let x = Vector3::new(cos, sin, 0.0);
+                let y = Vector3::new(-sin * tilt_cos, cos * tilt_cos, tilt_sin);
+                Obb3::new(
+                    Point3::new(next() * 3.0, next() * 3.0, next() * 3.0),
+                    [x, y, x.cross(y)],
+                    [0.2 + next(), 0.2 + next(), 0.2 + next()],
+                )
+                .unwrap()
+            });
+            let expected = boxes[0].intersects(&boxes[1]);
+            hits += expected as usize;
+            assert_eq!(intersects_3d(&boxes[0], &boxes[1]), expected);
+            boxes.swap(0, 1);
+            assert_eq!(intersects_3d(&boxes[0], &boxes[1]), expected);
+        }
+        assert!(hits > 50 && hits < 250);
+
+        let aabb = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+        let line = [Point3::new(-1.0, 0.5, 0.5), Point3::new(2.0, 0.5, 0.5)];
+        let beside = [Point3::new(-1.0, 1.5, 0.5), Point3::new(2.0, 1.5, 0.5)];
+        assert!(intersects_3d(&aabb, &line[..]));
+        assert!(!intersects_3d(&aabb, &beside[..]));
+    }
+}
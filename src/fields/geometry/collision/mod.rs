@@ -0,0 +1,26 @@
+//! This module contains bounding volumes and collision detection.
+//!
+//! It is part of the `geometry` module. Bounding volumes are cheap to test
+//! against each other and suit the broad phase, where most pairs of objects are
+//! ruled out. The narrow phase runs GJK to decide whether two convex shapes
+//! overlap and EPA to find how far they penetrate. Both work on any shape that
+//! implements the `support` traits, including the bounding volumes themselves.
+//! The two dimensional axis-aligned box is the `bounding_box` module's `BoundingBox`.
+//!
+//! # Modules
+//!
+//! - `aabb`: Contains the three dimensional axis-aligned box `Aabb3`.
+//! - `bounding_circle`: Contains `BoundingCircle` and the minimum enclosing circle.
+//! - `bounding_sphere`: Contains `BoundingSphere` and the minimum enclosing sphere.
+//! - `epa`: Contains the expanding polytope algorithm for penetration depth.
+//! - `gjk`: Contains the Gilbert–Johnson–Keerthi intersection test.
+//! - `obb`: Contains the oriented boxes `Obb2` and `Obb3`.
+//! - `support`: Contains the `Support2` and `Support3` traits for convex shapes.
+
+pub mod aabb;
+pub mod bounding_circle;
+pub mod bounding_sphere;
+pub mod epa;
+pub mod gjk;
+pub mod obb;
+pub mod support;
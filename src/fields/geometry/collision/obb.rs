@@ -0,0 +1,444 @@
+//! This module provides oriented bounding box types.
+//!
+//! It is part of the `collision` module. An oriented box is a centre, a set of
+//! orthonormal axes and a half extent along each axis, so it can follow a
+//! rotated object more tightly than an axis-aligned box. `Obb2` is the planar
+//! box and `Obb3` the solid one. Two boxes are tested with the separating axis
+//! theorem: they are disjoint exactly when their projections onto one of the
+//! face normals, or in 3D one of the cross products of edge directions, do not
+//! overlap.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::collision::obb::Obb2;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::types::Angle;
+//!
+//! // A 2 × 2 square turned 45° reaches √2 from its centre along the axes.
+//! let diamond = Obb2::new(Point2::new(0.0, 0.0), Angle::from_degrees(45.0), 1.0, 1.0);
+//!
+//! assert!(diamond.contains_point(Point2::new(1.4, 0.0)));
+//! assert!(!diamond.contains_point(Point2::new(1.0, 1.0)));
+//! assert!(diamond.intersects_box(&BoundingBox::new(Point2::new(1.3, -0.1), Point2::new(2.0, 0.1))));
+//! assert!(!diamond.intersects_box(&BoundingBox::new(Point2::new(0.8, 0.8), Point2::new(2.0, 2.0))));
+//!
+//! let square = Obb2::new(Point2::new(2.4, 0.0), Angle::zero(), 1.0, 1.0);
+//! assert!(diamond.intersects(&square));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a box from its centre, orientation and half extents.
+//! - `from_bounding_box` and `from_aabb`: Construct a box from an axis-aligned one.
+//! - `center`, `axes` and `half_extents`: Get the parts of the box.
+//! - `corners`: Gets the corners.
+//! - `bounding_box`: Gets the smallest axis-aligned box containing the box.
+//! - `closest_point`: Finds the point of the box nearest a point.
+//! - `contains_point`: Checks whether a point lies in the box.
+//! - `intersects`: Checks whether two boxes overlap.
+//! - `intersects_box`: Checks whether the box overlaps an axis-aligned box.
+//! - `intersects_circle` and `intersects_sphere`: Check whether the box overlaps a bounding circle or sphere.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::collision::aabb::Aabb3;
+use crate::fields::geometry::collision::bounding_circle::BoundingCircle;
+use crate::fields::geometry::collision::bounding_sphere::BoundingSphere;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::{Point2, Point3};
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use crate::types::Angle;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb2<T> {
+    center: Point2<T>,
+    axes: [Vector2<T>; 2],
+    half_extents: [T; 2],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb3<T> {
+    center: Point3<T>,
+    axes: [Vector3<T>; 3],
+    half_extents: [T; 3],
+}
+
+impl<T> Obb2<T>
+where
+    T: Float,
+{
+    // Function to create a box from its centre, the angle of its first axis and its half extents.
+    pub fn new(center: Point2<T>, rotation: Angle<T>, half_width: T, half_height: T) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+        let axis = Vector2::new(cos, sin);
+        Obb2 {
+            center,
+            axes: [axis, axis.perpendicular()],
+            half_extents: [half_width.abs(), half_height.abs()],
+        }
+    }
+
+    // Function to create the box covering an axis-aligned box.
+    pub fn from_bounding_box(bounds: &BoundingBox<T>) -> Self {
+        let two = T::from(2).unwrap();
+        Obb2::new(
+            bounds.center(),
+            Angle::zero(),
+            bounds.width() / two,
+            bounds.height() / two,
+        )
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point2<T> {
+        self.center
+    }
+
+    // Function to get the unit axes.
+    pub fn axes(&self) -> [Vector2<T>; 2] {
+        self.axes
+    }
+
+    // Function to get the half extents along the axes.
+    pub fn half_extents(&self) -> [T; 2] {
+        self.half_extents
+    }
+
+    // Function to get the corners, counter-clockwise.
+    pub fn corners(&self) -> [Point2<T>; 4] {
+        let u = self.axes[0] * self.half_extents[0];
+        let v = self.axes[1] * self.half_extents[1];
+        [
+            self.center - u - v,
+            self.center + u - v,
+            self.center + u + v,
+            self.center - u + v,
+        ]
+    }
+
+    // Function to get the smallest axis-aligned box containing the box.
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        let reach = |axis: fn(&Vector2<T>) -> T| {
+            (0..2).fold(T::zero(), |sum, i| {
+                sum + axis(&self.axes[i]).abs() * self.half_extents[i]
+            })
+        };
+        let extent = Vector2::new(reach(|v| v.x), reach(|v| v.y));
+        BoundingBox::new(self.center - extent, self.center + extent)
+    }
+
+    // Function to get the point of the box nearest a point.
+    pub fn closest_point(&self, point: Point2<T>) -> Point2<T> {
+        let offset = point - self.center;
+        (0..2).fold(self.center, |closest, i| {
+            let h = self.half_extents[i];
+            closest + self.axes[i] * offset.dot(self.axes[i]).max(-h).min(h)
+        })
+    }
+
+    // Function to check whether a point lies in the box, boundary included.
+    pub fn contains_point(&self, point: Point2<T>) -> bool {
+        let offset = point - self.center;
+        (0..2).all(|i| offset.dot(self.axes[i]).abs() <= self.half_extents[i] * tolerance())
+    }
+
+    // Function to check whether two boxes overlap, touching included.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let offset = other.center - self.center;
+        let radius = |obb: &Self, axis: Vector2<T>| {
+            (0..2).fold(T::zero(), |sum, i| {
+                sum + obb.axes[i].dot(axis).abs() * obb.half_extents[i]
+            })
+        };
+        self.axes.iter().chain(other.axes.iter()).all(|&axis| {
+            offset.dot(axis).abs() <= (radius(self, axis) + radius(other, axis)) * tolerance()
+        })
+    }
+
+    // Function to check whether the box overlaps an axis-aligned box, touching included.
+    pub fn intersects_box(&self, bounds: &BoundingBox<T>) -> bool {
+        self.intersects(&Obb2::from_bounding_box(bounds))
+    }
+
+    // Function to check whether the box overlaps a bounding circle, touching included.
+    pub fn intersects_circle(&self, circle: &BoundingCircle<T>) -> bool {
+        let gap = self
+            .closest_point(circle.center())
+            .distance(circle.center());
+        gap <= circle.radius() * tolerance()
+    }
+}
+
+impl<T> Obb3<T>
+where
+    T: Float,
+{
+    // Function to create a box from its centre, orthonormal axes and half extents.
+    pub fn new(
+        center: Point3<T>,
+        axes: [Vector3<T>; 3],
+        half_extents: [T; 3],
+    ) -> Result<Self, GeometryError> {
+        let limit = T::from(1e-9).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { T::one() } else { T::zero() };
+                if (axes[i].dot(axes[j]) - expected).abs() > limit {
+                    return Err(GeometryError::new("Box axes must be orthonormal"));
+                }
+            }
+        }
+        Ok(Obb3 {
+            center,
+            axes,
+            half_extents: half_extents.map(|h| h.abs()),
+        })
+    }
+
+    // Function to create the box covering an axis-aligned box.
+    pub fn from_aabb(aabb: &Aabb3<T>) -> Self {
+        let half = aabb.half_extents();
+        Obb3 {
+            center: aabb.center(),
+            axes: [
+                Vector3::new(T::one(), T::zero(), T::zero()),
+                Vector3::new(T::zero(), T::one(), T::zero()),
+                Vector3::new(T::zero(), T::zero(), T::one()),
+            ],
+            half_extents: [half.x, half.y, half.z],
+        }
+    }
+
+    // Function to get the centre.
+    pub fn center(&self) -> Point3<T> {
+        self.center
+    }
+
+    // Function to get the unit axes.
+    pub fn axes(&self) -> [Vector3<T>; 3] {
+        self.axes
+    }
+
+    // Function to get the half extents along the axes.
+    pub fn half_extents(&self) -> [T; 3] {
+        self.half_extents
+    }
+
+    // Function to get the corners, with bit i of the index choosing the side along axis i.
+    pub fn corners(&self) -> [Point3<T>; 8] {
+        std::array::from_fn(|index| {
+            (0..3).fold(self.center, |corner, i| {
+                let step = self.axes[i] * self.half_extents[i];
+                if index & (1 << i) == 0 {
+                    corner - step
+                } else {
+                    corner + step
+                }
+            })
+        })
+    }
+
+    // Function to get the smallest axis-aligned box containing the box.
+    pub fn bounding_box(&self) -> Aabb3<T> {
+        Aabb3::from_points(&self.corners()).unwrap()
+    }
+
+    // Function to get the point of the box nearest a point.
+    pub fn closest_point(&self, point: Point3<T>) -> Point3<T> {
+        let offset = point - self.center;
+        (0..3).fold(self.center, |closest, i| {
+            let h = self.half_extents[i];
+            closest + self.axes[i] * offset.dot(self.axes[i]).max(-h).min(h)
+        })
+    }
+
+    // Function to check whether a point lies in the box, boundary included.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        let offset = point - self.center;
+        (0..3).all(|i| offset.dot(self.axes[i]).abs() <= self.half_extents[i] * tolerance())
+    }
+
+    // Function to check whether two boxes overlap, touching included.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let offset = other.center - self.center;
+        let radius = |obb: &Self, axis: Vector3<T>| {
+            (0..3).fold(T::zero(), |sum, i| {
+                sum + obb.axes[i].dot(axis).abs() * obb.half_extents[i]
+            })
+        };
+        let separates = |axis: Vector3<T>| {
+            offset.dot(axis).abs() > (radius(self, axis) + radius(other, axis)) * tolerance()
+        };
+        if self
+            .axes
+            .iter()
+            .chain(other.axes.iter())
+            .any(|&axis| separates(axis))
+        {
+            return false;
+        }
+        // Parallel edges give a zero cross product, which the face axes already cover.
+        let parallel = T::from(1e-12).unwrap();
+        !self.axes.iter().any(|a| {
+            other.axes.iter().any(|b| {
+                let axis = a.cross(*b);
+                axis.norm_squared() > parallel && separates(axis)
+            })
+        })
+    }
+
+    // Function to check whether the box overlaps an axis-aligned box, touching included.
+    pub fn intersects_box(&self, aabb: &Aabb3<T>) -> bool {
+        self.intersects(&Obb3::from_aabb(aabb))
+    }
+
+    // Function to check whether the box overlaps a bounding sphere, touching included.
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere<T>) -> bool {
+        let gap = self
+            .closest_point(sphere.center())
+            .distance(sphere.center());
+        gap <= sphere.radius() * tolerance()
+    }
+}
+
+// Scales comparisons up slightly so that touching shapes still count after rounding.
+fn tolerance<T: Float>() -> T {
+    T::one() + T::from(1e-12).unwrap()
+}
+
+#[cfg(test)]
+mod geometry_collision_obb_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    // A box turned about the z axis.
+    fn turned(center: Point3<f64>, degrees: f64, half_extents: [f64; 3]) -> Obb3<f64> {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let axes = [
+            Vector3::new(cos, sin, 0.0),
+            Vector3::new(-sin, cos, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        Obb3::new(center, axes, half_extents).unwrap()
+    }
+
+    #[test]
+    fn obb2_shape_test() {
+        let obb = Obb2::new(Point2::new(1.0, 1.0), Angle::from_degrees(90.0), 2.0, 1.0);
+        let corners = obb.corners();
+        assert_close(corners[0].x, 2.0);
+        assert_close(corners[0].y, -1.0);
+        assert_close(corners[2].x, 0.0);
+        assert_close(corners[2].y, 3.0);
+
+        let bounds = obb.bounding_box();
+        assert_close(bounds.width(), 2.0);
+        assert_close(bounds.height(), 4.0);
+
+        let closest = obb.closest_point(Point2::new(5.0, 1.5));
+        assert_close(closest.x, 2.0);
+        assert_close(closest.y, 1.5);
+
+        let aligned = Obb2::from_bounding_box(&BoundingBox::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 2.0),
+        ));
+        assert_eq!(aligned.center(), Point2::new(2.0, 1.0));
+        assert_eq!(aligned.half_extents(), [2.0, 1.0]);
+        assert_eq!(aligned.axes()[1], Vector2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn obb2_intersects_test() {
+        let a = Obb2::new(Point2::new(0.0, 0.0), Angle::from_degrees(30.0), 2.0, 0.5);
+        let b = Obb2::new(Point2::new(2.5, 1.0), Angle::from_degrees(-60.0), 1.0, 1.0);
+        let far = Obb2::new(Point2::new(0.0, 3.0), Angle::from_degrees(30.0), 2.0, 0.5);
+
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+        assert!(!a.intersects(&far));
+
+        // The bounding boxes overlap even though the boxes themselves do not.
+        let c = Obb2::new(Point2::new(-1.0, 1.6), Angle::from_degrees(30.0), 1.0, 0.3);
+        assert!(a.bounding_box().intersects(&c.bounding_box()));
+        assert!(!a.intersects(&c));
+
+        let square = Obb2::new(Point2::new(0.0, 0.0), Angle::zero(), 1.0, 1.0);
+        let touching = Obb2::new(Point2::new(2.0, 0.0), Angle::zero(), 1.0, 1.0);
+        assert!(square.intersects(&touching));
+
+        assert!(square.intersects_circle(&BoundingCircle::new(Point2::new(2.0, 2.0), 1.5)));
+        assert!(!square.intersects_circle(&BoundingCircle::new(Point2::new(2.0, 2.0), 1.4)));
+    }
+
+    #[test]
+    fn obb3_shape_test() {
+        let axes = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+        ];
+        assert!(Obb3::new(Point3::origin(), axes, [1.0, 1.0, 1.0]).is_err());
+
+        let obb = turned(Point3::new(0.0, 0.0, 0.0), 45.0, [1.0, 1.0, 2.0]);
+        let bounds = obb.bounding_box();
+        assert_close(bounds.max().x, 2f64.sqrt());
+        assert_close(bounds.max().z, 2.0);
+        assert_eq!(obb.corners().len(), 8);
+
+        assert!(obb.contains_point(Point3::new(1.4, 0.0, -2.0)));
+        assert!(!obb.contains_point(Point3::new(1.0, 1.0, 0.0)));
+        let closest = obb.closest_point(Point3::new(3.0, 0.0, 5.0));
+        assert_close(closest.x, 2f64.sqrt());
+        assert_close(closest.z, 2.0);
+
+        let aabb = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 4.0, 6.0));
+        let aligned = Obb3::from_aabb(&aabb);
+        assert_eq!(aligned.half_extents(), [1.0, 2.0, 3.0]);
+        assert_eq!(aligned.bounding_box(), aabb);
+    }
+
+    #[test]
+    fn obb3_intersects_test() {
+        let a = turned(Point3::new(0.0, 0.0, 0.0), 45.0, [1.0, 1.0, 1.0]);
+        let near = turned(Point3::new(2.3, 0.0, 0.0), 0.0, [1.0, 1.0, 1.0]);
+        let far = turned(Point3::new(2.5, 0.0, 0.0), 0.0, [1.0, 1.0, 1.0]);
+        assert!(a.intersects(&near));
+        assert!(!a.intersects(&far));
+
+        // Two edge-on boxes are only separated by the cross product of their edges.
+        let edge = Obb3::new(
+            Point3::new(0.0, 0.0, 0.0),
+            [
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 1.0, 1.0) / 2f64.sqrt(),
+                Vector3::new(0.0, -1.0, 1.0) / 2f64.sqrt(),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let crossed = Obb3::new(
+            Point3::new(0.0, 2.0, 1.75),
+            [
+                Vector3::new(1.0, 1.0, 0.0) / 2f64.sqrt(),
+                Vector3::new(-1.0, 1.0, 0.0) / 2f64.sqrt(),
+                Vector3::new(0.0, 0.0, 1.0),
+            ],
+            [1.0, 1.0, 0.5],
+        )
+        .unwrap();
+        assert!(edge.bounding_box().intersects(&crossed.bounding_box()));
+        assert!(!edge.intersects(&crossed));
+
+        let aabb = Aabb3::new(Point3::new(1.3, -0.1, -0.1), Point3::new(2.0, 0.1, 0.1));
+        assert!(a.intersects_box(&aabb));
+        let sphere = BoundingSphere::new(Point3::new(1.5, 0.0, 0.0), 0.05);
+        assert!(!a.intersects_sphere(&sphere));
+        assert!(a.intersects_sphere(&BoundingSphere::new(Point3::new(1.5, 0.0, 0.0), 0.1)));
+    }
+}
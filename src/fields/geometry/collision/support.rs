@@ -0,0 +1,219 @@
+//! This module provides the support traits that describe convex shapes.
+//!
+//! It is part of the `collision` module. The support point of a convex shape in
+//! a direction is a point of the shape that lies farthest along it. GJK and EPA
+//! only ever query shapes through their support points, so any convex shape can
+//! take part by implementing `Support2` or `Support3`. The traits are implemented
+//! for point sets, which stand for their convex hull, convex polygons, and the
+//! bounding volumes. A `Polygon` is treated as the hull of its vertices.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::collision::bounding_sphere::BoundingSphere;
+//! use crabmath::fields::geometry::collision::support::{Support2, Support3};
+//! use crabmath::fields::geometry::point::{Point2, Point3};
+//! use crabmath::fields::geometry::vector::{Vector2, Vector3};
+//!
+//! let bounds = BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+//! assert_eq!(bounds.support(Vector2::new(1.0, -3.0)), Point2::new(2.0, 0.0));
+//!
+//! let sphere = BoundingSphere::new(Point3::new(1.0, 0.0, 0.0), 2.0);
+//! assert_eq!(sphere.support(Vector3::new(0.0, 0.0, -5.0)), Point3::new(1.0, 0.0, -2.0));
+//!
+//! let points = [Point2::new(0.0, 0.0), Point2::new(3.0, 1.0), Point2::new(1.0, 2.0)];
+//! assert_eq!(points[..].support(Vector2::new(0.0, 1.0)), Point2::new(1.0, 2.0));
+//! ```
+//!
+//! # Functions
+//!
+//! - `support`: Gets the point of the shape farthest in a direction.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::collision::aabb::Aabb3;
+use crate::fields::geometry::collision::bounding_circle::BoundingCircle;
+use crate::fields::geometry::collision::bounding_sphere::BoundingSphere;
+use crate::fields::geometry::collision::obb::{Obb2, Obb3};
+use crate::fields::geometry::point::{Point2, Point3};
+use crate::fields::geometry::polygon::Polygon;
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use num_traits::Float;
+
+pub trait Support2<T> {
+    // Function to get a point of the shape farthest along a direction.
+    fn support(&self, direction: Vector2<T>) -> Point2<T>;
+}
+
+pub trait Support3<T> {
+    // Function to get a point of the shape farthest along a direction.
+    fn support(&self, direction: Vector3<T>) -> Point3<T>;
+}
+
+// Picks the lower or upper bound by the sign of a direction component.
+fn side<T: Float>(component: T, lower: T, upper: T) -> T {
+    if component < T::zero() {
+        lower
+    } else {
+        upper
+    }
+}
+
+impl<T: Float> Support2<T> for [Point2<T>] {
+    // An empty set has no support point, and panics.
+    fn support(&self, direction: Vector2<T>) -> Point2<T> {
+        *self
+            .iter()
+            .reduce(|best, point| {
+                if point.to_vector().dot(direction) > best.to_vector().dot(direction) {
+                    point
+                } else {
+                    best
+                }
+            })
+            .expect("A point set needs at least one point")
+    }
+}
+
+impl<T: Float> Support2<T> for Polygon<T> {
+    fn support(&self, direction: Vector2<T>) -> Point2<T> {
+        self.vertices().support(direction)
+    }
+}
+
+impl<T: Float> Support2<T> for BoundingBox<T> {
+    fn support(&self, direction: Vector2<T>) -> Point2<T> {
+        let (min, max) = (self.min(), self.max());
+        Point2::new(
+            side(direction.x, min.x, max.x),
+            side(direction.y, min.y, max.y),
+        )
+    }
+}
+
+impl<T: Float> Support2<T> for BoundingCircle<T> {
+    fn support(&self, direction: Vector2<T>) -> Point2<T> {
+        match direction.normalize() {
+            Some(unit) => self.center() + unit * self.radius(),
+            None => self.center(),
+        }
+    }
+}
+
+impl<T: Float> Support2<T> for Obb2<T> {
+    fn support(&self, direction: Vector2<T>) -> Point2<T> {
+        let half_extents = self.half_extents();
+        self.axes()
+            .iter()
+            .zip(half_extents)
+            .fold(self.center(), |point, (&axis, h)| {
+                point + axis * side(axis.dot(direction), -h, h)
+            })
+    }
+}
+
+impl<T: Float> Support3<T> for [Point3<T>] {
+    // An empty set has no support point, and panics.
+    fn support(&self, direction: Vector3<T>) -> Point3<T> {
+        *self
+            .iter()
+            .reduce(|best, point| {
+                if point.to_vector().dot(direction) > best.to_vector().dot(direction) {
+                    point
+                } else {
+                    best
+                }
+            })
+            .expect("A point set needs at least one point")
+    }
+}
+
+impl<T: Float> Support3<T> for Aabb3<T> {
+    fn support(&self, direction: Vector3<T>) -> Point3<T> {
+        let (min, max) = (self.min(), self.max());
+        Point3::new(
+            side(direction.x, min.x, max.x),
+            side(direction.y, min.y, max.y),
+            side(direction.z, min.z, max.z),
+        )
+    }
+}
+
+impl<T: Float> Support3<T> for BoundingSphere<T> {
+    fn support(&self, direction: Vector3<T>) -> Point3<T> {
+        match direction.normalize() {
+            Some(unit) => self.center() + unit * self.radius(),
+            None => self.center(),
+        }
+    }
+}
+
+impl<T: Float> Support3<T> for Obb3<T> {
+    fn support(&self, direction: Vector3<T>) -> Point3<T> {
+        let half_extents = self.half_extents();
+        self.axes()
+            .iter()
+            .zip(half_extents)
+            .fold(self.center(), |point, (&axis, h)| {
+                point + axis * side(axis.dot(direction), -h, h)
+            })
+    }
+}
+
+#[cfg(test)]
+mod geometry_collision_support_tests {
+    use super::*;
+    use crate::types::Angle;
+
+    #[test]
+    fn support2_test() {
+        let square = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ]);
+        assert_eq!(
+            square.support(Vector2::new(1.0, 2.0)),
+            Point2::new(1.0, 1.0)
+        );
+        assert_eq!(
+            square.support(Vector2::new(-1.0, -0.5)),
+            Point2::new(0.0, 0.0)
+        );
+
+        let circle = BoundingCircle::new(Point2::new(1.0, 1.0), 2.0);
+        assert_eq!(
+            circle.support(Vector2::new(0.0, -3.0)),
+            Point2::new(1.0, -1.0)
+        );
+        assert_eq!(circle.support(Vector2::zero()), Point2::new(1.0, 1.0));
+
+        let obb = Obb2::new(Point2::new(0.0, 0.0), Angle::from_degrees(90.0), 2.0, 1.0);
+        let point = obb.support(Vector2::new(1.0, 1.0));
+        assert!((point.x - 1.0).abs() < 1e-12 && (point.y - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn support3_test() {
+        let aabb = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            aabb.support(Vector3::new(-1.0, 1.0, 1.0)),
+            Point3::new(0.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            Obb3::from_aabb(&aabb).support(Vector3::new(-1.0, 1.0, 1.0)),
+            Point3::new(0.0, 2.0, 3.0)
+        );
+
+        let points = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 4.0),
+        ];
+        assert_eq!(
+            points[..].support(Vector3::new(1.0, 0.0, 0.3)),
+            Point3::new(0.0, 0.0, 4.0)
+        );
+    }
+}
@@ -0,0 +1,506 @@
+//! This module provides point-in-polygon and polygon containment queries.
+//!
+//! It is part of the `geometry` module. Points are located against a `Polygon`
+//! either by winding number (the non-zero rule) or by ray casting (the even-odd
+//! rule). The two rules agree for simple polygons and differ only for
+//! self-intersecting ones. Points on an edge or vertex are always reported as
+//! `Location::Boundary`, before any crossing is counted, so the answer never
+//! depends on the direction of a ray or on which edge a vertex belongs to.
+//!
+//! All decisions are made with the robust predicates from `predicates`, so they
+//! are deterministic even for points within rounding distance of an edge. The
+//! `_exact` variants evaluate the predicates in the coordinate type instead,
+//! which makes them exact for rational coordinates such as `Fraction`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::containment::{
+//!     point_in_polygon_ray_casting, point_in_polygon_winding, polygon_in_polygon, Location,
+//! };
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::Polygon;
+//!
+//! let fence = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(4.0, 4.0),
+//!     Point2::new(0.0, 4.0),
+//! ]);
+//!
+//! assert_eq!(point_in_polygon_winding(Point2::new(1.0, 1.0), &fence), Location::Inside);
+//! assert_eq!(point_in_polygon_winding(Point2::new(4.0, 2.0), &fence), Location::Boundary);
+//! assert_eq!(point_in_polygon_ray_casting(Point2::new(5.0, 2.0), &fence), Location::Outside);
+//!
+//! let yard = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//! ]);
+//! assert!(polygon_in_polygon(&yard, &fence));
+//! assert!(!polygon_in_polygon(&fence, &yard));
+//! ```
+//!
+//! # Functions
+//!
+//! - `winding_number`: Computes the winding number of a polygon around a point.
+//! - `winding_number_exact`: Computes the winding number in the coordinate type.
+//! - `point_in_polygon_winding`: Locates a point with the non-zero winding rule.
+//! - `point_in_polygon_winding_exact`: Locates a point with the non-zero rule in the coordinate type.
+//! - `point_in_polygon_ray_casting`: Locates a point with the even-odd ray casting rule.
+//! - `point_in_polygon_ray_casting_exact`: Locates a point with the even-odd rule in the coordinate type.
+//! - `point_in_polygon_with_holes`: Locates a point in a polygon with holes.
+//! - `polygon_in_polygon`: Checks whether one polygon lies within another.
+//! - `polygon_in_polygon_with_holes`: Checks whether a polygon lies within a polygon with holes.
+
+use crate::fields::geometry::intersection::{
+    segment_intersection, within_box, SegmentIntersection,
+};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::predicates::{orientation, orientation_exact, Orientation};
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    // The point lies in the interior.
+    Inside,
+    // The point lies on an edge or a vertex.
+    Boundary,
+    // The point lies in the exterior.
+    Outside,
+}
+
+// Function to get the winding number of the polygon around a point.
+//
+// Returns None when the point lies on the boundary, where the winding number is undefined.
+pub fn winding_number<T: Float>(point: Point2<T>, polygon: &Polygon<T>) -> Option<i32> {
+    winding(point, polygon, orientation)
+}
+
+// Function to get the winding number of the polygon around a point, evaluated in the coordinate type.
+pub fn winding_number_exact<T>(point: Point2<T>, polygon: &Polygon<T>) -> Option<i32>
+where
+    T: Num + Copy + PartialOrd,
+{
+    winding(point, polygon, orientation_exact)
+}
+
+// Function to locate a point with the non-zero winding rule.
+pub fn point_in_polygon_winding<T: Float>(point: Point2<T>, polygon: &Polygon<T>) -> Location {
+    from_winding(winding_number(point, polygon))
+}
+
+// Function to locate a point with the non-zero winding rule, evaluated in the coordinate type.
+pub fn point_in_polygon_winding_exact<T>(point: Point2<T>, polygon: &Polygon<T>) -> Location
+where
+    T: Num + Copy + PartialOrd,
+{
+    from_winding(winding_number_exact(point, polygon))
+}
+
+// Function to locate a point with the even-odd rule by casting a ray towards +x.
+pub fn point_in_polygon_ray_casting<T: Float>(point: Point2<T>, polygon: &Polygon<T>) -> Location {
+    ray_casting(point, polygon, orientation)
+}
+
+// Function to locate a point with the even-odd rule, evaluated in the coordinate type.
+pub fn point_in_polygon_ray_casting_exact<T>(point: Point2<T>, polygon: &Polygon<T>) -> Location
+where
+    T: Num + Copy + PartialOrd,
+{
+    ray_casting(point, polygon, orientation_exact)
+}
+
+// Function to locate a point in a polygon with holes.
+//
+// Points on the boundary of a hole are on the boundary of the shape, and points
+// inside a hole are outside it.
+pub fn point_in_polygon_with_holes<T: Float>(
+    point: Point2<T>,
+    polygon: &PolygonWithHoles<T>,
+) -> Location {
+    let location = point_in_polygon_winding(point, polygon.exterior());
+    if location != Location::Inside {
+        return location;
+    }
+    for hole in polygon.holes() {
+        match point_in_polygon_winding(point, hole) {
+            Location::Inside => return Location::Outside,
+            Location::Boundary => return Location::Boundary,
+            Location::Outside => {}
+        }
+    }
+    Location::Inside
+}
+
+// Function to check whether `inner` lies within the closed region of `outer`.
+//
+// Shared boundary is allowed, so a polygon lies within itself. Both polygons are
+// expected to be simple.
+pub fn polygon_in_polygon<T: Float>(inner: &Polygon<T>, outer: &Polygon<T>) -> bool {
+    boundary_samples(inner, outer)
+        .into_iter()
+        .all(|point| point_in_polygon_winding(point, outer) != Location::Outside)
+}
+
+// Function to check whether `inner` lies within the closed region of a polygon with holes.
+//
+// `inner` may touch the exterior and the holes, but its interior may not overlap any hole.
+pub fn polygon_in_polygon_with_holes<T: Float>(
+    inner: &Polygon<T>,
+    outer: &PolygonWithHoles<T>,
+) -> bool {
+    polygon_in_polygon(inner, outer.exterior())
+        && outer
+            .holes()
+            .iter()
+            .all(|hole| interiors_disjoint(inner, hole))
+}
+
+fn from_winding(winding: Option<i32>) -> Location {
+    match winding {
+        None => Location::Boundary,
+        Some(0) => Location::Outside,
+        Some(_) => Location::Inside,
+    }
+}
+
+// Sunday's winding number algorithm, with upward crossings counted when the point
+// is left of the edge and downward crossings when it is right of it.
+fn winding<T, F>(point: Point2<T>, polygon: &Polygon<T>, orient: F) -> Option<i32>
+where
+    T: Num + Copy + PartialOrd,
+    F: Fn(Point2<T>, Point2<T>, Point2<T>) -> Orientation,
+{
+    let mut winding = 0;
+    for (a, b) in polygon.edges() {
+        let side = orient(a, b, point);
+        if side == Orientation::Collinear && within_box(point, a, b) {
+            return None;
+        }
+        if a.y <= point.y {
+            if b.y > point.y && side == Orientation::CounterClockwise {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side == Orientation::Clockwise {
+            winding -= 1;
+        }
+    }
+    Some(winding)
+}
+
+// Even-odd ray casting. Each edge is treated as half open in y, so a ray through a
+// vertex counts exactly one of its two edges.
+fn ray_casting<T, F>(point: Point2<T>, polygon: &Polygon<T>, orient: F) -> Location
+where
+    T: Num + Copy + PartialOrd,
+    F: Fn(Point2<T>, Point2<T>, Point2<T>) -> Orientation,
+{
+    let mut inside = false;
+    for (a, b) in polygon.edges() {
+        let side = orient(a, b, point);
+        if side == Orientation::Collinear && within_box(point, a, b) {
+            return Location::Boundary;
+        }
+        let upward = a.y <= point.y && b.y > point.y;
+        let downward = b.y <= point.y && a.y > point.y;
+        // The crossing is to the right of the point when the point is left of an
+        // upward edge or right of a downward one.
+        if (upward && side == Orientation::CounterClockwise)
+            || (downward && side == Orientation::Clockwise)
+        {
+            inside = !inside;
+        }
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+// Whether the interiors of two simple polygons do not overlap.
+fn interiors_disjoint<T: Float>(a: &Polygon<T>, b: &Polygon<T>) -> bool {
+    let a_samples = boundary_samples(a, b);
+    let b_samples = boundary_samples(b, a);
+    let a_locations: Vec<Location> = a_samples
+        .iter()
+        .map(|&point| point_in_polygon_winding(point, b))
+        .collect();
+    let b_inside = b_samples
+        .iter()
+        .any(|&point| point_in_polygon_winding(point, a) == Location::Inside);
+    // Boundaries that never enter each other leave the interiors either disjoint or
+    // identical, and they are identical exactly when one boundary lies on the other.
+    !b_inside
+        && !a_locations.contains(&Location::Inside)
+        && !a_locations
+            .iter()
+            .all(|&location| location == Location::Boundary)
+}
+
+// The vertices of `polygon` and the midpoints of its edges after splitting them
+// wherever they meet the edges of `other`. Every sample lies strictly inside,
+// strictly outside or on the boundary of `other` along its whole piece, so the
+// samples decide how the boundary of `polygon` relates to `other`.
+fn boundary_samples<T: Float>(polygon: &Polygon<T>, other: &Polygon<T>) -> Vec<Point2<T>> {
+    let mut samples = polygon.vertices().to_vec();
+    let two = T::one() + T::one();
+    for (p, q) in polygon.edges() {
+        let direction = q - p;
+        let length_squared = direction.norm_squared();
+        if length_squared.is_zero() {
+            continue;
+        }
+        let parameter = |point: Point2<T>| (point - p).dot(direction) / length_squared;
+        let mut cuts = vec![T::zero(), T::one()];
+        for (r, s) in other.edges() {
+            match segment_intersection(p, q, r, s) {
+                SegmentIntersection::None => {}
+                SegmentIntersection::Point(point) => cuts.push(parameter(point)),
+                SegmentIntersection::Overlap(start, end) => {
+                    cuts.push(parameter(start));
+                    cuts.push(parameter(end));
+                }
+            }
+        }
+        cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        cuts.dedup();
+        for pair in cuts.windows(2) {
+            samples.push(p + direction.scale((pair[0] + pair[1]) / two));
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod geometry_containment_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+        Polygon::new(vec![p(x0, y0), p(x1, y0), p(x1, y1), p(x0, y1)])
+    }
+
+    // A U shape open at the top, with the notch between x = 1 and x = 3.
+    fn u_shape() -> Polygon<f64> {
+        Polygon::new(vec![
+            p(0.0, 0.0),
+            p(4.0, 0.0),
+            p(4.0, 4.0),
+            p(3.0, 4.0),
+            p(3.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 4.0),
+            p(0.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn winding_number_test() {
+        let square = rectangle(0.0, 0.0, 2.0, 2.0);
+
+        assert_eq!(winding_number(p(1.0, 1.0), &square), Some(1));
+        assert_eq!(winding_number(p(1.0, 1.0), &square.reversed()), Some(-1));
+        assert_eq!(winding_number(p(3.0, 1.0), &square), Some(0));
+        assert_eq!(winding_number(p(2.0, 1.0), &square), None);
+        assert_eq!(winding_number(p(0.0, 0.0), &square), None);
+    }
+
+    #[test]
+    fn point_in_polygon_concave_test() {
+        let shape = u_shape();
+        let cases = [
+            (p(0.5, 3.0), Location::Inside),
+            (p(2.0, 0.5), Location::Inside),
+            (p(2.0, 3.0), Location::Outside),
+            (p(2.0, 1.0), Location::Boundary),
+            (p(3.0, 4.0), Location::Boundary),
+            (p(1.0, 2.5), Location::Boundary),
+            // Rays through the vertices of the notch.
+            (p(-1.0, 1.0), Location::Outside),
+            (p(-1.0, 4.0), Location::Outside),
+            (p(0.5, 1.0), Location::Inside),
+            (p(5.0, 0.0), Location::Outside),
+        ];
+        for (point, expected) in cases {
+            assert_eq!(point_in_polygon_winding(point, &shape), expected);
+            assert_eq!(point_in_polygon_ray_casting(point, &shape), expected);
+            assert_eq!(point_in_polygon_winding(point, &shape.reversed()), expected);
+            assert_eq!(
+                point_in_polygon_ray_casting(point, &shape.reversed()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn point_in_polygon_self_intersecting_test() {
+        // A pentagram winds twice around its centre.
+        let star = Polygon::new(
+            (0..5)
+                .map(|i| {
+                    let angle =
+                        std::f64::consts::FRAC_PI_2 + 4.0 * std::f64::consts::PI * i as f64 / 5.0;
+                    p(angle.cos(), angle.sin())
+                })
+                .collect(),
+        );
+        let centre = p(0.0, 0.0);
+
+        assert_eq!(winding_number(centre, &star), Some(2));
+        assert_eq!(point_in_polygon_winding(centre, &star), Location::Inside);
+        assert_eq!(
+            point_in_polygon_ray_casting(centre, &star),
+            Location::Outside
+        );
+    }
+
+    #[test]
+    fn point_in_polygon_near_boundary_test() {
+        // Points a rounding error away from a slanted edge are never on it.
+        let triangle = Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.3), p(0.0, 1.0)]);
+        let on_edge = p(0.5, 0.15);
+        let below = p(0.5, f64::from_bits(0.15f64.to_bits() - 1));
+        let above = p(0.5, f64::from_bits(0.15f64.to_bits() + 1));
+
+        assert_eq!(
+            point_in_polygon_winding(on_edge, &triangle),
+            Location::Boundary
+        );
+        assert_eq!(
+            point_in_polygon_ray_casting(on_edge, &triangle),
+            Location::Boundary
+        );
+        assert_eq!(
+            point_in_polygon_winding(below, &triangle),
+            Location::Outside
+        );
+        assert_eq!(
+            point_in_polygon_ray_casting(below, &triangle),
+            Location::Outside
+        );
+        assert_eq!(point_in_polygon_winding(above, &triangle), Location::Inside);
+        assert_eq!(
+            point_in_polygon_ray_casting(above, &triangle),
+            Location::Inside
+        );
+    }
+
+    #[test]
+    fn point_in_polygon_exact_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let triangle = Polygon::new(vec![
+            Point2::new(frac(0, 1), frac(0, 1)),
+            Point2::new(frac(1, 1), frac(0, 1)),
+            Point2::new(frac(0, 1), frac(1, 1)),
+        ]);
+        let on_edge = Point2::new(frac(1, 3), frac(2, 3));
+        let inside = Point2::new(frac(1, 3), frac(1, 3));
+        let outside = Point2::new(frac(2, 3), frac(2, 3));
+
+        assert_eq!(
+            point_in_polygon_winding_exact(on_edge, &triangle),
+            Location::Boundary
+        );
+        assert_eq!(
+            point_in_polygon_ray_casting_exact(on_edge, &triangle),
+            Location::Boundary
+        );
+        assert_eq!(winding_number_exact(inside, &triangle), Some(1));
+        assert_eq!(
+            point_in_polygon_ray_casting_exact(outside, &triangle),
+            Location::Outside
+        );
+    }
+
+    #[test]
+    fn point_in_polygon_with_holes_test() {
+        let shape = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            vec![
+                rectangle(2.0, 2.0, 4.0, 4.0),
+                rectangle(6.0, 6.0, 8.0, 8.0).reversed(),
+            ],
+        );
+
+        assert_eq!(
+            point_in_polygon_with_holes(p(1.0, 1.0), &shape),
+            Location::Inside
+        );
+        assert_eq!(
+            point_in_polygon_with_holes(p(3.0, 3.0), &shape),
+            Location::Outside
+        );
+        assert_eq!(
+            point_in_polygon_with_holes(p(7.0, 7.0), &shape),
+            Location::Outside
+        );
+        assert_eq!(
+            point_in_polygon_with_holes(p(4.0, 3.0), &shape),
+            Location::Boundary
+        );
+        assert_eq!(
+            point_in_polygon_with_holes(p(10.0, 3.0), &shape),
+            Location::Boundary
+        );
+        assert_eq!(
+            point_in_polygon_with_holes(p(11.0, 3.0), &shape),
+            Location::Outside
+        );
+    }
+
+    #[test]
+    fn polygon_in_polygon_test() {
+        let shape = u_shape();
+
+        assert!(polygon_in_polygon(&rectangle(0.0, 0.0, 1.0, 4.0), &shape));
+        assert!(polygon_in_polygon(&shape, &shape));
+        assert!(polygon_in_polygon(&rectangle(0.5, 0.5, 3.5, 1.0), &shape));
+        // Every vertex of the bar is inside, but it spans the notch.
+        assert!(!polygon_in_polygon(&rectangle(0.5, 2.0, 3.5, 3.0), &shape));
+        // A bar lying along the bottom of the notch touches it from outside.
+        assert!(!polygon_in_polygon(&rectangle(1.0, 1.0, 3.0, 2.0), &shape));
+        assert!(!polygon_in_polygon(&rectangle(-1.0, 0.0, 1.0, 1.0), &shape));
+    }
+
+    #[test]
+    fn polygon_in_polygon_with_holes_test() {
+        let shape = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            vec![rectangle(4.0, 4.0, 6.0, 6.0)],
+        );
+
+        assert!(polygon_in_polygon_with_holes(
+            &rectangle(1.0, 1.0, 3.0, 3.0),
+            &shape
+        ));
+        // Touching the hole from outside is allowed.
+        assert!(polygon_in_polygon_with_holes(
+            &rectangle(1.0, 4.0, 4.0, 6.0),
+            &shape
+        ));
+        assert!(!polygon_in_polygon_with_holes(
+            &rectangle(1.0, 1.0, 5.0, 5.0),
+            &shape
+        ));
+        assert!(!polygon_in_polygon_with_holes(
+            &rectangle(4.0, 4.0, 6.0, 6.0),
+            &shape
+        ));
+        assert!(!polygon_in_polygon_with_holes(
+            &rectangle(4.5, 4.5, 5.5, 5.5),
+            &shape
+        ));
+        assert!(!polygon_in_polygon_with_holes(
+            &rectangle(3.0, 3.0, 7.0, 7.0),
+            &shape
+        ));
+    }
+}
@@ -0,0 +1,598 @@
+//! This module provides convex hull algorithms for 2D and 3D point sets.
+//!
+//! It is part of the `geometry` module. The 2D algorithms, Andrew's monotone chain
+//! and the Graham scan, run in O(n log n) and return the indices of the hull
+//! vertices in counter-clockwise order, starting from the lowest-leftmost point.
+//! Duplicate points are reported once and points lying on a hull edge are left
+//! out. Both use the robust `orient2d` predicate, so nearly collinear input is
+//! handled consistently and the two algorithms return identical results.
+//!
+//! The 3D algorithm, quickhull, returns triangular faces as index triples wound
+//! counter-clockwise when seen from outside the hull. Input that does not span
+//! three dimensions (fewer than four points, or all points coplanar) has no
+//! faces.
+//!
+//! Hull areas, perimeters, volumes and surface areas are computed with the
+//! `area`, `perimeter` and `solids` functions.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::convex_hull::{hull_area, hull_perimeter, monotone_chain};
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let points = vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(1.0, 1.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(1.0, 0.0),
+//!     Point2::new(0.0, 2.0),
+//!     Point2::new(0.0, 0.0),
+//! ];
+//! let hull = monotone_chain(&points);
+//!
+//! assert_eq!(hull, vec![0, 2, 3, 5]);
+//! assert_eq!(hull_area(&points, &hull), 4.0);
+//! assert_eq!(hull_perimeter(&points, &hull), 8.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `monotone_chain`: Computes a 2D hull with Andrew's monotone chain algorithm.
+//! - `graham_scan`: Computes a 2D hull with the Graham scan.
+//! - `quickhull`: Computes a 3D hull with the quickhull algorithm.
+//! - `hull_area`: Computes the area enclosed by a 2D hull.
+//! - `hull_perimeter`: Computes the perimeter of a 2D hull.
+//! - `hull_volume`: Computes the volume enclosed by a 3D hull.
+//! - `hull_surface_area`: Computes the surface area of a 3D hull.
+
+use crate::fields::geometry::area::get_area_polygon;
+use crate::fields::geometry::perimeter::get_perimeter_polygon;
+use crate::fields::geometry::point::{Point2, Point3};
+use crate::fields::geometry::predicates::orient2d;
+use crate::fields::geometry::solids::surface_area::get_surface_area_polyhedron;
+use crate::fields::geometry::solids::volume::get_volume_polyhedron;
+use crate::fields::geometry::vector::Vector3;
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// Function to compute the 2D convex hull with Andrew's monotone chain algorithm.
+pub fn monotone_chain<T: Float>(points: &[Point2<T>]) -> Vec<usize> {
+    let order = sorted_unique(points);
+    if order.len() < 3 {
+        return order;
+    }
+
+    let mut hull: Vec<usize> = Vec::with_capacity(2 * order.len());
+    // Lower hull from left to right, then upper hull from right to left. The upper
+    // hull may not pop below the rightmost point of the lower hull.
+    let lower = order.iter();
+    let upper = order.iter().rev().skip(1);
+    let mut floor = 2;
+    for (step, &index) in lower.chain(upper).enumerate() {
+        if step == order.len() {
+            floor = hull.len() + 1;
+        }
+        while hull.len() >= floor
+            && orient2d(
+                points[hull[hull.len() - 2]],
+                points[hull[hull.len() - 1]],
+                points[index],
+            ) <= 0f64
+        {
+            hull.pop();
+        }
+        hull.push(index);
+    }
+    // The last point repeats the first.
+    hull.pop();
+    hull
+}
+
+// Function to compute the 2D convex hull with the Graham scan.
+pub fn graham_scan<T: Float>(points: &[Point2<T>]) -> Vec<usize> {
+    let unique = sorted_unique(points);
+    if unique.len() < 3 {
+        return unique;
+    }
+
+    // The pivot is the lowest point, ties broken by the smallest x.
+    let pivot = *unique
+        .iter()
+        .min_by(|&&a, &&b| {
+            let (pa, pb) = (points[a], points[b]);
+            pa.y.partial_cmp(&pb.y)
+                .unwrap()
+                .then(pa.x.partial_cmp(&pb.x).unwrap())
+        })
+        .unwrap();
+    let origin = points[pivot];
+
+    // Every other point lies in the half plane above the pivot, so sorting by the
+    // orientation predicate sorts by polar angle. Collinear points go nearest first.
+    let mut others: Vec<usize> = unique.into_iter().filter(|&i| i != pivot).collect();
+    others.sort_by(|&a, &b| {
+        let turn = orient2d(origin, points[a], points[b]);
+        if turn > 0f64 {
+            Ordering::Less
+        } else if turn < 0f64 {
+            Ordering::Greater
+        } else {
+            let da = origin.distance_squared(points[a]);
+            let db = origin.distance_squared(points[b]);
+            da.partial_cmp(&db).unwrap()
+        }
+    });
+
+    let mut hull = vec![pivot];
+    for index in others {
+        while hull.len() >= 2
+            && orient2d(
+                points[hull[hull.len() - 2]],
+                points[hull[hull.len() - 1]],
+                points[index],
+            ) <= 0f64
+        {
+            hull.pop();
+        }
+        hull.push(index);
+    }
+
+    // Start from the lowest-leftmost point to match `monotone_chain`.
+    let start = (0..hull.len())
+        .min_by(|&a, &b| compare_points(points[hull[a]], points[hull[b]]))
+        .unwrap();
+    hull.rotate_left(start);
+    hull
+}
+
+// Function to compute the 3D convex hull with the quickhull algorithm.
+pub fn quickhull<T: Float>(points: &[Point3<T>]) -> Vec<[usize; 3]> {
+    let coordinates: Vec<Vector3<f64>> = points
+        .iter()
+        .map(|p| {
+            Vector3::new(
+                p.x.to_f64().unwrap(),
+                p.y.to_f64().unwrap(),
+                p.z.to_f64().unwrap(),
+            )
+        })
+        .collect();
+    Quickhull::new(&coordinates).map_or_else(Vec::new, |hull| hull.run())
+}
+
+// Function to get the area enclosed by a 2D hull.
+pub fn hull_area<T: Float>(points: &[Point2<T>], hull: &[usize]) -> T {
+    get_area_polygon(&gather(points, hull))
+}
+
+// Function to get the perimeter of a 2D hull.
+pub fn hull_perimeter<T: Float>(points: &[Point2<T>], hull: &[usize]) -> T {
+    get_perimeter_polygon(&gather(points, hull))
+}
+
+// Function to get the volume enclosed by a 3D hull.
+pub fn hull_volume<T: Float>(points: &[Point3<T>], faces: &[[usize; 3]]) -> T {
+    get_volume_polyhedron(points, faces)
+}
+
+// Function to get the surface area of a 3D hull.
+pub fn hull_surface_area<T: Float>(points: &[Point3<T>], faces: &[[usize; 3]]) -> T {
+    get_surface_area_polyhedron(points, faces)
+}
+
+fn gather<T: Float>(points: &[Point2<T>], hull: &[usize]) -> Vec<Point2<T>> {
+    hull.iter().map(|&i| points[i]).collect()
+}
+
+fn compare_points<T: Float>(a: Point2<T>, b: Point2<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap()
+        .then(a.y.partial_cmp(&b.y).unwrap())
+}
+
+// Indices sorted by (x, y), keeping only the first index of each distinct point.
+fn sorted_unique<T: Float>(points: &[Point2<T>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| compare_points(points[a], points[b]).then(a.cmp(&b)));
+    order.dedup_by(|a, b| points[*a] == points[*b]);
+    order
+}
+
+struct Face {
+    vertices: [usize; 3],
+    normal: Vector3<f64>,
+    offset: f64,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+struct Quickhull<'a> {
+    points: &'a [Vector3<f64>],
+    faces: Vec<Face>,
+    // Maps each directed edge to the face on its left.
+    edges: HashMap<(usize, usize), usize>,
+    epsilon: f64,
+}
+
+impl<'a> Quickhull<'a> {
+    // Builds the initial tetrahedron, or returns None for degenerate input.
+    fn new(points: &'a [Vector3<f64>]) -> Option<Self> {
+        if points.len() < 4 {
+            return None;
+        }
+        let max_abs =
+            |f: fn(&Vector3<f64>) -> f64| points.iter().map(|p| f(p).abs()).fold(0f64, f64::max);
+        let scale = max_abs(|p| p.x) + max_abs(|p| p.y) + max_abs(|p| p.z);
+        let epsilon = 3f64 * f64::EPSILON * scale.max(f64::MIN_POSITIVE);
+
+        // Two points far apart along the widest axis.
+        let extremes = |f: fn(&Vector3<f64>) -> f64| {
+            let min = (0..points.len())
+                .min_by(|&a, &b| f(&points[a]).partial_cmp(&f(&points[b])).unwrap())?;
+            let max = (0..points.len())
+                .max_by(|&a, &b| f(&points[a]).partial_cmp(&f(&points[b])).unwrap())?;
+            Some((min, max, f(&points[max]) - f(&points[min])))
+        };
+        let candidates = [extremes(|p| p.x)?, extremes(|p| p.y)?, extremes(|p| p.z)?];
+        let (a, b, width) = candidates
+            .into_iter()
+            .max_by(|x, y| x.2.partial_cmp(&y.2).unwrap())?;
+        if width <= epsilon {
+            return None;
+        }
+
+        // The point farthest from line ab.
+        let ab = points[b] - points[a];
+        let c = (0..points.len()).max_by(|&i, &j| {
+            let di = ab.cross(points[i] - points[a]).norm_squared();
+            let dj = ab.cross(points[j] - points[a]).norm_squared();
+            di.partial_cmp(&dj).unwrap()
+        })?;
+        if ab.cross(points[c] - points[a]).norm() <= epsilon * ab.norm() {
+            return None;
+        }
+
+        // The point farthest from plane abc.
+        let normal = ab.cross(points[c] - points[a]);
+        let d = (0..points.len()).max_by(|&i, &j| {
+            let di = normal.dot(points[i] - points[a]).abs();
+            let dj = normal.dot(points[j] - points[a]).abs();
+            di.partial_cmp(&dj).unwrap()
+        })?;
+        if normal.dot(points[d] - points[a]).abs() <= epsilon * normal.norm() {
+            return None;
+        }
+
+        let mut hull = Quickhull {
+            points,
+            faces: Vec::new(),
+            edges: HashMap::new(),
+            epsilon,
+        };
+        // Wind the base so that d lies below it, then add the three sides.
+        let base = if normal.dot(points[d] - points[a]) > 0f64 {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        };
+        let [p, q, r] = base;
+        let initial = [[p, q, r], [p, d, q], [q, d, r], [r, d, p]];
+        let face_indices: Vec<usize> = initial.iter().map(|v| hull.add_face(*v)).collect();
+
+        let simplex = [a, b, c, d];
+        for index in 0..points.len() {
+            if !simplex.contains(&index) {
+                hull.assign(index, &face_indices);
+            }
+        }
+        Some(hull)
+    }
+
+    fn run(mut self) -> Vec<[usize; 3]> {
+        // Outside points are only ever assigned to newly appended faces, so a
+        // single forward pass visits every face that still needs expanding.
+        let mut cursor = 0;
+        while cursor < self.faces.len() {
+            let face = &self.faces[cursor];
+            if face.alive && !face.outside.is_empty() {
+                self.expand(cursor);
+            }
+            cursor += 1;
+        }
+        self.faces
+            .iter()
+            .filter(|face| face.alive)
+            .map(|face| face.vertices)
+            .collect()
+    }
+
+    // Adds the farthest outside point of `face_index` to the hull.
+    fn expand(&mut self, face_index: usize) {
+        let face = &self.faces[face_index];
+        let apex = *face
+            .outside
+            .iter()
+            .max_by(|&&i, &&j| {
+                self.distance(face, i)
+                    .partial_cmp(&self.distance(face, j))
+                    .unwrap()
+            })
+            .unwrap();
+
+        // Flood fill the faces visible from the apex and collect the horizon edges.
+        let mut visible = vec![face_index];
+        let mut stack = vec![face_index];
+        self.faces[face_index].alive = false;
+        let mut horizon = Vec::new();
+        while let Some(current) = stack.pop() {
+            let [x, y, z] = self.faces[current].vertices;
+            for (from, to) in [(x, y), (y, z), (z, x)] {
+                let neighbor = self.edges[&(to, from)];
+                if !self.faces[neighbor].alive {
+                    continue;
+                }
+                if self.distance(&self.faces[neighbor], apex) > self.epsilon {
+                    self.faces[neighbor].alive = false;
+                    visible.push(neighbor);
+                    stack.push(neighbor);
+                } else {
+                    horizon.push((from, to));
+                }
+            }
+        }
+        // A horizon edge whose neighbor later turned visible is not on the horizon.
+        horizon.retain(|(from, to)| self.faces[self.edges[&(*to, *from)]].alive);
+
+        let mut orphans = Vec::new();
+        for &index in &visible {
+            let [x, y, z] = self.faces[index].vertices;
+            for edge in [(x, y), (y, z), (z, x)] {
+                if self.edges.get(&edge) == Some(&index) {
+                    self.edges.remove(&edge);
+                }
+            }
+            orphans.append(&mut self.faces[index].outside);
+        }
+
+        let new_faces: Vec<usize> = horizon
+            .iter()
+            .map(|&(from, to)| self.add_face([from, to, apex]))
+            .collect();
+        for index in orphans {
+            if index != apex {
+                self.assign(index, &new_faces);
+            }
+        }
+    }
+
+    fn add_face(&mut self, vertices: [usize; 3]) -> usize {
+        let [a, b, c] = vertices.map(|i| self.points[i]);
+        let normal = (b - a).cross(c - a).normalize().unwrap_or(Vector3::zero());
+        let index = self.faces.len();
+        self.faces.push(Face {
+            vertices,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            alive: true,
+        });
+        let [x, y, z] = vertices;
+        for edge in [(x, y), (y, z), (z, x)] {
+            self.edges.insert(edge, index);
+        }
+        index
+    }
+
+    // Puts a point in the outside set of the first candidate face it lies above.
+    fn assign(&mut self, index: usize, candidates: &[usize]) {
+        for &face_index in candidates {
+            if self.distance(&self.faces[face_index], index) > self.epsilon {
+                self.faces[face_index].outside.push(index);
+                return;
+            }
+        }
+    }
+
+    fn distance(&self, face: &Face, index: usize) -> f64 {
+        face.normal.dot(self.points[index]) - face.offset
+    }
+}
+
+#[cfg(test)]
+mod geometry_convex_hull_tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    // A small deterministic pseudo-random generator for reproducible point clouds.
+    fn random_points(count: usize, seed: u64) -> impl Iterator<Item = f64> {
+        let mut state = seed;
+        (0..count).map(move |_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        })
+    }
+
+    fn square_with_noise() -> Vec<Point2<f64>> {
+        let mut points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ];
+        // Collinear points on every edge, duplicates of the corners and interior points.
+        for i in 1..4 {
+            let t = i as f64;
+            points.push(Point2::new(t, 0.0));
+            points.push(Point2::new(4.0, t));
+            points.push(Point2::new(t, 4.0));
+            points.push(Point2::new(0.0, t));
+            points.push(Point2::new(t, t));
+        }
+        points.push(Point2::new(4.0, 4.0));
+        points.push(Point2::new(0.0, 0.0));
+        points
+    }
+
+    #[test]
+    fn monotone_chain_test() {
+        let points = square_with_noise();
+        let hull = monotone_chain(&points);
+
+        assert_eq!(hull, vec![0, 1, 2, 3]);
+        assert_eq!(hull_area(&points, &hull), 16.0);
+        assert_eq!(hull_perimeter(&points, &hull), 16.0);
+    }
+
+    #[test]
+    fn graham_scan_test() {
+        let points = square_with_noise();
+        let hull = graham_scan(&points);
+
+        assert_eq!(hull, vec![0, 1, 2, 3]);
+        assert_eq!(hull_area(&points, &hull), 16.0);
+    }
+
+    #[test]
+    fn hull_degenerate_test() {
+        let empty: Vec<Point2<f64>> = Vec::new();
+        assert!(monotone_chain(&empty).is_empty());
+        assert!(graham_scan(&empty).is_empty());
+
+        let same = vec![Point2::new(1.0, 1.0); 5];
+        assert_eq!(monotone_chain(&same), vec![0]);
+        assert_eq!(graham_scan(&same), vec![0]);
+
+        let line: Vec<Point2<f64>> = (0..10)
+            .map(|i| Point2::new(i as f64, 2.0 * i as f64))
+            .rev()
+            .collect();
+        assert_eq!(monotone_chain(&line), vec![9, 0]);
+        assert_eq!(graham_scan(&line), vec![9, 0]);
+        assert_eq!(hull_area(&line, &monotone_chain(&line)), 0.0);
+    }
+
+    #[test]
+    fn hull_circle_test() {
+        // Points on a circle are all hull vertices; the centre is not.
+        let mut points: Vec<Point2<f64>> = (0..64)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / 64.0;
+                Point2::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        points.push(Point2::new(0.0, 0.0));
+
+        let chain = monotone_chain(&points);
+        let graham = graham_scan(&points);
+        assert_eq!(chain.len(), 64);
+        assert_eq!(chain, graham);
+        let expected = 32.0 * (2.0 * PI / 64.0).sin();
+        assert!((hull_area(&points, &chain) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hull_random_agreement_test() {
+        let coordinates: Vec<f64> = random_points(20_000, 7).collect();
+        let points: Vec<Point2<f64>> = coordinates
+            .chunks(2)
+            .map(|c| Point2::new(c[0], c[1]))
+            .collect();
+
+        let chain = monotone_chain(&points);
+        let graham = graham_scan(&points);
+        assert_eq!(chain, graham);
+
+        // Every point lies inside or on the hull.
+        for point in &points {
+            for k in 0..chain.len() {
+                let a = points[chain[k]];
+                let b = points[chain[(k + 1) % chain.len()]];
+                assert!(orient2d(a, b, *point) >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn hull_large_input_test() {
+        // A million points should be handled comfortably by the O(n log n) algorithms.
+        let coordinates: Vec<f64> = random_points(2_000_000, 11).collect();
+        let points: Vec<Point2<f64>> = coordinates
+            .chunks(2)
+            .map(|c| Point2::new(c[0], c[1]))
+            .collect();
+
+        let hull = monotone_chain(&points);
+        let area = hull_area(&points, &hull);
+        assert!(area > 0.99 && area <= 1.0);
+    }
+
+    #[test]
+    fn quickhull_cube_test() {
+        let mut points = Vec::new();
+        for &x in &[0.0, 1.0] {
+            for &y in &[0.0, 1.0] {
+                for &z in &[0.0, 1.0] {
+                    points.push(Point3::new(x, y, z));
+                }
+            }
+        }
+        // Interior points, face centres and duplicates do not change the hull.
+        points.push(Point3::new(0.5, 0.5, 0.5));
+        points.push(Point3::new(0.5, 0.5, 1.0));
+        points.push(Point3::new(0.0, 0.5, 0.5));
+        points.push(Point3::new(1.0, 1.0, 1.0));
+
+        let faces = quickhull(&points);
+        assert!((hull_volume(&points, &faces) - 1.0).abs() < 1e-12);
+        assert!((hull_surface_area(&points, &faces) - 6.0).abs() < 1e-12);
+
+        // Faces are wound counter-clockwise when seen from outside.
+        let centre = Vector3::new(0.5, 0.5, 0.5);
+        for face in &faces {
+            let [a, b, c] = face.map(|i| points[i].to_vector());
+            let normal = (b - a).cross(c - a);
+            assert!(normal.dot(a - centre) > 0.0);
+        }
+    }
+
+    #[test]
+    fn quickhull_sphere_test() {
+        let coordinates: Vec<f64> = random_points(3 * 2_000, 3).collect();
+        let points: Vec<Point3<f64>> = coordinates
+            .chunks(3)
+            .map(|c| {
+                // Spread points over the unit sphere.
+                let z = 2.0 * c[0] - 1.0;
+                let angle = 2.0 * PI * c[1];
+                let r = (1.0 - z * z).sqrt();
+                Point3::new(r * angle.cos(), r * angle.sin(), z)
+            })
+            .collect();
+
+        let faces = quickhull(&points);
+        // Euler's formula for a triangulated sphere: F = 2V - 4.
+        assert_eq!(faces.len(), 2 * points.len() - 4);
+        let volume = hull_volume(&points, &faces);
+        assert!(volume < 4.0 / 3.0 * PI && volume > 4.0);
+    }
+
+    #[test]
+    fn quickhull_degenerate_test() {
+        let flat: Vec<Point3<f64>> = (0..10)
+            .map(|i| Point3::new(i as f64, (i * i) as f64, 0.0))
+            .collect();
+        assert!(quickhull(&flat).is_empty());
+
+        let few = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        assert!(quickhull(&few).is_empty());
+    }
+}
@@ -0,0 +1,873 @@
+//! This module provides polynomial and rational Bézier curves.
+//!
+//! It is part of the `curves` module. A `BezierCurve` of degree n has n + 1
+//! control points and is defined on the parameter interval [0, 1]. It starts at
+//! the first control point and ends at the last one. Rational curves also give
+//! each control point a positive weight, which lets them represent conic
+//! sections such as circular arcs exactly. Polynomial curves are the case where
+//! every weight is one.
+//!
+//! Evaluation and subdivision use de Casteljau's algorithm in homogeneous
+//! coordinates. Arc length uses adaptive Gauss-Legendre quadrature, and the
+//! bounding box and curve-curve intersections subdivide until the pieces are
+//! flat. `signed_area` gives the area swept between the curve and the origin.
+//! Summing it over a closed sequence of curves gives the enclosed area, which
+//! `area::get_area_bezier_path` does.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::curves::bezier::BezierCurve;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let arch = BezierCurve::cubic(
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(0.0, 4.0),
+//!     Point2::new(4.0, 4.0),
+//!     Point2::new(4.0, 0.0),
+//! );
+//!
+//! assert_eq!(arch.point_at(0.5), Point2::new(2.0, 3.0));
+//! assert_eq!(arch.derivative_at(0.5).y, 0.0);
+//!
+//! let bounds = arch.bounding_box();
+//! assert!((bounds.max().y - 3.0f64).abs() < 1e-9);
+//!
+//! let (left, right) = arch.split(0.5);
+//! assert_eq!(left.end(), right.start());
+//!
+//! let beam = BezierCurve::line(Point2::new(0.0, 1.5), Point2::new(4.0, 1.5));
+//! assert_eq!(arch.intersections(&beam, 1e-9).len(), 2);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a polynomial curve from its control points.
+//! - `rational`: Constructs a rational curve from its control points and weights.
+//! - `line`, `quadratic` and `cubic`: Construct curves of degree one, two and three.
+//! - `control_points`: Gets the control points.
+//! - `weights`: Gets the weights of the control points.
+//! - `degree`: Gets the degree.
+//! - `is_rational`: Checks whether any weight differs from one.
+//! - `start` and `end`: Get the end points.
+//! - `point_at`: Evaluates the curve at a parameter.
+//! - `derivative_at`: Evaluates the first derivative at a parameter.
+//! - `derivative`: Gets the derivative curve of a polynomial curve.
+//! - `split`: Subdivides the curve at a parameter.
+//! - `reversed`: Gets the curve traversed in the opposite direction.
+//! - `flatten`: Approximates the curve with a polyline.
+//! - `length`: Computes the arc length to a tolerance.
+//! - `bounding_box`: Computes the tight axis-aligned bounding box.
+//! - `signed_area`: Computes the signed area swept from the origin.
+//! - `intersections`: Finds the intersections with another curve.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::intersection::{
+    point_segment_distance, segment_intersection, SegmentIntersection,
+};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::vector::Vector2;
+use num_traits::Float;
+
+// Subdivision depth at which flattening and intersection stop refining.
+const MAX_DEPTH: usize = 48;
+
+// Most pairs of pieces intersection refines at once, which bounds its work where
+// the curves run along each other.
+const MAX_PAIRS: usize = 4096;
+
+// A control point in homogeneous coordinates (wx, wy, w).
+pub(crate) type Homogeneous<T> = [T; 3];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierCurve<T> {
+    points: Vec<Point2<T>>,
+    weights: Vec<T>,
+}
+
+impl<T> BezierCurve<T>
+where
+    T: Float,
+{
+    // Function to create a polynomial curve from its control points.
+    pub fn new(points: Vec<Point2<T>>) -> Result<Self, GeometryError> {
+        let weights = vec![T::one(); points.len()];
+        BezierCurve::rational(points, weights)
+    }
+
+    // Function to create a rational curve from its control points and their weights.
+    pub fn rational(points: Vec<Point2<T>>, weights: Vec<T>) -> Result<Self, GeometryError> {
+        if points.is_empty() {
+            return Err(GeometryError::new(
+                "A Bézier curve needs at least one control point",
+            ));
+        }
+        if points.len() != weights.len() {
+            return Err(GeometryError::new(
+                "A Bézier curve needs one weight per control point",
+            ));
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w <= T::zero()) {
+            return Err(GeometryError::new(
+                "Bézier weights must be finite, positive numbers",
+            ));
+        }
+        Ok(BezierCurve { points, weights })
+    }
+
+    // Function to create the straight line from `a` to `b`.
+    pub fn line(a: Point2<T>, b: Point2<T>) -> Self {
+        BezierCurve::from_points(vec![a, b])
+    }
+
+    // Function to create a quadratic curve.
+    pub fn quadratic(p0: Point2<T>, p1: Point2<T>, p2: Point2<T>) -> Self {
+        BezierCurve::from_points(vec![p0, p1, p2])
+    }
+
+    // Function to create a cubic curve.
+    pub fn cubic(p0: Point2<T>, p1: Point2<T>, p2: Point2<T>, p3: Point2<T>) -> Self {
+        BezierCurve::from_points(vec![p0, p1, p2, p3])
+    }
+
+    // Function to get the control points.
+    pub fn control_points(&self) -> &[Point2<T>] {
+        &self.points
+    }
+
+    // Function to get the weights of the control points.
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+
+    // Function to get the degree, one less than the number of control points.
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    // Function to check whether the curve is rational, with a weight other than one.
+    pub fn is_rational(&self) -> bool {
+        self.weights.iter().any(|&w| w != T::one())
+    }
+
+    // Function to get the point at parameter 0.
+    pub fn start(&self) -> Point2<T> {
+        self.points[0]
+    }
+
+    // Function to get the point at parameter 1.
+    pub fn end(&self) -> Point2<T> {
+        self.points[self.points.len() - 1]
+    }
+
+    // Function to evaluate the curve at parameter t.
+    pub fn point_at(&self, t: T) -> Point2<T> {
+        let mut level = self.homogeneous();
+        while level.len() > 1 {
+            level = casteljau_step(&level, t);
+        }
+        project(level[0])
+    }
+
+    // Function to evaluate the first derivative with respect to the parameter at t.
+    pub fn derivative_at(&self, t: T) -> Vector2<T> {
+        let degree = self.degree();
+        if degree == 0 {
+            return Vector2::zero();
+        }
+        let mut level = self.homogeneous();
+        while level.len() > 2 {
+            level = casteljau_step(&level, t);
+        }
+        let value = lerp(level[0], level[1], t);
+        let n = T::from(degree).unwrap();
+        let slope: Homogeneous<T> = [0, 1, 2].map(|k| n * (level[1][k] - level[0][k]));
+        derivative_of_projection(value, slope)
+    }
+
+    // Function to get the derivative of a polynomial curve, one degree lower.
+    //
+    // The control points of the result are the derivative vectors as points. Rational
+    // curves have no derivative of this form and give None.
+    pub fn derivative(&self) -> Option<Self> {
+        if self.is_rational() {
+            return None;
+        }
+        if self.degree() == 0 {
+            return Some(BezierCurve::from_points(vec![Point2::origin()]));
+        }
+        let n = T::from(self.degree()).unwrap();
+        let points = self
+            .points
+            .windows(2)
+            .map(|pair| Point2::from((pair[1] - pair[0]).scale(n)))
+            .collect();
+        Some(BezierCurve::from_points(points))
+    }
+
+    // Function to split the curve at parameter t into the parts before and after it.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let (left, right) = casteljau_split(&self.homogeneous(), t);
+        (
+            BezierCurve::from_homogeneous(&left),
+            BezierCurve::from_homogeneous(&right),
+        )
+    }
+
+    // Function to get the same curve traversed from end to start.
+    pub fn reversed(&self) -> Self {
+        BezierCurve {
+            points: self.points.iter().rev().copied().collect(),
+            weights: self.weights.iter().rev().copied().collect(),
+        }
+    }
+
+    // Function to approximate the curve with a polyline within a distance tolerance.
+    //
+    // The polyline starts at `start` and ends at `end`.
+    pub fn flatten(&self, tolerance: T) -> Vec<Point2<T>> {
+        let mut polyline = vec![self.start()];
+        flatten_piece(&Piece::whole(self), tolerance, 0, &mut polyline);
+        polyline
+    }
+
+    // Function to get the arc length, accurate to about the given tolerance.
+    pub fn length(&self, tolerance: T) -> T {
+        let speed = |t: T| self.derivative_at(t).norm();
+        integrate(&speed, T::zero(), T::one(), tolerance, MAX_DEPTH)
+    }
+
+    // Function to get the smallest axis-aligned box containing the curve.
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        let whole = Piece::whole(self);
+        let hull = whole.bounds();
+        let precision = (hull.width() + hull.height()) * T::from(1e-12).unwrap();
+        let mut bounds = BoundingBox::new(self.start(), self.end());
+        expand_bounds(&whole, &mut bounds, precision, 0);
+        bounds
+    }
+
+    // Function to get the signed area swept between the curve and the origin.
+    //
+    // This is half the integral of x dy - y dx along the curve. It is positive when the
+    // curve turns counter-clockwise around the origin. Over a closed sequence of curves
+    // the sum is the enclosed area.
+    pub fn signed_area(&self) -> T {
+        if self.is_rational() {
+            let hull = Piece::whole(self).bounds();
+            let extent = hull.width() + hull.height() + hull.min().x.abs() + hull.min().y.abs();
+            let tolerance = extent * extent * T::from(1e-12).unwrap();
+            let sweep = |t: T| {
+                let point = self.point_at(t);
+                let tangent = self.derivative_at(t);
+                (point.x * tangent.y - point.y * tangent.x) / (T::one() + T::one())
+            };
+            return integrate(&sweep, T::zero(), T::one(), tolerance, MAX_DEPTH);
+        }
+        // Exact integral of products of Bernstein polynomials of degrees n and n - 1.
+        let n = self.degree();
+        let mut sum = T::zero();
+        for (i, p) in self.points.iter().enumerate() {
+            for (j, pair) in self.points.windows(2).enumerate() {
+                let delta = pair[1] - pair[0];
+                let coefficient = binomial(n, i) * binomial(n - 1, j) / binomial(2 * n - 1, i + j);
+                sum = sum + T::from(coefficient).unwrap() * (p.x * delta.y - p.y * delta.x);
+            }
+        }
+        sum / T::from(4).unwrap()
+    }
+
+    // Function to find where the curve meets another curve.
+    //
+    // Returns pairs of parameters (on this curve, on the other), in order along this
+    // curve. Crossings closer together than the tolerance are reported once, and
+    // stretches where the curves coincide to within the tolerance are not reported.
+    // A tangential touch is reported where the flattened curves meet, but may be
+    // missed when the flattening passes within the tolerance without meeting.
+    pub fn intersections(&self, other: &Self, tolerance: T) -> Vec<(T, T)> {
+        let (mut hits, mut stretches) = (Vec::new(), Vec::new());
+        intersect_pieces(
+            Piece::whole(self),
+            Piece::whole(other),
+            tolerance,
+            &mut hits,
+            &mut stretches,
+        );
+        // Chords along a shared stretch cross where pieces meet, so drop those hits.
+        let stretches = merge_intervals(stretches);
+        hits.retain(|&(s, _)| {
+            let index = stretches.partition_point(|&(_, end)| end < s);
+            stretches.get(index).is_none_or(|&(start, _)| start > s)
+        });
+        merge_close(hits, |t| self.point_at(t), tolerance)
+    }
+
+    fn from_points(points: Vec<Point2<T>>) -> Self {
+        let weights = vec![T::one(); points.len()];
+        BezierCurve { points, weights }
+    }
+
+    pub(crate) fn from_homogeneous(control: &[Homogeneous<T>]) -> Self {
+        BezierCurve {
+            points: control.iter().map(|&h| project(h)).collect(),
+            weights: control.iter().map(|h| h[2]).collect(),
+        }
+    }
+
+    fn homogeneous(&self) -> Vec<Homogeneous<T>> {
+        self.points
+            .iter()
+            .zip(&self.weights)
+            .map(|(p, &w)| [p.x * w, p.y * w, w])
+            .collect()
+    }
+}
+
+// A part of a curve, with the parameter interval it covers on the whole curve.
+#[derive(Debug, Clone)]
+struct Piece<T> {
+    control: Vec<Homogeneous<T>>,
+    start: T,
+    end: T,
+}
+
+impl<T: Float> Piece<T> {
+    fn whole(curve: &BezierCurve<T>) -> Self {
+        Piece {
+            control: curve.homogeneous(),
+            start: T::zero(),
+            end: T::one(),
+        }
+    }
+
+    // The box around the control points, which contains the piece for positive weights.
+    fn bounds(&self) -> BoundingBox<T> {
+        let points: Vec<Point2<T>> = self.control.iter().map(|&h| project(h)).collect();
+        BoundingBox::from_points(&points).unwrap()
+    }
+
+    fn chord(&self) -> (Point2<T>, Point2<T>) {
+        (
+            project(self.control[0]),
+            project(self.control[self.control.len() - 1]),
+        )
+    }
+
+    // The farthest a control point lies from the chord, which bounds how far the
+    // piece strays from it.
+    fn deviation(&self) -> T {
+        let (a, b) = self.chord();
+        self.control.iter().fold(T::zero(), |farthest, &h| {
+            farthest.max(point_segment_distance(project(h), a, b))
+        })
+    }
+
+    fn is_flat(&self, tolerance: T) -> bool {
+        self.deviation() <= tolerance
+    }
+
+    fn halves(&self) -> (Self, Self) {
+        let half = T::from(0.5).unwrap();
+        let middle = self.start + (self.end - self.start) * half;
+        let (left, right) = casteljau_split(&self.control, half);
+        (
+            Piece {
+                control: left,
+                start: self.start,
+                end: middle,
+            },
+            Piece {
+                control: right,
+                start: middle,
+                end: self.end,
+            },
+        )
+    }
+
+    // Whether the pieces have the same control polygon, in either direction, so
+    // trace the same stretch of curve.
+    fn matches(&self, other: &Self, tolerance: T) -> bool {
+        let n = self.control.len();
+        if other.control.len() != n {
+            return false;
+        }
+        // Weights are compared relative to the first, since scaling them all leaves the curve.
+        let close = |h: Homogeneous<T>, g: Homogeneous<T>, g_first: T| {
+            project(h).distance(project(g)) <= tolerance
+                && (h[2] / self.control[0][2] - g[2] / g_first).abs() <= tolerance
+        };
+        let (first, last) = (other.control[0][2], other.control[n - 1][2]);
+        (0..n).all(|i| close(self.control[i], other.control[i], first))
+            || (0..n).all(|i| close(self.control[i], other.control[n - 1 - i], last))
+    }
+
+    // The parameter of the point on the chord closest to `point`.
+    fn parameter_near(&self, point: Point2<T>) -> T {
+        let (a, b) = self.chord();
+        let ab = b - a;
+        let length_squared = ab.norm_squared();
+        let s = if length_squared.is_zero() {
+            T::zero()
+        } else {
+            ((point - a).dot(ab) / length_squared)
+                .max(T::zero())
+                .min(T::one())
+        };
+        self.start + (self.end - self.start) * s
+    }
+}
+
+fn flatten_piece<T: Float>(
+    piece: &Piece<T>,
+    tolerance: T,
+    depth: usize,
+    polyline: &mut Vec<Point2<T>>,
+) {
+    if depth == MAX_DEPTH || piece.is_flat(tolerance) {
+        polyline.push(piece.chord().1);
+        return;
+    }
+    let (left, right) = piece.halves();
+    flatten_piece(&left, tolerance, depth + 1, polyline);
+    flatten_piece(&right, tolerance, depth + 1, polyline);
+}
+
+// Grows `bounds` until it contains the piece, subdividing where the control points stick out.
+fn expand_bounds<T: Float>(
+    piece: &Piece<T>,
+    bounds: &mut BoundingBox<T>,
+    precision: T,
+    depth: usize,
+) {
+    let hull = piece.bounds();
+    if bounds.contains(&hull) {
+        return;
+    }
+    if depth == MAX_DEPTH || hull.width() + hull.height() <= precision {
+        *bounds = bounds.union(&hull);
+        return;
+    }
+    let (left, right) = piece.halves();
+    bounds.expand_to(left.chord().1);
+    expand_bounds(&left, bounds, precision, depth + 1);
+    expand_bounds(&right, bounds, precision, depth + 1);
+}
+
+// Subdivides both curves level by level, keeping the pairs of pieces whose boxes
+// still overlap, until each pair is flat enough to resolve by its chords.
+fn intersect_pieces<T: Float>(
+    a: Piece<T>,
+    b: Piece<T>,
+    tolerance: T,
+    hits: &mut Vec<(T, T)>,
+    stretches: &mut Vec<(T, T)>,
+) {
+    let mut pairs = vec![(a, b)];
+    for depth in 0..=MAX_DEPTH {
+        let mut next = Vec::new();
+        for (a, b) in pairs {
+            if !a.bounds().intersects(&b.bounds()) {
+                continue;
+            }
+            if a.matches(&b, tolerance) {
+                stretches.push((a.start, a.end));
+                continue;
+            }
+            let (a_flat, b_flat) = (a.is_flat(tolerance), b.is_flat(tolerance));
+            if (a_flat && b_flat) || depth == MAX_DEPTH {
+                resolve_chords(&a, &b, tolerance, hits, stretches);
+                continue;
+            }
+            let parts = |piece: Piece<T>, flat: bool| {
+                if flat {
+                    vec![piece]
+                } else {
+                    let (left, right) = piece.halves();
+                    vec![left, right]
+                }
+            };
+            for a_part in parts(a, a_flat) {
+                for b_part in parts(b.clone(), b_flat) {
+                    next.push((a_part.clone(), b_part));
+                }
+            }
+        }
+        if next.len() > MAX_PAIRS {
+            // Curves that run along each other keep every piece of the shared stretch
+            // alive, so drop the pairs that coincide at the precision reached so far.
+            next.retain(|(a, b)| {
+                let coarse = tolerance.max(a.deviation()).max(b.deviation());
+                let coincide = runs_along(a.chord(), b.chord(), coarse);
+                if coincide {
+                    stretches.push((a.start, a.end));
+                }
+                !coincide
+            });
+        }
+        if next.len() > MAX_PAIRS {
+            // Still too many: settle for the chords rather than let the work explode.
+            for (a, b) in &next {
+                let coarse = tolerance.max(a.deviation()).max(b.deviation());
+                resolve_chords(a, b, coarse, hits, stretches);
+            }
+            return;
+        }
+        pairs = next;
+    }
+}
+
+// Records where the chords of two pieces cross, or the stretch where they run along
+// each other.
+fn resolve_chords<T: Float>(
+    a: &Piece<T>,
+    b: &Piece<T>,
+    tolerance: T,
+    hits: &mut Vec<(T, T)>,
+    stretches: &mut Vec<(T, T)>,
+) {
+    let (p1, p2) = a.chord();
+    let (q1, q2) = b.chord();
+    if runs_along((p1, p2), (q1, q2), tolerance) {
+        stretches.push((a.start, a.end));
+    } else if let SegmentIntersection::Point(point) = segment_intersection(p1, p2, q1, q2) {
+        hits.push((a.parameter_near(point), b.parameter_near(point)));
+    }
+}
+
+// Whether two chords of flat pieces lie along each other for more than the
+// tolerance, as where two curves coincide, rather than crossing. Each piece is
+// within the tolerance of its chord, so coinciding chords are within twice it.
+fn runs_along<T: Float>(
+    a: (Point2<T>, Point2<T>),
+    b: (Point2<T>, Point2<T>),
+    tolerance: T,
+) -> bool {
+    let ((p1, p2), (q1, q2)) = if a.0.distance(a.1) >= b.0.distance(b.1) {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let length = p1.distance(p2);
+    if length <= tolerance {
+        return false;
+    }
+    let direction = (p2 - p1).scale(length.recip());
+    let (s1, s2) = ((q1 - p1).dot(direction), (q2 - p1).dot(direction));
+    let (low, high) = (s1.min(s2).max(T::zero()), s1.max(s2).min(length));
+    if high - low <= tolerance {
+        return false;
+    }
+    // The offset of the shorter chord from the longer one where they overlap.
+    let (c1, c2) = ((q1 - p1).cross(direction), (q2 - p1).cross(direction));
+    let across = |s: T| (c1 + (c2 - c1) * (s - s1) / (s2 - s1)).abs();
+    let limit = tolerance + tolerance;
+    across(low) <= limit && across(high) <= limit
+}
+
+// Sorts parameter intervals and joins those that overlap or touch.
+fn merge_intervals<T: Float>(mut intervals: Vec<(T, T)>) -> Vec<(T, T)> {
+    intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+// Sorts intersection parameters along the first curve and drops hits within the
+// tolerance of the previous one, which arise where a crossing falls on a subdivision point.
+pub(crate) fn merge_close<T, F>(mut hits: Vec<(T, T)>, point_at: F, tolerance: T) -> Vec<(T, T)>
+where
+    T: Float,
+    F: Fn(T) -> Point2<T>,
+{
+    hits.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(hits.len());
+    for hit in hits {
+        match merged.last() {
+            Some(last) if point_at(last.0).distance(point_at(hit.0)) <= tolerance => {}
+            _ => merged.push(hit),
+        }
+    }
+    merged
+}
+
+// One round of de Casteljau's algorithm, one control point shorter.
+fn casteljau_step<T: Float>(control: &[Homogeneous<T>], t: T) -> Vec<Homogeneous<T>> {
+    control
+        .windows(2)
+        .map(|pair| lerp(pair[0], pair[1], t))
+        .collect()
+}
+
+// Control points of the parts of a curve before and after parameter t.
+fn casteljau_split<T: Float>(
+    control: &[Homogeneous<T>],
+    t: T,
+) -> (Vec<Homogeneous<T>>, Vec<Homogeneous<T>>) {
+    let mut level = control.to_vec();
+    let mut left = vec![level[0]];
+    let mut right = vec![level[level.len() - 1]];
+    while level.len() > 1 {
+        level = casteljau_step(&level, t);
+        left.push(level[0]);
+        right.push(level[level.len() - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+pub(crate) fn lerp<T: Float>(a: Homogeneous<T>, b: Homogeneous<T>, t: T) -> Homogeneous<T> {
+    [0, 1, 2].map(|k| a[k] + (b[k] - a[k]) * t)
+}
+
+pub(crate) fn project<T: Float>(h: Homogeneous<T>) -> Point2<T> {
+    Point2::new(h[0] / h[2], h[1] / h[2])
+}
+
+// The derivative of (wx, wy) / w by the quotient rule, given a homogeneous value and its derivative.
+pub(crate) fn derivative_of_projection<T: Float>(
+    value: Homogeneous<T>,
+    slope: Homogeneous<T>,
+) -> Vector2<T> {
+    let point = project(value);
+    Vector2::new(
+        (slope[0] - slope[2] * point.x) / value[2],
+        (slope[1] - slope[2] * point.y) / value[2],
+    )
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1f64, |product, i| product * (n - i) as f64 / (i + 1) as f64)
+}
+
+// Five-point Gauss-Legendre quadrature on [a, b].
+fn gauss_legendre<T: Float, F: Fn(T) -> T>(f: &F, a: T, b: T) -> T {
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.568_888_888_888_888_9),
+        (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+        (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    ];
+    let half = (b - a) / (T::one() + T::one());
+    let middle = a + half;
+    NODES.iter().fold(T::zero(), |sum, &(node, weight)| {
+        sum + T::from(weight).unwrap() * f(middle + half * T::from(node).unwrap())
+    }) * half
+}
+
+// Integrates f over [a, b], halving intervals until the two estimates agree.
+fn integrate<T: Float, F: Fn(T) -> T>(f: &F, a: T, b: T, tolerance: T, depth: usize) -> T {
+    let middle = (a + b) / (T::one() + T::one());
+    let whole = gauss_legendre(f, a, b);
+    let halves = gauss_legendre(f, a, middle) + gauss_legendre(f, middle, b);
+    if depth == 0 || (halves - whole).abs() <= tolerance {
+        return halves;
+    }
+    let tolerance = tolerance / (T::one() + T::one());
+    integrate(f, a, middle, tolerance, depth - 1) + integrate(f, middle, b, tolerance, depth - 1)
+}
+
+#[cfg(test)]
+mod geometry_bezier_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+    use std::time::{Duration, Instant};
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn assert_close(a: f64, b: f64, tolerance: f64) {
+        assert!((a - b).abs() <= tolerance, "{} != {}", a, b);
+    }
+
+    // The quarter of the unit circle from (1, 0) to (0, 1).
+    fn quarter_circle() -> BezierCurve<f64> {
+        BezierCurve::rational(
+            vec![p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)],
+            vec![1.0, FRAC_1_SQRT_2, 1.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bezier_evaluation_test() {
+        let curve = BezierCurve::cubic(p(0.0, 0.0), p(1.0, 3.0), p(3.0, 3.0), p(4.0, 0.0));
+        assert_eq!(curve.degree(), 3);
+        assert_eq!(curve.point_at(0.0), p(0.0, 0.0));
+        assert_eq!(curve.point_at(1.0), p(4.0, 0.0));
+        assert_eq!(curve.point_at(0.5), p(2.0, 2.25));
+        assert_eq!(curve.derivative_at(0.0), Vector2::new(3.0, 9.0));
+        assert_eq!(curve.derivative_at(0.5), Vector2::new(4.5, 0.0));
+
+        let derivative = curve.derivative().unwrap();
+        assert_eq!(derivative.degree(), 2);
+        for t in [0.1, 0.4, 0.8] {
+            assert!(Point2::from(curve.derivative_at(t)).distance(derivative.point_at(t)) < 1e-12);
+        }
+
+        let circle = quarter_circle();
+        assert!(circle.is_rational());
+        assert!(circle.derivative().is_none());
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let point = circle.point_at(t);
+            let tangent = circle.derivative_at(t);
+            assert_close(point.to_vector().norm(), 1.0, 1e-12);
+            assert_close(point.to_vector().dot(tangent), 0.0, 1e-12);
+        }
+
+        assert!(BezierCurve::<f64>::new(vec![]).is_err());
+        assert!(BezierCurve::rational(vec![p(0.0, 0.0), p(1.0, 0.0)], vec![1.0, 0.0]).is_err());
+        assert!(BezierCurve::rational(vec![p(0.0, 0.0)], vec![1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn bezier_split_test() {
+        for curve in [
+            BezierCurve::cubic(p(0.0, 0.0), p(1.0, 3.0), p(3.0, -1.0), p(4.0, 2.0)),
+            quarter_circle(),
+        ] {
+            let (left, right) = curve.split(0.3);
+            for i in 0..=10 {
+                let s = i as f64 / 10.0;
+                assert!(left.point_at(s).distance(curve.point_at(0.3 * s)) < 1e-12);
+                assert!(right.point_at(s).distance(curve.point_at(0.3 + 0.7 * s)) < 1e-12);
+            }
+            let reversed = curve.reversed();
+            assert!(reversed.point_at(0.25).distance(curve.point_at(0.75)) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn bezier_length_test() {
+        let line = BezierCurve::line(p(1.0, 1.0), p(4.0, 5.0));
+        assert_close(line.length(1e-12), 5.0, 1e-12);
+        assert_close(quarter_circle().length(1e-12), PI / 2.0, 1e-10);
+
+        // A fine polyline approaches the length from below.
+        let curve = BezierCurve::cubic(p(0.0, 0.0), p(2.0, 5.0), p(3.0, -4.0), p(6.0, 1.0));
+        let polyline = curve.flatten(1e-7);
+        let chords: f64 = polyline.windows(2).map(|w| w[0].distance(w[1])).sum();
+        assert_eq!(polyline[0], curve.start());
+        assert_eq!(polyline[polyline.len() - 1], curve.end());
+        assert_close(curve.length(1e-10), chords, 1e-5);
+        assert!(chords <= curve.length(1e-10));
+    }
+
+    #[test]
+    fn bezier_bounding_box_test() {
+        let arch = BezierCurve::cubic(p(0.0, 0.0), p(0.0, 1.0), p(1.0, 1.0), p(1.0, 0.0));
+        let bounds = arch.bounding_box();
+        assert_eq!(bounds.min(), p(0.0, 0.0));
+        assert_close(bounds.max().x, 1.0, 1e-12);
+        assert_close(bounds.max().y, 0.75, 1e-10);
+
+        let bounds = quarter_circle().bounding_box();
+        assert_eq!(bounds, BoundingBox::new(p(0.0, 0.0), p(1.0, 1.0)));
+
+        // An S-curve overshoots both ends.
+        let s = BezierCurve::cubic(p(0.0, 0.0), p(3.0, 0.0), p(-2.0, 1.0), p(1.0, 1.0));
+        let bounds = s.bounding_box();
+        let samples: Vec<Point2<f64>> = (0..=10000)
+            .map(|i| s.point_at(i as f64 / 10000.0))
+            .collect();
+        let sampled = BoundingBox::from_points(&samples).unwrap();
+        assert!(bounds.contains(&sampled));
+        assert_close(bounds.min().x, sampled.min().x, 1e-8);
+        assert_close(bounds.max().x, sampled.max().x, 1e-8);
+    }
+
+    #[test]
+    fn bezier_area_test() {
+        // A parabolic arch closed by its base encloses 2/3 of the bounding rectangle.
+        let arch = BezierCurve::quadratic(p(0.0, 0.0), p(1.0, 2.0), p(2.0, 0.0));
+        let base = BezierCurve::line(p(2.0, 0.0), p(0.0, 0.0));
+        assert_close(arch.signed_area() + base.signed_area(), -4.0 / 3.0, 1e-12);
+
+        // The quarter circle sweeps a quarter of the unit disc from the origin.
+        assert_close(quarter_circle().signed_area(), PI / 4.0, 1e-12);
+    }
+
+    #[test]
+    fn bezier_intersections_test() {
+        let a = BezierCurve::cubic(p(0.0, 0.0), p(1.0, 2.0), p(2.0, -2.0), p(3.0, 0.0));
+        let axis = BezierCurve::line(p(-1.0, 0.0), p(4.0, 0.0));
+        let hits = a.intersections(&axis, 1e-10);
+        assert_eq!(hits.len(), 3);
+        for &(s, t) in &hits {
+            assert!(a.point_at(s).distance(axis.point_at(t)) < 1e-9);
+            assert!(a.point_at(s).y.abs() < 1e-9);
+        }
+        assert_close(hits[1].0, 0.5, 1e-9);
+
+        // The circle meets the diagonal once.
+        let diagonal = BezierCurve::line(p(0.0, 0.0), p(2.0, 2.0));
+        let hits = quarter_circle().intersections(&diagonal, 1e-10);
+        assert_eq!(hits.len(), 1);
+        assert_close(quarter_circle().point_at(hits[0].0).x, FRAC_1_SQRT_2, 1e-9);
+
+        let far = BezierCurve::line(p(5.0, 5.0), p(6.0, 6.0));
+        assert!(a.intersections(&far, 1e-10).is_empty());
+    }
+
+    #[test]
+    fn bezier_coincident_intersections_test() {
+        let q = BezierCurve::quadratic(p(0.0, 0.0), p(1.0, 2.0), p(2.0, 0.0));
+        assert!(q.intersections(&q, 1e-9).is_empty());
+        assert!(q.intersections(&q.reversed(), 1e-9).is_empty());
+        assert!(quarter_circle()
+            .intersections(&quarter_circle(), 1e-9)
+            .is_empty());
+
+        // A stretch of the curve overlaps it without crossing it.
+        let (_, tail) = q.split(0.3);
+        let (middle, _) = tail.split(0.6);
+        assert!(q.intersections(&middle, 1e-9).is_empty());
+        assert!(middle.intersections(&q, 1e-9).is_empty());
+
+        let axis = BezierCurve::line(p(-1.0, 0.0), p(1.0, 0.0));
+        let overlap = BezierCurve::line(p(0.0, 0.0), p(2.0, 0.0));
+        assert!(axis.intersections(&overlap, 1e-9).is_empty());
+
+        // A tangential touch that falls on a subdivision point is reported.
+        let arch = BezierCurve::cubic(p(0.0, 0.0), p(0.0, 4.0), p(4.0, 4.0), p(4.0, 0.0));
+        let hits = arch.intersections(&BezierCurve::line(p(0.0, 3.0), p(4.0, 3.0)), 1e-9);
+        assert_eq!(hits, vec![(0.5, 0.5)]);
+
+        // Curves that only share an end point still meet there.
+        let (left, right) = q.split(0.5);
+        let hits = left.intersections(&right, 1e-9);
+        assert_eq!(hits.len(), 1);
+        assert_close(hits[0].0, 1.0, 1e-9);
+        assert_close(hits[0].1, 0.0, 1e-9);
+    }
+
+    #[test]
+    fn bezier_overlap_small_tolerance_test() {
+        // Refining a shared stretch down to a tiny tolerance stays fast.
+        let c = BezierCurve::cubic(p(0.0, 0.0), p(1.0, 3.0), p(3.0, -1.0), p(4.0, 2.0));
+        let (_, tail) = c.split(0.3);
+        let (middle, _) = tail.split(0.6);
+        let started = Instant::now();
+        for tolerance in [1e-9, 1e-12, 1e-14] {
+            assert!(c.intersections(&middle, tolerance).is_empty());
+            assert!(middle.intersections(&c, tolerance).is_empty());
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        // Crossings are still found to the full tolerance.
+        let line = BezierCurve::line(p(0.0, 1.0), p(4.0, 1.0));
+        let hits = c.intersections(&line, 1e-14);
+        assert_eq!(hits.len(), 3);
+        for (s, _) in hits {
+            assert_close(c.point_at(s).y, 1.0, 1e-13);
+        }
+    }
+}
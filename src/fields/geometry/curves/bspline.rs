@@ -0,0 +1,561 @@
+//! This module provides B-spline and NURBS curves.
+//!
+//! It is part of the `curves` module. A `BSpline` of degree p is a chain of
+//! degree p polynomial pieces joined at the values of a non-decreasing knot
+//! vector. The curve has n control points and n + p + 1 knots, and it is
+//! defined on the domain from knot p to knot n. `uniform` builds a clamped
+//! uniform knot vector on [0, 1], so the curve starts and ends at the end
+//! control points. `new` accepts any non-uniform knot vector. `nurbs` also gives
+//! each control point a positive weight, which makes the curve a non-uniform
+//! rational B-spline able to represent circles and other conics exactly.
+//!
+//! Evaluation uses de Boor's algorithm and `insert_knot` uses Boehm's algorithm.
+//! `to_bezier` inserts knots until each piece is a separate (rational)
+//! `BezierCurve`. Arc length, bounding box, area and intersections work on those
+//! pieces.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::curves::bspline::BSpline;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let h = 0.5f64.sqrt();
+//! let circle = BSpline::nurbs(
+//!     2,
+//!     vec![
+//!         Point2::new(1.0, 0.0),
+//!         Point2::new(1.0, 1.0),
+//!         Point2::new(0.0, 1.0),
+//!         Point2::new(-1.0, 1.0),
+//!         Point2::new(-1.0, 0.0),
+//!         Point2::new(-1.0, -1.0),
+//!         Point2::new(0.0, -1.0),
+//!         Point2::new(1.0, -1.0),
+//!         Point2::new(1.0, 0.0),
+//!     ],
+//!     vec![1.0, h, 1.0, h, 1.0, h, 1.0, h, 1.0],
+//!     vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0],
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(circle.point_at(0.5), Point2::new(-1.0, 0.0));
+//! assert_eq!(circle.to_bezier().len(), 4);
+//! assert!((circle.signed_area() - std::f64::consts::PI).abs() < 1e-9);
+//! assert!((circle.length(1e-10) - 2.0 * std::f64::consts::PI).abs() < 1e-9);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a B-spline from its degree, control points and knots.
+//! - `uniform`: Constructs a B-spline with a clamped uniform knot vector.
+//! - `nurbs`: Constructs a rational B-spline with weighted control points.
+//! - `degree`: Gets the degree.
+//! - `control_points`: Gets the control points.
+//! - `weights`: Gets the weights of the control points.
+//! - `knots`: Gets the knot vector.
+//! - `domain`: Gets the parameter interval the curve is defined on.
+//! - `is_rational`: Checks whether any weight differs from one.
+//! - `point_at`: Evaluates the curve at a parameter.
+//! - `derivative_at`: Evaluates the first derivative at a parameter.
+//! - `insert_knot`: Inserts a knot without changing the curve.
+//! - `to_bezier`: Splits the curve into Bézier pieces.
+//! - `length`: Computes the arc length to a tolerance.
+//! - `bounding_box`: Computes the tight axis-aligned bounding box.
+//! - `signed_area`: Computes the signed area swept from the origin.
+//! - `intersections`: Finds the intersections with another B-spline.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::curves::bezier::{
+    derivative_of_projection, lerp, merge_close, project, BezierCurve, Homogeneous,
+};
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::vector::Vector2;
+use num_traits::Float;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<T> {
+    degree: usize,
+    points: Vec<Point2<T>>,
+    weights: Vec<T>,
+    knots: Vec<T>,
+}
+
+impl<T> BSpline<T>
+where
+    T: Float,
+{
+    // Function to create a B-spline from its degree, control points and knot vector.
+    pub fn new(
+        degree: usize,
+        points: Vec<Point2<T>>,
+        knots: Vec<T>,
+    ) -> Result<Self, GeometryError> {
+        let weights = vec![T::one(); points.len()];
+        BSpline::nurbs(degree, points, weights, knots)
+    }
+
+    // Function to create a B-spline with a clamped uniform knot vector on [0, 1].
+    //
+    // The end knots are repeated degree + 1 times and the interior knots are evenly spaced.
+    pub fn uniform(degree: usize, points: Vec<Point2<T>>) -> Result<Self, GeometryError> {
+        let spans = points.len().saturating_sub(degree).max(1);
+        let interior = (1..spans).map(|i| T::from(i).unwrap() / T::from(spans).unwrap());
+        let knots = std::iter::repeat_n(T::zero(), degree + 1)
+            .chain(interior)
+            .chain(std::iter::repeat_n(T::one(), degree + 1))
+            .collect();
+        BSpline::new(degree, points, knots)
+    }
+
+    // Function to create a non-uniform rational B-spline from weighted control points.
+    pub fn nurbs(
+        degree: usize,
+        points: Vec<Point2<T>>,
+        weights: Vec<T>,
+        knots: Vec<T>,
+    ) -> Result<Self, GeometryError> {
+        let n = points.len();
+        if n <= degree {
+            return Err(GeometryError::new(
+                "A B-spline needs more control points than its degree",
+            ));
+        }
+        if weights.len() != n {
+            return Err(GeometryError::new(
+                "A B-spline needs one weight per control point",
+            ));
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w <= T::zero()) {
+            return Err(GeometryError::new(
+                "B-spline weights must be finite, positive numbers",
+            ));
+        }
+        if knots.len() != n + degree + 1 {
+            return Err(GeometryError::new(&format!(
+                "A B-spline of degree {} with {} control points needs {} knots",
+                degree,
+                n,
+                n + degree + 1
+            )));
+        }
+        if knots.iter().any(|k| !k.is_finite()) || knots.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(GeometryError::new(
+                "B-spline knots must be finite and non-decreasing",
+            ));
+        }
+        if knots[degree] >= knots[n] {
+            return Err(GeometryError::new(
+                "The knot vector leaves the B-spline with an empty domain",
+            ));
+        }
+        Ok(BSpline {
+            degree,
+            points,
+            weights,
+            knots,
+        })
+    }
+
+    // Function to get the degree.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    // Function to get the control points.
+    pub fn control_points(&self) -> &[Point2<T>] {
+        &self.points
+    }
+
+    // Function to get the weights of the control points.
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+
+    // Function to get the knot vector.
+    pub fn knots(&self) -> &[T] {
+        &self.knots
+    }
+
+    // Function to get the first and last parameter of the curve.
+    pub fn domain(&self) -> (T, T) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    // Function to check whether the curve is rational, with a weight other than one.
+    pub fn is_rational(&self) -> bool {
+        self.weights.iter().any(|&w| w != T::one())
+    }
+
+    // Function to evaluate the curve at parameter t, clamped to the domain.
+    pub fn point_at(&self, t: T) -> Point2<T> {
+        let t = self.clamp(t);
+        let span = self.span(t);
+        project(de_boor(
+            self.degree,
+            &self.homogeneous(),
+            &self.knots,
+            span,
+            t,
+        ))
+    }
+
+    // Function to evaluate the first derivative with respect to the parameter at t.
+    pub fn derivative_at(&self, t: T) -> Vector2<T> {
+        if self.degree == 0 {
+            return Vector2::zero();
+        }
+        let t = self.clamp(t);
+        let span = self.span(t);
+        let control = self.homogeneous();
+        let value = de_boor(self.degree, &control, &self.knots, span, t);
+
+        // The derivative is a B-spline of one degree lower on the inner knots.
+        let p = T::from(self.degree).unwrap();
+        let differences: Vec<Homogeneous<T>> = (0..control.len() - 1)
+            .map(|i| {
+                let width = self.knots[i + self.degree + 1] - self.knots[i + 1];
+                if width.is_zero() {
+                    [T::zero(); 3]
+                } else {
+                    [0, 1, 2].map(|k| p * (control[i + 1][k] - control[i][k]) / width)
+                }
+            })
+            .collect();
+        let inner = &self.knots[1..self.knots.len() - 1];
+        let slope = de_boor(self.degree - 1, &differences, inner, span - 1, t);
+        derivative_of_projection(value, slope)
+    }
+
+    // Function to insert a knot at t, giving the same curve with one more control point.
+    pub fn insert_knot(&self, t: T) -> Result<Self, GeometryError> {
+        let (start, end) = self.domain();
+        if !(start <= t && t <= end) {
+            return Err(GeometryError::new(
+                "A knot can only be inserted inside the domain",
+            ));
+        }
+        let (p, k) = (self.degree, self.span(t));
+        let control = self.homogeneous();
+        let inserted: Vec<Homogeneous<T>> = (0..=control.len())
+            .map(|i| {
+                if i + p <= k {
+                    control[i]
+                } else if i > k {
+                    control[i - 1]
+                } else {
+                    let alpha = (t - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+                    lerp(control[i - 1], control[i], alpha)
+                }
+            })
+            .collect();
+        let mut knots = self.knots.clone();
+        knots.insert(k + 1, t);
+        Ok(BSpline {
+            degree: p,
+            points: inserted.iter().map(|&h| project(h)).collect(),
+            weights: inserted.iter().map(|h| h[2]).collect(),
+            knots,
+        })
+    }
+
+    // Function to split the curve into one Bézier curve per non-empty knot span, in order.
+    pub fn to_bezier(&self) -> Vec<BezierCurve<T>> {
+        self.pieces()
+            .into_iter()
+            .map(|(curve, _, _)| curve)
+            .collect()
+    }
+
+    // Function to get the arc length, accurate to about the given tolerance.
+    pub fn length(&self, tolerance: T) -> T {
+        let pieces = self.pieces();
+        let share = tolerance / T::from(pieces.len()).unwrap();
+        pieces
+            .iter()
+            .fold(T::zero(), |sum, (curve, _, _)| sum + curve.length(share))
+    }
+
+    // Function to get the smallest axis-aligned box containing the curve.
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        self.to_bezier()
+            .iter()
+            .map(|curve| curve.bounding_box())
+            .reduce(|a, b| a.union(&b))
+            .unwrap()
+    }
+
+    // Function to get the signed area swept between the curve and the origin.
+    //
+    // For a closed curve this is the enclosed area, positive when counter-clockwise.
+    pub fn signed_area(&self) -> T {
+        self.to_bezier()
+            .iter()
+            .fold(T::zero(), |sum, curve| sum + curve.signed_area())
+    }
+
+    // Function to find where the curve meets another B-spline.
+    //
+    // Returns pairs of parameters (on this curve, on the other), in order along this
+    // curve, with the same conventions as `BezierCurve::intersections`.
+    pub fn intersections(&self, other: &Self, tolerance: T) -> Vec<(T, T)> {
+        let mut hits = Vec::new();
+        let other_pieces = other.pieces();
+        for (curve, a0, a1) in self.pieces() {
+            for (other_curve, b0, b1) in &other_pieces {
+                for (s, t) in curve.intersections(other_curve, tolerance) {
+                    hits.push((a0 + (a1 - a0) * s, *b0 + (*b1 - *b0) * t));
+                }
+            }
+        }
+        merge_close(hits, |t| self.point_at(t), tolerance)
+    }
+
+    // The Bézier pieces with the parameter interval each one covers.
+    fn pieces(&self) -> Vec<(BezierCurve<T>, T, T)> {
+        let (start, end) = self.domain();
+        let mut breakpoints: Vec<T> = self
+            .knots
+            .iter()
+            .copied()
+            .filter(|&u| start <= u && u <= end)
+            .collect();
+        breakpoints.dedup();
+
+        // Each breakpoint repeated `degree` times makes every span a separate Bézier piece.
+        let mut spline = self.clone();
+        for u in breakpoints {
+            while spline.knots.iter().filter(|&&k| k == u).count() < self.degree {
+                spline = spline.insert_knot(u).unwrap();
+            }
+        }
+        let control = spline.homogeneous();
+        let p = self.degree;
+        (p..control.len())
+            .filter(|&k| spline.knots[k] < spline.knots[k + 1])
+            .map(|k| {
+                (
+                    BezierCurve::from_homogeneous(&control[k - p..=k]),
+                    spline.knots[k],
+                    spline.knots[k + 1],
+                )
+            })
+            .collect()
+    }
+
+    fn clamp(&self, t: T) -> T {
+        let (start, end) = self.domain();
+        t.max(start).min(end)
+    }
+
+    // The last non-empty knot span [knots[k], knots[k + 1]) that starts at or before t.
+    fn span(&self, t: T) -> usize {
+        (self.degree..self.points.len())
+            .rev()
+            .find(|&k| self.knots[k] <= t && self.knots[k] < self.knots[k + 1])
+            .unwrap_or(self.degree)
+    }
+
+    fn homogeneous(&self) -> Vec<Homogeneous<T>> {
+        self.points
+            .iter()
+            .zip(&self.weights)
+            .map(|(p, &w)| [p.x * w, p.y * w, w])
+            .collect()
+    }
+}
+
+// De Boor's algorithm for the point at t in knot span k.
+fn de_boor<T: Float>(
+    degree: usize,
+    control: &[Homogeneous<T>],
+    knots: &[T],
+    k: usize,
+    t: T,
+) -> Homogeneous<T> {
+    let mut d: Vec<Homogeneous<T>> = control[k - degree..=k].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = j + k - degree;
+            let width = knots[i + degree + 1 - r] - knots[i];
+            let alpha = if width.is_zero() {
+                T::zero()
+            } else {
+                (t - knots[i]) / width
+            };
+            d[j] = lerp(d[j - 1], d[j], alpha);
+        }
+    }
+    d[degree]
+}
+
+#[cfg(test)]
+mod geometry_bspline_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn circle() -> BSpline<f64> {
+        let h = FRAC_1_SQRT_2;
+        BSpline::nurbs(
+            2,
+            vec![
+                p(1.0, 0.0),
+                p(1.0, 1.0),
+                p(0.0, 1.0),
+                p(-1.0, 1.0),
+                p(-1.0, 0.0),
+                p(-1.0, -1.0),
+                p(0.0, -1.0),
+                p(1.0, -1.0),
+                p(1.0, 0.0),
+            ],
+            vec![1.0, h, 1.0, h, 1.0, h, 1.0, h, 1.0],
+            vec![
+                0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+            ],
+        )
+        .unwrap()
+    }
+
+    fn wave() -> BSpline<f64> {
+        BSpline::new(
+            3,
+            vec![
+                p(0.0, 0.0),
+                p(1.0, 2.0),
+                p(2.0, -1.0),
+                p(3.0, 2.0),
+                p(4.0, -2.0),
+                p(5.0, 1.0),
+                p(6.0, 0.0),
+            ],
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 3.0, 4.0, 4.0, 4.0, 4.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bspline_evaluation_test() {
+        // With one span a clamped uniform B-spline is a Bézier curve.
+        let points = vec![p(0.0, 0.0), p(1.0, 3.0), p(3.0, 3.0), p(4.0, 0.0)];
+        let spline = BSpline::uniform(3, points.clone()).unwrap();
+        let bezier = BezierCurve::new(points).unwrap();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!(spline.point_at(t).distance(bezier.point_at(t)) < 1e-12);
+            assert!((spline.derivative_at(t) - bezier.derivative_at(t)).norm() < 1e-12);
+        }
+
+        let circle = circle();
+        assert!(circle.is_rational());
+        for i in 0..=40 {
+            let t = i as f64 / 40.0;
+            let point = circle.point_at(t);
+            assert!((point.to_vector().norm() - 1.0).abs() < 1e-12);
+            assert!(point.to_vector().dot(circle.derivative_at(t)).abs() < 1e-12);
+        }
+        assert_eq!(circle.point_at(2.0), p(1.0, 0.0));
+
+        // A finite difference agrees with the derivative of a non-uniform spline.
+        let wave = wave();
+        assert_eq!(wave.domain(), (0.0, 4.0));
+        for t in [0.2, 0.7, 2.0, 3.5] {
+            let step = 1e-6;
+            let difference = (wave.point_at(t + step) - wave.point_at(t - step)).scale(0.5 / step);
+            assert!((difference - wave.derivative_at(t)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn bspline_knot_insertion_test() {
+        for spline in [wave(), circle()] {
+            let refined = spline.insert_knot(0.6).unwrap().insert_knot(0.6).unwrap();
+            assert_eq!(
+                refined.control_points().len(),
+                spline.control_points().len() + 2
+            );
+            for i in 0..=20 {
+                let t = spline.domain().1 * i as f64 / 20.0;
+                assert!(refined.point_at(t).distance(spline.point_at(t)) < 1e-12);
+            }
+
+            let (start, end) = spline.domain();
+            let pieces = spline.pieces();
+            assert_eq!(pieces[0].1, start);
+            assert_eq!(pieces[pieces.len() - 1].2, end);
+            for (curve, a, b) in pieces {
+                for s in [0.0, 0.3, 1.0] {
+                    let t = a + (b - a) * s;
+                    assert!(curve.point_at(s).distance(spline.point_at(t)) < 1e-12);
+                }
+            }
+        }
+        assert!(wave().insert_knot(5.0).is_err());
+    }
+
+    #[test]
+    fn bspline_measures_test() {
+        let circle = circle();
+        assert!((circle.length(1e-10) - 2.0 * PI).abs() < 1e-9);
+        assert!((circle.signed_area() - PI).abs() < 1e-9);
+        let bounds = circle.bounding_box();
+        assert!((bounds.min().x + 1.0).abs() < 1e-12 && (bounds.max().y - 1.0).abs() < 1e-12);
+
+        let wave = wave();
+        let samples: Vec<Point2<f64>> = (0..=40000)
+            .map(|i| wave.point_at(i as f64 / 10000.0))
+            .collect();
+        let sampled = BoundingBox::from_points(&samples).unwrap();
+        let bounds = wave.bounding_box();
+        assert!(bounds.contains(&sampled));
+        assert!((bounds.min().y - sampled.min().y).abs() < 1e-6);
+        assert!((bounds.max().y - sampled.max().y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bspline_intersections_test() {
+        let axis = BSpline::uniform(1, vec![p(-1.0, 0.0), p(7.0, 0.0)]).unwrap();
+        let wave = wave();
+        let hits = wave.intersections(&axis, 1e-10);
+        for &(s, t) in &hits {
+            assert!(wave.point_at(s).distance(axis.point_at(t)) < 1e-9);
+        }
+        // The wave changes sign between samples as often as it crosses the axis between its ends.
+        let crossings = (0..3999)
+            .filter(|&i| {
+                let a = wave.point_at((i as f64 + 0.5) / 1000.0).y;
+                let b = wave.point_at((i as f64 + 1.5) / 1000.0).y;
+                a * b < 0.0
+            })
+            .count();
+        assert_eq!(hits.len(), crossings + 2);
+
+        let circle = circle();
+        let vertical = BSpline::uniform(1, vec![p(0.0, -2.0), p(0.0, 2.0)]).unwrap();
+        let hits = circle.intersections(&vertical, 1e-10);
+        assert_eq!(hits.len(), 2);
+        assert!((hits[0].0 - 0.25).abs() < 1e-9 && (hits[0].1 - 0.75).abs() < 1e-9);
+        assert!((hits[1].0 - 0.75).abs() < 1e-9 && (hits[1].1 - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bspline_invalid_test() {
+        let points = vec![p(0.0, 0.0), p(1.0, 1.0), p(2.0, 0.0)];
+        assert!(BSpline::uniform(3, points.clone()).is_err());
+        assert!(BSpline::new(2, points.clone(), vec![0.0, 0.0, 0.0, 1.0, 1.0]).is_err());
+        assert!(BSpline::new(2, points.clone(), vec![0.0, 0.0, 1.0, 0.5, 1.0, 1.0]).is_err());
+        assert!(BSpline::new(2, points.clone(), vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0]).is_err());
+        assert!(BSpline::nurbs(
+            2,
+            points,
+            vec![1.0, -1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,14 @@
+//! This module contains parametric curves.
+//!
+//! It is part of the `geometry` module. The curves map a parameter to a `Point2`
+//! and support evaluation, derivatives, subdivision, arc length, bounding boxes,
+//! intersections and the area enclosed by closed sequences of curves. Such
+//! sequences describe the curved outlines found in SVG and CAD files.
+//!
+//! # Modules
+//!
+//! - `bezier`: Contains the polynomial and rational `BezierCurve` type.
+//! - `bspline`: Contains the `BSpline` type for uniform, non-uniform and rational B-splines.
+
+pub mod bezier;
+pub mod bspline;
@@ -0,0 +1,610 @@
+//! This module provides 2D Delaunay triangulation and natural neighbour queries.
+//!
+//! It is part of the `geometry` module. `DelaunayTriangulation` is built with the
+//! Bowyer–Watson algorithm. Points are inserted one at a time. The triangles
+//! whose circumcircle contains the new point are removed, and the hole they leave
+//! is filled with triangles fanning out from the point. The convex hull is closed
+//! off with "ghost" triangles that share a vertex at infinity. Points outside the
+//! hull are then handled like any other point, with no enclosing super triangle
+//! whose corners could distort the hull. Every decision uses the robust
+//! `orient2d` and `incircle` predicates, so collinear and cocircular input always
+//! gives a valid triangulation.
+//!
+//! Repeated points are triangulated once, and later copies share the vertex of
+//! the first copy. When every point is collinear there are no triangles, and the
+//! neighbours of a point are the points next to it along the line.
+//!
+//! Natural neighbour (Sibson) coordinates give each site the share of the area a
+//! query point would take from its Voronoi cell if it were inserted. They are
+//! used for smooth interpolation over scattered data with `interpolate`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::delaunay::DelaunayTriangulation;
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let sensors = vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//!     Point2::new(1.0, 1.0),
+//! ];
+//! let readings = [10.0, 20.0, 30.0, 20.0, 20.0];
+//! let triangulation = DelaunayTriangulation::new(&sensors);
+//!
+//! assert_eq!(triangulation.triangles().len(), 4);
+//! assert_eq!(triangulation.nearest(Point2::new(1.9, 0.2)), Some(1));
+//!
+//! let value = triangulation.interpolate(Point2::new(1.0, 1.0), &readings).unwrap();
+//! assert_eq!(value, 20.0);
+//! assert_eq!(triangulation.interpolate(Point2::new(3.0, 3.0), &readings), None);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Triangulates a set of points.
+//! - `points`: Gets the triangulated points.
+//! - `triangles`: Gets the triangles as counter-clockwise index triples.
+//! - `neighbors`: Gets the Delaunay neighbours of a point.
+//! - `nearest`: Finds the point nearest to a query point.
+//! - `natural_neighbors`: Computes the natural neighbour coordinates of a query point.
+//! - `interpolate`: Interpolates values given at the points with natural neighbour weights.
+//! - `circumcenter`: Computes the centre of the circle through three points.
+
+use crate::fields::geometry::clipping::{key, Key};
+use crate::fields::geometry::intersection::within_box;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::predicates::{incircle, orient2d};
+use num_traits::Float;
+use std::collections::{HashMap, HashSet};
+
+// The vertex at infinity shared by the ghost triangles outside the hull.
+const GHOST: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+pub struct DelaunayTriangulation<T> {
+    points: Vec<Point2<T>>,
+    // The first copy of each point, which repeated points are mapped to.
+    representative: Vec<usize>,
+    first_copy: HashMap<Key, usize>,
+    triangles: Vec<[usize; 3]>,
+    mesh: Mesh,
+    adjacency: Vec<Vec<usize>>,
+}
+
+// Triangles including ghosts, with the triangle on the left of each directed edge.
+#[derive(Debug, Clone, Default)]
+struct Mesh {
+    triangles: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    edges: HashMap<(usize, usize), usize>,
+    last: usize,
+}
+
+// The triangles a new point would replace and the edges around them, in
+// counter-clockwise order.
+struct Cavity {
+    triangles: HashSet<usize>,
+    boundary: Vec<(usize, usize)>,
+}
+
+impl<T> DelaunayTriangulation<T>
+where
+    T: Float,
+{
+    // Function to triangulate a set of points.
+    pub fn new(points: &[Point2<T>]) -> Self {
+        let mut first_copy: HashMap<Key, usize> = HashMap::new();
+        let representative: Vec<usize> = points
+            .iter()
+            .enumerate()
+            .map(|(i, &point)| *first_copy.entry(key(point)).or_insert(i))
+            .collect();
+        let unique: Vec<usize> = (0..points.len())
+            .filter(|&i| representative[i] == i)
+            .collect();
+
+        let mut triangulation = DelaunayTriangulation {
+            points: points.to_vec(),
+            representative,
+            first_copy,
+            triangles: Vec::new(),
+            mesh: Mesh::default(),
+            adjacency: vec![Vec::new(); points.len()],
+        };
+        let seed = (unique.len() >= 3)
+            .then(|| {
+                let (a, b) = (unique[0], unique[1]);
+                unique[2..]
+                    .iter()
+                    .find(|&&c| orient2d(points[a], points[b], points[c]) != 0f64)
+                    .map(|&c| (a, b, c))
+            })
+            .flatten();
+        match seed {
+            Some((a, b, c)) => {
+                triangulation.seed(a, b, c);
+                for &i in &unique {
+                    if i != a && i != b && i != c {
+                        triangulation.insert(i);
+                    }
+                }
+                triangulation.collect_triangles();
+            }
+            None => triangulation.link_collinear(&unique),
+        }
+        triangulation
+    }
+
+    // Function to get the triangulated points, including repeated ones.
+    pub fn points(&self) -> &[Point2<T>] {
+        &self.points
+    }
+
+    // Function to get the triangles as counter-clockwise index triples.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    // Function to get the indices of the points joined to a point by a Delaunay edge.
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        &self.adjacency[self.representative[index]]
+    }
+
+    // Function to find the index of the point nearest to a query point.
+    //
+    // The search walks along Delaunay edges towards the query point, which always
+    // ends at the nearest point.
+    pub fn nearest(&self, point: Point2<T>) -> Option<usize> {
+        let mut current = *self.representative.first()?;
+        let mut best = self.points[current].distance_squared(point);
+        loop {
+            let closer = self.adjacency[current]
+                .iter()
+                .map(|&i| (i, self.points[i].distance_squared(point)))
+                .filter(|&(_, distance)| distance < best)
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            match closer {
+                Some((index, distance)) => {
+                    current = index;
+                    best = distance;
+                }
+                None => return Some(current),
+            }
+        }
+    }
+
+    // Function to get the natural neighbour (Sibson) coordinates of a point.
+    //
+    // Returns pairs of point index and weight. The weights are positive, add up to
+    // one and reproduce the query point as a weighted average of the points.
+    // Returns None outside the convex hull, where the coordinates are undefined.
+    pub fn natural_neighbors(&self, point: Point2<T>) -> Option<Vec<(usize, T)>> {
+        if self.triangles.is_empty() {
+            return None;
+        }
+        if let Some(&index) = self.first_copy.get(&key(point)) {
+            return Some(vec![(index, T::one())]);
+        }
+        let start = self.locate(point)?;
+        let cavity = self.cavity(start, point);
+
+        // On a hull edge the coordinates reduce to linear interpolation along it.
+        if let Some(&(a, b)) = cavity
+            .boundary
+            .iter()
+            .find(|&&(a, b)| a == GHOST || b == GHOST)
+        {
+            let ghost = self.mesh.triangles[self.mesh.edges[&(a, b)]];
+            let [u, v] = self.hull_edge(ghost);
+            if orient2d(self.points[u], self.points[v], point) != 0f64 {
+                return None;
+            }
+            let edge = self.points[v] - self.points[u];
+            let t = (point - self.points[u]).dot(edge) / edge.norm_squared();
+            return Some(vec![(u, T::one() - t), (v, t)]);
+        }
+
+        let mut stolen = Vec::with_capacity(cavity.boundary.len());
+        for k in 0..cavity.boundary.len() {
+            let (u, v) = cavity.boundary[k];
+            let (_, w) = cavity.boundary[(k + 1) % cavity.boundary.len()];
+            stolen.push((v, self.stolen_area(&cavity, point, u, v, w)));
+        }
+        let total = stolen.iter().fold(0f64, |sum, &(_, area)| sum + area);
+        Some(
+            stolen
+                .into_iter()
+                .map(|(i, area)| (i, T::from(area / total).unwrap()))
+                .collect(),
+        )
+    }
+
+    // Function to interpolate values given at the points with natural neighbour weights.
+    //
+    // `values` holds one value per point. Returns None outside the convex hull.
+    pub fn interpolate(&self, point: Point2<T>, values: &[T]) -> Option<T> {
+        let weights = self.natural_neighbors(point)?;
+        Some(
+            weights
+                .into_iter()
+                .fold(T::zero(), |sum, (i, weight)| sum + weight * values[i]),
+        )
+    }
+
+    fn seed(&mut self, a: usize, b: usize, c: usize) {
+        let (b, c) = if orient2d(self.points[a], self.points[b], self.points[c]) > 0f64 {
+            (b, c)
+        } else {
+            (c, b)
+        };
+        for triangle in [[a, b, c], [b, a, GHOST], [c, b, GHOST], [a, c, GHOST]] {
+            self.add(triangle);
+        }
+    }
+
+    fn insert(&mut self, index: usize) {
+        let point = self.points[index];
+        let start = match self.locate(point) {
+            Some(start) => start,
+            None => return,
+        };
+        let cavity = self.cavity(start, point);
+        for &t in &cavity.triangles {
+            self.mesh.alive[t] = false;
+            for edge in triangle_edges(self.mesh.triangles[t]) {
+                if self.mesh.edges.get(&edge) == Some(&t) {
+                    self.mesh.edges.remove(&edge);
+                }
+            }
+        }
+        for (u, v) in cavity.boundary {
+            self.mesh.last = self.add([u, v, index]);
+        }
+    }
+
+    fn add(&mut self, triangle: [usize; 3]) -> usize {
+        let index = self.mesh.triangles.len();
+        self.mesh.triangles.push(triangle);
+        self.mesh.alive.push(true);
+        for edge in triangle_edges(triangle) {
+            self.mesh.edges.insert(edge, index);
+        }
+        index
+    }
+
+    // Whether a point lies inside the circumcircle of a triangle. For a ghost the
+    // circle becomes the open half plane beyond its hull edge, plus the edge itself.
+    fn conflicts(&self, triangle: usize, point: Point2<T>) -> bool {
+        let vertices = self.mesh.triangles[triangle];
+        if vertices.contains(&GHOST) {
+            let [u, v] = self.hull_edge(vertices);
+            let (pu, pv) = (self.points[u], self.points[v]);
+            let side = orient2d(pu, pv, point);
+            return side > 0f64 || (side == 0f64 && within_box(point, pu, pv));
+        }
+        let [a, b, c] = vertices.map(|i| self.points[i]);
+        incircle(a, b, c, point) > 0f64
+    }
+
+    // The hull edge of a ghost triangle, wound so that the hull lies to its right.
+    fn hull_edge(&self, ghost: [usize; 3]) -> [usize; 2] {
+        let position = ghost.iter().position(|&i| i == GHOST).unwrap();
+        [ghost[(position + 1) % 3], ghost[(position + 2) % 3]]
+    }
+
+    // Walks from the last new triangle towards a point and returns a triangle in
+    // conflict with it, or None if the point is a repeated vertex.
+    fn locate(&self, point: Point2<T>) -> Option<usize> {
+        let mut current = self.mesh.last;
+        for _ in 0..self.mesh.triangles.len() {
+            let vertices = self.mesh.triangles[current];
+            if vertices.contains(&GHOST) {
+                if self.conflicts(current, point) {
+                    return Some(current);
+                }
+                let [u, v] = self.hull_edge(vertices);
+                current = self.mesh.edges[&(v, u)];
+                continue;
+            }
+            let step = triangle_edges(vertices)
+                .into_iter()
+                .find(|&(u, v)| orient2d(self.points[u], self.points[v], point) < 0f64);
+            match step {
+                Some((u, v)) => current = self.mesh.edges[&(v, u)],
+                None => return self.conflicts(current, point).then_some(current),
+            }
+        }
+        // The walk cannot cycle on a Delaunay triangulation, but fall back to a scan.
+        (0..self.mesh.triangles.len()).find(|&t| self.mesh.alive[t] && self.conflicts(t, point))
+    }
+
+    fn cavity(&self, start: usize, point: Point2<T>) -> Cavity {
+        let mut triangles = HashSet::from([start]);
+        let mut stack = vec![start];
+        let mut next: HashMap<usize, (usize, usize)> = HashMap::new();
+        while let Some(t) = stack.pop() {
+            for (u, v) in triangle_edges(self.mesh.triangles[t]) {
+                let neighbor = self.mesh.edges[&(v, u)];
+                if triangles.contains(&neighbor) {
+                    continue;
+                }
+                if self.conflicts(neighbor, point) {
+                    triangles.insert(neighbor);
+                    stack.push(neighbor);
+                } else {
+                    next.insert(u, (u, v));
+                }
+            }
+        }
+        // Chain the boundary edges head to tail.
+        let mut boundary = Vec::with_capacity(next.len());
+        if let Some(&first) = next.keys().next() {
+            let mut vertex = first;
+            while let Some(&(u, v)) = next.get(&vertex) {
+                boundary.push((u, v));
+                vertex = v;
+                if vertex == first || boundary.len() == next.len() {
+                    break;
+                }
+            }
+        }
+        Cavity {
+            triangles,
+            boundary,
+        }
+    }
+
+    // The area a point inserted into the cavity takes from the Voronoi cell of v,
+    // where u, v, w are consecutive vertices on the cavity boundary.
+    fn stolen_area(&self, cavity: &Cavity, point: Point2<T>, u: usize, v: usize, w: usize) -> f64 {
+        let to_f64 = |p: Point2<T>| Point2::new(p.x.to_f64().unwrap(), p.y.to_f64().unwrap());
+        let centre = |a: Point2<T>, b: Point2<T>, c: Point2<T>| {
+            circumcenter(to_f64(a), to_f64(b), to_f64(c))
+        };
+        let mut corners = vec![centre(self.points[u], self.points[v], point)];
+        // Old triangles around v inside the cavity, from edge uv round to edge vw.
+        let mut incoming = u;
+        for _ in 0..cavity.triangles.len() {
+            let t = self.mesh.edges[&(incoming, v)];
+            let [a, b, c] = self.mesh.triangles[t].map(|i| self.points[i]);
+            corners.push(centre(a, b, c));
+            let outgoing = third(self.mesh.triangles[t], incoming, v);
+            if !cavity.triangles.contains(&self.mesh.edges[&(outgoing, v)]) {
+                break;
+            }
+            incoming = outgoing;
+        }
+        corners.push(centre(self.points[v], self.points[w], point));
+        let n = corners.len();
+        let twice_area = (0..n).fold(0f64, |sum, i| {
+            let (a, b) = (corners[i], corners[(i + 1) % n]);
+            sum + a.x * b.y - b.x * a.y
+        });
+        twice_area.abs() / 2f64
+    }
+
+    fn collect_triangles(&mut self) {
+        self.triangles = (0..self.mesh.triangles.len())
+            .filter(|&t| self.mesh.alive[t] && !self.mesh.triangles[t].contains(&GHOST))
+            .map(|t| self.mesh.triangles[t])
+            .collect();
+        for triangle in &self.triangles {
+            for (u, v) in triangle_edges(*triangle) {
+                self.adjacency[u].push(v);
+                self.adjacency[v].push(u);
+            }
+        }
+        for list in &mut self.adjacency {
+            list.sort_unstable();
+            list.dedup();
+        }
+    }
+
+    // Neighbours of collinear points are their neighbours along the line.
+    fn link_collinear(&mut self, unique: &[usize]) {
+        let mut order = unique.to_vec();
+        if let [first, .., last] = order[..] {
+            let direction = self.points[last] - self.points[first];
+            let origin = self.points[first];
+            order.sort_by(|&a, &b| {
+                let (pa, pb) = (self.points[a] - origin, self.points[b] - origin);
+                pa.dot(direction).partial_cmp(&pb.dot(direction)).unwrap()
+            });
+        }
+        for pair in order.windows(2) {
+            self.adjacency[pair[0]].push(pair[1]);
+            self.adjacency[pair[1]].push(pair[0]);
+        }
+    }
+}
+
+// Function to get the centre of the circle through three points.
+//
+// Returns non-finite coordinates when the points are collinear.
+pub fn circumcenter<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Point2<T> {
+    let (ab, ac) = (b - a, c - a);
+    let two = T::one() + T::one();
+    let denominator = two * ab.cross(ac);
+    let (ab2, ac2) = (ab.norm_squared(), ac.norm_squared());
+    Point2::new(
+        a.x + (ac.y * ab2 - ab.y * ac2) / denominator,
+        a.y + (ab.x * ac2 - ac.x * ab2) / denominator,
+    )
+}
+
+fn triangle_edges(triangle: [usize; 3]) -> [(usize, usize); 3] {
+    let [a, b, c] = triangle;
+    [(a, b), (b, c), (c, a)]
+}
+
+fn third(triangle: [usize; 3], a: usize, b: usize) -> usize {
+    *triangle.iter().find(|&&i| i != a && i != b).unwrap()
+}
+
+#[cfg(test)]
+mod geometry_delaunay_tests {
+    use super::*;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn random_points(count: usize, seed: u64) -> Vec<Point2<f64>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count).map(|_| p(next(), next())).collect()
+    }
+
+    // Checks orientation and the empty circle property against every point.
+    fn assert_delaunay(triangulation: &DelaunayTriangulation<f64>) {
+        let points = triangulation.points();
+        for &[a, b, c] in triangulation.triangles() {
+            assert!(orient2d(points[a], points[b], points[c]) > 0.0);
+            for point in points {
+                assert!(incircle(points[a], points[b], points[c], *point) <= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn delaunay_random_test() {
+        let points = random_points(300, 5);
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert_delaunay(&triangulation);
+
+        // Euler: a triangulation of n points with h on the hull has 2n - 2 - h triangles.
+        let hull = crate::fields::geometry::convex_hull::monotone_chain(&points);
+        assert_eq!(
+            triangulation.triangles().len(),
+            2 * points.len() - 2 - hull.len()
+        );
+    }
+
+    #[test]
+    fn delaunay_grid_test() {
+        // A grid is maximally cocircular; every square must still be split in two.
+        let mut points = Vec::new();
+        for i in 0..6 {
+            for j in 0..6 {
+                points.push(p(i as f64, j as f64));
+            }
+        }
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert_delaunay(&triangulation);
+        assert_eq!(triangulation.triangles().len(), 50);
+
+        let area: f64 = triangulation
+            .triangles()
+            .iter()
+            .map(|&[a, b, c]| orient2d(points[a], points[b], points[c]) / 2.0)
+            .sum();
+        assert_eq!(area, 25.0);
+    }
+
+    #[test]
+    fn delaunay_degenerate_test() {
+        let empty: Vec<Point2<f64>> = Vec::new();
+        assert!(DelaunayTriangulation::new(&empty).triangles().is_empty());
+        assert_eq!(
+            DelaunayTriangulation::new(&empty).nearest(p(0.0, 0.0)),
+            None
+        );
+
+        // Collinear points have no triangles but are linked along the line.
+        let line = vec![p(2.0, 2.0), p(0.0, 0.0), p(3.0, 3.0), p(1.0, 1.0)];
+        let triangulation = DelaunayTriangulation::new(&line);
+        assert!(triangulation.triangles().is_empty());
+        assert_eq!(triangulation.neighbors(3), &[0, 1]);
+        assert_eq!(triangulation.nearest(p(2.9, 3.5)), Some(2));
+
+        // Repeated points and collinear points on the hull.
+        let points = vec![
+            p(0.0, 0.0),
+            p(1.0, 0.0),
+            p(2.0, 0.0),
+            p(1.0, 1.0),
+            p(1.0, 0.0),
+            p(0.0, 0.0),
+            p(3.0, 0.0),
+        ];
+        let triangulation = DelaunayTriangulation::new(&points);
+        assert_delaunay(&triangulation);
+        assert_eq!(triangulation.triangles().len(), 3);
+        assert_eq!(triangulation.neighbors(4), triangulation.neighbors(1));
+    }
+
+    #[test]
+    fn delaunay_nearest_test() {
+        let points = random_points(200, 9);
+        let triangulation = DelaunayTriangulation::new(&points);
+        for query in random_points(50, 21) {
+            let expected = (0..points.len())
+                .min_by(|&a, &b| {
+                    let (da, db) = (points[a].distance(query), points[b].distance(query));
+                    da.partial_cmp(&db).unwrap()
+                })
+                .unwrap();
+            assert_eq!(triangulation.nearest(query), Some(expected));
+        }
+    }
+
+    #[test]
+    fn natural_neighbors_test() {
+        let points = random_points(100, 17);
+        let triangulation = DelaunayTriangulation::new(&points);
+        for query in random_points(30, 4) {
+            let Some(weights) = triangulation.natural_neighbors(query) else {
+                continue;
+            };
+            let total: f64 = weights.iter().map(|&(_, w)| w).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+            assert!(weights.iter().all(|&(_, w)| w > 0.0));
+
+            // The coordinates reproduce the query point.
+            let x: f64 = weights.iter().map(|&(i, w)| w * points[i].x).sum();
+            let y: f64 = weights.iter().map(|&(i, w)| w * points[i].y).sum();
+            assert!((x - query.x).abs() < 1e-9 && (y - query.y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn interpolate_test() {
+        let points = random_points(80, 3);
+        let triangulation = DelaunayTriangulation::new(&points);
+        // Natural neighbour interpolation reproduces linear functions exactly.
+        let f = |q: Point2<f64>| 3.0 * q.x - 2.0 * q.y + 1.0;
+        let values: Vec<f64> = points.iter().map(|&q| f(q)).collect();
+
+        let query = p(0.5, 0.5);
+        let value = triangulation.interpolate(query, &values).unwrap();
+        assert!((value - f(query)).abs() < 1e-9);
+        assert_eq!(
+            triangulation.interpolate(points[7], &values),
+            Some(values[7])
+        );
+        assert_eq!(triangulation.interpolate(p(2.0, 2.0), &values), None);
+
+        // On a hull edge the interpolation is linear along the edge.
+        let square = vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)];
+        let triangulation = DelaunayTriangulation::new(&square);
+        let values = [0.0, 4.0, 8.0, 4.0];
+        assert_eq!(triangulation.interpolate(p(0.5, 0.0), &values), Some(1.0));
+    }
+
+    #[test]
+    fn circumcenter_test() {
+        let centre = circumcenter(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 2.0));
+        assert_eq!(centre, p(2.0, 1.0));
+        assert!(!circumcenter(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0))
+            .x
+            .is_finite());
+    }
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct GeometryError {
+    details: String,
+}
+
+impl GeometryError {
+    pub fn new(msg: &str) -> GeometryError {
+        GeometryError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for GeometryError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
@@ -0,0 +1,523 @@
+//! This module provides intersection and distance queries between segments, lines and circles.
+//!
+//! It is part of the `geometry` module. Segment intersection is classified with the
+//! robust predicates from `predicates`, so touching, collinear and overlapping
+//! segments are always reported consistently. `segment_intersection_exact` and
+//! `point_segment_distance_squared` work entirely in the coordinate type, which
+//! makes them exact for rational coordinates such as `Fraction` as long as the
+//! intermediate values fit; `Fraction` panics on overflow rather than rounding.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::intersection::{
+//!     circle_circle_intersection, line_circle_intersection, point_segment_distance,
+//!     segment_intersection, CircleIntersection, SegmentIntersection,
+//! };
+//! use crabmath::fields::geometry::point::Point2;
+//!
+//! let crossing = segment_intersection(
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//!     Point2::new(2.0, 0.0),
+//! );
+//! assert_eq!(crossing, SegmentIntersection::Point(Point2::new(1.0, 1.0)));
+//!
+//! let hits = line_circle_intersection(
+//!     Point2::new(-5.0, 0.0),
+//!     Point2::new(5.0, 0.0),
+//!     Point2::new(0.0, 0.0),
+//!     2.0,
+//! );
+//! assert_eq!(hits, vec![Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0)]);
+//!
+//! let circles = circle_circle_intersection(Point2::new(0.0, 0.0), 1.0, Point2::new(2.0, 0.0), 1.0);
+//! assert_eq!(circles, CircleIntersection::Points(vec![Point2::new(1.0, 0.0)]));
+//!
+//! let distance = point_segment_distance(Point2::new(1.0, 1.0), Point2::new(0.0, 0.0), Point2::new(2.0, 0.0));
+//! assert_eq!(distance, 1.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `segment_intersection`: Intersects two segments using robust predicates.
+//! - `segment_intersection_exact`: Intersects two segments in the coordinate type.
+//! - `line_circle_intersection`: Intersects the line through two points with a circle.
+//! - `circle_circle_intersection`: Intersects two circles.
+//! - `closest_point_on_segment`: Finds the point on a segment closest to a point.
+//! - `point_segment_distance`: Computes the distance from a point to a segment.
+//! - `point_segment_distance_squared`: Computes the squared distance in the coordinate type.
+
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::predicates::{orientation, orientation_exact, Orientation};
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SegmentIntersection<T> {
+    // The segments do not meet.
+    None,
+    // The segments meet in a single point.
+    Point(Point2<T>),
+    // The segments are collinear and share the segment between these two points.
+    Overlap(Point2<T>, Point2<T>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircleIntersection<T> {
+    // The circles are identical, so they share every point.
+    Coincident,
+    // The circles meet in zero, one (tangent) or two points.
+    Points(Vec<Point2<T>>),
+}
+
+// Function to intersect segment p1p2 with segment q1q2 using robust predicates.
+pub fn segment_intersection<T: Float>(
+    p1: Point2<T>,
+    p2: Point2<T>,
+    q1: Point2<T>,
+    q2: Point2<T>,
+) -> SegmentIntersection<T> {
+    intersect_segments(p1, p2, q1, q2, orientation)
+}
+
+// Function to intersect segment p1p2 with segment q1q2 in the coordinate type.
+//
+// With `Fraction` coordinates both the classification and the intersection point are
+// exact, or the arithmetic panics on overflow.
+pub fn segment_intersection_exact<T>(
+    p1: Point2<T>,
+    p2: Point2<T>,
+    q1: Point2<T>,
+    q2: Point2<T>,
+) -> SegmentIntersection<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    intersect_segments(p1, p2, q1, q2, orientation_exact)
+}
+
+// Function to intersect the infinite line through p1 and p2 with a circle.
+//
+// The points are returned in order along the direction from p1 to p2: none when
+// the line misses, one when it is tangent and two when it crosses the circle.
+pub fn line_circle_intersection<T: Float>(
+    p1: Point2<T>,
+    p2: Point2<T>,
+    center: Point2<T>,
+    radius: T,
+) -> Vec<Point2<T>> {
+    let direction = p2 - p1;
+    let a = direction.norm_squared();
+    if a.is_zero() {
+        return Vec::new();
+    }
+    // Solve |p1 + t d - c|^2 = r^2 relative to the foot of the perpendicular from c.
+    let offset = p1 - center;
+    let t_foot = -offset.dot(direction) / a;
+    let foot = p1 + direction.scale(t_foot);
+    let distance_squared = (foot - center).norm_squared();
+    let radius_squared = radius * radius;
+    if distance_squared > radius_squared {
+        return Vec::new();
+    }
+    let half_chord = (radius_squared - distance_squared).sqrt();
+    if half_chord.is_zero() {
+        return vec![foot];
+    }
+    let step = direction.scale(half_chord / a.sqrt());
+    vec![foot - step, foot + step]
+}
+
+// Function to intersect the circle (c1, r1) with the circle (c2, r2).
+pub fn circle_circle_intersection<T: Float>(
+    c1: Point2<T>,
+    r1: T,
+    c2: Point2<T>,
+    r2: T,
+) -> CircleIntersection<T> {
+    let between = c2 - c1;
+    let d = between.norm();
+    if d.is_zero() {
+        return if r1 == r2 {
+            CircleIntersection::Coincident
+        } else {
+            CircleIntersection::Points(Vec::new())
+        };
+    }
+    if d > r1 + r2 || d < (r1 - r2).abs() {
+        return CircleIntersection::Points(Vec::new());
+    }
+    // Distance from c1 to the radical line along c1c2, and the half chord length.
+    let two = T::one() + T::one();
+    let a = (r1 * r1 - r2 * r2 + d * d) / (two * d);
+    let h_squared = r1 * r1 - a * a;
+    let unit = between / d;
+    let base = c1 + unit.scale(a);
+    if h_squared <= T::zero() {
+        return CircleIntersection::Points(vec![base]);
+    }
+    let offset = unit.perpendicular().scale(h_squared.sqrt());
+    CircleIntersection::Points(vec![base + offset, base - offset])
+}
+
+// Function to get the point on segment ab closest to p.
+pub fn closest_point_on_segment<T: Float>(p: Point2<T>, a: Point2<T>, b: Point2<T>) -> Point2<T> {
+    let ab = b - a;
+    let length_squared = ab.norm_squared();
+    if length_squared.is_zero() {
+        return a;
+    }
+    let t = ((p - a).dot(ab) / length_squared)
+        .max(T::zero())
+        .min(T::one());
+    a + ab.scale(t)
+}
+
+// Function to get the distance from p to segment ab.
+pub fn point_segment_distance<T: Float>(p: Point2<T>, a: Point2<T>, b: Point2<T>) -> T {
+    p.distance(closest_point_on_segment(p, a, b))
+}
+
+// Function to get the squared distance from p to segment ab, in the coordinate type.
+pub fn point_segment_distance_squared<T>(p: Point2<T>, a: Point2<T>, b: Point2<T>) -> T
+where
+    T: Num + Copy + PartialOrd,
+{
+    let ab = b - a;
+    let ap = p - a;
+    let length_squared = ab.norm_squared();
+    let projection = ap.dot(ab);
+    if length_squared.is_zero() || projection <= T::zero() {
+        return ap.norm_squared();
+    }
+    if projection >= length_squared {
+        return (p - b).norm_squared();
+    }
+    // |ap|^2 - (ap . ab)^2 / |ab|^2, the squared perpendicular distance.
+    let cross = ap.cross(ab);
+    cross * cross / length_squared
+}
+
+// Shared classification for both the robust floating point and the exact variants.
+fn intersect_segments<T, F>(
+    p1: Point2<T>,
+    p2: Point2<T>,
+    q1: Point2<T>,
+    q2: Point2<T>,
+    orient: F,
+) -> SegmentIntersection<T>
+where
+    T: Num + Copy + PartialOrd,
+    F: Fn(Point2<T>, Point2<T>, Point2<T>) -> Orientation,
+{
+    let p_degenerate = p1 == p2;
+    let q_degenerate = q1 == q2;
+    if p_degenerate && q_degenerate {
+        return if p1 == q1 {
+            SegmentIntersection::Point(p1)
+        } else {
+            SegmentIntersection::None
+        };
+    }
+    if p_degenerate {
+        return point_on_segment(p1, q1, q2, &orient);
+    }
+    if q_degenerate {
+        return point_on_segment(q1, p1, p2, &orient);
+    }
+
+    let o1 = orient(p1, p2, q1);
+    let o2 = orient(p1, p2, q2);
+    let o3 = orient(q1, q2, p1);
+    let o4 = orient(q1, q2, p2);
+
+    if o1 == Orientation::Collinear && o2 == Orientation::Collinear {
+        return collinear_overlap(p1, p2, q1, q2);
+    }
+
+    let straddles = |a: Orientation, b: Orientation| {
+        a != b && a != Orientation::Collinear && b != Orientation::Collinear
+    };
+    if straddles(o1, o2) && straddles(o3, o4) {
+        // p1 + t (p2 - p1) with t = (q1 - p1) x s / (r x s).
+        let r = p2 - p1;
+        let s = q2 - q1;
+        let t = (q1 - p1).cross(s) / r.cross(s);
+        return SegmentIntersection::Point(p1 + r.scale(t));
+    }
+
+    // Otherwise the segments can only meet at an endpoint lying on the other segment.
+    let touching = [
+        (o1, q1, p1, p2),
+        (o2, q2, p1, p2),
+        (o3, p1, q1, q2),
+        (o4, p2, q1, q2),
+    ];
+    for (o, point, a, b) in touching {
+        if o == Orientation::Collinear && within_box(point, a, b) {
+            return SegmentIntersection::Point(point);
+        }
+    }
+    SegmentIntersection::None
+}
+
+fn point_on_segment<T, F>(
+    point: Point2<T>,
+    a: Point2<T>,
+    b: Point2<T>,
+    orient: &F,
+) -> SegmentIntersection<T>
+where
+    T: Num + Copy + PartialOrd,
+    F: Fn(Point2<T>, Point2<T>, Point2<T>) -> Orientation,
+{
+    if orient(a, b, point) == Orientation::Collinear && within_box(point, a, b) {
+        SegmentIntersection::Point(point)
+    } else {
+        SegmentIntersection::None
+    }
+}
+
+// Overlap of two collinear, non-degenerate segments.
+fn collinear_overlap<T>(
+    p1: Point2<T>,
+    p2: Point2<T>,
+    q1: Point2<T>,
+    q2: Point2<T>,
+) -> SegmentIntersection<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    // Order points along the line lexicographically, which is monotone along any line.
+    let less = |a: &Point2<T>, b: &Point2<T>| a.x < b.x || (a.x == b.x && a.y < b.y);
+    let (p_min, p_max) = if less(&p2, &p1) { (p2, p1) } else { (p1, p2) };
+    let (q_min, q_max) = if less(&q2, &q1) { (q2, q1) } else { (q1, q2) };
+    let start = if less(&p_min, &q_min) { q_min } else { p_min };
+    let end = if less(&p_max, &q_max) { p_max } else { q_max };
+    if less(&end, &start) {
+        SegmentIntersection::None
+    } else if start == end {
+        SegmentIntersection::Point(start)
+    } else {
+        SegmentIntersection::Overlap(start, end)
+    }
+}
+
+// Whether `point` lies within the axis-aligned bounding box of segment ab.
+pub(crate) fn within_box<T>(point: Point2<T>, a: Point2<T>, b: Point2<T>) -> bool
+where
+    T: Num + Copy + PartialOrd,
+{
+    let between = |v: T, a: T, b: T| (a <= v && v <= b) || (b <= v && v <= a);
+    between(point.x, a.x, b.x) && between(point.y, a.y, b.y)
+}
+
+#[cfg(test)]
+mod geometry_intersection_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn segment_intersection_crossing_test() {
+        let result = segment_intersection(p(0.0, 0.0), p(4.0, 4.0), p(0.0, 4.0), p(4.0, 0.0));
+        assert_eq!(result, SegmentIntersection::Point(p(2.0, 2.0)));
+
+        let result = segment_intersection(p(0.0, 0.0), p(1.0, 1.0), p(0.0, 4.0), p(4.0, 0.0));
+        assert_eq!(result, SegmentIntersection::None);
+
+        let result = segment_intersection(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 1.0), p(4.0, 1.0));
+        assert_eq!(result, SegmentIntersection::None);
+    }
+
+    #[test]
+    fn segment_intersection_touching_test() {
+        // T-junction: an endpoint lies in the interior of the other segment.
+        let result = segment_intersection(p(0.0, 0.0), p(4.0, 0.0), p(2.0, 0.0), p(2.0, 3.0));
+        assert_eq!(result, SegmentIntersection::Point(p(2.0, 0.0)));
+
+        // Shared endpoint.
+        let result = segment_intersection(p(0.0, 0.0), p(1.0, 1.0), p(1.0, 1.0), p(2.0, 0.0));
+        assert_eq!(result, SegmentIntersection::Point(p(1.0, 1.0)));
+    }
+
+    #[test]
+    fn segment_intersection_collinear_test() {
+        let result = segment_intersection(p(0.0, 0.0), p(4.0, 4.0), p(2.0, 2.0), p(6.0, 6.0));
+        assert_eq!(
+            result,
+            SegmentIntersection::Overlap(p(2.0, 2.0), p(4.0, 4.0))
+        );
+
+        let result = segment_intersection(p(4.0, 4.0), p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0));
+        assert_eq!(
+            result,
+            SegmentIntersection::Overlap(p(1.0, 1.0), p(2.0, 2.0))
+        );
+
+        let result = segment_intersection(p(0.0, 0.0), p(2.0, 0.0), p(2.0, 0.0), p(5.0, 0.0));
+        assert_eq!(result, SegmentIntersection::Point(p(2.0, 0.0)));
+
+        let result = segment_intersection(p(0.0, 0.0), p(1.0, 0.0), p(2.0, 0.0), p(5.0, 0.0));
+        assert_eq!(result, SegmentIntersection::None);
+
+        // Vertical collinear segments.
+        let result = segment_intersection(p(1.0, 0.0), p(1.0, 3.0), p(1.0, 5.0), p(1.0, 2.0));
+        assert_eq!(
+            result,
+            SegmentIntersection::Overlap(p(1.0, 2.0), p(1.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn segment_intersection_degenerate_test() {
+        let result = segment_intersection(p(1.0, 1.0), p(1.0, 1.0), p(0.0, 0.0), p(2.0, 2.0));
+        assert_eq!(result, SegmentIntersection::Point(p(1.0, 1.0)));
+
+        let result = segment_intersection(p(0.0, 0.0), p(2.0, 2.0), p(1.0, 0.0), p(1.0, 0.0));
+        assert_eq!(result, SegmentIntersection::None);
+
+        let result = segment_intersection(p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0));
+        assert_eq!(result, SegmentIntersection::Point(p(1.0, 1.0)));
+    }
+
+    #[test]
+    fn segment_intersection_near_collinear_test() {
+        // q1 sits one ulp above the segment, so the segments cannot touch there.
+        let q1 = p(0.5, 0.5 + f64::EPSILON / 2.0);
+        let result = segment_intersection(p(0.0, 0.0), p(1.0, 1.0), q1, p(0.5, 2.0));
+        assert_eq!(result, SegmentIntersection::None);
+
+        let result = segment_intersection(p(0.0, 0.0), p(1.0, 1.0), q1, p(0.5, -2.0));
+        assert!(matches!(result, SegmentIntersection::Point(_)));
+    }
+
+    #[test]
+    fn segment_intersection_exact_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let pf = |x, y| Point2::new(x, y);
+        let result = segment_intersection_exact(
+            pf(frac(0, 1), frac(0, 1)),
+            pf(frac(1, 1), frac(1, 1)),
+            pf(frac(0, 1), frac(1, 1)),
+            pf(frac(1, 1), frac(0, 1)),
+        );
+        assert_eq!(
+            result,
+            SegmentIntersection::Point(pf(frac(1, 2), frac(1, 2)))
+        );
+
+        // The crossing at (1/3, 1/3) is not representable in binary floating point.
+        let result = segment_intersection_exact(
+            pf(frac(0, 1), frac(0, 1)),
+            pf(frac(1, 1), frac(1, 1)),
+            pf(frac(0, 1), frac(1, 1)),
+            pf(frac(1, 1), frac(-1, 1)),
+        );
+        assert_eq!(
+            result,
+            SegmentIntersection::Point(pf(frac(1, 3), frac(1, 3)))
+        );
+
+        let result = segment_intersection_exact(
+            pf(frac(0, 1), frac(0, 1)),
+            pf(frac(2, 3), frac(2, 3)),
+            pf(frac(1, 3), frac(1, 3)),
+            pf(frac(1, 1), frac(1, 1)),
+        );
+        assert_eq!(
+            result,
+            SegmentIntersection::Overlap(pf(frac(1, 3), frac(1, 3)), pf(frac(2, 3), frac(2, 3)))
+        );
+
+        // Coprime denominators throughout.
+        let result = segment_intersection_exact(
+            pf(frac(1, 3), frac(1, 5)),
+            pf(frac(7, 11), frac(5, 13)),
+            pf(frac(2, 7), frac(3, 7)),
+            pf(frac(5, 9), frac(1, 17)),
+        );
+        assert_eq!(
+            result,
+            SegmentIntersection::Point(pf(frac(38657, 92961), frac(235, 939)))
+        );
+    }
+
+    #[test]
+    fn line_circle_intersection_test() {
+        let center = p(1.0, 1.0);
+
+        let result = line_circle_intersection(p(1.0, -5.0), p(1.0, 5.0), center, 2.0);
+        assert_eq!(result, vec![p(1.0, -1.0), p(1.0, 3.0)]);
+
+        let result = line_circle_intersection(p(1.0, 5.0), p(1.0, -5.0), center, 2.0);
+        assert_eq!(result, vec![p(1.0, 3.0), p(1.0, -1.0)]);
+
+        let result = line_circle_intersection(p(-5.0, 3.0), p(5.0, 3.0), center, 2.0);
+        assert_eq!(result, vec![p(1.0, 3.0)]);
+
+        let result = line_circle_intersection(p(-5.0, 4.0), p(5.0, 4.0), center, 2.0);
+        assert!(result.is_empty());
+
+        let result = line_circle_intersection(p(0.0, 0.0), p(0.0, 0.0), center, 2.0);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn circle_circle_intersection_test() {
+        let result = circle_circle_intersection(p(0.0, 0.0), 5.0, p(8.0, 0.0), 5.0);
+        assert_eq!(
+            result,
+            CircleIntersection::Points(vec![p(4.0, 3.0), p(4.0, -3.0)])
+        );
+
+        // Internally tangent.
+        let result = circle_circle_intersection(p(0.0, 0.0), 2.0, p(1.0, 0.0), 1.0);
+        assert_eq!(result, CircleIntersection::Points(vec![p(2.0, 0.0)]));
+
+        // Too far apart, and one inside the other.
+        let result = circle_circle_intersection(p(0.0, 0.0), 1.0, p(5.0, 0.0), 1.0);
+        assert_eq!(result, CircleIntersection::Points(Vec::new()));
+        let result = circle_circle_intersection(p(0.0, 0.0), 5.0, p(1.0, 0.0), 1.0);
+        assert_eq!(result, CircleIntersection::Points(Vec::new()));
+
+        let result = circle_circle_intersection(p(1.0, 1.0), 2.0, p(1.0, 1.0), 2.0);
+        assert_eq!(result, CircleIntersection::Coincident);
+        let result = circle_circle_intersection(p(1.0, 1.0), 2.0, p(1.0, 1.0), 3.0);
+        assert_eq!(result, CircleIntersection::Points(Vec::new()));
+    }
+
+    #[test]
+    fn point_segment_distance_test() {
+        let a = p(0.0, 0.0);
+        let b = p(4.0, 0.0);
+
+        assert_eq!(point_segment_distance(p(2.0, 3.0), a, b), 3.0);
+        assert_eq!(point_segment_distance(p(-3.0, 4.0), a, b), 5.0);
+        assert_eq!(point_segment_distance(p(7.0, -4.0), a, b), 5.0);
+        assert_eq!(point_segment_distance(p(3.0, 4.0), a, a), 5.0);
+        assert_eq!(closest_point_on_segment(p(2.0, 3.0), a, b), p(2.0, 0.0));
+    }
+
+    #[test]
+    fn point_segment_distance_squared_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let a = Point2::new(frac(0, 1), frac(0, 1));
+        let b = Point2::new(frac(1, 1), frac(1, 1));
+
+        // The distance from (1, 0) to the diagonal is 1/sqrt(2).
+        let result = point_segment_distance_squared(Point2::new(frac(1, 1), frac(0, 1)), a, b);
+        assert_eq!(result, frac(1, 2));
+
+        let result = point_segment_distance_squared(Point2::new(frac(2, 1), frac(1, 1)), a, b);
+        assert_eq!(result, frac(1, 1));
+
+        let result =
+            point_segment_distance_squared(Point2::new(3, 4), Point2::new(0, 0), Point2::new(0, 0));
+        assert_eq!(result, 25);
+    }
+}
@@ -0,0 +1,541 @@
+//! This module provides GeoJSON parsing and serialization.
+//!
+//! It is part of the `io` module. GeoJSON (RFC 7946) is the JSON format used by
+//! web maps, for example `{"type":"Point","coordinates":[1,2]}`. All seven
+//! geometry types are supported. A Feature is read as its geometry, and a
+//! FeatureCollection as a GeometryCollection of its features' geometries, with
+//! properties ignored. Positions with an altitude are rejected, since geometries
+//! are two dimensional, as are coordinates that are not finite and documents
+//! nested more than 128 deep. Output is compact JSON with no whitespace.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::io::geojson;
+//!
+//! let text = r#"{
+//!     "type": "Feature",
+//!     "properties": {"name": "Plot 7"},
+//!     "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [3, 0], [3, 2], [0, 0]]]}
+//! }"#;
+//! let geometry = geojson::from_geojson::<f64>(text).unwrap();
+//!
+//! assert_eq!(geometry.area(), 3.0);
+//! assert_eq!(
+//!     geojson::to_geojson(&geometry).unwrap(),
+//!     r#"{"type":"Polygon","coordinates":[[[0,0],[3,0],[3,2],[0,0]]]}"#
+//! );
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_geojson`: Parses a geometry from a GeoJSON geometry, Feature or FeatureCollection.
+//! - `to_geojson`: Writes a geometry as a GeoJSON geometry object.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::io::geometry::{
+    polygon_from_rings, polygon_rings, Geometry, MAX_NESTING_DEPTH,
+};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::PolygonWithHoles;
+use num_traits::Float;
+use std::fmt::{Display, Write};
+
+// A parsed JSON value. Object members keep their order.
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn member(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+// Function to parse a geometry from a GeoJSON geometry, Feature or FeatureCollection.
+pub fn from_geojson<T: Float>(text: &str) -> Result<Geometry<T>, GeometryError> {
+    let mut parser = JsonParser {
+        text: text.as_bytes(),
+        position: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.whitespace();
+    if parser.position != parser.text.len() {
+        return Err(GeometryError::new(
+            "Unexpected text after the GeoJSON value",
+        ));
+    }
+    read_object(&value)
+}
+
+// Function to write a geometry as a GeoJSON geometry object.
+pub fn to_geojson<T: Float + Display>(geometry: &Geometry<T>) -> Result<String, GeometryError> {
+    if !geometry.is_finite() {
+        return Err(GeometryError::new(
+            "GeoJSON cannot represent coordinates that are not finite",
+        ));
+    }
+    let mut text = String::new();
+    write_geometry(&mut text, geometry);
+    Ok(text)
+}
+
+fn read_object<T: Float>(value: &Json) -> Result<Geometry<T>, GeometryError> {
+    let kind = match value.member("type") {
+        Some(Json::String(kind)) => kind.as_str(),
+        _ => return Err(GeometryError::new("GeoJSON object has no \"type\" string")),
+    };
+    match kind {
+        "Feature" => match value.member("geometry") {
+            Some(Json::Null) | None => Err(GeometryError::new("GeoJSON Feature has no geometry")),
+            Some(geometry) => read_object(geometry),
+        },
+        "FeatureCollection" => {
+            let features = match value.member("features") {
+                Some(Json::Array(features)) => features,
+                _ => {
+                    return Err(GeometryError::new(
+                        "GeoJSON FeatureCollection has no \"features\" array",
+                    ))
+                }
+            };
+            // Features without a geometry are skipped.
+            let parts = features
+                .iter()
+                .filter(|feature| !matches!(feature.member("geometry"), Some(Json::Null)))
+                .map(read_object)
+                .collect::<Result<_, _>>()?;
+            Ok(Geometry::GeometryCollection(parts))
+        }
+        "GeometryCollection" => match value.member("geometries") {
+            Some(Json::Array(parts)) => Ok(Geometry::GeometryCollection(
+                parts.iter().map(read_object).collect::<Result<_, _>>()?,
+            )),
+            _ => Err(GeometryError::new(
+                "GeoJSON GeometryCollection has no \"geometries\" array",
+            )),
+        },
+        _ => {
+            let coordinates = value.member("coordinates").ok_or_else(|| {
+                GeometryError::new(&format!("GeoJSON {} has no \"coordinates\"", kind))
+            })?;
+            read_geometry(kind, coordinates)
+        }
+    }
+}
+
+fn read_geometry<T: Float>(kind: &str, coordinates: &Json) -> Result<Geometry<T>, GeometryError> {
+    match kind {
+        "Point" => Ok(Geometry::Point(read_position(coordinates)?)),
+        "LineString" => Ok(Geometry::LineString(read_path(coordinates)?)),
+        "Polygon" => Ok(Geometry::Polygon(read_polygon(coordinates)?)),
+        "MultiPoint" => Ok(Geometry::MultiPoint(read_path(coordinates)?)),
+        "MultiLineString" => Ok(Geometry::MultiLineString(read_array(
+            coordinates,
+            read_path,
+        )?)),
+        "MultiPolygon" => Ok(Geometry::MultiPolygon(read_array(
+            coordinates,
+            read_polygon,
+        )?)),
+        _ => Err(GeometryError::new(&format!(
+            "Unknown GeoJSON type \"{}\"",
+            kind
+        ))),
+    }
+}
+
+fn read_array<U>(
+    value: &Json,
+    read_item: impl Fn(&Json) -> Result<U, GeometryError>,
+) -> Result<Vec<U>, GeometryError> {
+    match value {
+        Json::Array(items) => items.iter().map(read_item).collect(),
+        _ => Err(GeometryError::new(
+            "Expected an array of GeoJSON coordinates",
+        )),
+    }
+}
+
+fn read_position<T: Float>(value: &Json) -> Result<Point2<T>, GeometryError> {
+    match value {
+        Json::Array(numbers) => match numbers.as_slice() {
+            [Json::Number(x), Json::Number(y)] => {
+                let coordinate = |value: f64| {
+                    T::from(value)
+                        .filter(|value| value.is_finite())
+                        .ok_or_else(|| GeometryError::new("GeoJSON coordinates must be finite"))
+                };
+                Ok(Point2::new(coordinate(*x)?, coordinate(*y)?))
+            }
+            [Json::Number(_), Json::Number(_), ..] => Err(GeometryError::new(
+                "Only two dimensional GeoJSON positions are supported",
+            )),
+            _ => Err(GeometryError::new("A GeoJSON position must be two numbers")),
+        },
+        _ => Err(GeometryError::new("A GeoJSON position must be an array")),
+    }
+}
+
+fn read_path<T: Float>(value: &Json) -> Result<Vec<Point2<T>>, GeometryError> {
+    read_array(value, read_position)
+}
+
+fn read_polygon<T: Float>(value: &Json) -> Result<PolygonWithHoles<T>, GeometryError> {
+    Ok(polygon_from_rings(read_array(value, read_path)?))
+}
+
+fn write_geometry<T: Float + Display>(text: &mut String, geometry: &Geometry<T>) {
+    let (kind, write_coordinates): (&str, &dyn Fn(&mut String)) = match geometry {
+        Geometry::Point(point) => ("Point", &|text| write_position(text, point)),
+        Geometry::LineString(path) => ("LineString", &|text| write_path(text, path)),
+        Geometry::Polygon(polygon) => ("Polygon", &|text| write_polygon(text, polygon)),
+        Geometry::MultiPoint(points) => ("MultiPoint", &|text| write_path(text, points)),
+        Geometry::MultiLineString(paths) => ("MultiLineString", &|text| {
+            write_array(text, paths, |text, path| write_path(text, path))
+        }),
+        Geometry::MultiPolygon(polygons) => ("MultiPolygon", &|text| {
+            write_array(text, polygons, write_polygon)
+        }),
+        Geometry::GeometryCollection(parts) => {
+            text.push_str("{\"type\":\"GeometryCollection\",\"geometries\":");
+            write_array(text, parts, write_geometry);
+            text.push('}');
+            return;
+        }
+    };
+    write!(text, "{{\"type\":\"{}\",\"coordinates\":", kind).unwrap();
+    write_coordinates(text);
+    text.push('}');
+}
+
+fn write_position<T: Display>(text: &mut String, point: &Point2<T>) {
+    write!(text, "[{},{}]", point.x, point.y).unwrap();
+}
+
+fn write_path<T: Display>(text: &mut String, path: &[Point2<T>]) {
+    write_array(text, path, write_position);
+}
+
+fn write_polygon<T: Float + Display>(text: &mut String, polygon: &PolygonWithHoles<T>) {
+    write_array(text, &polygon_rings(polygon), |text, ring| {
+        write_path(text, ring)
+    });
+}
+
+fn write_array<U>(text: &mut String, items: &[U], write_item: impl Fn(&mut String, &U)) {
+    text.push('[');
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            text.push(',');
+        }
+        write_item(text, item);
+    }
+    text.push(']');
+}
+
+// A minimal JSON reader, enough for GeoJSON documents.
+struct JsonParser<'a> {
+    text: &'a [u8],
+    position: usize,
+    // How many arrays and objects enclose the current position.
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self, message: &str) -> GeometryError {
+        GeometryError::new(&format!(
+            "{} at byte {} of the GeoJSON",
+            message, self.position
+        ))
+    }
+
+    fn whitespace(&mut self) {
+        while self
+            .text
+            .get(self.position)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn consume(&mut self, literal: &str) -> bool {
+        let matches = self.text[self.position..].starts_with(literal.as_bytes());
+        if matches {
+            self.position += literal.len();
+        }
+        matches
+    }
+
+    fn value(&mut self) -> Result<Json, GeometryError> {
+        self.whitespace();
+        match self.text.get(self.position) {
+            Some(b'{') => self.nested(JsonParser::object),
+            Some(b'[') => self.nested(JsonParser::array),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ if self.consume("true") => Ok(Json::Bool(true)),
+            _ if self.consume("false") => Ok(Json::Bool(false)),
+            _ if self.consume("null") => Ok(Json::Null),
+            _ => Err(self.error("Expected a JSON value")),
+        }
+    }
+
+    // Parses an array or object, failing once they are nested too deeply.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Json, GeometryError>,
+    ) -> Result<Json, GeometryError> {
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(self.error("GeoJSON is nested too deeply"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Json, GeometryError> {
+        self.position += 1;
+        let mut members = Vec::new();
+        self.whitespace();
+        if self.consume("}") {
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.whitespace();
+            if self.text.get(self.position) != Some(&b'"') {
+                return Err(self.error("Expected a member name"));
+            }
+            let name = self.string()?;
+            self.whitespace();
+            if !self.consume(":") {
+                return Err(self.error("Expected \":\""));
+            }
+            members.push((name, self.value()?));
+            self.whitespace();
+            if self.consume("}") {
+                return Ok(Json::Object(members));
+            }
+            if !self.consume(",") {
+                return Err(self.error("Expected \",\" or \"}\""));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, GeometryError> {
+        self.position += 1;
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.consume("]") {
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            if self.consume("]") {
+                return Ok(Json::Array(items));
+            }
+            if !self.consume(",") {
+                return Err(self.error("Expected \",\" or \"]\""));
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, GeometryError> {
+        let start = self.position;
+        while self
+            .text
+            .get(self.position)
+            .is_some_and(|&byte| byte.is_ascii_digit() || b"+-.eE".contains(&byte))
+        {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.text[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .map(Json::Number)
+            .ok_or_else(|| self.error("Invalid number"))
+    }
+
+    fn string(&mut self) -> Result<String, GeometryError> {
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = *self
+                .text
+                .get(self.position)
+                .ok_or_else(|| self.error("Unterminated string"))?;
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = *self
+                        .text
+                        .get(self.position)
+                        .ok_or_else(|| self.error("Unterminated string"))?;
+                    self.position += 1;
+                    let decoded = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.error("Invalid escape")),
+                    };
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(decoded.encode_utf8(&mut buffer).as_bytes());
+                }
+                _ => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("Invalid UTF-8 in string"))
+    }
+
+    // Decodes the digits of a \u escape, joining a surrogate pair if one follows.
+    fn unicode_escape(&mut self) -> Result<char, GeometryError> {
+        let high = self.hex_digits()?;
+        let code = if (0xD800..0xDC00).contains(&high) && self.consume("\\u") {
+            let low = self.hex_digits()?;
+            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, GeometryError> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("Invalid unicode escape"))?;
+        self.position += 4;
+        Ok(digits)
+    }
+}
+
+#[cfg(test)]
+mod geometry_io_geojson_tests {
+    use super::*;
+    use crate::fields::geometry::io::wkt::from_wkt;
+
+    #[test]
+    fn geojson_round_trip_test() {
+        for wkt in [
+            "POINT (1.5 -2)",
+            "LINESTRING (0 0, 1 1, 2 0.25)",
+            "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT ((0 0), (1 2))",
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 2))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 3 4))",
+            "LINESTRING EMPTY",
+        ] {
+            let geometry = from_wkt::<f64>(wkt).unwrap();
+            let text = to_geojson(&geometry).unwrap();
+            assert_eq!(from_geojson::<f64>(&text).unwrap(), geometry, "{}", text);
+        }
+    }
+
+    #[test]
+    fn to_geojson_test() {
+        let geometry =
+            from_wkt::<f64>("GEOMETRYCOLLECTION (POINT (1 2), MULTIPOINT ((0 0), (0.5 1)))")
+                .unwrap();
+
+        assert_eq!(
+            to_geojson(&geometry).unwrap(),
+            r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1,2]},{"type":"MultiPoint","coordinates":[[0,0],[0.5,1]]}]}"#
+        );
+    }
+
+    #[test]
+    fn from_geojson_feature_test() {
+        let text = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "id": 1, "properties": {"name": "café \"A\"", "tags": [true, null, -1.5e3]},
+             "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [2, 0], [2, 2], [0, 2], [0, 0]]]}},
+            {"type": "Feature", "properties": {}, "geometry": null},
+            {"type": "Feature", "properties": null,
+             "geometry": {"type": "LineString", "coordinates": [[0, 0], [3, 4]]}}
+        ]}"#;
+        let geometry = from_geojson::<f64>(text).unwrap();
+
+        match &geometry {
+            Geometry::GeometryCollection(parts) => assert_eq!(parts.len(), 2),
+            _ => panic!("expected a collection"),
+        }
+        assert_eq!(geometry.area(), 4.0);
+        assert_eq!(geometry.perimeter(), 8.0);
+        assert_eq!(geometry.length(), 5.0);
+    }
+
+    #[test]
+    fn from_geojson_error_test() {
+        for text in [
+            "",
+            "{}",
+            "[1, 2]",
+            r#"{"type": "Point"}"#,
+            r#"{"type": "Point", "coordinates": [1]}"#,
+            r#"{"type": "Point", "coordinates": [1, 2, 3]}"#,
+            r#"{"type": "Point", "coordinates": ["1", 2]}"#,
+            r#"{"type": "Circle", "coordinates": [1, 2]}"#,
+            r#"{"type": "Point", "coordinates": [1, 2]} extra"#,
+            r#"{"type": "Point", "coordinates": [1, 2],}"#,
+            r#"{"type": "Feature", "geometry": null}"#,
+            r#"{"type": "LineString", "coordinates": [[0, 0], [1, 1]"#,
+            r#"{"type": "Point\u12", "coordinates": [1, 2]}"#,
+            r#"{"type": "Point", "coordinates": [1e400, 2]}"#,
+            r#"{"type": "Point", "coordinates": [1, -1e400]}"#,
+        ] {
+            assert!(
+                from_geojson::<f64>(text).is_err(),
+                "{} should not parse",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn geojson_nesting_depth_test() {
+        let nested = |depth: usize| {
+            let mut text = r#"{"type":"GeometryCollection","geometries":["#.repeat(depth);
+            text.push_str(r#"{"type":"Point","coordinates":[1,2]}"#);
+            text + &"]}".repeat(depth)
+        };
+        // Each collection adds an object and an array, and the point two more.
+        assert!(from_geojson::<f64>(&nested(MAX_NESTING_DEPTH / 2 - 1)).is_ok());
+        assert!(from_geojson::<f64>(&nested(MAX_NESTING_DEPTH / 2)).is_err());
+        assert!(from_geojson::<f64>(&"[".repeat(200000)).is_err());
+    }
+
+    #[test]
+    fn to_geojson_non_finite_test() {
+        assert!(to_geojson(&Geometry::Point(Point2::new(f64::INFINITY, 2.0))).is_err());
+        let collection = Geometry::GeometryCollection(vec![Geometry::MultiPoint(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, f64::NAN),
+        ])]);
+        assert!(to_geojson(&collection).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! `Geometry` is an enum representing any shape read from or written to a text format
+//!
+//! It mirrors the geometry types shared by Well-Known Text and GeoJSON. Polygons
+//! are `PolygonWithHoles`, whose rings are implicitly closed, so the repeated
+//! closing vertex of a text ring is dropped on import and added back on export.
+//! Area and perimeter run directly on a geometry and add up over its parts.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::io::geometry::Geometry;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::Polygon;
+//!
+//! let square = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//! ]);
+//! let path = vec![Point2::new(0.0, 0.0), Point2::new(3.0, 4.0)];
+//! let collection = Geometry::GeometryCollection(vec![
+//!     Geometry::from(square),
+//!     Geometry::LineString(path),
+//! ]);
+//!
+//! assert_eq!(collection.area(), 4.0);
+//! assert_eq!(collection.perimeter(), 8.0);
+//! assert_eq!(collection.length(), 5.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `area`: Computes the total area of the polygonal parts.
+//! - `perimeter`: Computes the total boundary length of the polygonal parts.
+//! - `length`: Computes the total length of the line string parts.
+//! - `is_empty`: Checks whether the geometry has no coordinates.
+//! - `is_finite`: Checks whether every coordinate is finite.
+
+use crate::fields::geometry::area::get_area_multipolygon;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use num_traits::Float;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry<T> {
+    Point(Point2<T>),
+    LineString(Vec<Point2<T>>),
+    Polygon(PolygonWithHoles<T>),
+    MultiPoint(Vec<Point2<T>>),
+    MultiLineString(Vec<Vec<Point2<T>>>),
+    MultiPolygon(Vec<PolygonWithHoles<T>>),
+    GeometryCollection(Vec<Geometry<T>>),
+}
+
+impl<T> Geometry<T>
+where
+    T: Float,
+{
+    // Function to get the total area of the polygons, zero for points and lines.
+    pub fn area(&self) -> T {
+        match self {
+            Geometry::Polygon(polygon) => polygon.area(),
+            Geometry::MultiPolygon(polygons) => get_area_multipolygon(polygons),
+            Geometry::GeometryCollection(parts) => {
+                parts.iter().fold(T::zero(), |sum, part| sum + part.area())
+            }
+            _ => T::zero(),
+        }
+    }
+
+    // Function to get the total boundary length of the polygons, zero for points and lines.
+    pub fn perimeter(&self) -> T {
+        match self {
+            Geometry::Polygon(polygon) => polygon.perimeter(),
+            Geometry::MultiPolygon(polygons) => polygons
+                .iter()
+                .fold(T::zero(), |sum, polygon| sum + polygon.perimeter()),
+            Geometry::GeometryCollection(parts) => parts
+                .iter()
+                .fold(T::zero(), |sum, part| sum + part.perimeter()),
+            _ => T::zero(),
+        }
+    }
+
+    // Function to get the total length of the line strings, zero for points and polygons.
+    pub fn length(&self) -> T {
+        let path_length = |path: &[Point2<T>]| {
+            path.windows(2)
+                .fold(T::zero(), |sum, pair| sum + pair[0].distance(pair[1]))
+        };
+        match self {
+            Geometry::LineString(path) => path_length(path),
+            Geometry::MultiLineString(paths) => paths
+                .iter()
+                .fold(T::zero(), |sum, path| sum + path_length(path)),
+            Geometry::GeometryCollection(parts) => parts
+                .iter()
+                .fold(T::zero(), |sum, part| sum + part.length()),
+            _ => T::zero(),
+        }
+    }
+
+    // Function to check whether the geometry has no coordinates.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Geometry::Point(_) => false,
+            Geometry::LineString(points) | Geometry::MultiPoint(points) => points.is_empty(),
+            Geometry::Polygon(polygon) => polygon.exterior().is_empty(),
+            Geometry::MultiLineString(paths) => paths.iter().all(|path| path.is_empty()),
+            Geometry::MultiPolygon(polygons) => {
+                polygons.iter().all(|polygon| polygon.exterior().is_empty())
+            }
+            Geometry::GeometryCollection(parts) => parts.iter().all(|part| part.is_empty()),
+        }
+    }
+
+    // Function to check whether every coordinate is finite, as text formats require.
+    pub fn is_finite(&self) -> bool {
+        let finite = |point: &Point2<T>| point.x.is_finite() && point.y.is_finite();
+        let finite_polygon = |polygon: &PolygonWithHoles<T>| {
+            std::iter::once(polygon.exterior())
+                .chain(polygon.holes())
+                .all(|ring| ring.vertices().iter().all(finite))
+        };
+        match self {
+            Geometry::Point(point) => finite(point),
+            Geometry::LineString(points) | Geometry::MultiPoint(points) => {
+                points.iter().all(finite)
+            }
+            Geometry::Polygon(polygon) => finite_polygon(polygon),
+            Geometry::MultiLineString(paths) => paths.iter().flatten().all(finite),
+            Geometry::MultiPolygon(polygons) => polygons.iter().all(finite_polygon),
+            Geometry::GeometryCollection(parts) => parts.iter().all(|part| part.is_finite()),
+        }
+    }
+}
+
+impl<T> From<Point2<T>> for Geometry<T> {
+    fn from(point: Point2<T>) -> Self {
+        Geometry::Point(point)
+    }
+}
+
+impl<T> From<Polygon<T>> for Geometry<T>
+where
+    T: Float,
+{
+    fn from(polygon: Polygon<T>) -> Self {
+        Geometry::Polygon(PolygonWithHoles::from(polygon))
+    }
+}
+
+impl<T> From<PolygonWithHoles<T>> for Geometry<T> {
+    fn from(polygon: PolygonWithHoles<T>) -> Self {
+        Geometry::Polygon(polygon)
+    }
+}
+
+// The deepest nesting of arrays or collections the text parsers accept, so
+// that untrusted input cannot exhaust the stack.
+pub(crate) const MAX_NESTING_DEPTH: usize = 128;
+
+// Removes the repeated closing vertex of a ring, if it has one.
+pub(crate) fn open_ring<T: Float>(mut ring: Vec<Point2<T>>) -> Vec<Point2<T>> {
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring
+}
+
+// Repeats the first vertex of a ring at its end, as text formats expect.
+pub(crate) fn closed_ring<T: Float>(ring: &Polygon<T>) -> Vec<Point2<T>> {
+    let mut vertices = ring.vertices().to_vec();
+    if let Some(&first) = vertices.first() {
+        vertices.push(first);
+    }
+    vertices
+}
+
+// Builds a polygon from its rings, the first being the exterior.
+pub(crate) fn polygon_from_rings<T: Float>(rings: Vec<Vec<Point2<T>>>) -> PolygonWithHoles<T> {
+    let mut rings = rings.into_iter().map(|ring| Polygon::new(open_ring(ring)));
+    let exterior = rings.next().unwrap_or_else(|| Polygon::new(Vec::new()));
+    PolygonWithHoles::new(exterior, rings.collect())
+}
+
+// Gets the closed rings of a polygon, the exterior first, or none if it is empty.
+pub(crate) fn polygon_rings<T: Float>(polygon: &PolygonWithHoles<T>) -> Vec<Vec<Point2<T>>> {
+    if polygon.exterior().is_empty() && polygon.holes().is_empty() {
+        return Vec::new();
+    }
+    std::iter::once(polygon.exterior())
+        .chain(polygon.holes())
+        .map(closed_ring)
+        .collect()
+}
+
+#[cfg(test)]
+mod geometry_io_geometry_tests {
+    use super::*;
+
+    fn square(origin: f64, side: f64) -> Polygon<f64> {
+        Polygon::new(vec![
+            Point2::new(origin, origin),
+            Point2::new(origin + side, origin),
+            Point2::new(origin + side, origin + side),
+            Point2::new(origin, origin + side),
+        ])
+    }
+
+    #[test]
+    fn geometry_area_perimeter_test() {
+        let frame = PolygonWithHoles::new(square(0.0, 4.0), vec![square(1.0, 2.0)]);
+        let multi = Geometry::MultiPolygon(vec![frame.clone(), square(10.0, 1.0).into()]);
+
+        assert_eq!(Geometry::from(frame).area(), 12.0);
+        assert_eq!(multi.area(), 13.0);
+        assert_eq!(multi.perimeter(), 28.0);
+        assert_eq!(multi.length(), 0.0);
+        assert_eq!(Geometry::Point(Point2::new(1.0, 1.0)).area(), 0.0);
+    }
+
+    #[test]
+    fn geometry_length_test() {
+        let lines = Geometry::MultiLineString(vec![
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(3.0, 4.0),
+                Point2::new(3.0, 0.0),
+            ],
+            vec![Point2::new(0.0, 0.0)],
+        ]);
+        let collection = Geometry::GeometryCollection(vec![lines.clone(), square(0.0, 1.0).into()]);
+
+        assert_eq!(lines.length(), 9.0);
+        assert_eq!(collection.length(), 9.0);
+        assert_eq!(collection.area(), 1.0);
+        assert_eq!(lines.perimeter(), 0.0);
+    }
+
+    #[test]
+    fn geometry_is_empty_test() {
+        assert!(Geometry::<f64>::LineString(vec![]).is_empty());
+        assert!(Geometry::<f64>::GeometryCollection(vec![]).is_empty());
+        assert!(Geometry::<f64>::Polygon(Polygon::new(vec![]).into()).is_empty());
+        assert!(!Geometry::Point(Point2::new(0.0, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn geometry_is_finite_test() {
+        let frame = PolygonWithHoles::new(square(0.0, 4.0), vec![square(1.0, f64::INFINITY)]);
+        let lines = Geometry::MultiLineString(vec![vec![Point2::new(0.0, f64::NAN)]]);
+
+        assert!(Geometry::from(square(0.0, 1.0)).is_finite());
+        assert!(!Geometry::from(frame).is_finite());
+        assert!(!lines.is_finite());
+        assert!(!Geometry::GeometryCollection(vec![lines]).is_finite());
+        assert!(Geometry::<f64>::GeometryCollection(vec![]).is_finite());
+    }
+}
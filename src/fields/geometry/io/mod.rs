@@ -0,0 +1,16 @@
+//! This module contains text formats for exchanging geometries.
+//!
+//! It is part of the `geometry` module. Shapes are read into and written from a
+//! `Geometry`, which can hold points, line strings, polygons with holes, their
+//! multi-part forms and collections of any of these. Coordinates are two
+//! dimensional.
+//!
+//! # Modules
+//!
+//! - `geojson`: Contains GeoJSON parsing and serialization.
+//! - `geometry`: Contains the `Geometry` type and its area and length measures.
+//! - `wkt`: Contains Well-Known Text parsing and serialization.
+
+pub mod geojson;
+pub mod geometry;
+pub mod wkt;
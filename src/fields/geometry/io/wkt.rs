@@ -0,0 +1,433 @@
+//! This module provides Well-Known Text (WKT) parsing and serialization.
+//!
+//! It is part of the `io` module. WKT is the text format used by PostGIS and
+//! most GIS tools, for example `POLYGON ((0 0, 4 0, 4 3, 0 0))`. The POINT,
+//! LINESTRING, POLYGON, MULTIPOINT, MULTILINESTRING, MULTIPOLYGON and
+//! GEOMETRYCOLLECTION types are supported, along with EMPTY for all but POINT.
+//! Keywords are case-insensitive, and multipoints may be written with or without
+//! parentheses around each point. Z and M coordinates are rejected, since
+//! geometries are two dimensional, as are coordinates that are not finite and
+//! collections nested more than 128 deep.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::io::wkt;
+//!
+//! let text = "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))";
+//! let geometry = wkt::from_wkt::<f64>(text).unwrap();
+//!
+//! assert_eq!(geometry.area(), 15.0);
+//! assert_eq!(geometry.perimeter(), 20.0);
+//! assert_eq!(wkt::to_wkt(&geometry).unwrap(), text);
+//! assert!(wkt::from_wkt::<f64>("POLYGON ((0 0, 1 0").is_err());
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_wkt`: Parses a geometry from Well-Known Text.
+//! - `to_wkt`: Writes a geometry as Well-Known Text.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::io::geometry::{
+    polygon_from_rings, polygon_rings, Geometry, MAX_NESTING_DEPTH,
+};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::PolygonWithHoles;
+use num_traits::Float;
+use std::fmt::{Display, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(f64),
+    Open,
+    Close,
+    Comma,
+}
+
+// Function to parse a geometry from Well-Known Text.
+pub fn from_wkt<T: Float>(text: &str) -> Result<Geometry<T>, GeometryError> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+        depth: 0,
+    };
+    let geometry = parser.geometry()?;
+    match parser.next() {
+        None => Ok(geometry),
+        Some(token) => Err(unexpected(Some(token), "the end of the text")),
+    }
+}
+
+// Function to write a geometry as Well-Known Text.
+pub fn to_wkt<T: Float + Display>(geometry: &Geometry<T>) -> Result<String, GeometryError> {
+    if !geometry.is_finite() {
+        return Err(GeometryError::new(
+            "WKT cannot represent coordinates that are not finite",
+        ));
+    }
+    let mut text = String::new();
+    write_geometry(&mut text, geometry);
+    Ok(text)
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, GeometryError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            _ => {
+                let mut end = start;
+                let is_word = c.is_ascii_alphabetic();
+                while let Some(&(index, c)) = chars.peek() {
+                    let continues = if is_word {
+                        c.is_ascii_alphabetic()
+                    } else {
+                        c.is_ascii_digit() || "+-.eE".contains(c)
+                    };
+                    if !continues {
+                        break;
+                    }
+                    end = index + c.len_utf8();
+                    chars.next();
+                }
+                if end == start {
+                    return Err(GeometryError::new(&format!("Unexpected \"{}\" in WKT", c)));
+                }
+                let lexeme = &text[start..end];
+                if is_word {
+                    tokens.push(Token::Word(lexeme.to_ascii_uppercase()));
+                } else {
+                    let number = lexeme.parse().map_err(|_| {
+                        GeometryError::new(&format!("Invalid WKT number \"{}\"", lexeme))
+                    })?;
+                    tokens.push(Token::Number(number));
+                }
+                continue;
+            }
+        };
+        tokens.push(token);
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+fn unexpected(found: Option<Token>, expected: &str) -> GeometryError {
+    match found {
+        Some(token) => GeometryError::new(&format!(
+            "Unexpected {:?} in WKT, expected {}",
+            token, expected
+        )),
+        None => GeometryError::new(&format!("WKT ended early, expected {}", expected)),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    // How many geometry collections enclose the current position.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, description: &str) -> Result<(), GeometryError> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            token => Err(unexpected(token, description)),
+        }
+    }
+
+    // Consumes an EMPTY keyword, returning whether there was one.
+    fn empty(&mut self) -> bool {
+        let is_empty = self.peek() == Some(&Token::Word("EMPTY".to_string()));
+        if is_empty {
+            self.position += 1;
+        }
+        is_empty
+    }
+
+    // Parses a parenthesised, comma-separated list of items.
+    fn list<U>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<U, GeometryError>,
+    ) -> Result<Vec<U>, GeometryError> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+        self.expect(Token::Open, "\"(\" or EMPTY")?;
+        let mut items = vec![item(self)?];
+        loop {
+            match self.next() {
+                Some(Token::Comma) => items.push(item(self)?),
+                Some(Token::Close) => return Ok(items),
+                token => return Err(unexpected(token, "\",\" or \")\"")),
+            }
+        }
+    }
+
+    fn geometry<T: Float>(&mut self) -> Result<Geometry<T>, GeometryError> {
+        let tag = match self.next() {
+            Some(Token::Word(word)) => word,
+            token => return Err(unexpected(token, "a geometry type")),
+        };
+        if let Some(Token::Word(word)) = self.peek() {
+            if matches!(word.as_str(), "Z" | "M" | "ZM") {
+                return Err(GeometryError::new(
+                    "Only two dimensional WKT coordinates are supported",
+                ));
+            }
+        }
+        match tag.as_str() {
+            "POINT" => {
+                if self.empty() {
+                    return Err(GeometryError::new("POINT EMPTY is not supported"));
+                }
+                self.expect(Token::Open, "\"(\"")?;
+                let point = self.coordinate()?;
+                self.expect(Token::Close, "\")\"")?;
+                Ok(Geometry::Point(point))
+            }
+            "LINESTRING" => Ok(Geometry::LineString(self.path()?)),
+            "POLYGON" => Ok(Geometry::Polygon(self.polygon()?)),
+            "MULTIPOINT" => Ok(Geometry::MultiPoint(self.list(|parser| {
+                // Each point may or may not be wrapped in parentheses.
+                if parser.peek() != Some(&Token::Open) {
+                    return parser.coordinate();
+                }
+                parser.next();
+                let point = parser.coordinate()?;
+                parser.expect(Token::Close, "\")\"")?;
+                Ok(point)
+            })?)),
+            "MULTILINESTRING" => Ok(Geometry::MultiLineString(self.list(Parser::path)?)),
+            "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(self.list(Parser::polygon)?)),
+            "GEOMETRYCOLLECTION" => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    return Err(GeometryError::new("WKT collections are nested too deeply"));
+                }
+                self.depth += 1;
+                let parts = self.list(Parser::geometry);
+                self.depth -= 1;
+                Ok(Geometry::GeometryCollection(parts?))
+            }
+            _ => Err(GeometryError::new(&format!(
+                "Unknown WKT geometry type \"{}\"",
+                tag
+            ))),
+        }
+    }
+
+    fn coordinate<T: Float>(&mut self) -> Result<Point2<T>, GeometryError> {
+        let mut number = || match self.next() {
+            Some(Token::Number(value)) => T::from(value)
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| GeometryError::new("WKT coordinates must be finite")),
+            token => Err(unexpected(token, "a coordinate")),
+        };
+        let point = Point2::new(number()?, number()?);
+        if let Some(Token::Number(_)) = self.peek() {
+            return Err(GeometryError::new(
+                "Only two dimensional WKT coordinates are supported",
+            ));
+        }
+        Ok(point)
+    }
+
+    fn path<T: Float>(&mut self) -> Result<Vec<Point2<T>>, GeometryError> {
+        self.list(Parser::coordinate)
+    }
+
+    fn polygon<T: Float>(&mut self) -> Result<PolygonWithHoles<T>, GeometryError> {
+        Ok(polygon_from_rings(self.list(Parser::path)?))
+    }
+}
+
+fn write_geometry<T: Float + Display>(text: &mut String, geometry: &Geometry<T>) {
+    match geometry {
+        Geometry::Point(point) => {
+            text.push_str("POINT (");
+            write_coordinate(text, point);
+            text.push(')');
+        }
+        Geometry::LineString(path) => {
+            text.push_str("LINESTRING ");
+            write_list(text, path, write_coordinate);
+        }
+        Geometry::Polygon(polygon) => {
+            text.push_str("POLYGON ");
+            write_polygon(text, polygon);
+        }
+        Geometry::MultiPoint(points) => {
+            text.push_str("MULTIPOINT ");
+            write_list(text, points, |text, point| {
+                text.push('(');
+                write_coordinate(text, point);
+                text.push(')');
+            });
+        }
+        Geometry::MultiLineString(paths) => {
+            text.push_str("MULTILINESTRING ");
+            write_list(text, paths, |text, path| {
+                write_list(text, path, write_coordinate)
+            });
+        }
+        Geometry::MultiPolygon(polygons) => {
+            text.push_str("MULTIPOLYGON ");
+            write_list(text, polygons, write_polygon);
+        }
+        Geometry::GeometryCollection(parts) => {
+            text.push_str("GEOMETRYCOLLECTION ");
+            write_list(text, parts, write_geometry);
+        }
+    }
+}
+
+fn write_coordinate<T: Display>(text: &mut String, point: &Point2<T>) {
+    write!(text, "{} {}", point.x, point.y).unwrap();
+}
+
+fn write_polygon<T: Float + Display>(text: &mut String, polygon: &PolygonWithHoles<T>) {
+    write_list(text, &polygon_rings(polygon), |text, ring| {
+        write_list(text, ring, write_coordinate)
+    });
+}
+
+// Writes a parenthesised list, or EMPTY.
+fn write_list<U>(text: &mut String, items: &[U], write_item: impl Fn(&mut String, &U)) {
+    if items.is_empty() {
+        text.push_str("EMPTY");
+        return;
+    }
+    text.push('(');
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            text.push_str(", ");
+        }
+        write_item(text, item);
+    }
+    text.push(')');
+}
+
+#[cfg(test)]
+mod geometry_io_wkt_tests {
+    use super::*;
+
+    fn round_trip(text: &str) {
+        let geometry = from_wkt::<f64>(text).unwrap();
+        assert_eq!(to_wkt(&geometry).unwrap(), text);
+        assert_eq!(
+            from_wkt::<f64>(&to_wkt(&geometry).unwrap()).unwrap(),
+            geometry
+        );
+    }
+
+    #[test]
+    fn wkt_round_trip_test() {
+        round_trip("POINT (1.5 -2)");
+        round_trip("LINESTRING (0 0, 1 1, 2 0.25)");
+        round_trip("LINESTRING EMPTY");
+        round_trip("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))");
+        round_trip("POLYGON EMPTY");
+        round_trip("MULTIPOINT ((0 0), (0.0000001 100000000000000000000))");
+        round_trip("MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 2))");
+        round_trip("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))");
+        round_trip(
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 3 4), GEOMETRYCOLLECTION EMPTY)",
+        );
+    }
+
+    #[test]
+    fn from_wkt_test() {
+        let geometry = from_wkt::<f64>("  multipoint( 1 2 ,(3 4) ) ").unwrap();
+        assert_eq!(
+            geometry,
+            Geometry::MultiPoint(vec![Point2::new(1.0, 2.0), Point2::new(3.0, 4.0)])
+        );
+
+        let polygon = from_wkt::<f64>("POLYGON((0 0,2 0,2 2,0 2,0 0))").unwrap();
+        match &polygon {
+            Geometry::Polygon(polygon) => assert_eq!(polygon.exterior().len(), 4),
+            _ => panic!("expected a polygon"),
+        }
+        assert_eq!(polygon.area(), 4.0);
+        assert_eq!(polygon.perimeter(), 8.0);
+
+        let collection = from_wkt::<f64>(
+            "GEOMETRYCOLLECTION (MULTIPOLYGON (((0 0, 3 0, 3 3, 0 3, 0 0))), LINESTRING (0 0, 3 4))",
+        )
+        .unwrap();
+        assert_eq!(collection.area(), 9.0);
+        assert_eq!(collection.length(), 5.0);
+    }
+
+    #[test]
+    fn from_wkt_error_test() {
+        for text in [
+            "",
+            "POINT",
+            "POINT EMPTY",
+            "POINT (1)",
+            "POINT (1 2 3)",
+            "POINT Z (1 2 3)",
+            "POINT (1 2) extra",
+            "LINESTRING (0 0, 1 1",
+            "LINESTRING (0 0 1 1)",
+            "POLYGON (0 0, 1 1)",
+            "CIRCLE (0 0, 1)",
+            "POINT (1..5 2)",
+            "POINT (1 2);",
+            "POINT (1e400 2)",
+            "POINT (1 -1e400)",
+        ] {
+            assert!(from_wkt::<f64>(text).is_err(), "{} should not parse", text);
+        }
+        assert!(from_wkt::<f32>("POINT (1e300 2)").is_err());
+    }
+
+    #[test]
+    fn from_wkt_error_lexeme_test() {
+        // The message quotes only the offending lexeme, not the rest of the input.
+        let error = from_wkt::<f64>("LINESTRING (0 0, 1..5 2, 3 4)").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid WKT number \"1..5\"");
+        let error = from_wkt::<f64>("POINT (1 2);").unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected \";\" in WKT");
+    }
+
+    #[test]
+    fn wkt_nesting_depth_test() {
+        let nested = |depth: usize| {
+            "GEOMETRYCOLLECTION (".repeat(depth) + "POINT (1 2)" + &")".repeat(depth)
+        };
+        assert!(from_wkt::<f64>(&nested(MAX_NESTING_DEPTH)).is_ok());
+        assert!(from_wkt::<f64>(&nested(MAX_NESTING_DEPTH + 1)).is_err());
+        assert!(from_wkt::<f64>(&"GEOMETRYCOLLECTION (".repeat(100000)).is_err());
+    }
+
+    #[test]
+    fn to_wkt_non_finite_test() {
+        assert!(to_wkt(&Geometry::Point(Point2::new(f64::INFINITY, 2.0))).is_err());
+        let collection = Geometry::GeometryCollection(vec![Geometry::LineString(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(f64::NAN, 1.0),
+        ])]);
+        assert!(to_wkt(&collection).is_err());
+    }
+}
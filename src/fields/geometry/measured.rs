@@ -0,0 +1,355 @@
+//! This module provides unit-aware versions of the area, perimeter and volume functions.
+//!
+//! It is part of the `geometry` module. The functions mirror those in `area`,
+//! `circumference`, `perimeter` and `solids` but take `Length`s and return a
+//! `Length`, `Area` or `Volume`. Inputs in different units can be mixed freely, and
+//! passing an area where a length is expected fails to compile. Polygon functions
+//! take plain coordinates along with the unit they are measured in.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::measured;
+//! use crabmath::types::quantity::unit::{AreaUnit, LengthUnit, VolumeUnit};
+//! use crabmath::types::Length;
+//!
+//! let length = Length::new(3.5, LengthUnit::Meter);
+//! let width = Length::new(3.5, LengthUnit::Foot);
+//!
+//! let floor = measured::get_area_rectangle(length, width);
+//! let room = measured::get_volume_cuboid(length, width, Length::new(8.0, LengthUnit::Foot));
+//!
+//! assert!((floor.to(AreaUnit::Square(LengthUnit::Meter)) - 3.7338f64).abs() < 1e-12);
+//! assert!((room.to(VolumeUnit::Liter) - 9104.49792).abs() < 1e-6);
+//! ```
+//!
+//! Areas are not lengths:
+//!
+//! ```compile_fail
+//! use crabmath::fields::geometry::measured;
+//! use crabmath::types::quantity::unit::AreaUnit;
+//! use crabmath::types::Area;
+//!
+//! let area = Area::new(2.0f64, AreaUnit::Hectare);
+//! measured::get_area_circle(area);
+//! ```
+//!
+//! # Functions
+//!
+//! - `get_area_circle`: Computes the area of a circle.
+//! - `get_area_parallelogram`: Computes the area of a parallelogram.
+//! - `get_area_polygon`: Computes the area of a simple polygon from its vertices.
+//! - `get_area_rectangle`: Computes the area of a rectangle.
+//! - `get_area_regular_polygon`: Computes the area of a regular polygon.
+//! - `get_area_sector`: Computes the area of a circular sector.
+//! - `get_area_square`: Computes the area of a square.
+//! - `get_area_trapezoid`: Computes the area of a trapezoid.
+//! - `get_area_triangle`: Computes the area of a triangle.
+//! - `get_arc_length`: Computes the length of a circular arc.
+//! - `get_circumference`: Computes the circumference of a circle.
+//! - `get_perimeter_polygon`: Computes the perimeter of a polygon from its vertices.
+//! - `get_perimeter_rectangle`: Computes the perimeter of a rectangle.
+//! - `get_perimeter_regular_polygon`: Computes the perimeter of a regular polygon.
+//! - `get_perimeter_triangle`: Computes the perimeter of a triangle.
+//! - `get_surface_area_cone`: Computes the surface area of a right circular cone.
+//! - `get_surface_area_cuboid`: Computes the surface area of a cuboid.
+//! - `get_surface_area_cylinder`: Computes the surface area of a right circular cylinder.
+//! - `get_surface_area_sphere`: Computes the surface area of a sphere.
+//! - `get_volume_cone`: Computes the volume of a right circular cone.
+//! - `get_volume_cuboid`: Computes the volume of a cuboid.
+//! - `get_volume_cylinder`: Computes the volume of a right circular cylinder.
+//! - `get_volume_prism`: Computes the volume of a right prism with a base of any area.
+//! - `get_volume_pyramid`: Computes the volume of a pyramid with a base of any area.
+//! - `get_volume_sphere`: Computes the volume of a sphere.
+
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::solids::{surface_area, volume};
+use crate::fields::geometry::{area, circumference, perimeter};
+use crate::types::quantity::unit::LengthUnit;
+use crate::types::{Angle, Area, Length, Volume};
+use num_traits::Float;
+
+// Function to get area of a circle.
+pub fn get_area_circle<T: Float>(radius: Length<T>) -> Area<T> {
+    Area::from_square_meters(area::get_area_circle(radius.meters()))
+}
+
+// Function to get area of a parallelogram.
+pub fn get_area_parallelogram<T: Float>(base: Length<T>, height: Length<T>) -> Area<T> {
+    base * height
+}
+
+// Function to get area of a simple polygon whose coordinates are measured in `unit`.
+pub fn get_area_polygon<T: Float>(vertices: &[Point2<T>], unit: LengthUnit) -> Area<T> {
+    let scale = Length::new(T::one(), unit);
+    scale * scale * area::get_area_polygon(vertices)
+}
+
+// Function to get area of a rectangle.
+pub fn get_area_rectangle<T: Float>(length: Length<T>, width: Length<T>) -> Area<T> {
+    length * width
+}
+
+// Function to get area of a regular polygon.
+pub fn get_area_regular_polygon<T: Float>(sides: usize, side: Length<T>) -> Area<T> {
+    Area::from_square_meters(area::get_area_regular_polygon(sides, side.meters()))
+}
+
+// Function to get area of a circular sector.
+pub fn get_area_sector<T: Float>(radius: Length<T>, angle: Angle<T>) -> Area<T> {
+    radius * radius * (angle.radians().abs() / (T::one() + T::one()))
+}
+
+// Function to get area of a square.
+pub fn get_area_square<T: Float>(side: Length<T>) -> Area<T> {
+    side * side
+}
+
+// Function to get area of a trapezoid.
+pub fn get_area_trapezoid<T: Float>(
+    base1: Length<T>,
+    base2: Length<T>,
+    height: Length<T>,
+) -> Area<T> {
+    Area::from_square_meters(area::get_area_trapezoid(
+        base1.meters(),
+        base2.meters(),
+        height.meters(),
+    ))
+}
+
+// Function to get area of a triangle.
+pub fn get_area_triangle<T: Float>(base: Length<T>, height: Length<T>) -> Area<T> {
+    Area::from_square_meters(area::get_area_triangle(base.meters(), height.meters()))
+}
+
+// Function to get length of a circular arc.
+pub fn get_arc_length<T: Float>(radius: Length<T>, angle: Angle<T>) -> Length<T> {
+    radius * angle.radians().abs()
+}
+
+// Function to get circumference of a circle.
+pub fn get_circumference<T: Float>(radius: Length<T>) -> Length<T> {
+    Length::from_meters(circumference::get_circumference(radius.meters()))
+}
+
+// Function to get perimeter of a polygon whose coordinates are measured in `unit`.
+pub fn get_perimeter_polygon<T: Float>(vertices: &[Point2<T>], unit: LengthUnit) -> Length<T> {
+    Length::new(perimeter::get_perimeter_polygon(vertices), unit)
+}
+
+// Function to get perimeter of a rectangle.
+pub fn get_perimeter_rectangle<T: Float>(length: Length<T>, width: Length<T>) -> Length<T> {
+    (length + width) * (T::one() + T::one())
+}
+
+// Function to get perimeter of a regular polygon.
+pub fn get_perimeter_regular_polygon<T: Float>(sides: usize, side: Length<T>) -> Length<T> {
+    side * T::from(sides).unwrap()
+}
+
+// Function to get perimeter of a triangle.
+pub fn get_perimeter_triangle<T: Float>(a: Length<T>, b: Length<T>, c: Length<T>) -> Length<T> {
+    a + b + c
+}
+
+// Function to get surface area of a right circular cone.
+pub fn get_surface_area_cone<T: Float>(radius: Length<T>, height: Length<T>) -> Area<T> {
+    Area::from_square_meters(surface_area::get_surface_area_cone(
+        radius.meters(),
+        height.meters(),
+    ))
+}
+
+// Function to get surface area of a cuboid.
+pub fn get_surface_area_cuboid<T: Float>(
+    length: Length<T>,
+    width: Length<T>,
+    height: Length<T>,
+) -> Area<T> {
+    Area::from_square_meters(surface_area::get_surface_area_cuboid(
+        length.meters(),
+        width.meters(),
+        height.meters(),
+    ))
+}
+
+// Function to get surface area of a right circular cylinder.
+pub fn get_surface_area_cylinder<T: Float>(radius: Length<T>, height: Length<T>) -> Area<T> {
+    Area::from_square_meters(surface_area::get_surface_area_cylinder(
+        radius.meters(),
+        height.meters(),
+    ))
+}
+
+// Function to get surface area of a sphere.
+pub fn get_surface_area_sphere<T: Float>(radius: Length<T>) -> Area<T> {
+    Area::from_square_meters(surface_area::get_surface_area_sphere(radius.meters()))
+}
+
+// Function to get volume of a right circular cone.
+pub fn get_volume_cone<T: Float>(radius: Length<T>, height: Length<T>) -> Volume<T> {
+    Volume::from_cubic_meters(volume::get_volume_cone(radius.meters(), height.meters()))
+}
+
+// Function to get volume of a cuboid.
+pub fn get_volume_cuboid<T: Float>(
+    length: Length<T>,
+    width: Length<T>,
+    height: Length<T>,
+) -> Volume<T> {
+    length * width * height
+}
+
+// Function to get volume of a right circular cylinder.
+pub fn get_volume_cylinder<T: Float>(radius: Length<T>, height: Length<T>) -> Volume<T> {
+    Volume::from_cubic_meters(volume::get_volume_cylinder(
+        radius.meters(),
+        height.meters(),
+    ))
+}
+
+// Function to get volume of a right prism from the area of its base.
+pub fn get_volume_prism<T: Float>(base: Area<T>, height: Length<T>) -> Volume<T> {
+    base * height
+}
+
+// Function to get volume of a pyramid from the area of its base.
+pub fn get_volume_pyramid<T: Float>(base: Area<T>, height: Length<T>) -> Volume<T> {
+    base * height / T::from(3).unwrap()
+}
+
+// Function to get volume of a sphere.
+pub fn get_volume_sphere<T: Float>(radius: Length<T>) -> Volume<T> {
+    Volume::from_cubic_meters(volume::get_volume_sphere(radius.meters()))
+}
+
+#[cfg(test)]
+mod geometry_measured_tests {
+    use super::*;
+    use crate::types::quantity::unit::{AreaUnit, VolumeUnit};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9 * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    fn feet(value: f64) -> Length<f64> {
+        Length::new(value, LengthUnit::Foot)
+    }
+
+    #[test]
+    fn measured_area_test() {
+        let square_feet = AreaUnit::Square(LengthUnit::Foot);
+
+        assert_close(
+            get_area_circle(feet(1.0)).to(square_feet),
+            std::f64::consts::PI,
+        );
+        assert_close(
+            get_area_parallelogram(feet(2.0), feet(3.0)).to(square_feet),
+            6.0,
+        );
+        assert_close(
+            get_area_rectangle(feet(3.0), Length::new(1.0, LengthUnit::Yard)).to(square_feet),
+            9.0,
+        );
+        assert_close(get_area_regular_polygon(4, feet(2.0)).to(square_feet), 4.0);
+        assert_close(
+            get_area_square(feet(3.0)).to(AreaUnit::Square(LengthUnit::Yard)),
+            1.0,
+        );
+        assert_close(
+            get_area_trapezoid(feet(1.0), feet(3.0), feet(2.0)).to(square_feet),
+            4.0,
+        );
+        assert_close(get_area_triangle(feet(4.0), feet(3.0)).to(square_feet), 6.0);
+        assert_close(
+            get_area_sector(feet(2.0), Angle::from_degrees(90.0)).to(square_feet),
+            std::f64::consts::PI,
+        );
+
+        let vertices = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 3.0),
+        ];
+        assert_close(
+            get_area_polygon(&vertices, LengthUnit::Kilometer).to(AreaUnit::Hectare),
+            600.0,
+        );
+    }
+
+    #[test]
+    fn measured_length_test() {
+        assert_close(
+            get_circumference(feet(1.0)).to(LengthUnit::Foot),
+            2.0 * std::f64::consts::PI,
+        );
+        assert_close(
+            get_arc_length(feet(2.0), Angle::from_degrees(-180.0)).to(LengthUnit::Foot),
+            2.0 * std::f64::consts::PI,
+        );
+        assert_close(
+            get_perimeter_rectangle(feet(1.0), feet(2.0)).to(LengthUnit::Foot),
+            6.0,
+        );
+        assert_close(
+            get_perimeter_regular_polygon(6, feet(2.0)).to(LengthUnit::Yard),
+            4.0,
+        );
+        assert_close(
+            get_perimeter_triangle(feet(3.0), feet(4.0), feet(5.0)).to(LengthUnit::Inch),
+            144.0,
+        );
+
+        let vertices = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 3.0),
+        ];
+        assert_close(
+            get_perimeter_polygon(&vertices, LengthUnit::Mile).to(LengthUnit::Mile),
+            12.0,
+        );
+    }
+
+    #[test]
+    fn measured_solid_test() {
+        let cubic_feet = VolumeUnit::Cubic(LengthUnit::Foot);
+        let square_feet = AreaUnit::Square(LengthUnit::Foot);
+        let pi = std::f64::consts::PI;
+
+        assert_close(
+            get_volume_cuboid(feet(1.0), feet(2.0), feet(3.0)).to(cubic_feet),
+            6.0,
+        );
+        assert_close(
+            get_volume_cylinder(feet(1.0), feet(2.0)).to(cubic_feet),
+            2.0 * pi,
+        );
+        assert_close(get_volume_cone(feet(1.0), feet(3.0)).to(cubic_feet), pi);
+        assert_close(get_volume_sphere(feet(1.0)).to(cubic_feet), 4.0 * pi / 3.0);
+        assert_close(
+            get_volume_prism(get_area_square(feet(2.0)), feet(3.0)).to(cubic_feet),
+            12.0,
+        );
+        assert_close(
+            get_volume_pyramid(get_area_square(feet(2.0)), feet(3.0)).to(cubic_feet),
+            4.0,
+        );
+
+        assert_close(
+            get_surface_area_cuboid(feet(1.0), feet(2.0), feet(3.0)).to(square_feet),
+            22.0,
+        );
+        assert_close(
+            get_surface_area_cylinder(feet(1.0), feet(1.0)).to(square_feet),
+            4.0 * pi,
+        );
+        assert_close(
+            get_surface_area_cone(feet(3.0), feet(4.0)).to(square_feet),
+            24.0 * pi,
+        );
+        assert_close(get_surface_area_sphere(feet(1.0)).to(square_feet), 4.0 * pi);
+    }
+}
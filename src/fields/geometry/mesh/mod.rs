@@ -0,0 +1,15 @@
+//! This module contains triangle meshes and the file formats that store them.
+//!
+//! It is part of the `geometry` module and extends the polyhedron functions in
+//! `solids` to meshes read from files, with mass properties and checks that a
+//! mesh encloses a solid.
+//!
+//! # Modules
+//!
+//! - `obj`: Contains Wavefront OBJ parsing and serialization.
+//! - `stl`: Contains ASCII and binary STL parsing and serialization.
+//! - `triangle_mesh`: Contains the `TriangleMesh` type and its measures.
+
+pub mod obj;
+pub mod stl;
+pub mod triangle_mesh;
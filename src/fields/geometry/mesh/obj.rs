@@ -0,0 +1,155 @@
+//! This module provides Wavefront OBJ parsing and serialization for triangle meshes.
+//!
+//! It is part of the `mesh` module. Only geometry is read: `v` lines give
+//! vertices and `f` lines give faces, while texture coordinates, normals, groups,
+//! materials and other statements are skipped. Face corners may be written as
+//! `i`, `i/t`, `i//n` or `i/t/n`, where only the vertex index `i` is used.
+//! Indices count from 1, and negative indices count back from the latest vertex.
+//! Faces with more than three corners are split into a fan of triangles, which
+//! is exact for convex faces.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::mesh::obj;
+//!
+//! let text = "# a unit square pyramid
+//! v 0 0 0
+//! v 1 0 0
+//! v 1 1 0
+//! v 0 1 0
+//! v 0.5 0.5 1
+//! f 4 3 2 1
+//! f 1/1 2/2 5/3
+//! f 2//1 3//1 5//1
+//! f -3 -2 -1
+//! f 4/1/1 1/2/1 5/3/1
+//! ";
+//! let mesh = obj::from_obj::<f64>(text).unwrap();
+//!
+//! assert_eq!(mesh.faces().len(), 6);
+//! assert!(mesh.is_outward());
+//! assert!((mesh.volume() - 1.0f64 / 3.0).abs() < 1e-15);
+//! assert_eq!(obj::from_obj::<f64>(&obj::to_obj(&mesh)).unwrap(), mesh);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_obj`: Parses a mesh from Wavefront OBJ.
+//! - `to_obj`: Writes a mesh as Wavefront OBJ.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::mesh::triangle_mesh::TriangleMesh;
+use crate::fields::geometry::point::Point3;
+use num_traits::Float;
+use std::fmt::{Display, Write};
+
+// Function to parse a mesh from Wavefront OBJ.
+pub fn from_obj<T: Float>(text: &str) -> Result<TriangleMesh<T>, GeometryError> {
+    let mut vertices = Vec::new();
+    let mut faces = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap();
+        let mut tokens = line.split_whitespace();
+        let error =
+            |message: &str| GeometryError::new(&format!("Line {}: {}", number + 1, message));
+        match tokens.next() {
+            Some("v") => {
+                // An optional fourth weight is ignored.
+                let coordinates = tokens
+                    .take(3)
+                    .map(|token| token.parse::<f64>().map(|value| T::from(value).unwrap()))
+                    .collect::<Result<Vec<T>, _>>()
+                    .map_err(|_| error("Invalid vertex coordinate"))?;
+                if coordinates.len() != 3 {
+                    return Err(error("Vertex needs three coordinates"));
+                }
+                vertices.push(Point3::new(coordinates[0], coordinates[1], coordinates[2]));
+            }
+            Some("f") => {
+                let corners = tokens
+                    .map(|token| corner_index(token, vertices.len()))
+                    .collect::<Option<Vec<usize>>>()
+                    .ok_or_else(|| error("Invalid face index"))?;
+                if corners.len() < 3 {
+                    return Err(error("Face needs at least three corners"));
+                }
+                for i in 1..corners.len() - 1 {
+                    faces.push([corners[0], corners[i], corners[i + 1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+    TriangleMesh::new(vertices, faces)
+}
+
+// Function to write a mesh as Wavefront OBJ.
+pub fn to_obj<T: Float + Display>(mesh: &TriangleMesh<T>) -> String {
+    let mut text = String::new();
+    for vertex in mesh.vertices() {
+        writeln!(text, "v {} {} {}", vertex.x, vertex.y, vertex.z).unwrap();
+    }
+    for [a, b, c] in mesh.faces() {
+        writeln!(text, "f {} {} {}", a + 1, b + 1, c + 1).unwrap();
+    }
+    text
+}
+
+// Gets the zero-based vertex index of a face corner, given the vertices read so far.
+fn corner_index(token: &str, count: usize) -> Option<usize> {
+    let index: i64 = token.split('/').next()?.parse().ok()?;
+    match index {
+        1.. if index as usize <= count => Some(index as usize - 1),
+        ..=-1 => count.checked_sub(index.unsigned_abs() as usize),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod geometry_mesh_obj_tests {
+    use super::*;
+
+    #[test]
+    fn obj_from_obj_test() {
+        let text =
+            "o quad\nv 0 0 0\nv 2 0 0 1\nv 2 3 0\nv 0 3 0 # corner\nvn 0 0 1\ns off\nf 1 2 3 4\n";
+        let mesh = from_obj::<f64>(text).unwrap();
+
+        assert_eq!(mesh.vertices()[3], Point3::new(0.0, 3.0, 0.0));
+        assert_eq!(mesh.faces(), &[[0, 1, 2], [0, 2, 3]]);
+        assert_eq!(mesh.surface_area(), 6.0);
+        assert!(!mesh.is_watertight());
+
+        let relative = from_obj::<f64>("v 0 0 0\nv 1 0 0\nv 0 1 0\nf -3/1 -2/2/2 -1//3\n").unwrap();
+        assert_eq!(relative.faces(), &[[0, 1, 2]]);
+    }
+
+    #[test]
+    fn obj_to_obj_test() {
+        let mesh = TriangleMesh::new(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.5, 0.0, 0.0),
+                Point3::new(0.0, -2.0, 0.25),
+            ],
+            vec![[0, 1, 2]],
+        )
+        .unwrap();
+
+        let text = to_obj(&mesh);
+        assert_eq!(text, "v 0 0 0\nv 1.5 0 0\nv 0 -2 0.25\nf 1 2 3\n");
+        assert_eq!(from_obj::<f64>(&text).unwrap(), mesh);
+    }
+
+    #[test]
+    fn obj_error_test() {
+        assert!(from_obj::<f64>("v 0 0\n").is_err());
+        assert!(from_obj::<f64>("v 0 0 x\n").is_err());
+        assert!(from_obj::<f64>("v 0 0 0\nv 1 0 0\nf 1 2\n").is_err());
+        assert!(from_obj::<f64>("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n").is_err());
+        assert!(from_obj::<f64>("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n").is_err());
+        assert!(from_obj::<f64>("v 0 0 0\nv 1 0 0\nv 0 1 0\nf -4 1 2\n").is_err());
+        assert!(from_obj::<f64>("f 1 2 3\nv 0 0 0\nv 1 0 0\nv 0 1 0\n").is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! This module provides STL parsing and serialization for triangle meshes.
+//!
+//! It is part of the `mesh` module. STL is the format most CAD and slicing tools
+//! export, in either an ASCII or a binary form. A binary file has an 80 byte
+//! header, a triangle count and 50 bytes per triangle. Input is read as binary
+//! when its length matches that layout, and as ASCII otherwise, since binary
+//! headers often start with `solid` too. Binary input padded with trailing bytes
+//! is also accepted when it is not valid ASCII STL.
+//!
+//! STL stores each triangle with its own copy of its corners, so corners with
+//! identical coordinates are merged into shared vertices. Triangles that collapse
+//! onto fewer than three vertices are dropped. Stored normals are ignored; the
+//! faces keep their winding, and normals are written from it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::mesh::stl;
+//!
+//! let text = "solid tetrahedron
+//! facet normal 0 0 -1 outer loop vertex 0 0 0 vertex 0 1 0 vertex 1 0 0 endloop endfacet
+//! facet normal 0 -1 0 outer loop vertex 0 0 0 vertex 1 0 0 vertex 0 0 1 endloop endfacet
+//! facet normal -1 0 0 outer loop vertex 0 0 0 vertex 0 0 1 vertex 0 1 0 endloop endfacet
+//! facet normal 1 1 1 outer loop vertex 1 0 0 vertex 0 1 0 vertex 0 0 1 endloop endfacet
+//! endsolid tetrahedron";
+//! let mesh = stl::from_stl::<f64>(text.as_bytes()).unwrap();
+//!
+//! assert_eq!(mesh.vertices().len(), 4);
+//! assert!(mesh.is_outward());
+//! assert!((mesh.volume() - 1.0f64 / 6.0).abs() < 1e-15);
+//!
+//! let binary = stl::to_stl_binary(&mesh);
+//! assert_eq!(binary.len(), 84 + 4 * 50);
+//! assert_eq!(stl::from_stl::<f64>(&binary).unwrap(), mesh);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_stl`: Parses a mesh from ASCII or binary STL.
+//! - `to_stl_ascii`: Writes a mesh as ASCII STL.
+//! - `to_stl_binary`: Writes a mesh as binary STL.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::mesh::triangle_mesh::TriangleMesh;
+use crate::fields::geometry::point::Point3;
+use num_traits::Float;
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
+const HEADER_LENGTH: usize = 80;
+const TRIANGLE_LENGTH: usize = 50;
+
+// Function to parse a mesh from ASCII or binary STL.
+pub fn from_stl<T: Float>(bytes: &[u8]) -> Result<TriangleMesh<T>, GeometryError> {
+    let count = binary_count(bytes);
+    if let Some(count) = count {
+        if bytes.len() == HEADER_LENGTH + 4 + count * TRIANGLE_LENGTH {
+            return weld(&read_binary(bytes, count));
+        }
+    }
+    let ascii = std::str::from_utf8(bytes)
+        .map_err(|_| GeometryError::new("STL is neither binary nor ASCII text"))
+        .and_then(read_ascii);
+    match (ascii, count) {
+        (Ok(triangles), _) => weld(&triangles),
+        // Some exporters pad binary files, so extra bytes after the triangles are ignored.
+        (Err(_), Some(count)) => weld(&read_binary(bytes, count)),
+        (Err(error), None) => Err(error),
+    }
+}
+
+// Function to write a mesh as ASCII STL.
+pub fn to_stl_ascii<T: Float + Display>(mesh: &TriangleMesh<T>, name: &str) -> String {
+    let mut text = format!("solid {}\n", name);
+    for face in 0..mesh.faces().len() {
+        let corners = mesh.triangle(face);
+        let normal = normal(corners);
+        writeln!(
+            text,
+            "  facet normal {} {} {}",
+            normal[0], normal[1], normal[2]
+        )
+        .unwrap();
+        text.push_str("    outer loop\n");
+        for corner in corners {
+            writeln!(text, "      vertex {} {} {}", corner.x, corner.y, corner.z).unwrap();
+        }
+        text.push_str("    endloop\n  endfacet\n");
+    }
+    writeln!(text, "endsolid {}", name).unwrap();
+    text
+}
+
+// Function to write a mesh as binary STL, with coordinates rounded to `f32`.
+pub fn to_stl_binary<T: Float>(mesh: &TriangleMesh<T>) -> Vec<u8> {
+    let count = mesh.faces().len();
+    let mut bytes = Vec::with_capacity(HEADER_LENGTH + 4 + count * TRIANGLE_LENGTH);
+    bytes.extend_from_slice(&[0; HEADER_LENGTH]);
+    bytes.extend_from_slice(&(count as u32).to_le_bytes());
+    for face in 0..count {
+        let corners = mesh.triangle(face);
+        let values = normal(corners).into_iter().chain(
+            corners
+                .into_iter()
+                .flat_map(|corner| [corner.x, corner.y, corner.z]),
+        );
+        for value in values {
+            bytes.extend_from_slice(&value.to_f32().unwrap().to_le_bytes());
+        }
+        // The attribute byte count, which has no standard meaning.
+        bytes.extend_from_slice(&[0, 0]);
+    }
+    bytes
+}
+
+// Gets the triangle count of a binary header, if the bytes are long enough to hold them.
+fn binary_count(bytes: &[u8]) -> Option<usize> {
+    let count = bytes.get(HEADER_LENGTH..HEADER_LENGTH + 4)?;
+    let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+    let length = count.checked_mul(TRIANGLE_LENGTH)? + HEADER_LENGTH + 4;
+    (bytes.len() >= length).then_some(count)
+}
+
+fn read_binary<T: Float>(bytes: &[u8], count: usize) -> Vec<[Point3<T>; 3]> {
+    bytes[HEADER_LENGTH + 4..]
+        .chunks_exact(TRIANGLE_LENGTH)
+        .take(count)
+        .map(|record| {
+            let value = |index: usize| {
+                let start = 4 * index;
+                let raw = f32::from_le_bytes(record[start..start + 4].try_into().unwrap());
+                T::from(raw).unwrap()
+            };
+            // The first three values are the normal.
+            [0, 1, 2].map(|corner| {
+                let index = 3 + 3 * corner;
+                Point3::new(value(index), value(index + 1), value(index + 2))
+            })
+        })
+        .collect()
+}
+
+fn read_ascii<T: Float>(text: &str) -> Result<Vec<[Point3<T>; 3]>, GeometryError> {
+    let mut tokens = text.split_whitespace();
+    if tokens.next() != Some("solid") {
+        return Err(GeometryError::new("ASCII STL must start with \"solid\""));
+    }
+    let mut triangles = Vec::new();
+    let mut corners = Vec::new();
+    let mut ended = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            "facet" | "outer" | "loop" | "endfacet" => {}
+            // The stored normal is recomputed from the winding.
+            "normal" => {
+                for _ in 0..3 {
+                    number::<f64>(tokens.next())?;
+                }
+            }
+            "vertex" => {
+                let x = number(tokens.next())?;
+                let y = number(tokens.next())?;
+                let z = number(tokens.next())?;
+                corners.push(Point3::new(x, y, z));
+            }
+            "endloop" => {
+                if corners.len() != 3 {
+                    return Err(GeometryError::new("STL facet must have three vertices"));
+                }
+                triangles.push([corners[0], corners[1], corners[2]]);
+                corners.clear();
+            }
+            "endsolid" => {
+                ended = true;
+                break;
+            }
+            // The solid's name may run over several words before the first facet.
+            _ if triangles.is_empty() && corners.is_empty() => {}
+            _ => {
+                return Err(GeometryError::new(&format!(
+                    "Unexpected \"{}\" in ASCII STL",
+                    token
+                )))
+            }
+        }
+    }
+    if !ended || !corners.is_empty() {
+        return Err(GeometryError::new("ASCII STL ends before \"endsolid\""));
+    }
+    Ok(triangles)
+}
+
+fn number<T: Float>(token: Option<&str>) -> Result<T, GeometryError> {
+    let token = token.ok_or_else(|| GeometryError::new("ASCII STL ends in a coordinate"))?;
+    let value: f64 = token
+        .parse()
+        .map_err(|_| GeometryError::new(&format!("Invalid STL number \"{}\"", token)))?;
+    Ok(T::from(value).unwrap())
+}
+
+// Merges corners with identical coordinates into shared vertices.
+fn weld<T: Float>(triangles: &[[Point3<T>; 3]]) -> Result<TriangleMesh<T>, GeometryError> {
+    let mut vertices = Vec::new();
+    let mut indices = HashMap::new();
+    let mut faces = Vec::with_capacity(triangles.len());
+    for triangle in triangles {
+        let face = triangle.map(|corner| {
+            // Adding zero turns -0.0 into 0.0, so both share a key.
+            let key = [corner.x, corner.y, corner.z]
+                .map(|value| (value.to_f64().unwrap() + 0.0).to_bits());
+            *indices.entry(key).or_insert_with(|| {
+                vertices.push(corner);
+                vertices.len() - 1
+            })
+        });
+        if face[0] != face[1] && face[1] != face[2] && face[2] != face[0] {
+            faces.push(face);
+        }
+    }
+    TriangleMesh::new(vertices, faces)
+}
+
+// Gets the unit normal of a triangle from its winding, or zero if it is degenerate.
+fn normal<T: Float>(corners: [Point3<T>; 3]) -> [T; 3] {
+    let cross = (corners[1] - corners[0]).cross(corners[2] - corners[0]);
+    match cross.normalize() {
+        Some(unit) => [unit.x, unit.y, unit.z],
+        None => [T::zero(); 3],
+    }
+}
+
+#[cfg(test)]
+mod geometry_mesh_stl_tests {
+    use super::*;
+
+    fn cube() -> TriangleMesh<f64> {
+        let vertices = (0..8)
+            .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+            .collect();
+        let faces = vec![
+            [0, 2, 1],
+            [1, 2, 3],
+            [4, 5, 6],
+            [5, 7, 6],
+            [0, 1, 4],
+            [1, 5, 4],
+            [2, 6, 3],
+            [3, 6, 7],
+            [0, 4, 2],
+            [2, 4, 6],
+            [1, 3, 5],
+            [3, 7, 5],
+        ];
+        TriangleMesh::new(vertices, faces).unwrap()
+    }
+
+    // Welding numbers vertices in the order they are met, so compare the triangles.
+    fn triangles(mesh: &TriangleMesh<f64>) -> Vec<[Point3<f64>; 3]> {
+        (0..mesh.faces().len())
+            .map(|face| mesh.triangle(face))
+            .collect()
+    }
+
+    #[test]
+    fn stl_ascii_test() {
+        let mesh = cube();
+        let text = to_stl_ascii(&mesh, "cube");
+        assert!(text.starts_with("solid cube\n  facet normal 0 0 -1\n    outer loop\n"));
+        assert!(text.ends_with("endsolid cube\n"));
+
+        let parsed = from_stl::<f64>(text.as_bytes()).unwrap();
+        assert_eq!(parsed.vertices().len(), 8);
+        assert_eq!(triangles(&parsed), triangles(&mesh));
+        assert!(parsed.is_outward());
+        assert!((parsed.volume() - 1.0).abs() < 1e-15);
+
+        let named = text.replace("solid cube", "solid two words");
+        assert_eq!(from_stl::<f64>(named.as_bytes()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn stl_binary_test() {
+        let mesh = cube();
+        let bytes = to_stl_binary(&mesh);
+        assert_eq!(bytes.len(), 84 + 12 * 50);
+        assert_eq!(u32::from_le_bytes(bytes[80..84].try_into().unwrap()), 12);
+        assert_eq!(&bytes[84..96], &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 191]);
+
+        let parsed = from_stl::<f64>(&bytes).unwrap();
+        assert_eq!(triangles(&parsed), triangles(&mesh));
+
+        // A binary header may start with "solid" as well.
+        let mut labelled = bytes.clone();
+        labelled[..5].copy_from_slice(b"solid");
+        assert_eq!(from_stl::<f64>(&labelled).unwrap(), parsed);
+
+        // Trailing bytes after the triangles are ignored.
+        let mut padded = labelled.clone();
+        padded.extend_from_slice(&[0; 7]);
+        assert_eq!(from_stl::<f64>(&padded).unwrap(), parsed);
+        padded.truncate(bytes.len() - 1);
+        assert!(from_stl::<f64>(&padded).is_err());
+    }
+
+    #[test]
+    fn stl_weld_test() {
+        let text = "solid s
+            facet normal 0 0 1 outer loop vertex 0 0 0 vertex 1 0 0 vertex 0 1 0 endloop endfacet
+            facet normal 0 0 1 outer loop vertex -0 1 0 vertex 1 0 0 vertex 1 1 0 endloop endfacet
+            facet normal 0 0 0 outer loop vertex 1 1 0 vertex 1 1 0 vertex 0 0 0 endloop endfacet
+            endsolid s";
+        let mesh = from_stl::<f64>(text.as_bytes()).unwrap();
+        assert_eq!(mesh.vertices().len(), 4);
+        assert_eq!(mesh.faces(), &[[0, 1, 2], [2, 1, 3]]);
+        assert_eq!(mesh.surface_area(), 1.0);
+    }
+
+    #[test]
+    fn stl_error_test() {
+        let parse = |text: &str| from_stl::<f64>(text.as_bytes());
+
+        assert!(parse("").is_err());
+        assert!(parse("facet normal 0 0 1").is_err());
+        assert!(parse(
+            "solid s facet normal 0 0 1 outer loop vertex 0 0 0 endloop endfacet endsolid"
+        )
+        .is_err());
+        assert!(parse("solid s facet normal 0 0 1 outer loop vertex 0 0 x").is_err());
+        assert!(parse("solid s facet normal 0 0 1 outer loop vertex 0 0 0").is_err());
+        assert!(parse("solid s facet normal 0 0 1 outer loop vertex 0 0 0 vertex 1 0 0 vertex 0 1 0 endloop endfacet bogus endsolid").is_err());
+        assert!(from_stl::<f64>(&[0xff; 90]).is_err());
+        assert!(parse("solid empty\nendsolid empty")
+            .unwrap()
+            .faces()
+            .is_empty());
+    }
+}
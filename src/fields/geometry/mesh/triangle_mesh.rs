@@ -0,0 +1,343 @@
+//! `TriangleMesh` is a struct representing a surface made of triangles
+//!
+//! A mesh is a list of vertices and a list of faces, each holding three vertex
+//! indices. For a closed mesh whose faces wind counter-clockwise seen from
+//! outside, the signed volume is positive. The volume, centre of mass and inertia
+//! tensor come from the divergence theorem. Each face forms a tetrahedron with
+//! the origin, and the signed tetrahedra add up to the enclosed solid. These
+//! measures are only meaningful for watertight, consistently oriented meshes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::mesh::triangle_mesh::TriangleMesh;
+//! use crabmath::fields::geometry::point::Point3;
+//!
+//! let tetrahedron = TriangleMesh::new(
+//!     vec![
+//!         Point3::new(0.0, 0.0, 0.0),
+//!         Point3::new(1.0, 0.0, 0.0),
+//!         Point3::new(0.0, 1.0, 0.0),
+//!         Point3::new(0.0, 0.0, 1.0),
+//!     ],
+//!     vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+//! )
+//! .unwrap();
+//!
+//! assert!(tetrahedron.is_watertight());
+//! assert!(tetrahedron.is_outward());
+//! assert!((tetrahedron.volume() - 1.0f64 / 6.0).abs() < 1e-15);
+//! assert!((tetrahedron.surface_area() - (1.5f64 + 3f64.sqrt() / 2.0)).abs() < 1e-15);
+//! assert_eq!(tetrahedron.center_of_mass().unwrap().x, 0.25);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a mesh from vertices and faces.
+//! - `vertices`: Gets the vertices.
+//! - `faces`: Gets the faces as vertex indices.
+//! - `triangle`: Gets the corners of one face.
+//! - `surface_area`: Computes the total area of the faces.
+//! - `signed_volume`: Computes the enclosed volume, negative when the faces point inward.
+//! - `volume`: Computes the enclosed volume.
+//! - `center_of_mass`: Computes the centre of mass of the enclosed solid.
+//! - `inertia_tensor`: Computes the inertia tensor about the centre of mass.
+//! - `is_watertight`: Checks that every edge is shared by exactly two faces.
+//! - `is_consistently_oriented`: Checks that neighbouring faces wind the same way.
+//! - `is_outward`: Checks that the mesh is closed with its faces pointing outward.
+//! - `reversed`: Gets the mesh with every face turned around.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point3;
+use crate::fields::geometry::solids::surface_area::get_surface_area_polyhedron;
+use crate::fields::geometry::vector::Vector3;
+use num_traits::Float;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangleMesh<T> {
+    vertices: Vec<Point3<T>>,
+    faces: Vec<[usize; 3]>,
+}
+
+impl<T> TriangleMesh<T>
+where
+    T: Float,
+{
+    // Function to create a mesh, checking that every face index names a vertex.
+    pub fn new(vertices: Vec<Point3<T>>, faces: Vec<[usize; 3]>) -> Result<Self, GeometryError> {
+        if faces.iter().flatten().any(|&index| index >= vertices.len()) {
+            return Err(GeometryError::new("Face index is out of range"));
+        }
+        Ok(TriangleMesh { vertices, faces })
+    }
+
+    // Function to get the vertices.
+    pub fn vertices(&self) -> &[Point3<T>] {
+        &self.vertices
+    }
+
+    // Function to get the faces as vertex indices.
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    // Function to get the corners of a face.
+    pub fn triangle(&self, face: usize) -> [Point3<T>; 3] {
+        self.faces[face].map(|index| self.vertices[index])
+    }
+
+    // Function to get the total area of the faces.
+    pub fn surface_area(&self) -> T {
+        get_surface_area_polyhedron(&self.vertices, &self.faces)
+    }
+
+    // Function to get the enclosed volume, negative when the faces point inward.
+    pub fn signed_volume(&self) -> T {
+        let six = T::from(6).unwrap();
+        (0..self.faces.len()).fold(T::zero(), |sum, face| {
+            let [a, b, c] = self.triangle(face).map(|point| point.to_vector());
+            sum + a.dot(b.cross(c)) / six
+        })
+    }
+
+    // Function to get the enclosed volume.
+    pub fn volume(&self) -> T {
+        self.signed_volume().abs()
+    }
+
+    // Function to get the centre of mass of the enclosed solid of uniform density.
+    //
+    // Returns None when the mesh encloses no volume.
+    pub fn center_of_mass(&self) -> Option<Point3<T>> {
+        let (volume, moment) = self.moments();
+        if volume == T::zero() {
+            return None;
+        }
+        Some(Point3::from(moment / volume))
+    }
+
+    // Function to get the inertia tensor about the centre of mass for a uniform density.
+    //
+    // Inward-facing meshes give the same tensor as outward ones. Returns None when the
+    // mesh encloses no volume.
+    pub fn inertia_tensor(&self, density: T) -> Option<[[T; 3]; 3]> {
+        let center = self.center_of_mass()?.to_vector();
+        let twenty = T::from(20).unwrap();
+        let mut covariance = [[T::zero(); 3]; 3];
+        for face in 0..self.faces.len() {
+            let corners = self.triangle(face).map(|point| point.to_vector());
+            let volume = corners[0].dot(corners[1].cross(corners[2])) / T::from(6).unwrap();
+            let sum = corners[0] + corners[1] + corners[2];
+            // The second moment of a tetrahedron with a corner at the origin is
+            // V / 20 (Σ pᵢpᵢᵀ + s sᵀ), where s is the sum of the corners.
+            for (j, row) in covariance.iter_mut().enumerate() {
+                for (k, entry) in row.iter_mut().enumerate() {
+                    let corner_sum = corners.iter().fold(T::zero(), |total, p| {
+                        total + component(*p, j) * component(*p, k)
+                    });
+                    *entry = *entry
+                        + volume / twenty * (corner_sum + component(sum, j) * component(sum, k));
+                }
+            }
+        }
+        // Move the second moment to the centre of mass, then convert it to inertia.
+        let mass = self.signed_volume();
+        for (j, row) in covariance.iter_mut().enumerate() {
+            for (k, entry) in row.iter_mut().enumerate() {
+                *entry = *entry - mass * component(center, j) * component(center, k);
+            }
+        }
+        // The moments carry the sign of the volume, which is negative for inward faces.
+        let density = density * mass.signum();
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let mut inertia = [[T::zero(); 3]; 3];
+        for j in 0..3 {
+            for k in 0..3 {
+                let diagonal = if j == k { trace } else { T::zero() };
+                inertia[j][k] = density * (diagonal - covariance[j][k]);
+            }
+        }
+        Some(inertia)
+    }
+
+    // Function to check that every edge is shared by exactly two faces.
+    pub fn is_watertight(&self) -> bool {
+        let mut counts = HashMap::new();
+        for &[a, b, c] in &self.faces {
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                *counts.entry((u.min(v), u.max(v))).or_insert(0) += 1;
+            }
+        }
+        !counts.is_empty() && counts.values().all(|&count| count == 2)
+    }
+
+    // Function to check that faces sharing an edge traverse it in opposite directions.
+    pub fn is_consistently_oriented(&self) -> bool {
+        let mut seen = HashMap::new();
+        self.faces.iter().all(|&[a, b, c]| {
+            [(a, b), (b, c), (c, a)]
+                .into_iter()
+                .all(|edge| seen.insert(edge, ()).is_none())
+        })
+    }
+
+    // Function to check that the mesh is closed with its faces pointing outward.
+    pub fn is_outward(&self) -> bool {
+        self.is_watertight() && self.is_consistently_oriented() && self.signed_volume() > T::zero()
+    }
+
+    // Function to get the mesh with every face turned around.
+    pub fn reversed(&self) -> Self {
+        TriangleMesh {
+            vertices: self.vertices.clone(),
+            faces: self.faces.iter().map(|&[a, b, c]| [a, c, b]).collect(),
+        }
+    }
+
+    // Gets the signed volume and its first moment about the origin.
+    fn moments(&self) -> (T, Vector3<T>) {
+        let four = T::from(4).unwrap();
+        let six = T::from(6).unwrap();
+        (0..self.faces.len()).fold((T::zero(), Vector3::zero()), |(volume, moment), face| {
+            let [a, b, c] = self.triangle(face).map(|point| point.to_vector());
+            let tetrahedron = a.dot(b.cross(c)) / six;
+            (
+                volume + tetrahedron,
+                moment + (a + b + c) * (tetrahedron / four),
+            )
+        })
+    }
+}
+
+fn component<T: Copy>(vector: Vector3<T>, axis: usize) -> T {
+    match axis {
+        0 => vector.x,
+        1 => vector.y,
+        _ => vector.z,
+    }
+}
+
+#[cfg(test)]
+mod geometry_mesh_triangle_mesh_tests {
+    use super::*;
+
+    // An axis-aligned box from the origin with outward faces.
+    fn cuboid(x: f64, y: f64, z: f64) -> TriangleMesh<f64> {
+        let vertices = (0..8)
+            .map(|i| {
+                Point3::new(
+                    if i & 1 == 0 { 0.0 } else { x },
+                    if i & 2 == 0 { 0.0 } else { y },
+                    if i & 4 == 0 { 0.0 } else { z },
+                )
+            })
+            .collect();
+        let faces = vec![
+            [0, 2, 1],
+            [1, 2, 3],
+            [4, 5, 6],
+            [5, 7, 6],
+            [0, 1, 4],
+            [1, 5, 4],
+            [2, 6, 3],
+            [3, 6, 7],
+            [0, 4, 2],
+            [2, 4, 6],
+            [1, 3, 5],
+            [3, 7, 5],
+        ];
+        TriangleMesh::new(vertices, faces).unwrap()
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn triangle_mesh_new_test() {
+        let mesh = cuboid(1.0, 2.0, 3.0);
+
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.faces().len(), 12);
+        assert_eq!(mesh.triangle(0)[2], Point3::new(1.0, 0.0, 0.0));
+        assert!(TriangleMesh::new(vec![Point3::new(0.0, 0.0, 0.0)], vec![[0, 0, 1]]).is_err());
+    }
+
+    #[test]
+    fn triangle_mesh_measure_test() {
+        let mesh = cuboid(1.0, 2.0, 3.0);
+
+        assert_close(mesh.surface_area(), 22.0);
+        assert_close(mesh.signed_volume(), 6.0);
+        assert_close(mesh.reversed().signed_volume(), -6.0);
+        assert_close(mesh.reversed().volume(), 6.0);
+
+        let center = mesh.center_of_mass().unwrap();
+        assert_close(center.x, 0.5);
+        assert_close(center.y, 1.0);
+        assert_close(center.z, 1.5);
+    }
+
+    #[test]
+    fn triangle_mesh_inertia_test() {
+        // A solid box has I = m / 12 diag(b² + c², a² + c², a² + b²) about its centre.
+        let inertia = cuboid(1.0, 2.0, 3.0).inertia_tensor(2.0).unwrap();
+        let mass = 12.0;
+        assert_close(inertia[0][0], mass / 12.0 * 13.0);
+        assert_close(inertia[1][1], mass / 12.0 * 10.0);
+        assert_close(inertia[2][2], mass / 12.0 * 5.0);
+        assert_close(inertia[0][1], 0.0);
+        assert_close(inertia[0][2], 0.0);
+        assert_close(inertia[1][2], 0.0);
+
+        // A tetrahedron's products of inertia are not zero.
+        let tetrahedron = TriangleMesh::new(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.0, 0.0, 1.0),
+            ],
+            vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+        )
+        .unwrap();
+        let inertia = tetrahedron.inertia_tensor(1.0).unwrap();
+        assert_close(inertia[0][0], 1.0 / 80.0);
+        assert_close(inertia[0][1], 1.0 / 480.0);
+        assert_eq!(tetrahedron.reversed().inertia_tensor(1.0), Some(inertia));
+        let reversed = cuboid(1.0, 2.0, 3.0)
+            .reversed()
+            .inertia_tensor(2.0)
+            .unwrap();
+        assert_close(reversed[0][0], mass / 12.0 * 13.0);
+        assert_close(reversed[2][2], mass / 12.0 * 5.0);
+
+        let open =
+            TriangleMesh::new(cuboid(1.0, 1.0, 1.0).vertices().to_vec(), vec![[0, 1, 2]]).unwrap();
+        assert!(open.inertia_tensor(1.0).is_none());
+    }
+
+    #[test]
+    fn triangle_mesh_topology_test() {
+        let mesh = cuboid(1.0, 1.0, 1.0);
+        assert!(mesh.is_watertight());
+        assert!(mesh.is_consistently_oriented());
+        assert!(mesh.is_outward());
+        assert!(!mesh.reversed().is_outward());
+        assert!(mesh.reversed().is_consistently_oriented());
+
+        let mut faces = mesh.faces().to_vec();
+        faces[0] = [0, 1, 2];
+        let flipped = TriangleMesh::new(mesh.vertices().to_vec(), faces.clone()).unwrap();
+        assert!(flipped.is_watertight());
+        assert!(!flipped.is_consistently_oriented());
+
+        faces.pop();
+        let open = TriangleMesh::new(mesh.vertices().to_vec(), faces).unwrap();
+        assert!(!open.is_watertight());
+        assert!(!TriangleMesh::<f64>::new(vec![], vec![])
+            .unwrap()
+            .is_watertight());
+    }
+}
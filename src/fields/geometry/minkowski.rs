@@ -0,0 +1,279 @@
+//! This module provides Minkowski sums of polygons.
+//!
+//! It is part of the `geometry` module. The Minkowski sum of two shapes `A` and
+//! `B` is the set of all points `a + b` with `a` in `A` and `b` in `B`. Sliding a
+//! copy of `B` over every point of `A` sweeps out the sum, which is why it is used
+//! for configuration-space obstacles in motion planning and for offsetting.
+//!
+//! The sum of two convex polygons is convex and is built in linear time by merging
+//! their edges in order of direction. For general polygons, possibly with holes,
+//! both inputs are split into triangles with `triangulation::ear_clipping`, the
+//! convex sums of all pairs of triangles are formed and their union is taken with
+//! `clipping::union_all`. Holes appear in the result where the sum leaves them
+//! uncovered.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::minkowski::minkowski_sum_convex;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::Polygon;
+//!
+//! let square = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//! ]);
+//! let triangle = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(1.0, 0.0),
+//!     Point2::new(0.0, 1.0),
+//! ]);
+//!
+//! // A square and a triangle sum to a pentagon of area 4 + 2·2·1 + 0.5.
+//! let sum = minkowski_sum_convex(&square, &triangle);
+//! assert_eq!(sum.vertices().len(), 5);
+//! assert_eq!(sum.area(), 8.5);
+//! ```
+//!
+//! # Functions
+//!
+//! - `minkowski_sum_convex`: Computes the Minkowski sum of two convex polygons.
+//! - `minkowski_sum`: Computes the Minkowski sum of two polygons with holes.
+
+use crate::fields::geometry::clipping::union_all;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::triangulation::{ear_clipping, triangulation_vertices};
+use num_traits::Float;
+
+// Function to get the Minkowski sum of two convex polygons.
+//
+// The inputs may wind either way; the result is counter-clockwise and starts at
+// its lowest, then leftmost, vertex. Vertices where consecutive edges of both
+// inputs are parallel are merged into a single edge.
+pub fn minkowski_sum_convex<T: Float>(a: &Polygon<T>, b: &Polygon<T>) -> Polygon<T> {
+    let a = counter_clockwise_from_bottom(a);
+    let b = counter_clockwise_from_bottom(b);
+    if a.is_empty() || b.is_empty() {
+        return Polygon::new(Vec::new());
+    }
+
+    let (n, m) = (a.len(), b.len());
+    let mut vertices: Vec<Point2<T>> = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let current = Point2::from(a[i % n].to_vector() + b[j % m].to_vector());
+        if vertices.len() < 2
+            || (current - vertices[vertices.len() - 1])
+                .cross(vertices[vertices.len() - 1] - vertices[vertices.len() - 2])
+                != T::zero()
+        {
+            vertices.push(current);
+        } else {
+            // The new vertex continues the previous edge in a straight line.
+            *vertices.last_mut().unwrap() = current;
+        }
+        let edge_a = a[(i + 1) % n] - a[i % n];
+        let edge_b = b[(j + 1) % m] - b[j % m];
+        let turn = edge_a.cross(edge_b);
+        if j >= m || (i < n && turn > T::zero()) {
+            i += 1;
+        } else if i >= n || turn < T::zero() {
+            j += 1;
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+    // The walk ends back at the start, which may leave the first vertex collinear.
+    while vertices.len() > 2 {
+        let k = vertices.len();
+        if (vertices[0] - vertices[k - 1]).cross(vertices[1] - vertices[0]) == T::zero() {
+            vertices.remove(0);
+        } else {
+            break;
+        }
+    }
+    Polygon::new(vertices)
+}
+
+// Function to get the Minkowski sum of two polygons with holes.
+//
+// The polygons need not be convex. The result is a multipolygon, as returned by
+// the `clipping` operations. Inputs whose rings cross cannot be triangulated and
+// return an error.
+pub fn minkowski_sum<T: Float>(
+    a: &PolygonWithHoles<T>,
+    b: &PolygonWithHoles<T>,
+) -> Result<Vec<PolygonWithHoles<T>>, GeometryError> {
+    let a_triangles = triangles(a)?;
+    let b_triangles = triangles(b)?;
+    let mut parts = Vec::with_capacity(a_triangles.len() * b_triangles.len());
+    for ta in &a_triangles {
+        for tb in &b_triangles {
+            let sum = minkowski_sum_convex(ta, tb);
+            if sum.area() > T::zero() {
+                parts.push(vec![PolygonWithHoles::from(sum)]);
+            }
+        }
+    }
+    Ok(union_all(parts))
+}
+
+// Function to split a polygon with holes into triangles.
+fn triangles<T: Float>(polygon: &PolygonWithHoles<T>) -> Result<Vec<Polygon<T>>, GeometryError> {
+    let vertices = triangulation_vertices(polygon);
+    Ok(ear_clipping(polygon)?
+        .into_iter()
+        .map(|[i, j, k]| Polygon::new(vec![vertices[i], vertices[j], vertices[k]]))
+        .collect())
+}
+
+// Function to list the vertices of a convex polygon counter-clockwise from its lowest one.
+fn counter_clockwise_from_bottom<T: Float>(polygon: &Polygon<T>) -> Vec<Point2<T>> {
+    let mut vertices = if polygon.signed_area() < T::zero() {
+        polygon.reversed().vertices().to_vec()
+    } else {
+        polygon.vertices().to_vec()
+    };
+    vertices.dedup();
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    let start = (0..vertices.len()).min_by(|&i, &j| {
+        (vertices[i].y, vertices[i].x)
+            .partial_cmp(&(vertices[j].y, vertices[j].x))
+            .unwrap()
+    });
+    if let Some(start) = start {
+        vertices.rotate_left(start);
+    }
+    vertices
+}
+
+#[cfg(test)]
+mod geometry_minkowski_tests {
+    use super::*;
+    use crate::fields::geometry::area::get_area_multipolygon;
+    use crate::fields::geometry::convex_hull::monotone_chain;
+
+    fn rectangle(x: f64, y: f64, width: f64, height: f64) -> Polygon<f64> {
+        Polygon::new(vec![
+            Point2::new(x, y),
+            Point2::new(x + width, y),
+            Point2::new(x + width, y + height),
+            Point2::new(x, y + height),
+        ])
+    }
+
+    #[test]
+    fn minkowski_sum_convex_test() {
+        let sum = minkowski_sum_convex(
+            &rectangle(0.0, 0.0, 2.0, 1.0),
+            &rectangle(1.0, 1.0, 1.0, 3.0),
+        );
+        assert_eq!(sum.vertices(), rectangle(1.0, 1.0, 3.0, 4.0).vertices());
+
+        // Clockwise input and a point give a translated copy.
+        let point = Polygon::new(vec![Point2::new(5.0, -1.0)]);
+        let sum = minkowski_sum_convex(&rectangle(0.0, 0.0, 2.0, 1.0).reversed(), &point);
+        assert_eq!(sum.vertices(), rectangle(5.0, -1.0, 2.0, 1.0).vertices());
+
+        // The sum of two triangles pointing opposite ways is a hexagon.
+        let up = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 2.0),
+        ]);
+        let down = Polygon::new(vec![
+            Point2::new(0.0, 2.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 2.0),
+        ]);
+        let sum = minkowski_sum_convex(&up, &down);
+        assert_eq!(sum.vertices().len(), 6);
+        // Area of a sum of convex polygons: area(A) + area(B) + 2·mixed area.
+        assert_eq!(sum.area(), 2.0 + 2.0 + 2.0 * 4.0);
+
+        assert!(minkowski_sum_convex(&up, &Polygon::new(Vec::new()))
+            .vertices()
+            .is_empty());
+    }
+
+    #[test]
+    fn minkowski_sum_concave_test() {
+        let l_shape = PolygonWithHoles::from(Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ]));
+        let unit = PolygonWithHoles::from(rectangle(0.0, 0.0, 1.0, 1.0));
+        let sum = minkowski_sum(&l_shape, &unit).unwrap();
+        assert_eq!(sum.len(), 1);
+        assert_eq!(get_area_multipolygon(&sum), 21.0);
+        assert_eq!(sum, minkowski_sum(&unit, &l_shape).unwrap());
+    }
+
+    #[test]
+    fn minkowski_sum_random_convex_test() {
+        // For convex inputs the general sum must agree with the convex sum.
+        let mut state = 11u64;
+        let mut random = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let mut convex = |count: usize, size: f64| {
+            let points: Vec<Point2<f64>> = (0..count)
+                .map(|_| Point2::new(size * random(), size * random()))
+                .collect();
+            Polygon::new(
+                monotone_chain(&points)
+                    .into_iter()
+                    .map(|i| points[i])
+                    .collect(),
+            )
+        };
+        for k in 0..400 {
+            let (a, b) = (convex(3 + k % 7, 4.0), convex(3 + k % 5, 2.0));
+            if a.len() < 3 || b.len() < 3 {
+                continue;
+            }
+            let expected = minkowski_sum_convex(&a, &b).area();
+            let sum =
+                minkowski_sum(&PolygonWithHoles::from(a), &PolygonWithHoles::from(b)).unwrap();
+            assert_eq!(sum.len(), 1, "{k}");
+            assert!(sum[0].holes().is_empty(), "{k}");
+            assert!(
+                (get_area_multipolygon(&sum) - expected).abs() < 1e-9 * expected,
+                "{k}"
+            );
+        }
+    }
+
+    #[test]
+    fn minkowski_sum_holes_test() {
+        let frame = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 4.0, 4.0),
+            vec![rectangle(1.0, 1.0, 2.0, 2.0).reversed()],
+        );
+        let unit = PolygonWithHoles::from(rectangle(0.0, 0.0, 1.0, 1.0));
+        let sum = minkowski_sum(&frame, &unit).unwrap();
+        assert_eq!(sum.len(), 1);
+        assert_eq!(sum[0].holes().len(), 1);
+        assert_eq!(get_area_multipolygon(&sum), 24.0);
+
+        // A larger square closes the hole.
+        let big = PolygonWithHoles::from(rectangle(0.0, 0.0, 2.0, 2.0));
+        let sum = minkowski_sum(&frame, &big).unwrap();
+        assert!(sum[0].holes().is_empty());
+        assert_eq!(get_area_multipolygon(&sum), 36.0);
+    }
+}
@@ -6,10 +6,66 @@
 //!
 //! # Modules
 //!
+//! - `analytic`: Contains lines, planes, circles and conics in coordinate form.
 //! - `area`: Contains functions for calculating areas of different shapes.
+//! - `bounding_box`: Contains the axis-aligned `BoundingBox` type.
+//! - `clipping`: Contains polygon clipping and boolean operations.
 //! - `circumference` : Contains functions for calculating circumference
+//! - `collision`: Contains bounding volumes and GJK/EPA collision detection.
+//! - `containment`: Contains point-in-polygon and polygon containment queries.
+//! - `convex_hull`: Contains 2D and 3D convex hull algorithms.
+//! - `curves`: Contains Bézier curves and B-splines.
+//! - `delaunay`: Contains Delaunay triangulation and natural neighbour interpolation.
+//! - `error`: Contains the error type returned by fallible geometry operations.
+//! - `intersection`: Contains segment, line and circle intersection queries.
+//! - `io`: Contains Well-Known Text and GeoJSON import and export.
+//! - `measured`: Contains unit-aware area, perimeter and volume functions using typed quantities.
+//! - `mesh`: Contains triangle meshes with STL and OBJ parsing and mass properties.
+//! - `minkowski`: Contains Minkowski sums of convex and non-convex polygons.
+//! - `offset`: Contains polygon offsetting with round, miter and square joins.
 //! - `perimeter`: Contains functions for calculating perimeter
+//! - `point`: Contains the `Point2` and `Point3` coordinate types.
+//! - `polygon`: Contains the vertex-list `Polygon` and `PolygonWithHoles` types.
+//! - `predicates`: Contains robust orientation and in-circle predicates.
+//! - `regular_polygon`: Contains the `RegularPolygon` type for regular n-gons.
+//! - `section`: Contains centroids, second moments of area and other section properties.
+//! - `simplify`: Contains polyline simplification, smoothing and resampling.
+//! - `solids`: Contains functions for calculating volumes and surface areas of solids.
+//! - `spatial`: Contains the k-d tree and R-tree spatial indexes.
+//! - `svg`: Contains rendering of shapes to SVG documents.
+//! - `transform`: Contains the `Transform2` and `Transform3` affine transformations.
+//! - `triangulation`: Contains ear clipping and constrained Delaunay triangulation of polygons.
+//! - `vector`: Contains the `Vector2` and `Vector3` types and vector algebra.
+//! - `voronoi`: Contains Voronoi diagrams clipped to a bounding box.
 
+pub mod analytic;
 pub mod area;
+pub mod bounding_box;
 pub mod circumference;
+pub mod clipping;
+pub mod collision;
+pub mod containment;
+pub mod convex_hull;
+pub mod curves;
+pub mod delaunay;
+pub mod error;
+pub mod intersection;
+pub mod io;
+pub mod measured;
+pub mod mesh;
+pub mod minkowski;
+pub mod offset;
 pub mod perimeter;
+pub mod point;
+pub mod polygon;
+pub mod predicates;
+pub mod regular_polygon;
+pub mod section;
+pub mod simplify;
+pub mod solids;
+pub mod spatial;
+pub mod svg;
+pub mod transform;
+pub mod triangulation;
+pub mod vector;
+pub mod voronoi;
@@ -0,0 +1,466 @@
+//! This module provides polygon offsetting, also known as buffering.
+//!
+//! It is part of the `geometry` module. Offsetting a polygon by a distance `d`
+//! moves its boundary `d` outwards (for a positive distance) or inwards (for a
+//! negative one). Along the edges the offset boundary runs parallel to the
+//! original; the shape at each corner is chosen with a `Join`:
+//!
+//! - `Join::Round` follows a circular arc around the corner, approximated by
+//!   segments that stray at most the given tolerance from the true arc. A corner
+//!   gets at most 256 segments, so on the sharpest corners tolerances below about
+//!   `2e-5 · d` are not met; tolerances below machine epsilon times `d` are rejected.
+//! - `Join::Miter` extends both offset edges until they meet. Corners whose miter
+//!   would reach farther than `limit · d` from the vertex are squared off instead.
+//! - `Join::Square` cuts the corner at distance `d` from the vertex.
+//!
+//! The offset is computed by sweeping every edge: each edge contributes a
+//! rectangle of half-width `d` and each vertex a join patch on the side where the
+//! edge rectangles leave a gap. The union of these pieces is the band of width
+//! `2d` around the boundary. It is added to the polygon for outward offsets and
+//! removed from it for inward offsets, using the `clipping` operations. With round
+//! joins the result is the Minkowski sum (or difference) with a disk, so a square
+//! of side `s` buffered by `r` has area `s² + 4s·r + π·r²`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::area::get_area_multipolygon;
+//! use crabmath::fields::geometry::offset::{offset, Join};
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+//!
+//! let square = PolygonWithHoles::from(Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(4.0, 4.0),
+//!     Point2::new(0.0, 4.0),
+//! ]));
+//!
+//! let grown = offset(&[square.clone()], 1.0, Join::Miter(2.0)).unwrap();
+//! assert!((get_area_multipolygon(&grown) - 36.0f64).abs() < 1e-9);
+//!
+//! let shrunk = offset(&[square], -1.0, Join::Round(1e-3)).unwrap();
+//! assert!((get_area_multipolygon(&shrunk) - 4.0f64).abs() < 1e-9);
+//! ```
+//!
+//! # Functions
+//!
+//! - `offset`: Offsets a multipolygon by a signed distance.
+//! - `offset_polygon`: Offsets a single polygon by a signed distance.
+
+use crate::fields::geometry::clipping::{difference, union, union_all};
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::vector::Vector2;
+use num_traits::Float;
+
+// The most segments a round join may use at a single corner.
+const MAX_ARC_SEGMENTS: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join<T> {
+    // Circular arcs, with the largest allowed distance between arc and segments.
+    Round(T),
+    // Sharp corners, up to the given ratio of miter length to offset distance.
+    Miter(T),
+    Square,
+}
+
+// Function to offset a multipolygon by a signed distance.
+//
+// A positive distance grows the polygons and a negative one shrinks them. Parts
+// that grow into each other are merged and parts that shrink away vanish.
+pub fn offset<T: Float>(
+    polygons: &[PolygonWithHoles<T>],
+    distance: T,
+    join: Join<T>,
+) -> Result<Vec<PolygonWithHoles<T>>, GeometryError> {
+    match join {
+        Join::Round(tolerance) if tolerance.is_nan() || tolerance <= T::zero() => {
+            return Err(GeometryError::new("Arc tolerance must be positive"));
+        }
+        Join::Miter(limit) if limit.is_nan() || limit < T::one() => {
+            return Err(GeometryError::new("Miter limit must be at least 1"));
+        }
+        _ => {}
+    }
+    if !distance.is_finite() {
+        return Err(GeometryError::new("Offset distance must be finite"));
+    }
+    if distance == T::zero() {
+        return Ok(union(polygons, &[]));
+    }
+
+    let d = distance.abs();
+    if let Join::Round(tolerance) = join {
+        if tolerance / d < T::epsilon() {
+            return Err(GeometryError::new(
+                "Arc tolerance is too small for the offset distance",
+            ));
+        }
+    }
+    let mut pieces = Vec::new();
+    for polygon in polygons {
+        band_pieces(polygon.exterior(), d, join, &mut pieces);
+        for hole in polygon.holes() {
+            band_pieces(hole, d, join, &mut pieces);
+        }
+    }
+    let band = union_all(
+        pieces
+            .into_iter()
+            .map(|piece| vec![PolygonWithHoles::from(piece)])
+            .collect(),
+    );
+    Ok(if distance > T::zero() {
+        union(polygons, &band)
+    } else {
+        difference(polygons, &band)
+    })
+}
+
+// Function to offset a single polygon by a signed distance.
+pub fn offset_polygon<T: Float>(
+    polygon: &Polygon<T>,
+    distance: T,
+    join: Join<T>,
+) -> Result<Vec<PolygonWithHoles<T>>, GeometryError> {
+    offset(&[PolygonWithHoles::from(polygon.clone())], distance, join)
+}
+
+// Function to collect the edge rectangles and corner patches sweeping a ring.
+fn band_pieces<T: Float>(ring: &Polygon<T>, d: T, join: Join<T>, pieces: &mut Vec<Polygon<T>>) {
+    // Repeated vertices would give zero-length edges without a direction.
+    let mut vertices: Vec<Point2<T>> = Vec::with_capacity(ring.vertices().len());
+    for &v in ring.vertices() {
+        if vertices.last() != Some(&v) {
+            vertices.push(v);
+        }
+    }
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    let n = vertices.len();
+    if n < 2 {
+        return;
+    }
+
+    let directions: Vec<Vector2<T>> = (0..n)
+        .map(|i| (vertices[(i + 1) % n] - vertices[i]).normalize().unwrap())
+        .collect();
+    for i in 0..n {
+        let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+        let normal = directions[i].perpendicular() * d;
+        pieces.push(Polygon::new(vec![
+            a - normal,
+            b - normal,
+            b + normal,
+            a + normal,
+        ]));
+    }
+    for i in 0..n {
+        let incoming = directions[(i + n - 1) % n];
+        let outgoing = directions[i];
+        if let Some(patch) = corner_patch(vertices[i], incoming, outgoing, d, join) {
+            pieces.push(patch);
+        }
+    }
+}
+
+// Function to build the patch filling the gap between two edge rectangles at a vertex.
+//
+// The gap lies on the outer side of the turn: on the right of a left turn and on
+// the left of a right turn.
+fn corner_patch<T: Float>(
+    vertex: Point2<T>,
+    incoming: Vector2<T>,
+    outgoing: Vector2<T>,
+    d: T,
+    join: Join<T>,
+) -> Option<Polygon<T>> {
+    let turn = incoming.cross(outgoing);
+    let cos = incoming.dot(outgoing);
+    if turn == T::zero() && cos > T::zero() {
+        return None;
+    }
+    let side = if turn > T::zero() {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let n1 = incoming.perpendicular() * side;
+    let n2 = outgoing.perpendicular() * side;
+    // Angle between the two offset normals, in (0, π].
+    let sweep = turn.abs().atan2(cos);
+    let p1 = vertex + n1 * d;
+    let p2 = vertex + n2 * d;
+
+    let square = || {
+        // Cut perpendicular to the bisector at distance d from the vertex.
+        let bisector = (n1 + n2).normalize().unwrap_or(-incoming);
+        let half = (sweep / T::from(4).unwrap()).tan() * d;
+        let q1 = vertex + bisector * d + bisector.perpendicular() * (-half * side);
+        let q2 = vertex + bisector * d + bisector.perpendicular() * (half * side);
+        let (q1, q2) = if (q1 - p1).norm_squared() <= (q2 - p1).norm_squared() {
+            (q1, q2)
+        } else {
+            (q2, q1)
+        };
+        Polygon::new(vec![vertex, p1, q1, q2, p2])
+    };
+
+    let vertices = match join {
+        Join::Round(tolerance) => {
+            let ratio = (T::one() - tolerance / d).max(T::zero());
+            let step = (T::one() + T::one()) * ratio.acos();
+            let segments = (sweep / step)
+                .ceil()
+                .max(T::one())
+                .min(T::from(MAX_ARC_SEGMENTS).unwrap())
+                .to_usize()
+                .unwrap();
+            let rotation = -side * sweep / T::from(segments).unwrap();
+            let mut vertices = vec![vertex, p1];
+            let mut normal = n1;
+            let (sin, cos) = rotation.sin_cos();
+            for _ in 1..segments {
+                normal = Vector2::new(
+                    normal.x * cos - normal.y * sin,
+                    normal.x * sin + normal.y * cos,
+                );
+                vertices.push(vertex + normal * d);
+            }
+            vertices.push(p2);
+            vertices
+        }
+        Join::Miter(limit) => {
+            // The miter point lies 1 / cos(sweep / 2) offset distances from the vertex.
+            let half_cos = (sweep / (T::one() + T::one())).cos();
+            if half_cos * limit < T::one() {
+                return Some(square());
+            }
+            let bisector = (n1 + n2).normalize()?;
+            vec![vertex, p1, vertex + bisector * (d / half_cos), p2]
+        }
+        Join::Square => return Some(square()),
+    };
+    Some(Polygon::new(vertices))
+}
+
+#[cfg(test)]
+mod geometry_offset_tests {
+    use super::*;
+    use crate::fields::geometry::area::{get_area_circle, get_area_multipolygon, get_area_square};
+    use crate::fields::geometry::convex_hull::monotone_chain;
+    use crate::fields::geometry::perimeter::get_perimeter_square;
+
+    fn square(x: f64, y: f64, side: f64) -> PolygonWithHoles<f64> {
+        PolygonWithHoles::from(Polygon::new(vec![
+            Point2::new(x, y),
+            Point2::new(x + side, y),
+            Point2::new(x + side, y + side),
+            Point2::new(x, y + side),
+        ]))
+    }
+
+    fn l_shape() -> PolygonWithHoles<f64> {
+        PolygonWithHoles::from(Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ]))
+    }
+
+    #[test]
+    fn offset_round_square_test() {
+        let (side, r) = (3.0, 0.75);
+        let expected = get_area_square(side) + get_perimeter_square(side) * r + get_area_circle(r);
+        let buffered = offset(&[square(0.0, 0.0, side)], r, Join::Round(1e-4)).unwrap();
+        assert_eq!(buffered.len(), 1);
+        assert!(buffered[0].holes().is_empty());
+        let area = get_area_multipolygon(&buffered);
+        // The inscribed arc segments lose a little area against the true circle.
+        assert!(area < expected);
+        assert!(expected - area < 1e-3);
+
+        let reversed = PolygonWithHoles::from(square(0.0, 0.0, side).exterior().reversed());
+        let buffered = offset(&[reversed], r, Join::Round(1e-4)).unwrap();
+        assert!((get_area_multipolygon(&buffered) - area).abs() < 1e-9);
+    }
+
+    #[test]
+    fn offset_miter_and_square_test() {
+        let r = 0.5;
+        let mitered = offset(&[square(0.0, 0.0, 2.0)], r, Join::Miter(2.0)).unwrap();
+        assert!((get_area_multipolygon(&mitered) - 9.0).abs() < 1e-9);
+
+        // A right-angle miter reaches √2 offset distances, beyond a limit of 1.2.
+        let cut = (2.0f64.sqrt() - 1.0) * r;
+        let expected = 9.0 - 4.0 * cut * cut;
+        let limited = offset(&[square(0.0, 0.0, 2.0)], r, Join::Miter(1.2)).unwrap();
+        assert!((get_area_multipolygon(&limited) - expected).abs() < 1e-9);
+        let squared = offset(&[square(0.0, 0.0, 2.0)], r, Join::Square).unwrap();
+        assert!((get_area_multipolygon(&squared) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn offset_inward_test() {
+        for join in [Join::Round(1e-3), Join::Miter(4.0), Join::Square] {
+            let shrunk = offset(&[square(0.0, 0.0, 4.0)], -1.0, join).unwrap();
+            assert!((get_area_multipolygon(&shrunk) - 4.0).abs() < 1e-9);
+            let gone = offset(&[square(0.0, 0.0, 4.0)], -2.5, join).unwrap();
+            assert!(gone.is_empty());
+        }
+    }
+
+    #[test]
+    fn offset_concave_test() {
+        // Growing the L-shape keeps the notch sharp with miter joins.
+        let grown = offset(&[l_shape()], 0.5, Join::Miter(2.0)).unwrap();
+        assert!((get_area_multipolygon(&grown) - 21.0).abs() < 1e-9);
+
+        // Shrinking it leaves a sharp inner corner with miters and an arc with round joins.
+        let shrunk = offset(&[l_shape()], -0.5, Join::Miter(2.0)).unwrap();
+        assert!((get_area_multipolygon(&shrunk) - 5.0).abs() < 1e-9);
+        let shrunk = offset(&[l_shape()], -0.5, Join::Round(1e-5)).unwrap();
+        let expected = 5.0 + 0.25 - get_area_circle(0.5) / 4.0;
+        assert!((get_area_multipolygon(&shrunk) - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn offset_merge_and_holes_test() {
+        // Two squares one unit apart merge when grown by more than half a unit.
+        let parts = [square(0.0, 0.0, 2.0), square(3.0, 0.0, 2.0)];
+        let grown = offset(&parts, 0.75, Join::Miter(2.0)).unwrap();
+        assert_eq!(grown.len(), 1);
+        assert!((get_area_multipolygon(&grown) - 6.5 * 3.5).abs() < 1e-9);
+
+        // A frame's hole shrinks when the frame grows and disappears eventually.
+        let frame = PolygonWithHoles::new(
+            square(0.0, 0.0, 6.0).exterior().clone(),
+            vec![square(2.0, 2.0, 2.0).exterior().reversed()],
+        );
+        let grown = offset(std::slice::from_ref(&frame), 0.5, Join::Miter(2.0)).unwrap();
+        assert_eq!(grown[0].holes().len(), 1);
+        assert!((get_area_multipolygon(&grown) - (49.0 - 1.0)).abs() < 1e-9);
+        let grown = offset(&[frame], 1.5, Join::Miter(2.0)).unwrap();
+        assert!(grown[0].holes().is_empty());
+        assert!((get_area_multipolygon(&grown) - 81.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn offset_round_segment_cap_test() {
+        // A tolerance just above the rejection threshold is capped per corner
+        // instead of producing millions of arc segments.
+        let buffered = offset(&[square(0.0, 0.0, 1.0)], 1.0, Join::Round(1e-15)).unwrap();
+        let vertices = buffered[0].exterior().vertices().len();
+        assert!(
+            vertices <= 4 * (MAX_ARC_SEGMENTS + 1),
+            "{vertices} vertices"
+        );
+        let area = get_area_multipolygon(&buffered);
+        assert!((area - (5.0 + std::f64::consts::PI)).abs() < 1e-4);
+    }
+
+    // Random convex polygons, counter-clockwise, from the hulls of random points.
+    fn random_convex_polygons(count: usize) -> Vec<Vec<Point2<f64>>> {
+        let mut state = 3u64;
+        let mut random = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let mut polygons = Vec::new();
+        while polygons.len() < count {
+            let points: Vec<Point2<f64>> = (0..3 + polygons.len() % 8)
+                .map(|_| Point2::new(4.0 * random(), 4.0 * random()))
+                .collect();
+            let hull = monotone_chain(&points);
+            if hull.len() >= 3 {
+                polygons.push(hull.into_iter().map(|i| points[i]).collect());
+            }
+        }
+        polygons
+    }
+
+    // Area of the intersection of the half-planes bounded by the edges of a convex
+    // polygon moved outwards by d, which is the exact unlimited miter offset.
+    fn half_plane_area(vertices: &[Point2<f64>], d: f64) -> f64 {
+        let n = vertices.len();
+        let outward = |i: usize| {
+            let direction = (vertices[(i + 1) % n] - vertices[i]).normalize().unwrap();
+            -direction.perpendicular()
+        };
+        let corners = (0..n)
+            .map(|i| {
+                let (n1, n2) = (outward((i + n - 1) % n), outward(i));
+                vertices[i] + (n1 + n2) * (d / (1.0 + n1.dot(n2)))
+            })
+            .collect();
+        Polygon::new(corners).area()
+    }
+
+    #[test]
+    fn offset_random_convex_test() {
+        let hexagon = vec![
+            Point2::new(0.18954328165106205, 2.5235157188463146),
+            Point2::new(1.1892279714223508, 0.7699898607360196),
+            Point2::new(1.8940147854513296, 0.4557999111320754),
+            Point2::new(3.063633158131395, 0.8563809206797348),
+            Point2::new(2.5710063366080793, 1.527386041644729),
+            Point2::new(1.8782710430525382, 2.150186869111748),
+        ];
+        let polygons = std::iter::once(hexagon).chain(random_convex_polygons(150));
+        for (k, vertices) in polygons.enumerate() {
+            let d = if k == 0 {
+                0.9428798155689877
+            } else {
+                0.1 + k as f64 / 150.0
+            };
+            let polygon = Polygon::new(vertices.clone());
+            let parts = [PolygonWithHoles::from(polygon.clone())];
+
+            let exact = half_plane_area(&vertices, d);
+            let mitered = offset(&parts, d, Join::Miter(1e9)).unwrap();
+            assert_eq!(mitered.len(), 1);
+            let mitered_area = get_area_multipolygon(&mitered);
+            assert!((mitered_area - exact).abs() < 1e-9 * exact, "{k}");
+
+            // A tighter miter limit only cuts corners off.
+            let limited = offset(&parts, d, Join::Miter(2.0)).unwrap();
+            assert_eq!(limited.len(), 1);
+            assert!(
+                get_area_multipolygon(&limited) <= mitered_area + 1e-9,
+                "{k}"
+            );
+
+            // Round joins lie between the inscribed arcs and the true disk sweep.
+            let rounded = offset(&parts, d, Join::Round(1e-3)).unwrap();
+            assert_eq!(rounded.len(), 1);
+            let perimeter = polygon.perimeter();
+            let swept = polygon.area() + perimeter * d + std::f64::consts::PI * d * d;
+            let area = get_area_multipolygon(&rounded);
+            assert!(
+                area <= swept + 1e-9 && area >= swept - 2.0 * std::f64::consts::PI * d * 1e-3,
+                "{k}"
+            );
+        }
+    }
+
+    #[test]
+    fn offset_invalid_test() {
+        let parts = [square(0.0, 0.0, 1.0)];
+        assert!(offset(&parts, 1.0, Join::Round(0.0)).is_err());
+        assert!(offset(&parts, 1.0, Join::Round(1e-17)).is_err());
+        assert!(offset(&parts, -1e20, Join::Round(1e3)).is_err());
+        assert!(offset(&parts, 1.0, Join::Miter(0.5)).is_err());
+        assert!(offset(&parts, f64::NAN, Join::Square).is_err());
+        let same = offset(&parts, 0.0, Join::Square).unwrap();
+        assert_eq!(get_area_multipolygon(&same), 1.0);
+    }
+}
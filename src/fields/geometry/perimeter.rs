@@ -7,15 +7,24 @@
 //!
 //! ```rust
 //! use crabmath::fields::geometry::perimeter;
+//! use crabmath::fields::geometry::point::Point2;
 //!
 //! let perimeter_parallelogram = perimeter::get_perimeter_parallelogram(10.5, 10.5);
+//! let perimeter_polygon = perimeter::get_perimeter_polygon(&[
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(4.0, 0.0),
+//!     Point2::new(4.0, 3.0),
+//! ]);
 //! let perimeter_rectangle = perimeter::get_perimeter_rectangle(10.5, 10.5);
+//! let perimeter_regular_polygon = perimeter::get_perimeter_regular_polygon(6, 10.5);
 //! let perimeter_square = perimeter::get_perimeter_square(10.5);
 //! let perimeter_trapezoid = perimeter::get_perimeter_trapezoid(15.5, 15.5, 15.5, 15.5);
 //! let perimeter_triangle = perimeter::get_perimeter_triangle(5.5, 5.5, 5.5);
 //!
 //! assert_eq!(perimeter_parallelogram, 42.0);
+//! assert_eq!(perimeter_polygon, 12.0);
 //! assert_eq!(perimeter_rectangle,  42.0);
+//! assert_eq!(perimeter_regular_polygon, 63.0);
 //! assert_eq!(perimeter_square, 42.0);
 //! assert_eq!(perimeter_trapezoid, 62.0);
 //! assert_eq!(perimeter_triangle, 16.5);
@@ -24,15 +33,18 @@
 //! # Functions
 //!
 //! - `get_perimeter_parallelogram`: Computes the perimeter of a parallelogram.
+//! - `get_perimeter_polygon`: Computes the perimeter of a polygon from its vertices.
 //! - `get_perimeter_rectangle`: Computes the perimeter of a rectangle.
+//! - `get_perimeter_regular_polygon`: Computes the perimeter of a regular polygon.
 //! - `get_perimeter_square`: Computes the perimeter of a square.
 //! - `get_perimeter_trapezoid`: Computes the perimeter of a trapezoid.
 //! - `get_perimeter_triangle`: Computers the perimeter of a triangle.
 
+use crate::fields::geometry::point::Point2;
 use num_traits::{Num, NumCast};
 
 // Function to get perimeter of parallelogram.
-pub fn get_perimeter_parallelogram<T: Num>(adjacent1: T, adjacent2: T) -> T
+pub fn get_perimeter_parallelogram<T>(adjacent1: T, adjacent2: T) -> T
 where
     T: Num + NumCast,
 {
@@ -41,8 +53,28 @@ where
     T::from((2f64 * adjacent1_f64) + (2f64 * adjacent2_f64)).unwrap()
 }
 
+// Function to get perimeter of a polygon from its vertices.
+//
+// The polygon is implicitly closed, so the edge from the last vertex back to the
+// first one is included.
+pub fn get_perimeter_polygon<T>(vertices: &[Point2<T>]) -> T
+where
+    T: Num + NumCast + Copy,
+{
+    let n = vertices.len();
+    let mut perimeter_f64 = 0f64;
+    for i in 0..n {
+        let current = vertices[i];
+        let next = vertices[(i + 1) % n];
+        let dx = T::to_f64(&next.x).unwrap() - T::to_f64(&current.x).unwrap();
+        let dy = T::to_f64(&next.y).unwrap() - T::to_f64(&current.y).unwrap();
+        perimeter_f64 += dx.hypot(dy);
+    }
+    T::from(perimeter_f64).unwrap()
+}
+
 // Function to get perimeter of a rectangle.
-pub fn get_perimeter_rectangle<T: Num>(length: T, width: T) -> T
+pub fn get_perimeter_rectangle<T>(length: T, width: T) -> T
 where
     T: Num + NumCast,
 {
@@ -51,8 +83,17 @@ where
     T::from((2f64 * length_f64) + (2f64 * width_f64)).unwrap()
 }
 
+// Function to get perimeter of a regular polygon with `sides` sides of equal length.
+pub fn get_perimeter_regular_polygon<T>(sides: usize, side: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    T::from(sides as f64 * side_f64).unwrap()
+}
+
 // Function to get perimeter of a square.
-pub fn get_perimeter_square<T: Num>(side: T) -> T
+pub fn get_perimeter_square<T>(side: T) -> T
 where
     T: Num + NumCast,
 {
@@ -61,7 +102,7 @@ where
 }
 
 // Function to get perimeter of a trapezoid.
-pub fn get_perimeter_trapezoid<T: Num>(base1: T, base2: T, leg1: T, leg2: T) -> T
+pub fn get_perimeter_trapezoid<T>(base1: T, base2: T, leg1: T, leg2: T) -> T
 where
     T: Num + NumCast,
 {
@@ -74,7 +115,7 @@ where
 }
 
 // Function to get perimeter of a triangle.
-pub fn get_perimeter_triangle<T: Num>(a: T, b: T, c: T) -> T
+pub fn get_perimeter_triangle<T>(a: T, b: T, c: T) -> T
 where
     T: Num + NumCast,
 {
@@ -98,6 +139,23 @@ mod geometry_perimeter_tests {
         assert_eq!(result, 40);
     }
 
+    #[test]
+    fn get_perimeter_polygon_test() {
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.5, 0.0),
+            Point2::new(10.5, 10.5),
+            Point2::new(0.0, 10.5),
+        ];
+        assert_eq!(get_perimeter_polygon(&square), get_perimeter_square(10.5));
+
+        let triangle = [Point2::new(0, 0), Point2::new(3, 0), Point2::new(0, 4)];
+        assert_eq!(get_perimeter_polygon(&triangle), 12);
+
+        let empty: [Point2<f64>; 0] = [];
+        assert_eq!(get_perimeter_polygon(&empty), 0.0);
+    }
+
     #[test]
     fn get_perimeter_rectangle_test() {
         let result = get_perimeter_rectangle(10.5, 10.5);
@@ -107,6 +165,15 @@ mod geometry_perimeter_tests {
         assert_eq!(result, 40);
     }
 
+    #[test]
+    fn get_perimeter_regular_polygon_test() {
+        let result = get_perimeter_regular_polygon(6, 10.5);
+        assert_eq!(result, 63.0);
+
+        let result = get_perimeter_regular_polygon(5, 10);
+        assert_eq!(result, 50);
+    }
+
     #[test]
     fn get_perimeter_square_test() {
         let result = get_perimeter_square(10.5);
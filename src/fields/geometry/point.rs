@@ -0,0 +1,296 @@
+//! This module provides two and three dimensional point types.
+//!
+//! It is part of the `geometry` module. A point is a position, while a vector
+//! (see the `vector` module) is a displacement, so subtracting two points gives a
+//! vector and adding a vector to a point gives a point. Like the vector types,
+//! points work with any `num_traits::Num` coordinate type, including `Fraction`,
+//! and gain Euclidean distance for `num_traits::Float` coordinates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::{Point2, Point3};
+//! use crabmath::fields::geometry::vector::Vector2;
+//!
+//! let a = Point2::new(1.0, 1.0);
+//! let b = Point2::new(4.0, 5.0);
+//!
+//! assert_eq!(b - a, Vector2::new(3.0, 4.0));
+//! assert_eq!(a + Vector2::new(3.0, 4.0), b);
+//! assert_eq!(a.distance(b), 5.0);
+//! assert_eq!(a.midpoint(b), Point2::new(2.5, 3.0));
+//!
+//! let p = Point3::new(0.0, 0.0, 0.0);
+//! let q = Point3::new(2.0, 3.0, 6.0);
+//!
+//! assert_eq!(p.distance(q), 7.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a point from its coordinates.
+//! - `origin`: Constructs the origin.
+//! - `to_vector`: Gets the position vector of the point.
+//! - `distance_squared`: Computes the squared distance to another point.
+//! - `lerp`: Linearly interpolates towards another point.
+//! - `midpoint`: Computes the point halfway to another point.
+//! - `distance`: Computes the distance to another point (floating point only).
+
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use core::fmt;
+use num_traits::{Float, Num};
+use std::fmt::Formatter;
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new point.
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    // Function to create the origin.
+    pub fn origin() -> Self {
+        Point2::new(T::zero(), T::zero())
+    }
+
+    // Function to get the position vector of the point.
+    pub fn to_vector(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    // Function to get the squared distance to another point.
+    pub fn distance_squared(&self, other: Self) -> T {
+        (other - *self).norm_squared()
+    }
+
+    // Function to linearly interpolate, returning `self` at t = 0 and `other` at t = 1.
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self).scale(t)
+    }
+
+    // Function to get the point halfway to another point.
+    pub fn midpoint(&self, other: Self) -> Self {
+        self.lerp(other, T::one() / (T::one() + T::one()))
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: Float,
+{
+    // Function to get the distance to another point.
+    pub fn distance(&self, other: Self) -> T {
+        (other - *self).norm()
+    }
+}
+
+impl<T> Point3<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new point.
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    // Function to create the origin.
+    pub fn origin() -> Self {
+        Point3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    // Function to get the position vector of the point.
+    pub fn to_vector(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    // Function to get the squared distance to another point.
+    pub fn distance_squared(&self, other: Self) -> T {
+        (other - *self).norm_squared()
+    }
+
+    // Function to linearly interpolate, returning `self` at t = 0 and `other` at t = 1.
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self).scale(t)
+    }
+
+    // Function to get the point halfway to another point.
+    pub fn midpoint(&self, other: Self) -> Self {
+        self.lerp(other, T::one() / (T::one() + T::one()))
+    }
+}
+
+impl<T> Point3<T>
+where
+    T: Float,
+{
+    // Function to get the distance to another point.
+    pub fn distance(&self, other: Self) -> T {
+        (other - *self).norm()
+    }
+}
+
+impl<T: Num + Copy> Sub for Point2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num + Copy> Add<Vector2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector2<T>) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Num + Copy> Sub<Vector2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector2<T>) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num + Copy> Sub for Point3<T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Num + Copy> Add<Vector3<T>> for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector3<T>) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Num + Copy> Sub<Vector3<T>> for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector3<T>) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> From<Vector2<T>> for Point2<T> {
+    fn from(vector: Vector2<T>) -> Self {
+        Point2 {
+            x: vector.x,
+            y: vector.y,
+        }
+    }
+}
+
+impl<T> From<Vector3<T>> for Point3<T> {
+    fn from(vector: Vector3<T>) -> Self {
+        Point3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod geometry_point_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    fn frac(numerator: isize, denominator: usize) -> Fraction {
+        Fraction::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn point2_arithmetic_test() {
+        let a = Point2::new(1.0, 2.0);
+        let b = Point2::new(4.0, 6.0);
+        let v = Vector2::new(3.0, 4.0);
+
+        assert_eq!(b - a, v);
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(Point2::from(v), Point2::new(3.0, 4.0));
+        assert_eq!(a.to_vector(), Vector2::new(1.0, 2.0));
+        assert_eq!(Point2::<f64>::origin(), Point2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn point2_distance_test() {
+        let a = Point2::new(1.0, 2.0);
+        let b = Point2::new(4.0, 6.0);
+
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(a.distance_squared(b), 25.0);
+        assert_eq!(Point2::new(1, 2).distance_squared(Point2::new(4, 6)), 25);
+    }
+
+    #[test]
+    fn point2_lerp_test() {
+        let a = Point2::new(frac(0, 1), frac(1, 1));
+        let b = Point2::new(frac(1, 1), frac(0, 1));
+
+        assert_eq!(a.midpoint(b), Point2::new(frac(1, 2), frac(1, 2)));
+        assert_eq!(a.lerp(b, frac(1, 3)), Point2::new(frac(1, 3), frac(2, 3)));
+    }
+
+    #[test]
+    fn point3_arithmetic_test() {
+        let a = Point3::new(1.0, 2.0, 3.0);
+        let v = Vector3::new(1.0, -1.0, 0.5);
+        let b = a + v;
+
+        assert_eq!(b, Point3::new(2.0, 1.0, 3.5));
+        assert_eq!(b - a, v);
+        assert_eq!(b - v, a);
+        assert_eq!(Point3::from(v).to_vector(), v);
+    }
+
+    #[test]
+    fn point3_distance_test() {
+        let a = Point3::new(1.0, 1.0, 1.0);
+        let b = Point3::new(3.0, 4.0, 7.0);
+
+        assert_eq!(a.distance(b), 7.0);
+        assert_eq!(a.distance_squared(b), 49.0);
+        assert_eq!(a.midpoint(b), Point3::new(2.0, 2.5, 4.0));
+    }
+
+    #[test]
+    fn point_display_test() {
+        assert_eq!(Point2::new(1.5, -2.0).to_string(), "(1.5, -2)");
+        assert_eq!(Point3::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    }
+}
@@ -0,0 +1,317 @@
+//! This module provides vertex-list polygon types.
+//!
+//! It is part of the `geometry` module. A `Polygon` stores its vertices in order
+//! and is implicitly closed, so the last vertex connects back to the first. The
+//! vertices may wind either way; `signed_area` is positive for counter-clockwise
+//! polygons. Area and perimeter are computed with `area::get_area_polygon` and
+//! `perimeter::get_perimeter_polygon`.
+//!
+//! A `PolygonWithHoles` is an exterior ring with zero or more holes cut out of it.
+//! The holes are expected to lie inside the exterior and not to overlap each other;
+//! the winding of each ring does not matter.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+//!
+//! let square = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//! ]);
+//!
+//! assert_eq!(square.area(), 4.0);
+//! assert_eq!(square.perimeter(), 8.0);
+//! assert_eq!(square.centroid(), Some(Point2::new(1.0, 1.0)));
+//! assert!(square.is_counter_clockwise());
+//! assert_eq!(square.reversed().signed_area(), -4.0);
+//!
+//! let hole = Polygon::new(vec![
+//!     Point2::new(0.5, 0.5),
+//!     Point2::new(1.5, 0.5),
+//!     Point2::new(1.5, 1.5),
+//!     Point2::new(0.5, 1.5),
+//! ]);
+//! let frame = PolygonWithHoles::new(square, vec![hole]);
+//!
+//! assert_eq!(frame.area(), 3.0);
+//! assert_eq!(frame.perimeter(), 12.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a polygon from its vertices.
+//! - `vertices`: Gets the vertices in order.
+//! - `len`: Gets the number of vertices.
+//! - `is_empty`: Checks whether the polygon has no vertices.
+//! - `edges`: Iterates over the edges as pairs of points.
+//! - `signed_area`: Computes the signed area (positive when counter-clockwise).
+//! - `reversed`: Gets the polygon with the opposite winding order.
+//! - `is_counter_clockwise`: Checks whether the winding order is counter-clockwise.
+//! - `area`: Computes the area (floating point only).
+//! - `perimeter`: Computes the perimeter (floating point only).
+//! - `centroid`: Computes the centroid of the enclosed region (floating point only).
+//!
+//! `PolygonWithHoles` provides `new`, `exterior`, `holes`, `area` and `perimeter`.
+
+use crate::fields::geometry::area::get_area_polygon;
+use crate::fields::geometry::perimeter::get_perimeter_polygon;
+use crate::fields::geometry::point::Point2;
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point2<T>>,
+}
+
+impl<T> Polygon<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a polygon from its vertices.
+    pub fn new(vertices: Vec<Point2<T>>) -> Self {
+        Polygon { vertices }
+    }
+
+    // Function to get the vertices in order.
+    pub fn vertices(&self) -> &[Point2<T>] {
+        &self.vertices
+    }
+
+    // Function to get the number of vertices.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    // Function to check whether the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    // Function to iterate over the edges, including the closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    // Function to get the signed area, positive for counter-clockwise winding.
+    pub fn signed_area(&self) -> T {
+        let twice_area = self
+            .edges()
+            .fold(T::zero(), |sum, (a, b)| sum + (a.x * b.y - b.x * a.y));
+        twice_area / (T::one() + T::one())
+    }
+
+    // Function to get the polygon with the opposite winding order.
+    pub fn reversed(&self) -> Self {
+        Polygon::new(self.vertices.iter().rev().copied().collect())
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    // Function to check whether the vertices wind counter-clockwise.
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.signed_area() > T::zero()
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Float,
+{
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        get_area_polygon(&self.vertices)
+    }
+
+    // Function to get the perimeter.
+    pub fn perimeter(&self) -> T {
+        get_perimeter_polygon(&self.vertices)
+    }
+
+    // Function to get the centroid of the enclosed region, or None if the area is zero.
+    pub fn centroid(&self) -> Option<Point2<T>> {
+        let mut twice_area = T::zero();
+        let mut cx = T::zero();
+        let mut cy = T::zero();
+        for (a, b) in self.edges() {
+            let cross = a.x * b.y - b.x * a.y;
+            twice_area = twice_area + cross;
+            cx = cx + (a.x + b.x) * cross;
+            cy = cy + (a.y + b.y) * cross;
+        }
+        if twice_area.is_zero() {
+            return None;
+        }
+        let three = T::from(3).unwrap();
+        Some(Point2::new(
+            cx / (three * twice_area),
+            cy / (three * twice_area),
+        ))
+    }
+}
+
+impl<T> From<Vec<Point2<T>>> for Polygon<T>
+where
+    T: Num + Copy,
+{
+    fn from(vertices: Vec<Point2<T>>) -> Self {
+        Polygon::new(vertices)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonWithHoles<T> {
+    exterior: Polygon<T>,
+    holes: Vec<Polygon<T>>,
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a polygon from its exterior ring and holes.
+    pub fn new(exterior: Polygon<T>, holes: Vec<Polygon<T>>) -> Self {
+        PolygonWithHoles { exterior, holes }
+    }
+
+    // Function to get the exterior ring.
+    pub fn exterior(&self) -> &Polygon<T> {
+        &self.exterior
+    }
+
+    // Function to get the holes.
+    pub fn holes(&self) -> &[Polygon<T>] {
+        &self.holes
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Float,
+{
+    // Function to get the area of the exterior minus the area of the holes.
+    pub fn area(&self) -> T {
+        self.holes
+            .iter()
+            .fold(self.exterior.area(), |area, hole| area - hole.area())
+    }
+
+    // Function to get the total length of the exterior and hole boundaries.
+    pub fn perimeter(&self) -> T {
+        self.holes
+            .iter()
+            .fold(self.exterior.perimeter(), |length, hole| {
+                length + hole.perimeter()
+            })
+    }
+}
+
+impl<T> From<Polygon<T>> for PolygonWithHoles<T>
+where
+    T: Num + Copy,
+{
+    fn from(exterior: Polygon<T>) -> Self {
+        PolygonWithHoles::new(exterior, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod geometry_polygon_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    fn l_shape() -> Polygon<f64> {
+        Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 3.0),
+            Point2::new(0.0, 3.0),
+        ])
+    }
+
+    #[test]
+    fn polygon_signed_area_test() {
+        let polygon = l_shape();
+
+        assert_eq!(polygon.signed_area(), 6.0);
+        assert_eq!(polygon.reversed().signed_area(), -6.0);
+        assert!(polygon.is_counter_clockwise());
+        assert!(!polygon.reversed().is_counter_clockwise());
+    }
+
+    #[test]
+    fn polygon_area_perimeter_test() {
+        let polygon = l_shape();
+
+        assert_eq!(polygon.area(), 6.0);
+        assert_eq!(polygon.reversed().area(), 6.0);
+        assert_eq!(polygon.perimeter(), 14.0);
+        assert_eq!(polygon.len(), 6);
+        assert_eq!(polygon.edges().count(), 6);
+    }
+
+    #[test]
+    fn polygon_centroid_test() {
+        let polygon = l_shape();
+        let centroid = polygon.centroid().unwrap();
+
+        // Union of a 4x1 bar centred at (2, 0.5) and a 1x2 bar centred at (0.5, 2).
+        assert!((centroid.x - (4.0 * 2.0 + 2.0 * 0.5) / 6.0).abs() < 1e-12);
+        assert!((centroid.y - (4.0 * 0.5 + 2.0 * 2.0) / 6.0).abs() < 1e-12);
+
+        let line = Polygon::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)]);
+        assert_eq!(line.centroid(), None);
+    }
+
+    #[test]
+    fn polygon_fraction_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let triangle = Polygon::new(vec![
+            Point2::new(frac(0, 1), frac(0, 1)),
+            Point2::new(frac(1, 2), frac(0, 1)),
+            Point2::new(frac(0, 1), frac(1, 3)),
+        ]);
+
+        assert_eq!(triangle.signed_area(), frac(1, 12));
+        assert!(triangle.is_counter_clockwise());
+    }
+
+    #[test]
+    fn polygon_with_holes_test() {
+        let hole = |x: f64, y: f64| {
+            Polygon::new(vec![
+                Point2::new(x, y),
+                Point2::new(x, y + 0.5),
+                Point2::new(x + 0.5, y + 0.5),
+                Point2::new(x + 0.5, y),
+            ])
+        };
+        let shape = PolygonWithHoles::new(l_shape(), vec![hole(0.25, 0.25), hole(0.25, 2.0)]);
+
+        assert_eq!(shape.area(), 5.5);
+        assert_eq!(shape.perimeter(), 18.0);
+        assert_eq!(shape.holes().len(), 2);
+
+        let solid = PolygonWithHoles::from(l_shape());
+        assert_eq!(solid.area(), 6.0);
+        assert_eq!(solid.exterior(), &l_shape());
+    }
+
+    #[test]
+    fn polygon_empty_test() {
+        let polygon: Polygon<f64> = Polygon::new(Vec::new());
+
+        assert!(polygon.is_empty());
+        assert_eq!(polygon.area(), 0.0);
+        assert_eq!(polygon.perimeter(), 0.0);
+    }
+}
@@ -0,0 +1,456 @@
+//! This module provides robust geometric predicates.
+//!
+//! It is part of the `geometry` module. `orient2d` and `incircle` evaluate the
+//! classic orientation and in-circle determinants on floating point input with
+//! adaptive precision: a fast floating point evaluation is used when its error
+//! bound proves the sign correct, and otherwise the determinant is recomputed
+//! exactly with floating point expansions (Shewchuk's arithmetic). The returned
+//! value always has the correct sign, so near-collinear or near-cocircular
+//! inputs can never produce inconsistent answers.
+//!
+//! `orient2d_exact` and `incircle_exact` evaluate the same determinants in the
+//! coordinate type itself, which is exact for rational types such as `Fraction`
+//! as long as the intermediate values fit. `Fraction` works in 64-bit integers
+//! and panics when a value overflows, so it never returns a wrong sign, but the
+//! in-circle determinant of points with large coprime denominators can exceed it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::predicates::{incircle, orientation, Orientation};
+//!
+//! let a = Point2::new(0.0, 0.0);
+//! let b = Point2::new(1.0, 0.0);
+//! let c = Point2::new(0.0, 1.0);
+//!
+//! assert_eq!(orientation(a, b, c), Orientation::CounterClockwise);
+//! assert_eq!(orientation(a, c, b), Orientation::Clockwise);
+//! assert_eq!(orientation(a, b, Point2::new(2.0, 0.0)), Orientation::Collinear);
+//! assert!(incircle(a, b, c, Point2::new(0.5, 0.5)) > 0.0);
+//! assert!(incircle(a, b, c, Point2::new(2.0, 2.0)) < 0.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `orient2d`: Computes twice the signed area of a triangle, with an exact sign.
+//! - `orientation`: Classifies the turn made by three points.
+//! - `incircle`: Tests a point against the circle through three points, with an exact sign.
+//! - `orient2d_exact`: Computes the orientation determinant in the coordinate type.
+//! - `orientation_exact`: Classifies the turn made by three points in the coordinate type.
+//! - `incircle_exact`: Computes the in-circle determinant in the coordinate type.
+
+use crate::fields::geometry::point::Point2;
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+// Half an ulp of 1.0, the unit roundoff of f64 arithmetic.
+const EPSILON: f64 = f64::EPSILON / 2f64;
+// Shewchuk's error bounds for the fast floating point evaluations.
+const CCW_ERROR_BOUND: f64 = (3f64 + 16f64 * EPSILON) * EPSILON;
+const INCIRCLE_ERROR_BOUND: f64 = (10f64 + 96f64 * EPSILON) * EPSILON;
+
+// Function to get twice the signed area of triangle abc.
+//
+// The result is positive when a, b, c turn counter-clockwise, negative when they
+// turn clockwise and zero when they are collinear. The sign is always exact.
+pub fn orient2d<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> f64 {
+    let [ax, ay, bx, by, cx, cy] = to_f64s([a.x, a.y, b.x, b.y, c.x, c.y]);
+
+    let det_left = (ax - cx) * (by - cy);
+    let det_right = (ay - cy) * (bx - cx);
+    let det = det_left - det_right;
+
+    // When the two products have opposite signs there is no cancellation.
+    if (det_left > 0f64 && det_right <= 0f64) || (det_left < 0f64 && det_right >= 0f64) {
+        return det;
+    }
+    let error_bound = CCW_ERROR_BOUND * (det_left.abs() + det_right.abs());
+    if det.abs() >= error_bound && det != 0f64 {
+        return det;
+    }
+
+    // Exact evaluation of ax*by - ax*cy + bx*cy - bx*ay + cx*ay - cx*by.
+    let terms = [
+        two_product(ax, by),
+        two_product(-ax, cy),
+        two_product(bx, cy),
+        two_product(-bx, ay),
+        two_product(cx, ay),
+        two_product(-cx, by),
+    ];
+    let exact = terms
+        .iter()
+        .fold(Vec::new(), |sum, term| expansion_sum(&sum, term));
+    estimate(&exact)
+}
+
+// Function to classify the turn made by a, b, c.
+pub fn orientation<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Orientation {
+    classify(orient2d(a, b, c))
+}
+
+// Function to test d against the circle through a, b, c.
+//
+// For counter-clockwise a, b, c the result is positive when d lies inside the
+// circle, negative when it lies outside and zero when it lies on the circle.
+// The sign is reversed for clockwise a, b, c. The sign is always exact.
+pub fn incircle<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> f64 {
+    let [ax, ay, bx, by, cx, cy] = to_f64s([a.x, a.y, b.x, b.y, c.x, c.y]);
+    let dx = d.x.to_f64().unwrap();
+    let dy = d.y.to_f64().unwrap();
+
+    let (adx, ady) = (ax - dx, ay - dy);
+    let (bdx, bdy) = (bx - dx, by - dy);
+    let (cdx, cdy) = (cx - dx, cy - dy);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() > INCIRCLE_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    // Exact evaluation with every difference, product and lift kept as an expansion.
+    let adx = two_diff(ax, dx);
+    let ady = two_diff(ay, dy);
+    let bdx = two_diff(bx, dx);
+    let bdy = two_diff(by, dy);
+    let cdx = two_diff(cx, dx);
+    let cdy = two_diff(cy, dy);
+
+    let lift =
+        |x: &[f64], y: &[f64]| expansion_sum(&expansion_product(x, x), &expansion_product(y, y));
+    let cross = |x1: &[f64], y1: &[f64], x2: &[f64], y2: &[f64]| {
+        expansion_diff(&expansion_product(x1, y2), &expansion_product(x2, y1))
+    };
+
+    let a_term = expansion_product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+    let b_term = expansion_product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+    let c_term = expansion_product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+    let exact = expansion_sum(&expansion_sum(&a_term, &b_term), &c_term);
+    estimate(&exact)
+}
+
+// Function to get twice the signed area of triangle abc, evaluated in the coordinate type.
+pub fn orient2d_exact<T>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> T
+where
+    T: Num + Copy,
+{
+    (a - c).cross(b - c)
+}
+
+// Function to classify the turn made by a, b, c, evaluated in the coordinate type.
+pub fn orientation_exact<T>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Orientation
+where
+    T: Num + Copy + PartialOrd,
+{
+    let det = orient2d_exact(a, b, c);
+    if det > T::zero() {
+        Orientation::CounterClockwise
+    } else if det < T::zero() {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+// Function to test d against the circle through a, b, c, evaluated in the coordinate type.
+//
+// The terms are of degree four in the coordinates, so with `Fraction` their
+// denominators grow as the product of the squared input denominators.
+pub fn incircle_exact<T>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> T
+where
+    T: Num + Copy,
+{
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    ad.norm_squared() * bd.cross(cd)
+        + bd.norm_squared() * cd.cross(ad)
+        + cd.norm_squared() * ad.cross(bd)
+}
+
+pub(crate) fn classify(det: f64) -> Orientation {
+    if det > 0f64 {
+        Orientation::CounterClockwise
+    } else if det < 0f64 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+fn to_f64s<T: Float>(values: [T; 6]) -> [f64; 6] {
+    values.map(|value| value.to_f64().unwrap())
+}
+
+// An expansion is a sum of non-overlapping f64 components stored in increasing
+// order of magnitude with zeros removed, representing a value exactly.
+
+// Exact sum: a + b = x + y with x = fl(a + b).
+fn two_sum(a: f64, b: f64) -> [f64; 2] {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    let y = (a - a_virtual) + (b - b_virtual);
+    [y, x]
+}
+
+// Exact difference: a - b = x + y with x = fl(a - b).
+fn two_diff(a: f64, b: f64) -> [f64; 2] {
+    two_sum(a, -b)
+}
+
+// Exact product: a * b = x + y with x = fl(a * b), using a fused multiply-add for y.
+fn two_product(a: f64, b: f64) -> [f64; 2] {
+    let x = a * b;
+    [a.mul_add(b, -x), x]
+}
+
+// Adds two expansions exactly.
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut merged: Vec<f64> = e.iter().chain(f).copied().filter(|v| *v != 0f64).collect();
+    merged.sort_by(|x, y| x.abs().partial_cmp(&y.abs()).unwrap());
+    let mut components = Vec::with_capacity(merged.len());
+    let mut iter = merged.into_iter();
+    let mut q = match iter.next() {
+        Some(first) => first,
+        None => return components,
+    };
+    for g in iter {
+        let [h, sum] = two_sum(q, g);
+        if h != 0f64 {
+            components.push(h);
+        }
+        q = sum;
+    }
+    if q != 0f64 {
+        components.push(q);
+    }
+    components
+}
+
+// Subtracts two expansions exactly.
+fn expansion_diff(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let negated: Vec<f64> = f.iter().map(|v| -v).collect();
+    expansion_sum(e, &negated)
+}
+
+// Multiplies an expansion by a single f64 exactly.
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut components = Vec::with_capacity(2 * e.len());
+    let mut iter = e.iter();
+    let first = match iter.next() {
+        Some(first) => *first,
+        None => return components,
+    };
+    let [h, mut q] = two_product(first, b);
+    if h != 0f64 {
+        components.push(h);
+    }
+    for component in iter {
+        let [product_low, product_high] = two_product(*component, b);
+        let [h, sum] = two_sum(q, product_low);
+        if h != 0f64 {
+            components.push(h);
+        }
+        let [h, sum] = two_sum(product_high, sum);
+        if h != 0f64 {
+            components.push(h);
+        }
+        q = sum;
+    }
+    if q != 0f64 {
+        components.push(q);
+    }
+    components
+}
+
+// Multiplies two expansions exactly.
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(Vec::new(), |sum, component| {
+        expansion_sum(&sum, &scale_expansion(e, *component))
+    })
+}
+
+// Approximates an expansion; the result has the exact sign of the expansion.
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
+
+#[cfg(test)]
+mod geometry_predicates_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    const ULP_HALF: f64 = f64::EPSILON / 2.0;
+
+    #[test]
+    fn orient2d_test() {
+        let a = Point2::new(0.0, 0.0);
+        let b = Point2::new(4.0, 0.0);
+        let c = Point2::new(0.0, 3.0);
+
+        assert_eq!(orient2d(a, b, c), 12.0);
+        assert_eq!(orient2d(a, c, b), -12.0);
+        assert_eq!(orient2d(a, b, Point2::new(8.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn orientation_near_collinear_test() {
+        // c creeps off the line y = x by a single ulp at a time. The naive
+        // determinant loses these perturbations to rounding.
+        let a = Point2::new(12.0, 12.0);
+        let b = Point2::new(24.0, 24.0);
+        for i in 0..128 {
+            let c = Point2::new(0.5 + i as f64 * ULP_HALF, 0.5);
+            let expected = if i == 0 {
+                Orientation::Collinear
+            } else {
+                Orientation::Clockwise
+            };
+            assert_eq!(orientation(a, b, c), expected);
+            // Permuting the points must give consistent answers.
+            assert_eq!(orientation(b, c, a), expected);
+            assert_eq!(orientation(c, a, b), expected);
+            let reversed = orientation(b, a, c);
+            assert_eq!(
+                reversed == Orientation::Collinear,
+                expected == Orientation::Collinear
+            );
+        }
+    }
+
+    #[test]
+    fn orientation_consistency_test() {
+        // Points on a tiny grid around (0.5, 0.5) against a long nearly collinear line.
+        let a = Point2::new(0.5, 0.5);
+        let b = Point2::new(17.3, 17.3);
+        let step = f64::EPSILON;
+        for i in 0..16 {
+            for j in 0..16 {
+                let c = Point2::new(0.5 + i as f64 * step, 0.5 + j as f64 * step);
+                let expected = if i == j {
+                    Orientation::Collinear
+                } else if i > j {
+                    Orientation::Clockwise
+                } else {
+                    Orientation::CounterClockwise
+                };
+                assert_eq!(orientation(a, b, c), expected, "i = {}, j = {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn incircle_test() {
+        let a = Point2::new(1.0, 0.0);
+        let b = Point2::new(0.0, 1.0);
+        let c = Point2::new(-1.0, 0.0);
+
+        assert!(incircle(a, b, c, Point2::new(0.0, 0.0)) > 0.0);
+        assert!(incircle(a, b, c, Point2::new(2.0, 0.0)) < 0.0);
+        assert_eq!(incircle(a, b, c, Point2::new(0.0, -1.0)), 0.0);
+        // Clockwise input flips the sign.
+        assert!(incircle(c, b, a, Point2::new(0.0, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn incircle_near_cocircular_test() {
+        let a = Point2::new(1.0, 0.0);
+        let b = Point2::new(0.0, 1.0);
+        let c = Point2::new(-1.0, 0.0);
+        for i in 1..64 {
+            let outside = Point2::new(0.0, -(1.0 + i as f64 * f64::EPSILON));
+            let inside = Point2::new(0.0, -(1.0 - i as f64 * ULP_HALF));
+            assert!(incircle(a, b, c, outside) < 0.0, "i = {}", i);
+            assert!(incircle(a, b, c, inside) > 0.0, "i = {}", i);
+        }
+    }
+
+    #[test]
+    fn expansion_arithmetic_test() {
+        // (1 + 2^-60) * (1 - 2^-60) = 1 - 2^-120 exactly.
+        let tiny = 2f64.powi(-60);
+        let product = expansion_product(&two_sum(1.0, tiny), &two_diff(1.0, tiny));
+        let difference = expansion_diff(&product, &[1.0]);
+        assert_eq!(difference, vec![-2f64.powi(-120)]);
+        assert_eq!(expansion_sum(&[], &[]), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn orientation_exact_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let a = Point2::new(frac(0, 1), frac(0, 1));
+        let b = Point2::new(frac(1, 3), frac(1, 3));
+        let on_line = Point2::new(frac(2, 3), frac(2, 3));
+        let above = Point2::new(frac(2, 3), frac(7, 10));
+
+        assert_eq!(orient2d_exact(a, b, on_line), frac(0, 1));
+        assert_eq!(orientation_exact(a, b, on_line), Orientation::Collinear);
+        assert_eq!(
+            orientation_exact(a, b, above),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(orientation_exact(b, a, above), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn incircle_exact_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        // The circle through these points is centred at (1/2, 1/2) with radius^2 = 1/2.
+        let a = Point2::new(frac(0, 1), frac(0, 1));
+        let b = Point2::new(frac(1, 1), frac(0, 1));
+        let c = Point2::new(frac(1, 1), frac(1, 1));
+
+        assert_eq!(
+            incircle_exact(a, b, c, Point2::new(frac(0, 1), frac(1, 1))),
+            frac(0, 1)
+        );
+        assert!(incircle_exact(a, b, c, Point2::new(frac(1, 2), frac(1, 2))) > frac(0, 1));
+        assert!(incircle_exact(a, b, c, Point2::new(frac(2, 1), frac(1, 2))) < frac(0, 1));
+    }
+
+    #[test]
+    fn exact_coprime_denominators_test() {
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        let a = Point2::new(frac(1, 2), frac(1, 3));
+        let b = Point2::new(frac(5, 7), frac(1, 11));
+        let c = Point2::new(frac(9, 13), frac(12, 17));
+        let d = Point2::new(frac(4, 19), frac(15, 23));
+
+        assert_eq!(orient2d_exact(a, b, c), frac(12911, 102102));
+        assert_eq!(
+            incircle_exact(a, b, c, d),
+            frac(-63094062175, 1520868713364)
+        );
+        let to_f64 = |p: Point2<Fraction>| Point2::new(p.x.to_decimal(), p.y.to_decimal());
+        assert!(incircle(to_f64(a), to_f64(b), to_f64(c), to_f64(d)) < 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fraction arithmetic overflowed")]
+    fn incircle_exact_overflow_test() {
+        // The exact determinant has a 48-digit denominator, beyond Fraction's range.
+        let frac = |n, d| Fraction::new(n, d).unwrap();
+        incircle_exact(
+            Point2::new(frac(1, 997), frac(2, 991)),
+            Point2::new(frac(3, 983), frac(5, 977)),
+            Point2::new(frac(7, 971), frac(11, 967)),
+            Point2::new(frac(13, 953), frac(17, 947)),
+        );
+    }
+}
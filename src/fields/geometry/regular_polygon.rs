@@ -0,0 +1,267 @@
+//! This module provides functionality for working with regular polygons.
+//!
+//! It is part of the `geometry` module. A `RegularPolygon` is described by its
+//! number of sides plus any one of side length, apothem, circumradius or area,
+//! and every other measurement is derived from those two values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::regular_polygon::RegularPolygon;
+//!
+//! let hexagon = RegularPolygon::from_circumradius(6, 10.0f64).unwrap();
+//!
+//! assert!((hexagon.side_length() - 10.0).abs() < 1e-12);
+//! assert!((hexagon.apothem() - 8.660254037844386).abs() < 1e-12);
+//! assert!((hexagon.perimeter() - 60.0).abs() < 1e-12);
+//! assert!((hexagon.interior_angle_degrees() - 120.0).abs() < 1e-12);
+//! assert!((hexagon.exterior_angle_degrees() - 60.0).abs() < 1e-12);
+//!
+//! let square = RegularPolygon::from_area(4, 12.25f64).unwrap();
+//!
+//! assert!((square.side_length() - 3.5).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_side`: Constructs a regular polygon from its side length.
+//! - `from_apothem`: Constructs a regular polygon from its apothem.
+//! - `from_circumradius`: Constructs a regular polygon from its circumradius.
+//! - `from_area`: Constructs a regular polygon from its area.
+//! - `sides`: Gets the number of sides.
+//! - `side_length`: Gets the length of each side.
+//! - `apothem`: Gets the distance from the center to the midpoint of a side.
+//! - `circumradius`: Gets the distance from the center to a vertex.
+//! - `inradius`: Gets the radius of the inscribed circle (equal to the apothem).
+//! - `interior_angle`: Gets the interior angle in radians.
+//! - `interior_angle_degrees`: Gets the interior angle in degrees.
+//! - `exterior_angle`: Gets the exterior angle in radians.
+//! - `exterior_angle_degrees`: Gets the exterior angle in degrees.
+//! - `central_angle`: Gets the angle subtended by one side at the center, in radians.
+//! - `area`: Gets the area.
+//! - `perimeter`: Gets the perimeter.
+
+use crate::fields::geometry::area::get_area_regular_polygon;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::perimeter::get_perimeter_regular_polygon;
+use num_traits::{Num, NumCast};
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularPolygon<T> {
+    sides: usize,
+    // Side length is kept as f64 so that values derived from an integer
+    // measurement (e.g. a pentagon from its circumradius) stay consistent.
+    side_length: f64,
+    _marker: PhantomData<T>,
+}
+
+impl<T> RegularPolygon<T>
+where
+    T: Num + NumCast + Copy,
+{
+    // Function to create a regular polygon from its side length.
+    pub fn from_side(sides: usize, side: T) -> Result<Self, GeometryError> {
+        let side_f64 = Self::checked_measure(sides, side, "Side length")?;
+        Ok(Self::with_side_length(sides, side_f64))
+    }
+
+    // Function to create a regular polygon from its apothem.
+    pub fn from_apothem(sides: usize, apothem: T) -> Result<Self, GeometryError> {
+        let apothem_f64 = Self::checked_measure(sides, apothem, "Apothem")?;
+        // s = 2a * tan(pi / n)
+        let side_f64 = 2f64 * apothem_f64 * (PI / sides as f64).tan();
+        Ok(Self::with_side_length(sides, side_f64))
+    }
+
+    // Function to create a regular polygon from its circumradius.
+    pub fn from_circumradius(sides: usize, circumradius: T) -> Result<Self, GeometryError> {
+        let radius_f64 = Self::checked_measure(sides, circumradius, "Circumradius")?;
+        // s = 2R * sin(pi / n)
+        let side_f64 = 2f64 * radius_f64 * (PI / sides as f64).sin();
+        Ok(Self::with_side_length(sides, side_f64))
+    }
+
+    // Function to create a regular polygon from its area.
+    pub fn from_area(sides: usize, area: T) -> Result<Self, GeometryError> {
+        let area_f64 = Self::checked_measure(sides, area, "Area")?;
+        // A = n * s^2 / (4 * tan(pi / n))  =>  s = sqrt(4A * tan(pi / n) / n)
+        let n = sides as f64;
+        let side_f64 = (4f64 * area_f64 * (PI / n).tan() / n).sqrt();
+        Ok(Self::with_side_length(sides, side_f64))
+    }
+
+    // Function to get the number of sides.
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+
+    // Function to get the length of each side.
+    pub fn side_length(&self) -> T {
+        T::from(self.side_length).unwrap()
+    }
+
+    // Function to get the apothem (center to the midpoint of a side).
+    pub fn apothem(&self) -> T {
+        T::from(self.apothem_f64()).unwrap()
+    }
+
+    // Function to get the circumradius (center to a vertex).
+    pub fn circumradius(&self) -> T {
+        let radius_f64 = self.side_length / (2f64 * (PI / self.sides as f64).sin());
+        T::from(radius_f64).unwrap()
+    }
+
+    // Function to get the inradius, which for a regular polygon is the apothem.
+    pub fn inradius(&self) -> T {
+        self.apothem()
+    }
+
+    // Function to get the interior angle in radians.
+    pub fn interior_angle(&self) -> f64 {
+        PI - self.exterior_angle()
+    }
+
+    // Function to get the interior angle in degrees.
+    pub fn interior_angle_degrees(&self) -> f64 {
+        self.interior_angle().to_degrees()
+    }
+
+    // Function to get the exterior angle in radians.
+    pub fn exterior_angle(&self) -> f64 {
+        2f64 * PI / self.sides as f64
+    }
+
+    // Function to get the exterior angle in degrees.
+    pub fn exterior_angle_degrees(&self) -> f64 {
+        self.exterior_angle().to_degrees()
+    }
+
+    // Function to get the central angle in radians (equal to the exterior angle).
+    pub fn central_angle(&self) -> f64 {
+        self.exterior_angle()
+    }
+
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        T::from(get_area_regular_polygon(self.sides, self.side_length)).unwrap()
+    }
+
+    // Function to get the perimeter.
+    pub fn perimeter(&self) -> T {
+        T::from(get_perimeter_regular_polygon(self.sides, self.side_length)).unwrap()
+    }
+
+    fn with_side_length(sides: usize, side_length: f64) -> Self {
+        RegularPolygon {
+            sides,
+            side_length,
+            _marker: PhantomData,
+        }
+    }
+
+    fn apothem_f64(&self) -> f64 {
+        self.side_length / (2f64 * (PI / self.sides as f64).tan())
+    }
+
+    // Validates the side count and the given measurement, returning it as f64.
+    fn checked_measure(sides: usize, value: T, name: &str) -> Result<f64, GeometryError> {
+        if sides < 3 {
+            return Err(GeometryError::new(
+                "A regular polygon must have at least 3 sides",
+            ));
+        }
+        match T::to_f64(&value) {
+            Some(v) if v.is_finite() && v >= 0f64 => Ok(v),
+            _ => Err(GeometryError::new(&format!(
+                "{} must be a finite, non-negative number",
+                name
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod geometry_regular_polygon_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn regular_polygon_from_side_test() {
+        let hexagon = RegularPolygon::from_side(6, 1.0).unwrap();
+
+        assert_eq!(hexagon.sides(), 6);
+        assert_close(hexagon.side_length(), 1.0);
+        assert_close(hexagon.circumradius(), 1.0);
+        assert_close(hexagon.apothem(), 3f64.sqrt() / 2.0);
+        assert_close(hexagon.area(), 3.0 * 3f64.sqrt() / 2.0);
+        assert_close(hexagon.perimeter(), 6.0);
+    }
+
+    #[test]
+    fn regular_polygon_from_apothem_test() {
+        let square = RegularPolygon::from_apothem(4, 1.75).unwrap();
+
+        assert_close(square.side_length(), 3.5);
+        assert_close(square.inradius(), 1.75);
+        assert_close(square.circumradius(), 1.75 * 2f64.sqrt());
+    }
+
+    #[test]
+    fn regular_polygon_from_circumradius_test() {
+        let triangle = RegularPolygon::from_circumradius(3, 1.0).unwrap();
+
+        assert_close(triangle.side_length(), 3f64.sqrt());
+        assert_close(triangle.apothem(), 0.5);
+    }
+
+    #[test]
+    fn regular_polygon_from_area_test() {
+        let octagon = RegularPolygon::from_side(8, 2.5).unwrap();
+        let rebuilt = RegularPolygon::from_area(8, octagon.area()).unwrap();
+
+        assert_close(rebuilt.side_length(), 2.5);
+        assert_close(rebuilt.circumradius(), octagon.circumradius());
+    }
+
+    #[test]
+    fn regular_polygon_matches_square_test() {
+        use crate::fields::geometry::area::get_area_square;
+        use crate::fields::geometry::perimeter::get_perimeter_square;
+
+        let square = RegularPolygon::from_side(4, 3.5).unwrap();
+
+        assert_close(square.area(), get_area_square(3.5));
+        assert_close(square.perimeter(), get_perimeter_square(3.5));
+    }
+
+    #[test]
+    fn regular_polygon_angles_test() {
+        let pentagon = RegularPolygon::<f64>::from_side(5, 1.0).unwrap();
+
+        assert_close(pentagon.interior_angle_degrees(), 108.0);
+        assert_close(pentagon.exterior_angle_degrees(), 72.0);
+        assert_close(pentagon.central_angle(), pentagon.exterior_angle());
+        assert_close(pentagon.interior_angle() + pentagon.exterior_angle(), PI);
+    }
+
+    #[test]
+    fn regular_polygon_integer_test() {
+        let hexagon = RegularPolygon::from_side(6, 10).unwrap();
+
+        assert_eq!(hexagon.circumradius(), 10);
+        assert_eq!(hexagon.perimeter(), 60);
+        assert_eq!(hexagon.apothem(), 8);
+    }
+
+    #[test]
+    fn regular_polygon_invalid_test() {
+        assert!(RegularPolygon::from_side(2, 1.0).is_err());
+        assert!(RegularPolygon::from_side(6, -1.0).is_err());
+        assert!(RegularPolygon::from_area(6, f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,538 @@
+//! `Section` is a struct holding the area properties of a plane cross-section
+//!
+//! A section keeps its area and its first and second moments of area about the
+//! origin axes, along with its bounding box. From these it gives the centroid,
+//! the second moments about the centroid, the polar moment, radii of gyration,
+//! principal axes and elastic section moduli used in beam design.
+//!
+//! The shapes of the `area` module are constructed with their centroid at the
+//! origin and are moved into place with `translated`. Moments add up over
+//! disjoint parts, so composite sections such as I-beams, channels and tees are
+//! built by adding parts and subtracting cut-outs. A cut-out is expected to lie
+//! inside the section it is taken from, whose bounding box is kept. The
+//! `second_moments_about` function applies the parallel axis theorem to any point.
+//!
+//! Second moments follow the usual structural convention: `ixx` is about the
+//! horizontal axis, ∫y² dA, `iyy` is about the vertical axis, ∫x² dA, and `ixy`
+//! is the product ∫xy dA.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::section::Section;
+//!
+//! // A 200 × 300 I-beam with 20 thick flanges and a 10 thick web.
+//! let flange = Section::rectangle(200.0f64, 20.0);
+//! let beam = flange.translated(0.0, 140.0)
+//!     + Section::rectangle(10.0, 260.0)
+//!     + flange.translated(0.0, -140.0);
+//!
+//! assert_eq!(beam.area(), 10600.0);
+//! assert_eq!(beam.centroid(), Some(Point2::new(0.0, 0.0)));
+//!
+//! // The same beam as a solid block minus the two gaps beside the web.
+//! let gap = Section::rectangle(95.0, 260.0);
+//! let cut = Section::rectangle(200.0, 300.0) - gap.translated(-52.5, 0.0) - gap.translated(52.5, 0.0);
+//!
+//! let moments = cut.second_moments().unwrap();
+//! assert!((moments.ixx - beam.second_moments().unwrap().ixx).abs() < 1e-6);
+//! assert!((moments.ixx - 171713333.3333f64).abs() < 1e-3);
+//!
+//! let moduli = cut.section_moduli().unwrap();
+//! assert!((moduli.top - moments.ixx / 150.0).abs() < 1e-6);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_polygon`: Constructs the section of a simple polygon.
+//! - `from_polygon_with_holes`: Constructs the section of a polygon with holes.
+//! - `circle`, `rectangle`, `square`, `trapezoid`, `triangle`, `triangle_right` and `regular_polygon`: Construct a shape centred on its centroid.
+//! - `translated`: Moves the section.
+//! - `area`: Gets the area.
+//! - `bounds`: Gets the bounding box.
+//! - `centroid`: Computes the centroid.
+//! - `second_moments`: Computes the second moments about the centroidal axes.
+//! - `second_moments_about`: Computes the second moments about axes through a point.
+//! - `polar_moment`: Computes the polar moment about the centroid.
+//! - `radii_of_gyration`: Computes the radii of gyration about the centroidal axes.
+//! - `principal_axes`: Computes the principal moments and the direction of the major axis.
+//! - `section_moduli`: Computes the elastic section moduli to the extreme fibres.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::regular_polygon::RegularPolygon;
+use crate::types::Angle;
+use num_traits::{Float, FloatConst};
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Section<T> {
+    area: T,
+    // ∫x dA and ∫y dA about the origin.
+    first_x: T,
+    first_y: T,
+    // ∫y² dA, ∫x² dA and ∫xy dA about the origin.
+    ixx: T,
+    iyy: T,
+    ixy: T,
+    bounds: Option<BoundingBox<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecondMoments<T> {
+    pub ixx: T,
+    pub iyy: T,
+    pub ixy: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes<T> {
+    pub major: T,
+    pub minor: T,
+    // The direction of the axis with the larger moment, measured from the x axis.
+    pub angle: Angle<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionModuli<T> {
+    // About the horizontal centroidal axis, to the top and bottom fibres.
+    pub top: T,
+    pub bottom: T,
+    // About the vertical centroidal axis, to the left and right fibres.
+    pub left: T,
+    pub right: T,
+}
+
+impl<T> Section<T>
+where
+    T: Float,
+{
+    // Function to create the section of a simple polygon, wound either way.
+    pub fn from_polygon(polygon: &Polygon<T>) -> Self {
+        let two = T::from(2).unwrap();
+        let six = T::from(6).unwrap();
+        let twelve = T::from(12).unwrap();
+        let twenty_four = T::from(24).unwrap();
+        let mut section = Section::empty(BoundingBox::from_points(polygon.vertices()));
+        for (a, b) in polygon.edges() {
+            let cross = a.x * b.y - b.x * a.y;
+            section.area = section.area + cross / two;
+            section.first_x = section.first_x + (a.x + b.x) * cross / six;
+            section.first_y = section.first_y + (a.y + b.y) * cross / six;
+            section.ixx = section.ixx + (a.y * a.y + a.y * b.y + b.y * b.y) * cross / twelve;
+            section.iyy = section.iyy + (a.x * a.x + a.x * b.x + b.x * b.x) * cross / twelve;
+            section.ixy = section.ixy
+                + (a.x * b.y + two * a.x * a.y + two * b.x * b.y + b.x * a.y) * cross / twenty_four;
+        }
+        // Clockwise polygons give every integral with the wrong sign.
+        if section.area < T::zero() {
+            section.scale(-T::one())
+        } else {
+            section
+        }
+    }
+
+    // Function to create the section of a polygon with its holes removed.
+    pub fn from_polygon_with_holes(polygon: &PolygonWithHoles<T>) -> Self {
+        polygon.holes().iter().fold(
+            Section::from_polygon(polygon.exterior()),
+            |section, hole| section - Section::from_polygon(hole),
+        )
+    }
+
+    // Function to create a rectangle centred on the origin.
+    pub fn rectangle(width: T, height: T) -> Self {
+        let x = width.abs() / T::from(2).unwrap();
+        let y = height.abs() / T::from(2).unwrap();
+        Section::from_polygon(&Polygon::new(vec![
+            Point2::new(-x, -y),
+            Point2::new(x, -y),
+            Point2::new(x, y),
+            Point2::new(-x, y),
+        ]))
+    }
+
+    // Function to create a square centred on the origin.
+    pub fn square(side: T) -> Self {
+        Section::rectangle(side, side)
+    }
+
+    // Function to create an isosceles trapezoid centred on its centroid.
+    //
+    // The first base lies at the bottom and the second at the top.
+    pub fn trapezoid(base1: T, base2: T, height: T) -> Self {
+        let two = T::from(2).unwrap();
+        let (bottom, top) = (base1.abs() / two, base2.abs() / two);
+        Section::from_polygon(&Polygon::new(vec![
+            Point2::new(-bottom, T::zero()),
+            Point2::new(bottom, T::zero()),
+            Point2::new(top, height.abs()),
+            Point2::new(-top, height.abs()),
+        ]))
+        .centered()
+    }
+
+    // Function to create an isosceles triangle centred on its centroid, with its base at the bottom.
+    pub fn triangle(base: T, height: T) -> Self {
+        let half = base.abs() / T::from(2).unwrap();
+        Section::from_polygon(&Polygon::new(vec![
+            Point2::new(-half, T::zero()),
+            Point2::new(half, T::zero()),
+            Point2::new(T::zero(), height.abs()),
+        ]))
+        .centered()
+    }
+
+    // Function to create a right triangle centred on its centroid, with the right angle at the bottom left.
+    pub fn triangle_right(base: T, height: T) -> Self {
+        Section::from_polygon(&Polygon::new(vec![
+            Point2::new(T::zero(), T::zero()),
+            Point2::new(base.abs(), T::zero()),
+            Point2::new(T::zero(), height.abs()),
+        ]))
+        .centered()
+    }
+
+    // Function to create the section of a regular polygon centred on the origin, with a vertex at the top.
+    pub fn regular_polygon(polygon: &RegularPolygon<T>) -> Self {
+        let sides = polygon.sides();
+        let radius = polygon.circumradius();
+        let step = T::from(2.0 * std::f64::consts::PI / sides as f64).unwrap();
+        let vertices = (0..sides)
+            .map(|i| {
+                let (sin, cos) = (step * T::from(i).unwrap()).sin_cos();
+                // Start at the top, a quarter turn from the x axis.
+                Point2::new(-radius * sin, radius * cos)
+            })
+            .collect();
+        Section::from_polygon(&Polygon::new(vertices))
+    }
+
+    // Function to get the section moved by an offset.
+    pub fn translated(&self, dx: T, dy: T) -> Self {
+        let two = T::from(2).unwrap();
+        Section {
+            area: self.area,
+            first_x: self.first_x + dx * self.area,
+            first_y: self.first_y + dy * self.area,
+            ixx: self.ixx + two * dy * self.first_y + dy * dy * self.area,
+            iyy: self.iyy + two * dx * self.first_x + dx * dx * self.area,
+            ixy: self.ixy + dx * self.first_y + dy * self.first_x + dx * dy * self.area,
+            bounds: self.bounds.map(|bounds| {
+                BoundingBox::new(
+                    Point2::new(bounds.min().x + dx, bounds.min().y + dy),
+                    Point2::new(bounds.max().x + dx, bounds.max().y + dy),
+                )
+            }),
+        }
+    }
+
+    // Function to get the area.
+    pub fn area(&self) -> T {
+        self.area
+    }
+
+    // Function to get the bounding box, or None for a section built from no points.
+    pub fn bounds(&self) -> Option<BoundingBox<T>> {
+        self.bounds
+    }
+
+    // Function to get the centroid, or None when the area is zero.
+    pub fn centroid(&self) -> Option<Point2<T>> {
+        if self.area == T::zero() {
+            return None;
+        }
+        Some(Point2::new(
+            self.first_x / self.area,
+            self.first_y / self.area,
+        ))
+    }
+
+    // Function to get the second moments about the centroidal axes.
+    pub fn second_moments(&self) -> Option<SecondMoments<T>> {
+        let centroid = self.centroid()?;
+        Some(self.second_moments_about(centroid))
+    }
+
+    // Function to get the second moments about the axes through a point (parallel axis theorem).
+    pub fn second_moments_about(&self, point: Point2<T>) -> SecondMoments<T> {
+        let moved = self.translated(-point.x, -point.y);
+        SecondMoments {
+            ixx: moved.ixx,
+            iyy: moved.iyy,
+            ixy: moved.ixy,
+        }
+    }
+
+    // Function to get the polar moment of area about the centroid.
+    pub fn polar_moment(&self) -> Option<T> {
+        let moments = self.second_moments()?;
+        Some(moments.ixx + moments.iyy)
+    }
+
+    // Function to get the radii of gyration about the horizontal and vertical centroidal axes.
+    pub fn radii_of_gyration(&self) -> Option<(T, T)> {
+        let moments = self.second_moments()?;
+        Some((
+            (moments.ixx / self.area).sqrt(),
+            (moments.iyy / self.area).sqrt(),
+        ))
+    }
+
+    // Function to get the principal second moments and the direction of the major axis.
+    //
+    // The angle is in (−π/2, π/2]; for equal moments, such as a circle, it is zero.
+    pub fn principal_axes(&self) -> Option<PrincipalAxes<T>> {
+        let moments = self.second_moments()?;
+        let two = T::from(2).unwrap();
+        let mean = (moments.ixx + moments.iyy) / two;
+        let radius = ((moments.ixx - moments.iyy) / two).hypot(moments.ixy);
+        Some(PrincipalAxes {
+            major: mean + radius,
+            minor: mean - radius,
+            angle: Angle::from_radians((-two * moments.ixy).atan2(moments.ixx - moments.iyy) / two),
+        })
+    }
+
+    // Function to get the elastic section moduli, each second moment over the distance to an extreme fibre.
+    pub fn section_moduli(&self) -> Option<SectionModuli<T>> {
+        let moments = self.second_moments()?;
+        let centroid = self.centroid()?;
+        let bounds = self.bounds?;
+        Some(SectionModuli {
+            top: moments.ixx / (bounds.max().y - centroid.y),
+            bottom: moments.ixx / (centroid.y - bounds.min().y),
+            left: moments.iyy / (centroid.x - bounds.min().x),
+            right: moments.iyy / (bounds.max().x - centroid.x),
+        })
+    }
+
+    fn empty(bounds: Option<BoundingBox<T>>) -> Self {
+        Section {
+            area: T::zero(),
+            first_x: T::zero(),
+            first_y: T::zero(),
+            ixx: T::zero(),
+            iyy: T::zero(),
+            ixy: T::zero(),
+            bounds,
+        }
+    }
+
+    fn scale(&self, factor: T) -> Self {
+        Section {
+            area: self.area * factor,
+            first_x: self.first_x * factor,
+            first_y: self.first_y * factor,
+            ixx: self.ixx * factor,
+            iyy: self.iyy * factor,
+            ixy: self.ixy * factor,
+            bounds: self.bounds,
+        }
+    }
+
+    // Moves the section so its centroid is at the origin.
+    fn centered(&self) -> Self {
+        match self.centroid() {
+            Some(centroid) => self.translated(-centroid.x, -centroid.y),
+            None => *self,
+        }
+    }
+}
+
+impl<T> Section<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to create a circle centred on the origin.
+    pub fn circle(radius: T) -> Self {
+        let radius = radius.abs();
+        let area = T::PI() * radius * radius;
+        let moment = area * radius * radius / T::from(4).unwrap();
+        Section {
+            area,
+            ixx: moment,
+            iyy: moment,
+            ..Section::empty(Some(BoundingBox::new(
+                Point2::new(-radius, -radius),
+                Point2::new(radius, radius),
+            )))
+        }
+    }
+}
+
+impl<T: Float> Add for Section<T> {
+    type Output = Self;
+
+    // The bounding box covers both parts.
+    fn add(self, other: Self) -> Self {
+        let bounds = match (self.bounds, other.bounds) {
+            (Some(a), Some(b)) => Some(a.union(&b)),
+            (a, b) => a.or(b),
+        };
+        Section {
+            area: self.area + other.area,
+            first_x: self.first_x + other.first_x,
+            first_y: self.first_y + other.first_y,
+            ixx: self.ixx + other.ixx,
+            iyy: self.iyy + other.iyy,
+            ixy: self.ixy + other.ixy,
+            bounds,
+        }
+    }
+}
+
+impl<T: Float> Sub for Section<T> {
+    type Output = Self;
+
+    // The cut-out is assumed to lie inside, so the bounding box is kept.
+    fn sub(self, other: Self) -> Self {
+        Section {
+            bounds: self.bounds,
+            ..self + other.scale(-T::one())
+        }
+    }
+}
+
+#[cfg(test)]
+mod geometry_section_tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9 * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn section_shapes_test() {
+        let rectangle = Section::rectangle(4.0, 6.0);
+        let moments = rectangle.second_moments().unwrap();
+        assert_eq!(rectangle.area(), 24.0);
+        assert_close(moments.ixx, 4.0 * 216.0 / 12.0);
+        assert_close(moments.iyy, 6.0 * 64.0 / 12.0);
+        assert_close(moments.ixy, 0.0);
+
+        let circle = Section::circle(2.0);
+        assert_close(circle.area(), 4.0 * PI);
+        assert_close(circle.polar_moment().unwrap(), PI * 16.0 / 2.0);
+
+        let triangle = Section::triangle(3.0, 6.0);
+        assert_close(triangle.area(), 9.0);
+        assert_close(triangle.second_moments().unwrap().ixx, 3.0 * 216.0 / 36.0);
+        assert_close(triangle.bounds().unwrap().min().y, -2.0);
+
+        let right = Section::triangle_right(3.0, 6.0).second_moments().unwrap();
+        assert_close(right.ixy, -9.0 * 36.0 / 72.0);
+
+        let trapezoid = Section::trapezoid(6.0, 2.0, 3.0);
+        assert_close(trapezoid.area(), 12.0);
+        // (b1² + 4 b1 b2 + b2²) h³ / (36 (b1 + b2))
+        assert_close(trapezoid.second_moments().unwrap().ixx, 88.0 * 27.0 / 288.0);
+        assert_close(trapezoid.centroid().unwrap().y, 0.0);
+
+        let square = Section::square(2.0);
+        let hexagon = Section::regular_polygon(&RegularPolygon::from_side(6, 2.0).unwrap());
+        let moments = hexagon.second_moments().unwrap();
+        assert_close(hexagon.area(), 6.0 * 3f64.sqrt());
+        assert_close(moments.ixx, 5.0 * 3f64.sqrt() * 16.0 / 16.0);
+        assert_close(moments.ixx, moments.iyy);
+        assert_close(square.second_moments().unwrap().ixx, 16.0 / 12.0);
+    }
+
+    #[test]
+    fn section_polygon_test() {
+        let vertices = vec![
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 4.0),
+            Point2::new(3.0, 4.0),
+            Point2::new(3.0, 1.0),
+        ];
+        let clockwise = Section::from_polygon(&Polygon::new(vertices));
+        assert_eq!(clockwise.area(), 6.0);
+        let centroid = clockwise.centroid().unwrap();
+        assert_close(centroid.x, 2.0);
+        assert_close(centroid.y, 2.5);
+
+        let about_origin = clockwise.second_moments_about(Point2::new(0.0, 0.0));
+        assert_close(about_origin.ixx, 2.0 * 27.0 / 12.0 + 6.0 * 6.25);
+        assert_close(about_origin.ixy, 6.0 * 2.0 * 2.5);
+
+        let outer = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ]);
+        let hole = Polygon::new(vec![
+            Point2::new(1.0, 1.0),
+            Point2::new(3.0, 1.0),
+            Point2::new(3.0, 3.0),
+            Point2::new(1.0, 3.0),
+        ]);
+        let tube = Section::from_polygon_with_holes(&PolygonWithHoles::new(outer, vec![hole]));
+        assert_eq!(tube.area(), 12.0);
+        assert_close(tube.second_moments().unwrap().ixx, (256.0 - 16.0) / 12.0);
+        assert_eq!(tube.bounds().unwrap().max(), Point2::new(4.0, 4.0));
+
+        assert_eq!(
+            Section::<f64>::from_polygon(&Polygon::new(vec![])).centroid(),
+            None
+        );
+    }
+
+    #[test]
+    fn section_composite_test() {
+        // A channel: a 100 × 200 block with a 90 × 160 notch on the right.
+        let channel =
+            Section::rectangle(100.0, 200.0) - Section::rectangle(90.0, 160.0).translated(5.0, 0.0);
+        let centroid = channel.centroid().unwrap();
+        assert_close(channel.area(), 5600.0);
+        assert_close(centroid.x, (20000.0 * 0.0 - 14400.0 * 5.0) / 5600.0);
+
+        let moduli = channel.section_moduli().unwrap();
+        let moments = channel.second_moments().unwrap();
+        assert_close(moduli.top, moments.ixx / 100.0);
+        assert_close(moduli.bottom, moduli.top);
+        assert_close(moduli.left, moments.iyy / (centroid.x + 50.0));
+        assert_close(moduli.right, moments.iyy / (50.0 - centroid.x));
+
+        // A tee: a 120 × 20 flange on a 20 × 100 stem.
+        let tee = Section::rectangle(120.0, 20.0).translated(0.0, 110.0)
+            + Section::rectangle(20.0, 100.0).translated(0.0, 50.0);
+        let centroid = tee.centroid().unwrap();
+        assert_close(centroid.y, (2400.0 * 110.0 + 2000.0 * 50.0) / 4400.0);
+        let ixx = 120.0 * 8000.0 / 12.0
+            + 2400.0 * (110.0 - centroid.y).powi(2)
+            + 20.0 * 1e6 / 12.0
+            + 2000.0 * (50.0 - centroid.y).powi(2);
+        assert_close(tee.second_moments().unwrap().ixx, ixx);
+        assert_eq!(tee.bounds().unwrap().min(), Point2::new(-60.0, 0.0));
+
+        let (rx, ry) = tee.radii_of_gyration().unwrap();
+        assert_close(rx, (ixx / 4400.0).sqrt());
+        assert!(ry > 0.0);
+    }
+
+    #[test]
+    fn section_principal_axes_test() {
+        let rectangle = Section::rectangle(2.0, 6.0).principal_axes().unwrap();
+        assert_close(rectangle.major, 36.0);
+        assert_close(rectangle.minor, 4.0);
+        assert_eq!(rectangle.angle.radians(), 0.0);
+
+        // An angle section, 4 × 4 with legs 1 thick, has its major axis along y = x.
+        let angle = Section::rectangle(4.0, 1.0).translated(2.0, 0.5)
+            + Section::rectangle(1.0, 3.0).translated(0.5, 2.5);
+        let axes = angle.principal_axes().unwrap();
+        let moments = angle.second_moments().unwrap();
+        assert_close(axes.angle.degrees(), 45.0);
+        assert_close(axes.major, moments.ixx - moments.ixy);
+        assert_close(axes.minor, moments.ixx + moments.ixy);
+        assert_close(axes.major + axes.minor, angle.polar_moment().unwrap());
+
+        let circle = Section::circle(1.0).principal_axes().unwrap();
+        assert_eq!(circle.angle.radians(), 0.0);
+    }
+}
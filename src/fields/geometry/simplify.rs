@@ -0,0 +1,473 @@
+//! This module provides polyline simplification, smoothing and resampling.
+//!
+//! It is part of the `geometry` module. Dense polylines, such as GPS tracks,
+//! carry far more vertices than their shape needs. The functions here reduce or
+//! regularize the vertices:
+//!
+//! - Ramer–Douglas–Peucker keeps the vertices that lie farther than a tolerance
+//!   from the simplified line, splitting recursively at the farthest vertex.
+//! - Visvalingam–Whyatt repeatedly removes the vertex whose triangle with its
+//!   neighbours has the smallest area, until every remaining triangle is large
+//!   enough. It tends to keep the overall shape better at strong reductions.
+//! - Chaikin smoothing cuts every corner at a quarter and three quarters of each
+//!   edge, approaching a quadratic B-spline as it is repeated.
+//! - Resampling places vertices at a fixed arc-length spacing.
+//!
+//! Simplification and resampling keep the first and last vertex. A closed ring
+//! can be passed with its first vertex repeated at the end. `measure` reports how
+//! much the perimeter and area changed, using `perimeter::get_perimeter_polygon`
+//! and `area::get_area_polygon`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::simplify;
+//!
+//! let track = [
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(1.0, 0.1),
+//!     Point2::new(2.0, -0.1),
+//!     Point2::new(3.0, 5.0),
+//!     Point2::new(4.0, 6.0),
+//!     Point2::new(5.0, 7.0),
+//! ];
+//!
+//! let simplified = simplify::douglas_peucker(&track, 0.5);
+//! assert_eq!(simplified, vec![track[0], track[2], track[3], track[5]]);
+//!
+//! let simplified = simplify::visvalingam_whyatt(&track, 0.5);
+//! assert_eq!(simplified, vec![track[0], track[2], track[3], track[5]]);
+//!
+//! let metrics = simplify::measure(&track, &simplified, false);
+//! assert!((metrics.perimeter_change() - -0.022293f64).abs() < 1e-6);
+//!
+//! let resampled = simplify::resample(&simplified, 1.0).unwrap();
+//! assert_eq!(resampled.len(), 12);
+//! assert!((resampled[0].distance(resampled[1]) - 1.0f64).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `douglas_peucker`: Simplifies a polyline with the Ramer–Douglas–Peucker algorithm.
+//! - `visvalingam_whyatt`: Simplifies a polyline with the Visvalingam–Whyatt algorithm.
+//! - `chaikin`: Smooths a polyline or ring with Chaikin's corner cutting.
+//! - `resample`: Places vertices along a polyline at a fixed spacing.
+//! - `measure`: Compares the perimeter and area of a polyline before and after a change.
+
+use crate::fields::geometry::area::get_area_polygon;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::intersection::point_segment_distance;
+use crate::fields::geometry::perimeter::get_perimeter_polygon;
+use crate::fields::geometry::point::Point2;
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimplificationMetrics<T> {
+    pub perimeter_before: T,
+    pub perimeter_after: T,
+    pub area_before: T,
+    pub area_after: T,
+}
+
+impl<T> SimplificationMetrics<T>
+where
+    T: Float,
+{
+    // Function to get the change in perimeter, negative when it shrank.
+    pub fn perimeter_change(&self) -> T {
+        self.perimeter_after - self.perimeter_before
+    }
+
+    // Function to get the change in area, negative when it shrank.
+    pub fn area_change(&self) -> T {
+        self.area_after - self.area_before
+    }
+}
+
+// Function to simplify a polyline with the Ramer–Douglas–Peucker algorithm.
+//
+// Every removed vertex lies within `tolerance` of the simplified polyline.
+pub fn douglas_peucker<T: Float>(points: &[Point2<T>], tolerance: T) -> Vec<Point2<T>> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    // Spans are split on a stack rather than by recursion, so long tracks cannot overflow it.
+    let mut spans = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = spans.pop() {
+        let farthest = (first + 1..last)
+            .map(|i| {
+                let distance = point_segment_distance(points[i], points[first], points[last]);
+                (i, distance)
+            })
+            .fold(None, |best: Option<(usize, T)>, (i, distance)| match best {
+                Some((_, best_distance)) if best_distance >= distance => best,
+                _ => Some((i, distance)),
+            });
+        if let Some((index, distance)) = farthest {
+            if distance > tolerance {
+                keep[index] = true;
+                spans.push((first, index));
+                spans.push((index, last));
+            }
+        }
+    }
+    kept(points, &keep)
+}
+
+// Function to simplify a polyline with the Visvalingam–Whyatt algorithm.
+//
+// Vertices are removed while the smallest triangle they form with their neighbours
+// has an area below `min_area`. A vertex's area never drops below that of a vertex
+// removed before it, so removal follows the order of significance.
+pub fn visvalingam_whyatt<T: Float>(points: &[Point2<T>], min_area: T) -> Vec<Point2<T>> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut previous: Vec<usize> = (0..n).map(|i| i.wrapping_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=n).collect();
+    let mut areas: Vec<T> = vec![T::infinity(); n];
+    let mut heap = BinaryHeap::new();
+    for i in 1..n - 1 {
+        areas[i] = triangle_area(points[i - 1], points[i], points[i + 1]);
+        heap.push(Candidate {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    let mut keep = vec![true; n];
+    let mut floor = T::neg_infinity();
+    while let Some(Candidate { area, index }) = heap.pop() {
+        // Entries left behind by later updates are skipped.
+        if !keep[index] || area != areas[index] {
+            continue;
+        }
+        if area >= min_area {
+            break;
+        }
+        keep[index] = false;
+        floor = floor.max(area);
+        let (before, after) = (previous[index], next[index]);
+        next[before] = after;
+        previous[after] = before;
+        for neighbour in [before, after] {
+            if neighbour == 0 || neighbour == n - 1 {
+                continue;
+            }
+            let (a, c) = (previous[neighbour], next[neighbour]);
+            areas[neighbour] = triangle_area(points[a], points[neighbour], points[c]).max(floor);
+            heap.push(Candidate {
+                area: areas[neighbour],
+                index: neighbour,
+            });
+        }
+    }
+    kept(points, &keep)
+}
+
+// Function to smooth a polyline with Chaikin's corner cutting, repeated `iterations` times.
+//
+// An open polyline keeps its end points; a closed one is treated as a ring and
+// has all its corners cut.
+pub fn chaikin<T: Float>(points: &[Point2<T>], iterations: usize, closed: bool) -> Vec<Point2<T>> {
+    let quarter = T::from(0.25).unwrap();
+    let three_quarters = T::from(0.75).unwrap();
+    let mut current = points.to_vec();
+    for _ in 0..iterations {
+        let n = current.len();
+        if n < 3 {
+            break;
+        }
+        let mut smoothed = Vec::with_capacity(2 * n);
+        if closed {
+            for i in 0..n {
+                let (a, b) = (current[i], current[(i + 1) % n]);
+                smoothed.push(a.lerp(b, quarter));
+                smoothed.push(a.lerp(b, three_quarters));
+            }
+        } else {
+            smoothed.push(current[0]);
+            for i in 0..n - 1 {
+                let (a, b) = (current[i], current[i + 1]);
+                if i > 0 {
+                    smoothed.push(a.lerp(b, quarter));
+                }
+                if i < n - 2 {
+                    smoothed.push(a.lerp(b, three_quarters));
+                }
+            }
+            smoothed.push(current[n - 1]);
+        }
+        current = smoothed;
+    }
+    current
+}
+
+// Function to place vertices along a polyline every `spacing` of arc length.
+//
+// The first vertex is kept and the last is appended after the final full step, so
+// the last gap may be shorter than the spacing.
+pub fn resample<T: Float>(
+    points: &[Point2<T>],
+    spacing: T,
+) -> Result<Vec<Point2<T>>, GeometryError> {
+    if spacing.is_nan() || spacing <= T::zero() {
+        return Err(GeometryError::new("Resampling spacing must be positive"));
+    }
+    let Some((&first, _)) = points.split_first() else {
+        return Ok(Vec::new());
+    };
+    let mut resampled = vec![first];
+    // The arc length travelled so far and the position of the next sample.
+    let mut travelled = T::zero();
+    let mut step = 1;
+    for pair in points.windows(2) {
+        let length = pair[0].distance(pair[1]);
+        if length == T::zero() {
+            continue;
+        }
+        loop {
+            let target = spacing * T::from(step).unwrap();
+            if target >= travelled + length {
+                break;
+            }
+            resampled.push(pair[0].lerp(pair[1], (target - travelled) / length));
+            step += 1;
+        }
+        travelled = travelled + length;
+    }
+    let last = points[points.len() - 1];
+    if resampled[resampled.len() - 1] != last {
+        resampled.push(last);
+    }
+    Ok(resampled)
+}
+
+// Function to compare the perimeter and area of a polyline before and after a change.
+//
+// A closed polyline is measured as a polygon. An open one is measured by its length,
+// and its area is that of the polygon closed by the chord between its ends, which
+// simplification keeps.
+pub fn measure<T: Float>(
+    original: &[Point2<T>],
+    changed: &[Point2<T>],
+    closed: bool,
+) -> SimplificationMetrics<T> {
+    let perimeter = |points: &[Point2<T>]| {
+        let ring = get_perimeter_polygon(points);
+        match (closed, points.first(), points.last()) {
+            (false, Some(first), Some(last)) => ring - first.distance(*last),
+            _ => ring,
+        }
+    };
+    SimplificationMetrics {
+        perimeter_before: perimeter(original),
+        perimeter_after: perimeter(changed),
+        area_before: get_area_polygon(original),
+        area_after: get_area_polygon(changed),
+    }
+}
+
+fn kept<T: Copy>(points: &[T], keep: &[bool]) -> Vec<T> {
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, &keep)| keep)
+        .map(|(&point, _)| point)
+        .collect()
+}
+
+fn triangle_area<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> T {
+    get_area_polygon(&[a, b, c])
+}
+
+// A vertex waiting for removal, ordered so the smallest area comes out of the heap first.
+struct Candidate<T> {
+    area: T,
+    index: usize,
+}
+
+impl<T: Float> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    // Equal areas fall back to the index, so the earlier vertex goes first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod geometry_simplify_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    fn zigzag(count: usize) -> Vec<Point2<f64>> {
+        (0..count)
+            .map(|i| Point2::new(i as f64, if i % 2 == 0 { 0.0 } else { 0.1 }))
+            .collect()
+    }
+
+    #[test]
+    fn douglas_peucker_test() {
+        let line = zigzag(101);
+        assert_eq!(douglas_peucker(&line, 0.2), vec![line[0], line[100]]);
+        assert_eq!(douglas_peucker(&line, 0.05), line);
+
+        let corner = [
+            Point2::new(0.0, 0.0),
+            Point2::new(5.0, 0.1),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+        ];
+        assert_eq!(
+            douglas_peucker(&corner, 1.0),
+            vec![corner[0], corner[2], corner[3]]
+        );
+
+        // A closed ring repeats its first vertex, which is measured as a point.
+        let ring = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 0.05),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(0.0, 0.0),
+        ];
+        assert_eq!(douglas_peucker(&ring, 0.5).len(), 5);
+        assert_eq!(douglas_peucker(&ring[..2], 0.5), ring[..2].to_vec());
+    }
+
+    #[test]
+    fn visvalingam_whyatt_test() {
+        let line = zigzag(101);
+        // Each zigzag triangle has area 0.1.
+        assert_eq!(visvalingam_whyatt(&line, 0.09), line);
+        assert_eq!(visvalingam_whyatt(&line, 1e3).len(), 2);
+
+        let hill = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.05),
+            Point2::new(2.0, 0.0),
+            Point2::new(3.0, 3.0),
+            Point2::new(4.0, 0.0),
+        ];
+        assert_eq!(
+            visvalingam_whyatt(&hill, 1.0),
+            vec![hill[0], hill[2], hill[3], hill[4]]
+        );
+        assert_eq!(visvalingam_whyatt(&hill, 100.0), vec![hill[0], hill[4]]);
+        assert_eq!(visvalingam_whyatt(&hill, 0.0), hill.to_vec());
+    }
+
+    #[test]
+    fn chaikin_test() {
+        let corner = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+        ];
+        let smoothed = chaikin(&corner, 1, false);
+        assert_eq!(
+            smoothed,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(3.0, 0.0),
+                Point2::new(4.0, 1.0),
+                Point2::new(4.0, 4.0),
+            ]
+        );
+        assert_eq!(chaikin(&corner, 3, false).len(), 10);
+        assert_eq!(chaikin(&corner[..2], 5, false), corner[..2].to_vec());
+
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ];
+        let smoothed = chaikin(&square, 1, true);
+        assert_eq!(smoothed.len(), 8);
+        // Cutting each corner removes a right triangle with legs of 1.
+        assert_close(get_area_polygon(&smoothed), 16.0 - 4.0 * 0.5);
+    }
+
+    #[test]
+    fn resample_test() {
+        let path = [
+            Point2::new(0.0, 0.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(3.0, 2.5),
+        ];
+        let resampled = resample(&path, 1.0).unwrap();
+        assert_eq!(
+            resampled,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+                Point2::new(2.0, 0.0),
+                Point2::new(3.0, 0.0),
+                Point2::new(3.0, 1.0),
+                Point2::new(3.0, 2.0),
+                Point2::new(3.0, 2.5),
+            ]
+        );
+        // A sample landing on the end is not repeated.
+        assert_eq!(
+            resample(&path, 2.75).unwrap(),
+            vec![path[0], Point2::new(2.75, 0.0), path[3]]
+        );
+        assert_eq!(resample(&path[..1], 1.0).unwrap(), path[..1].to_vec());
+        assert!(resample::<f64>(&[], 1.0).unwrap().is_empty());
+        assert!(resample(&path, 0.0).is_err());
+        assert!(resample(&path, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn measure_test() {
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ];
+        let triangle = [square[0], square[1], square[4]];
+
+        let metrics = measure(&square, &triangle, true);
+        assert_eq!(metrics.perimeter_before, 8.0);
+        assert_close(metrics.perimeter_after, 4.0 + 8f64.sqrt());
+        assert_eq!(metrics.area_change(), -2.0);
+
+        let metrics = measure(&square, &triangle, false);
+        assert_close(metrics.perimeter_before, 6.0);
+        assert_close(metrics.perimeter_change(), 8f64.sqrt() - 4.0);
+        assert_eq!(metrics.area_after, 2.0);
+    }
+}
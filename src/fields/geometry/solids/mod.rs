@@ -0,0 +1,13 @@
+//! This module contains functions for three-dimensional solids.
+//!
+//! It is part of the `geometry` module and extends the two-dimensional `area`
+//! and `perimeter` functions to volumes and surface areas. The functions share
+//! the generic `Num + NumCast` signatures used throughout `geometry`.
+//!
+//! # Modules
+//!
+//! - `surface_area`: Contains functions for calculating lateral and total surface areas.
+//! - `volume`: Contains functions for calculating volumes.
+
+pub mod surface_area;
+pub mod volume;
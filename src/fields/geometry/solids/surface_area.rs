@@ -0,0 +1,625 @@
+//! This module provides functionality for calculating the surface area of various solids.
+//!
+//! It is part of the `solids` module. Every solid has a `get_lateral_area_*` function,
+//! which excludes the flat base(s) of the solid, and a `get_surface_area_*` function,
+//! which gives the total surface area. Solids without flat bases, such as spheres and
+//! tori, have a lateral area equal to their total surface area.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::solids::surface_area;
+//!
+//! let lateral_cube = surface_area::get_lateral_area_cube(3.0);
+//! let surface_cube = surface_area::get_surface_area_cube(3.0);
+//! let lateral_cylinder = surface_area::get_lateral_area_cylinder(2.0, 5.0);
+//! let surface_cylinder = surface_area::get_surface_area_cylinder(2.0, 5.0);
+//! let surface_sphere = surface_area::get_surface_area_sphere(3.0);
+//!
+//! assert_eq!(lateral_cube, 36.0);
+//! assert_eq!(surface_cube, 54.0);
+//! assert_eq!(lateral_cylinder, 62.83185307179586);
+//! assert_eq!(surface_cylinder, 87.96459430051421);
+//! assert_eq!(surface_sphere, 113.09733552923255);
+//! ```
+//!
+//! # Functions
+//!
+//! - `get_lateral_area_capsule` / `get_surface_area_capsule`: Capsule.
+//! - `get_lateral_area_cone` / `get_surface_area_cone`: Right circular cone.
+//! - `get_lateral_area_cube` / `get_surface_area_cube`: Cube.
+//! - `get_lateral_area_cuboid` / `get_surface_area_cuboid`: Cuboid.
+//! - `get_lateral_area_cylinder` / `get_surface_area_cylinder`: Right circular cylinder.
+//! - `get_lateral_area_ellipsoid` / `get_surface_area_ellipsoid`: Ellipsoid.
+//! - `get_lateral_area_frustum` / `get_surface_area_frustum`: Conical frustum.
+//! - `get_lateral_area_hemisphere` / `get_surface_area_hemisphere`: Hemisphere.
+//! - `get_surface_area_polyhedron`: Triangulated polyhedron.
+//! - `get_lateral_area_prism` / `get_surface_area_prism`: Right prism with a regular base.
+//! - `get_lateral_area_pyramid` / `get_surface_area_pyramid`: Right pyramid with a regular base.
+//! - `get_lateral_area_sphere` / `get_surface_area_sphere`: Sphere.
+//! - `get_lateral_area_spherical_cap` / `get_surface_area_spherical_cap`: Spherical cap.
+//! - `get_lateral_area_torus` / `get_surface_area_torus`: Torus.
+
+use crate::fields::geometry::area::get_area_regular_polygon;
+use crate::fields::geometry::point::Point3;
+use num_traits::{Num, NumCast};
+use std::f64::consts::PI;
+
+// Function to get lateral area of a capsule (it has no flat bases).
+pub fn get_lateral_area_capsule<T>(radius: T, length: T) -> T
+where
+    T: Num + NumCast,
+{
+    get_surface_area_capsule(radius, length)
+}
+
+// Function to get surface area of a capsule (a cylinder capped by two hemispheres).
+pub fn get_surface_area_capsule<T>(radius: T, length: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let length_f64 = T::to_f64(&length).unwrap();
+    T::from(2f64 * PI * radius_f64 * (2f64 * radius_f64 + length_f64)).unwrap()
+}
+
+// Function to get lateral (curved) area of a right circular cone.
+pub fn get_lateral_area_cone<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let slant_f64 = radius_f64.hypot(height_f64);
+    T::from(PI * radius_f64 * slant_f64).unwrap()
+}
+
+// Function to get surface area of a right circular cone.
+pub fn get_surface_area_cone<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let slant_f64 = radius_f64.hypot(height_f64);
+    T::from(PI * radius_f64 * (radius_f64 + slant_f64)).unwrap()
+}
+
+// Function to get lateral area of a cube (the four side faces).
+pub fn get_lateral_area_cube<T>(side: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    T::from(4f64 * side_f64.powi(2)).unwrap()
+}
+
+// Function to get surface area of a cube.
+pub fn get_surface_area_cube<T>(side: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    T::from(6f64 * side_f64.powi(2)).unwrap()
+}
+
+// Function to get lateral area of a cuboid (the four faces adjacent to the base).
+pub fn get_lateral_area_cuboid<T>(length: T, width: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let length_f64 = T::to_f64(&length).unwrap();
+    let width_f64 = T::to_f64(&width).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(2f64 * height_f64 * (length_f64 + width_f64)).unwrap()
+}
+
+// Function to get surface area of a cuboid.
+pub fn get_surface_area_cuboid<T>(length: T, width: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let length_f64 = T::to_f64(&length).unwrap();
+    let width_f64 = T::to_f64(&width).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let faces = length_f64 * width_f64 + length_f64 * height_f64 + width_f64 * height_f64;
+    T::from(2f64 * faces).unwrap()
+}
+
+// Function to get lateral (curved) area of a right circular cylinder.
+pub fn get_lateral_area_cylinder<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(2f64 * PI * radius_f64 * height_f64).unwrap()
+}
+
+// Function to get surface area of a right circular cylinder.
+pub fn get_surface_area_cylinder<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(2f64 * PI * radius_f64 * (radius_f64 + height_f64)).unwrap()
+}
+
+// Function to get lateral area of an ellipsoid (it has no flat bases).
+pub fn get_lateral_area_ellipsoid<T>(a: T, b: T, c: T) -> T
+where
+    T: Num + NumCast,
+{
+    get_surface_area_ellipsoid(a, b, c)
+}
+
+// Function to get surface area of an ellipsoid from its three semi-axes.
+//
+// The area is evaluated exactly through Carlson's symmetric elliptic integrals
+// rather than with an approximation such as Knud Thomsen's formula.
+pub fn get_surface_area_ellipsoid<T>(a: T, b: T, c: T) -> T
+where
+    T: Num + NumCast,
+{
+    let mut axes = [
+        T::to_f64(&a).unwrap().abs(),
+        T::to_f64(&b).unwrap().abs(),
+        T::to_f64(&c).unwrap().abs(),
+    ];
+    axes.sort_by(|x, y| y.partial_cmp(x).unwrap());
+    let [a_f64, b_f64, c_f64] = axes;
+
+    // A sphere has no eccentricity, so the general formula degenerates.
+    if a_f64 == c_f64 {
+        return T::from(4f64 * PI * a_f64.powi(2)).unwrap();
+    }
+
+    // S = 2 pi c^2 + 2 pi a b / sin(phi) * (E(phi, k) sin^2(phi) + F(phi, k) cos^2(phi))
+    let cos_phi = c_f64 / a_f64;
+    let sin_phi = (1f64 - cos_phi.powi(2)).sqrt();
+    let k2 = (a_f64.powi(2) * (b_f64.powi(2) - c_f64.powi(2)))
+        / (b_f64.powi(2) * (a_f64.powi(2) - c_f64.powi(2)));
+
+    let x = cos_phi.powi(2);
+    let y = 1f64 - k2 * sin_phi.powi(2);
+    let rf = carlson_rf(x, y, 1f64);
+    let rd = carlson_rd(x, y, 1f64);
+
+    let elliptic_f = sin_phi * rf;
+    let elliptic_e = sin_phi * rf - k2 * sin_phi.powi(3) * rd / 3f64;
+
+    let area_f64 = 2f64 * PI * c_f64.powi(2)
+        + 2f64 * PI * a_f64 * b_f64 / sin_phi
+            * (elliptic_e * sin_phi.powi(2) + elliptic_f * cos_phi.powi(2));
+    T::from(area_f64).unwrap()
+}
+
+// Function to get lateral area of a conical frustum (the slanted side).
+pub fn get_lateral_area_frustum<T>(radius1: T, radius2: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius1_f64 = T::to_f64(&radius1).unwrap();
+    let radius2_f64 = T::to_f64(&radius2).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let slant_f64 = (radius1_f64 - radius2_f64).hypot(height_f64);
+    T::from(PI * (radius1_f64 + radius2_f64) * slant_f64).unwrap()
+}
+
+// Function to get surface area of a conical frustum.
+pub fn get_surface_area_frustum<T>(radius1: T, radius2: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius1_f64 = T::to_f64(&radius1).unwrap();
+    let radius2_f64 = T::to_f64(&radius2).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let slant_f64 = (radius1_f64 - radius2_f64).hypot(height_f64);
+    let lateral_f64 = PI * (radius1_f64 + radius2_f64) * slant_f64;
+    let bases_f64 = PI * (radius1_f64.powi(2) + radius2_f64.powi(2));
+    T::from(lateral_f64 + bases_f64).unwrap()
+}
+
+// Function to get lateral (curved) area of a hemisphere.
+pub fn get_lateral_area_hemisphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    T::from(2f64 * PI * radius_f64.powi(2)).unwrap()
+}
+
+// Function to get surface area of a hemisphere, including its flat base.
+pub fn get_surface_area_hemisphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    T::from(3f64 * PI * radius_f64.powi(2)).unwrap()
+}
+
+// Function to get surface area of a polyhedron given as triangular faces.
+//
+// Each face lists three indices into `vertices`; the winding order does not matter.
+pub fn get_surface_area_polyhedron<T>(vertices: &[Point3<T>], faces: &[[usize; 3]]) -> T
+where
+    T: Num + NumCast + Copy,
+{
+    let to_f64 = |point: &Point3<T>| {
+        [
+            T::to_f64(&point.x).unwrap(),
+            T::to_f64(&point.y).unwrap(),
+            T::to_f64(&point.z).unwrap(),
+        ]
+    };
+    let mut area_f64 = 0f64;
+    for face in faces {
+        let a = to_f64(&vertices[face[0]]);
+        let b = to_f64(&vertices[face[1]]);
+        let c = to_f64(&vertices[face[2]]);
+        let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+        let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+        let cross = [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ];
+        area_f64 += 0.5f64 * (cross[0].powi(2) + cross[1].powi(2) + cross[2].powi(2)).sqrt();
+    }
+    T::from(area_f64).unwrap()
+}
+
+// Function to get lateral area of a right prism whose base is a regular polygon.
+pub fn get_lateral_area_prism<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(sides as f64 * side_f64 * height_f64).unwrap()
+}
+
+// Function to get surface area of a right prism whose base is a regular polygon.
+pub fn get_surface_area_prism<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let lateral_f64 = sides as f64 * side_f64 * height_f64;
+    let base_f64 = get_area_regular_polygon(sides, side_f64);
+    T::from(lateral_f64 + 2f64 * base_f64).unwrap()
+}
+
+// Function to get lateral area of a right pyramid whose base is a regular polygon.
+pub fn get_lateral_area_pyramid<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(pyramid_lateral_f64(sides, side_f64, height_f64)).unwrap()
+}
+
+// Function to get surface area of a right pyramid whose base is a regular polygon.
+pub fn get_surface_area_pyramid<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let lateral_f64 = pyramid_lateral_f64(sides, side_f64, height_f64);
+    let base_f64 = get_area_regular_polygon(sides, side_f64);
+    T::from(lateral_f64 + base_f64).unwrap()
+}
+
+// Function to get lateral area of a sphere (it has no flat bases).
+pub fn get_lateral_area_sphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    get_surface_area_sphere(radius)
+}
+
+// Function to get surface area of a sphere.
+pub fn get_surface_area_sphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    T::from(4f64 * PI * radius_f64.powi(2)).unwrap()
+}
+
+// Function to get lateral (curved) area of a spherical cap.
+pub fn get_lateral_area_spherical_cap<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(2f64 * PI * radius_f64 * height_f64).unwrap()
+}
+
+// Function to get surface area of a spherical cap, including its flat base.
+pub fn get_surface_area_spherical_cap<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    // The base radius a satisfies a^2 = h (2R - h).
+    let base_f64 = PI * height_f64 * (2f64 * radius_f64 - height_f64);
+    T::from(2f64 * PI * radius_f64 * height_f64 + base_f64).unwrap()
+}
+
+// Function to get lateral area of a torus (it has no flat bases).
+pub fn get_lateral_area_torus<T>(major_radius: T, minor_radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    get_surface_area_torus(major_radius, minor_radius)
+}
+
+// Function to get surface area of a torus from its major (tube center) and minor (tube) radii.
+pub fn get_surface_area_torus<T>(major_radius: T, minor_radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let major_f64 = T::to_f64(&major_radius).unwrap();
+    let minor_f64 = T::to_f64(&minor_radius).unwrap();
+    T::from(4f64 * PI.powi(2) * major_f64 * minor_f64).unwrap()
+}
+
+// Lateral area of a regular pyramid: n triangles of base `side` and the slant height.
+fn pyramid_lateral_f64(sides: usize, side: f64, height: f64) -> f64 {
+    let apothem = side / (2f64 * (PI / sides as f64).tan());
+    let slant = apothem.hypot(height);
+    0.5f64 * sides as f64 * side * slant
+}
+
+// Carlson's symmetric elliptic integral of the first kind, R_F(x, y, z).
+fn carlson_rf(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+    loop {
+        let mean = (x + y + z) / 3f64;
+        let dx = 1f64 - x / mean;
+        let dy = 1f64 - y / mean;
+        let dz = 1f64 - z / mean;
+        if dx.abs().max(dy.abs()).max(dz.abs()) < 1e-4 {
+            let e2 = dx * dy - dz * dz;
+            let e3 = dx * dy * dz;
+            return (1f64 - e2 / 10f64 + e3 / 14f64 + e2 * e2 / 24f64 - 3f64 * e2 * e3 / 44f64)
+                / mean.sqrt();
+        }
+        let lambda = (x * y).sqrt() + (x * z).sqrt() + (y * z).sqrt();
+        x = (x + lambda) / 4f64;
+        y = (y + lambda) / 4f64;
+        z = (z + lambda) / 4f64;
+    }
+}
+
+// Carlson's symmetric elliptic integral of the second kind, R_D(x, y, z).
+fn carlson_rd(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+    let mut sum = 0f64;
+    let mut factor = 1f64;
+    loop {
+        let mean = (x + y + 3f64 * z) / 5f64;
+        let dx = 1f64 - x / mean;
+        let dy = 1f64 - y / mean;
+        let dz = 1f64 - z / mean;
+        if dx.abs().max(dy.abs()).max(dz.abs()) < 1e-4 {
+            let ea = dx * dy;
+            let eb = dz * dz;
+            let ec = ea - eb;
+            let ed = ea - 6f64 * eb;
+            let ee = ed + ec + ec;
+            let series = 1f64
+                + ed * (-3f64 / 14f64 + 9f64 / 88f64 * ed - 9f64 / 52f64 * dz * ee)
+                + dz * (ee / 6f64 + dz * (-9f64 / 22f64 * ec + dz * 3f64 / 26f64 * ea));
+            return 3f64 * sum + factor * series / (mean * mean.sqrt());
+        }
+        let lambda = (x * y).sqrt() + (x * z).sqrt() + (y * z).sqrt();
+        sum += factor / (z.sqrt() * (z + lambda));
+        factor /= 4f64;
+        x = (x + lambda) / 4f64;
+        y = (y + lambda) / 4f64;
+        z = (z + lambda) / 4f64;
+    }
+}
+
+#[cfg(test)]
+mod geometry_solids_surface_area_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn get_surface_area_capsule_test() {
+        let result = get_surface_area_capsule(1.0, 2.0);
+        assert_close(result, 8.0 * PI);
+        assert_close(get_lateral_area_capsule(1.0, 2.0), result);
+
+        let result = get_surface_area_capsule(1, 2);
+        assert_eq!(result, 25);
+    }
+
+    #[test]
+    fn get_surface_area_cone_test() {
+        let result = get_lateral_area_cone(3.0, 4.0);
+        assert_close(result, 15.0 * PI);
+
+        let result = get_surface_area_cone(3.0, 4.0);
+        assert_close(result, 24.0 * PI);
+
+        let result = get_surface_area_cone(3, 4);
+        assert_eq!(result, 75);
+    }
+
+    #[test]
+    fn get_surface_area_cube_test() {
+        let result = get_lateral_area_cube(3.5);
+        assert_eq!(result, 49.0);
+
+        let result = get_surface_area_cube(3.5);
+        assert_eq!(result, 73.5);
+
+        let result = get_surface_area_cube(3);
+        assert_eq!(result, 54);
+    }
+
+    #[test]
+    fn get_surface_area_cuboid_test() {
+        let result = get_lateral_area_cuboid(2.0, 3.0, 4.0);
+        assert_eq!(result, 40.0);
+
+        let result = get_surface_area_cuboid(2.0, 3.0, 4.0);
+        assert_eq!(result, 52.0);
+
+        let result = get_surface_area_cuboid(2, 3, 4);
+        assert_eq!(result, 52);
+    }
+
+    #[test]
+    fn get_surface_area_cylinder_test() {
+        let result = get_lateral_area_cylinder(2.0, 5.0);
+        assert_close(result, 20.0 * PI);
+
+        let result = get_surface_area_cylinder(2.0, 5.0);
+        assert_close(result, 28.0 * PI);
+
+        let result = get_surface_area_cylinder(2, 5);
+        assert_eq!(result, 87);
+    }
+
+    #[test]
+    fn get_surface_area_ellipsoid_test() {
+        // A sphere.
+        let result = get_surface_area_ellipsoid(2.0, 2.0, 2.0);
+        assert_close(result, get_surface_area_sphere(2.0));
+
+        // A prolate spheroid has the closed form 2 pi b^2 (1 + a / (b e) * asin(e)).
+        let (a, b) = (2.0f64, 1.0f64);
+        let e = (1.0 - b * b / (a * a)).sqrt();
+        let expected = 2.0 * PI * b * b * (1.0 + a / (b * e) * e.asin());
+        assert_close(get_surface_area_ellipsoid(a, b, b), expected);
+
+        // An oblate spheroid has the closed form 2 pi a^2 (1 + (1 - e^2) / e * atanh(e)).
+        let (a, c) = (2.0f64, 1.0f64);
+        let e = (1.0 - c * c / (a * a)).sqrt();
+        let expected = 2.0 * PI * a * a * (1.0 + (1.0 - e * e) / e * e.atanh());
+        assert_close(get_surface_area_ellipsoid(a, a, c), expected);
+
+        // Scalene reference value.
+        let result: f64 = get_surface_area_ellipsoid(3.0, 2.0, 1.0);
+        assert!((result - 48.88214630).abs() < 1e-6);
+        assert_close(get_lateral_area_ellipsoid(3.0, 2.0, 1.0), result);
+
+        let result = get_surface_area_ellipsoid(1, 2, 3);
+        assert_eq!(result, 48);
+    }
+
+    #[test]
+    fn get_surface_area_frustum_test() {
+        let result = get_lateral_area_frustum(3.0, 0.0, 4.0);
+        assert_close(result, get_lateral_area_cone(3.0, 4.0));
+
+        let result = get_surface_area_frustum(2.0, 2.0, 5.0);
+        assert_close(result, get_surface_area_cylinder(2.0, 5.0));
+
+        let result = get_surface_area_frustum(4, 1, 4);
+        assert_eq!(result, 131);
+    }
+
+    #[test]
+    fn get_surface_area_hemisphere_test() {
+        let result = get_lateral_area_hemisphere(3.0);
+        assert_close(result, get_surface_area_sphere(3.0) / 2.0);
+
+        let result = get_surface_area_hemisphere(3.0);
+        assert_close(result, 27.0 * PI);
+
+        let result = get_surface_area_hemisphere(3);
+        assert_eq!(result, 84);
+    }
+
+    #[test]
+    fn get_surface_area_polyhedron_test() {
+        let vertices = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        ];
+        let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+        assert_close(
+            get_surface_area_polyhedron(&vertices, &faces),
+            1.5 + 3f64.sqrt() / 2.0,
+        );
+
+        let vertices = [
+            Point3::new(0, 0, 0),
+            Point3::new(2, 0, 0),
+            Point3::new(0, 2, 0),
+        ];
+        assert_eq!(get_surface_area_polyhedron(&vertices, &[[0, 1, 2]]), 2);
+    }
+
+    #[test]
+    fn get_surface_area_prism_test() {
+        let result = get_lateral_area_prism(4, 3.0, 2.0);
+        assert_close(result, get_lateral_area_cuboid(3.0, 3.0, 2.0));
+
+        let result = get_surface_area_prism(4, 3.0, 2.0);
+        assert_close(result, get_surface_area_cuboid(3.0, 3.0, 2.0));
+
+        let result = get_surface_area_prism(6, 2, 10);
+        assert_eq!(result, 140);
+    }
+
+    #[test]
+    fn get_surface_area_pyramid_test() {
+        // Square base of side 6 and height 4 gives a slant height of 5.
+        let result = get_lateral_area_pyramid(4, 6.0, 4.0);
+        assert_close(result, 60.0);
+
+        let result = get_surface_area_pyramid(4, 6.0, 4.0);
+        assert_close(result, 96.0);
+
+        let result = get_surface_area_pyramid(4, 6, 4);
+        assert_eq!(result, 96);
+    }
+
+    #[test]
+    fn get_surface_area_sphere_test() {
+        let result = get_surface_area_sphere(3.0);
+        assert_close(result, 36.0 * PI);
+        assert_close(get_lateral_area_sphere(3.0), result);
+
+        let result = get_surface_area_sphere(3);
+        assert_eq!(result, 113);
+    }
+
+    #[test]
+    fn get_surface_area_spherical_cap_test() {
+        let result = get_lateral_area_spherical_cap(3.0, 3.0);
+        assert_close(result, get_lateral_area_hemisphere(3.0));
+
+        let result = get_surface_area_spherical_cap(3.0, 3.0);
+        assert_close(result, get_surface_area_hemisphere(3.0));
+
+        let result = get_surface_area_spherical_cap(3, 1);
+        assert_eq!(result, 34);
+    }
+
+    #[test]
+    fn get_surface_area_torus_test() {
+        let result = get_surface_area_torus(3.0, 1.0);
+        assert_close(result, 12.0 * PI * PI);
+        assert_close(get_lateral_area_torus(3.0, 1.0), result);
+
+        let result = get_surface_area_torus(3, 1);
+        assert_eq!(result, 118);
+    }
+}
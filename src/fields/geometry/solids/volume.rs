@@ -0,0 +1,358 @@
+//! This module provides functionality for calculating the volume of various solids.
+//!
+//! It is part of the `solids` module and includes functions to handle volume
+//! calculations for different solids, such as cubes, spheres, cones, etc.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::solids::volume;
+//!
+//! let volume_cube = volume::get_volume_cube(3.0);
+//! let volume_cuboid = volume::get_volume_cuboid(2.0, 3.0, 4.0);
+//! let volume_cylinder = volume::get_volume_cylinder(2.0, 5.0);
+//! let volume_prism = volume::get_volume_prism(4, 3.0f64, 2.0);
+//! let volume_sphere = volume::get_volume_sphere(3.0);
+//!
+//! assert_eq!(volume_cube, 27.0);
+//! assert_eq!(volume_cuboid, 24.0);
+//! assert_eq!(volume_cylinder, 62.83185307179586);
+//! assert!((volume_prism - 18.0).abs() < 1e-12);
+//! assert_eq!(volume_sphere, 113.09733552923254);
+//! ```
+//!
+//! # Functions
+//!
+//! - `get_volume_capsule`: Computes the volume of a capsule.
+//! - `get_volume_cone`: Computes the volume of a right circular cone.
+//! - `get_volume_cube`: Computes the volume of a cube.
+//! - `get_volume_cuboid`: Computes the volume of a cuboid.
+//! - `get_volume_cylinder`: Computes the volume of a right circular cylinder.
+//! - `get_volume_ellipsoid`: Computes the volume of an ellipsoid.
+//! - `get_volume_frustum`: Computes the volume of a conical frustum.
+//! - `get_volume_hemisphere`: Computes the volume of a hemisphere.
+//! - `get_volume_polyhedron`: Computes the volume of a closed triangulated polyhedron.
+//! - `get_volume_prism`: Computes the volume of a right prism with a regular base.
+//! - `get_volume_pyramid`: Computes the volume of a right pyramid with a regular base.
+//! - `get_volume_sphere`: Computes the volume of a sphere.
+//! - `get_volume_spherical_cap`: Computes the volume of a spherical cap.
+//! - `get_volume_torus`: Computes the volume of a torus.
+
+use crate::fields::geometry::area::get_area_regular_polygon;
+use crate::fields::geometry::point::Point3;
+use num_traits::{Num, NumCast};
+use std::f64::consts::PI;
+
+// Function to get volume of a capsule (a cylinder capped by two hemispheres).
+pub fn get_volume_capsule<T>(radius: T, length: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let length_f64 = T::to_f64(&length).unwrap();
+    let volume_f64 = PI * radius_f64.powi(2) * (4f64 / 3f64 * radius_f64 + length_f64);
+    T::from(volume_f64).unwrap()
+}
+
+// Function to get volume of a right circular cone.
+pub fn get_volume_cone<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(PI * radius_f64.powi(2) * height_f64 / 3f64).unwrap()
+}
+
+// Function to get volume of a cube.
+pub fn get_volume_cube<T>(side: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    T::from(side_f64.powi(3)).unwrap()
+}
+
+// Function to get volume of a cuboid.
+pub fn get_volume_cuboid<T: Num>(length: T, width: T, height: T) -> T {
+    length * width * height
+}
+
+// Function to get volume of a right circular cylinder.
+pub fn get_volume_cylinder<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(PI * radius_f64.powi(2) * height_f64).unwrap()
+}
+
+// Function to get volume of an ellipsoid from its three semi-axes.
+pub fn get_volume_ellipsoid<T>(a: T, b: T, c: T) -> T
+where
+    T: Num + NumCast,
+{
+    let a_f64 = T::to_f64(&a).unwrap();
+    let b_f64 = T::to_f64(&b).unwrap();
+    let c_f64 = T::to_f64(&c).unwrap();
+    T::from(4f64 / 3f64 * PI * a_f64 * b_f64 * c_f64).unwrap()
+}
+
+// Function to get volume of a conical frustum.
+pub fn get_volume_frustum<T>(radius1: T, radius2: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius1_f64 = T::to_f64(&radius1).unwrap();
+    let radius2_f64 = T::to_f64(&radius2).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let radii = radius1_f64.powi(2) + radius1_f64 * radius2_f64 + radius2_f64.powi(2);
+    T::from(PI * height_f64 * radii / 3f64).unwrap()
+}
+
+// Function to get volume of a hemisphere.
+pub fn get_volume_hemisphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    T::from(2f64 / 3f64 * PI * radius_f64.powi(3)).unwrap()
+}
+
+// Function to get volume of a closed polyhedron given as triangular faces.
+//
+// Each face lists three indices into `vertices`. The faces must be consistently
+// oriented (all outward or all inward); the volume follows from the divergence
+// theorem as the sum of the signed tetrahedra formed with the origin.
+pub fn get_volume_polyhedron<T>(vertices: &[Point3<T>], faces: &[[usize; 3]]) -> T
+where
+    T: Num + NumCast + Copy,
+{
+    let to_f64 = |point: &Point3<T>| {
+        [
+            T::to_f64(&point.x).unwrap(),
+            T::to_f64(&point.y).unwrap(),
+            T::to_f64(&point.z).unwrap(),
+        ]
+    };
+    let mut six_volume_f64 = 0f64;
+    for face in faces {
+        let [ax, ay, az] = to_f64(&vertices[face[0]]);
+        let [bx, by, bz] = to_f64(&vertices[face[1]]);
+        let [cx, cy, cz] = to_f64(&vertices[face[2]]);
+        six_volume_f64 +=
+            ax * (by * cz - bz * cy) - ay * (bx * cz - bz * cx) + az * (bx * cy - by * cx);
+    }
+    T::from((six_volume_f64 / 6f64).abs()).unwrap()
+}
+
+// Function to get volume of a right prism whose base is a regular polygon.
+pub fn get_volume_prism<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let base_f64 = get_area_regular_polygon(sides, side_f64);
+    T::from(base_f64 * height_f64).unwrap()
+}
+
+// Function to get volume of a right pyramid whose base is a regular polygon.
+pub fn get_volume_pyramid<T>(sides: usize, side: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let side_f64 = T::to_f64(&side).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    let base_f64 = get_area_regular_polygon(sides, side_f64);
+    T::from(base_f64 * height_f64 / 3f64).unwrap()
+}
+
+// Function to get volume of a sphere.
+pub fn get_volume_sphere<T>(radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    T::from(4f64 / 3f64 * PI * radius_f64.powi(3)).unwrap()
+}
+
+// Function to get volume of a spherical cap of height `height` cut from a sphere of `radius`.
+pub fn get_volume_spherical_cap<T>(radius: T, height: T) -> T
+where
+    T: Num + NumCast,
+{
+    let radius_f64 = T::to_f64(&radius).unwrap();
+    let height_f64 = T::to_f64(&height).unwrap();
+    T::from(PI * height_f64.powi(2) * (3f64 * radius_f64 - height_f64) / 3f64).unwrap()
+}
+
+// Function to get volume of a torus from its major (tube center) and minor (tube) radii.
+pub fn get_volume_torus<T>(major_radius: T, minor_radius: T) -> T
+where
+    T: Num + NumCast,
+{
+    let major_f64 = T::to_f64(&major_radius).unwrap();
+    let minor_f64 = T::to_f64(&minor_radius).unwrap();
+    T::from(2f64 * PI.powi(2) * major_f64 * minor_f64.powi(2)).unwrap()
+}
+
+#[cfg(test)]
+mod geometry_solids_volume_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn get_volume_capsule_test() {
+        let result = get_volume_capsule(1.0, 2.0);
+        assert_close(
+            result,
+            get_volume_sphere(1.0) + get_volume_cylinder(1.0, 2.0),
+        );
+
+        let result = get_volume_capsule(1, 2);
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn get_volume_cone_test() {
+        let result = get_volume_cone(3.0, 4.0);
+        assert_close(result, 12.0 * PI);
+
+        let result = get_volume_cone(3, 4);
+        assert_eq!(result, 37);
+    }
+
+    #[test]
+    fn get_volume_cube_test() {
+        let result = get_volume_cube(3.5);
+        assert_eq!(result, 42.875);
+
+        let result = get_volume_cube(3);
+        assert_eq!(result, 27);
+    }
+
+    #[test]
+    fn get_volume_cuboid_test() {
+        let result = get_volume_cuboid(2.5, 3.0, 4.0);
+        assert_eq!(result, 30.0);
+
+        let result = get_volume_cuboid(2, 3, 4);
+        assert_eq!(result, 24);
+    }
+
+    #[test]
+    fn get_volume_cylinder_test() {
+        let result = get_volume_cylinder(2.0, 5.0);
+        assert_close(result, 20.0 * PI);
+
+        let result = get_volume_cylinder(2, 5);
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn get_volume_ellipsoid_test() {
+        let result = get_volume_ellipsoid(3.0, 3.0, 3.0);
+        assert_close(result, get_volume_sphere(3.0));
+
+        let result = get_volume_ellipsoid(1, 2, 3);
+        assert_eq!(result, 25);
+    }
+
+    #[test]
+    fn get_volume_frustum_test() {
+        let result = get_volume_frustum(3.0, 0.0, 4.0);
+        assert_close(result, get_volume_cone(3.0, 4.0));
+
+        let result = get_volume_frustum(2.0, 2.0, 5.0);
+        assert_close(result, get_volume_cylinder(2.0, 5.0));
+
+        let result = get_volume_frustum(2, 1, 3);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn get_volume_hemisphere_test() {
+        let result = get_volume_hemisphere(3.0);
+        assert_close(result, get_volume_sphere(3.0) / 2.0);
+
+        let result = get_volume_hemisphere(3);
+        assert_eq!(result, 56);
+    }
+
+    #[test]
+    fn get_volume_polyhedron_test() {
+        let vertices = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+            Point3::new(0.0, 0.0, 2.0),
+        ];
+        let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+        assert_close(get_volume_polyhedron(&vertices, &faces), 8.0 / 6.0);
+
+        // Reversing every face gives the same volume.
+        let inward: Vec<[usize; 3]> = faces.iter().map(|f| [f[0], f[2], f[1]]).collect();
+        assert_close(get_volume_polyhedron(&vertices, &inward), 8.0 / 6.0);
+
+        let vertices = [
+            Point3::new(0, 0, 0),
+            Point3::new(6, 0, 0),
+            Point3::new(0, 6, 0),
+            Point3::new(0, 0, 6),
+        ];
+        assert_eq!(get_volume_polyhedron(&vertices, &faces), 36);
+    }
+
+    #[test]
+    fn get_volume_prism_test() {
+        let result = get_volume_prism(4, 3.0, 2.0);
+        assert_close(result, get_volume_cuboid(3.0, 3.0, 2.0));
+
+        let result = get_volume_prism(6, 2, 10);
+        assert_eq!(result, 103);
+    }
+
+    #[test]
+    fn get_volume_pyramid_test() {
+        let result = get_volume_pyramid(4, 3.0, 2.0);
+        assert_close(result, 6.0);
+
+        let result = get_volume_pyramid(4, 3, 2);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn get_volume_sphere_test() {
+        let result = get_volume_sphere(3.0);
+        assert_eq!(result, 113.09733552923254);
+
+        let result = get_volume_sphere(3);
+        assert_eq!(result, 113);
+    }
+
+    #[test]
+    fn get_volume_spherical_cap_test() {
+        let result = get_volume_spherical_cap(3.0, 3.0);
+        assert_close(result, get_volume_hemisphere(3.0));
+
+        let result = get_volume_spherical_cap(3.0, 6.0);
+        assert_close(result, get_volume_sphere(3.0));
+
+        let result = get_volume_spherical_cap(3, 1);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn get_volume_torus_test() {
+        let result = get_volume_torus(3.0, 1.0);
+        assert_close(result, 6.0 * PI * PI);
+
+        let result = get_volume_torus(3, 1);
+        assert_eq!(result, 59);
+    }
+}
@@ -0,0 +1,497 @@
+//! This module provides a 2D k-d tree for point queries.
+//!
+//! It is part of the `spatial` module. A `KdTree` splits the plane alternately on
+//! x and y. `from_points` builds a balanced tree by median splits in O(n log n).
+//! Points added later with `insert` descend to a new leaf. Removed points stay
+//! in the tree as markers until they outnumber the live points, and the tree is
+//! rebuilt whenever it has grown to twice its size at the last build, so the
+//! depth stays logarithmic in the long run.
+//!
+//! Queries return point ids. `from_points` gives point `i` the id `i`, and
+//! `insert` hands out the next unused id.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::spatial::kdtree::KdTree;
+//!
+//! let mut tree = KdTree::from_points(&[
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(3.0, 4.0),
+//!     Point2::new(1.0, 1.0),
+//! ]);
+//! let id = tree.insert(Point2::new(-1.0, 0.0));
+//!
+//! assert_eq!(tree.nearest(Point2::new(0.9, 0.8)), Some(2));
+//! assert_eq!(tree.nearest_k(Point2::new(0.0, 0.0), 2), vec![(0, 0.0), (id, 1.0)]);
+//! assert_eq!(tree.within_radius(Point2::new(0.0, 0.0), 1.5), vec![0, 2, 3]);
+//!
+//! let bounds = BoundingBox::new(Point2::new(0.5, 0.5), Point2::new(4.0, 4.0));
+//! assert_eq!(tree.within_box(&bounds), vec![1, 2]);
+//!
+//! assert!(tree.remove(2));
+//! assert_eq!(tree.nearest(Point2::new(0.9, 0.8)), Some(0));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs an empty tree.
+//! - `from_points`: Builds a balanced tree from a set of points.
+//! - `len`: Gets the number of stored points.
+//! - `is_empty`: Checks whether the tree stores no points.
+//! - `point`: Gets the point stored under an id.
+//! - `insert`: Adds a point and returns its id.
+//! - `remove`: Removes the point with an id.
+//! - `nearest`: Finds the stored point nearest to a query point.
+//! - `nearest_k`: Finds the k stored points nearest to a query point.
+//! - `within_radius`: Finds the stored points within a distance of a query point.
+//! - `within_box`: Finds the stored points inside a bounding box.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::point::Point2;
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+    // The node holding each id, or None once the id is removed.
+    slots: Vec<Option<usize>>,
+    len: usize,
+    // Number of live points when the tree was last built.
+    built: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    point: Point2<T>,
+    id: usize,
+    // 0 splits on x and 1 splits on y.
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+    live: bool,
+}
+
+// A found point ordered by distance, so a max-heap keeps the farthest on top.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance_squared: f64,
+    id: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance_squared
+            .total_cmp(&other.distance_squared)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+impl<T> KdTree<T>
+where
+    T: Float,
+{
+    // Function to create an empty tree.
+    pub fn new() -> Self {
+        KdTree {
+            nodes: Vec::new(),
+            root: None,
+            slots: Vec::new(),
+            len: 0,
+            built: 0,
+        }
+    }
+
+    // Function to build a balanced tree, giving each point its index as id.
+    pub fn from_points(points: &[Point2<T>]) -> Self {
+        let mut tree = KdTree::new();
+        tree.slots = vec![None; points.len()];
+        let mut entries: Vec<(usize, Point2<T>)> = points.iter().copied().enumerate().collect();
+        tree.build(&mut entries);
+        tree
+    }
+
+    // Function to get the number of stored points.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Function to check whether the tree stores no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Function to get the point stored under an id, or None if it was removed.
+    pub fn point(&self, id: usize) -> Option<Point2<T>> {
+        let node = (*self.slots.get(id)?)?;
+        Some(self.nodes[node].point)
+    }
+
+    // Function to add a point and get its id.
+    pub fn insert(&mut self, point: Point2<T>) -> usize {
+        let id = self.slots.len();
+        let mut parent = None;
+        let mut current = self.root;
+        let mut depth = 0;
+        while let Some(index) = current {
+            let node = &self.nodes[index];
+            let go_left = coordinate(point, node.axis) < coordinate(node.point, node.axis);
+            parent = Some((index, go_left));
+            current = if go_left { node.left } else { node.right };
+            depth += 1;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            point,
+            id,
+            axis: depth % 2,
+            left: None,
+            right: None,
+            live: true,
+        });
+        match parent {
+            Some((parent, true)) => self.nodes[parent].left = Some(index),
+            Some((parent, false)) => self.nodes[parent].right = Some(index),
+            None => self.root = Some(index),
+        }
+        self.slots.push(Some(index));
+        self.len += 1;
+        if self.len > 2 * self.built.max(8) {
+            self.rebuild();
+        }
+        id
+    }
+
+    // Function to remove the point with an id, returning whether it was present.
+    pub fn remove(&mut self, id: usize) -> bool {
+        let Some(node) = self.slots.get(id).copied().flatten() else {
+            return false;
+        };
+        self.nodes[node].live = false;
+        self.slots[id] = None;
+        self.len -= 1;
+        if self.nodes.len() > 2 * self.len {
+            self.rebuild();
+        }
+        true
+    }
+
+    // Function to find the id of the stored point nearest to a query point.
+    pub fn nearest(&self, point: Point2<T>) -> Option<usize> {
+        self.nearest_k(point, 1).first().map(|&(id, _)| id)
+    }
+
+    // Function to find the k stored points nearest to a query point.
+    //
+    // Returns pairs of id and distance, nearest first. Ties are broken by id.
+    pub fn nearest_k(&self, point: Point2<T>, k: usize) -> Vec<(usize, T)> {
+        let mut found: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+        if k == 0 {
+            return Vec::new();
+        }
+        // Each entry carries a lower bound on the squared distance to its subtree.
+        let mut stack: Vec<(usize, f64)> = self.root.map(|root| (root, 0f64)).into_iter().collect();
+        while let Some((index, bound)) = stack.pop() {
+            if found.len() == k && bound > found.peek().unwrap().distance_squared {
+                continue;
+            }
+            let node = &self.nodes[index];
+            if node.live {
+                let distance_squared = to_f64(node.point.distance_squared(point));
+                found.push(Candidate {
+                    distance_squared,
+                    id: node.id,
+                });
+                if found.len() > k {
+                    found.pop();
+                }
+            }
+            let difference =
+                to_f64(coordinate(point, node.axis) - coordinate(node.point, node.axis));
+            let (near, far) = if difference < 0f64 {
+                (node.left, node.right)
+            } else {
+                (node.right, node.left)
+            };
+            // Push the far side first so the near side is searched first.
+            if let Some(far) = far {
+                stack.push((far, bound.max(difference * difference)));
+            }
+            if let Some(near) = near {
+                stack.push((near, bound));
+            }
+        }
+        found
+            .into_sorted_vec()
+            .into_iter()
+            .map(|candidate| {
+                (
+                    candidate.id,
+                    T::from(candidate.distance_squared.sqrt()).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    // Function to find the ids of the stored points within a distance of a point, in id order.
+    pub fn within_radius(&self, center: Point2<T>, radius: T) -> Vec<usize> {
+        let radius_squared = radius * radius;
+        let bounds = BoundingBox::new(
+            Point2::new(center.x - radius, center.y - radius),
+            Point2::new(center.x + radius, center.y + radius),
+        );
+        let mut ids = self.search(&bounds, |point| {
+            point.distance_squared(center) <= radius_squared
+        });
+        ids.sort_unstable();
+        ids
+    }
+
+    // Function to find the ids of the stored points inside a bounding box, in id order.
+    pub fn within_box(&self, bounds: &BoundingBox<T>) -> Vec<usize> {
+        let mut ids = self.search(bounds, |_| true);
+        ids.sort_unstable();
+        ids
+    }
+
+    // Collects live points in the box that pass the filter.
+    fn search<F>(&self, bounds: &BoundingBox<T>, keep: F) -> Vec<usize>
+    where
+        F: Fn(Point2<T>) -> bool,
+    {
+        let (min, max) = (bounds.min(), bounds.max());
+        let mut ids = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.live && bounds.contains_point(node.point) && keep(node.point) {
+                ids.push(node.id);
+            }
+            let split = coordinate(node.point, node.axis);
+            if let Some(left) = node.left {
+                if coordinate(min, node.axis) < split {
+                    stack.push(left);
+                }
+            }
+            if let Some(right) = node.right {
+                if coordinate(max, node.axis) >= split {
+                    stack.push(right);
+                }
+            }
+        }
+        ids
+    }
+
+    fn rebuild(&mut self) {
+        let mut entries: Vec<(usize, Point2<T>)> = self
+            .nodes
+            .iter()
+            .filter(|node| node.live)
+            .map(|node| (node.id, node.point))
+            .collect();
+        self.build(&mut entries);
+    }
+
+    fn build(&mut self, entries: &mut [(usize, Point2<T>)]) {
+        self.nodes.clear();
+        self.nodes.reserve(entries.len());
+        self.len = entries.len();
+        self.built = entries.len();
+        self.root = self.build_subtree(entries, 0);
+    }
+
+    fn build_subtree(&mut self, entries: &mut [(usize, Point2<T>)], depth: usize) -> Option<usize> {
+        if entries.is_empty() {
+            return None;
+        }
+        let axis = depth % 2;
+        let median = entries.len() / 2;
+        entries.select_nth_unstable_by(median, |a, b| {
+            coordinate(a.1, axis)
+                .partial_cmp(&coordinate(b.1, axis))
+                .unwrap()
+        });
+        // Points equal to the median on the split axis must all go right, so the
+        // node is the first of them after moving the smaller points to the front.
+        let split = coordinate(entries[median].1, axis);
+        let mut position = 0;
+        for i in 0..entries.len() {
+            if coordinate(entries[i].1, axis) < split {
+                entries.swap(i, position);
+                position += 1;
+            }
+        }
+        let equal = (position..entries.len())
+            .find(|&i| coordinate(entries[i].1, axis) == split)
+            .unwrap();
+        entries.swap(position, equal);
+        let (id, point) = entries[position];
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            point,
+            id,
+            axis,
+            left: None,
+            right: None,
+            live: true,
+        });
+        self.slots[id] = Some(index);
+        let (lower, upper) = entries.split_at_mut(position);
+        let left = self.build_subtree(lower, depth + 1);
+        let right = self.build_subtree(&mut upper[1..], depth + 1);
+        self.nodes[index].left = left;
+        self.nodes[index].right = right;
+        Some(index)
+    }
+}
+
+impl<T> Default for KdTree<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        KdTree::new()
+    }
+}
+
+fn coordinate<T: Copy>(point: Point2<T>, axis: usize) -> T {
+    if axis == 0 {
+        point.x
+    } else {
+        point.y
+    }
+}
+
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap()
+}
+
+#[cfg(test)]
+mod geometry_kdtree_tests {
+    use super::*;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn random_points(count: usize, seed: u64) -> Vec<Point2<f64>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count).map(|_| p(next(), next())).collect()
+    }
+
+    fn brute_nearest_k(points: &[Option<Point2<f64>>], query: Point2<f64>, k: usize) -> Vec<usize> {
+        let mut ids: Vec<usize> = (0..points.len()).filter(|&i| points[i].is_some()).collect();
+        ids.sort_by(|&a, &b| {
+            let (da, db) = (
+                points[a].unwrap().distance(query),
+                points[b].unwrap().distance(query),
+            );
+            da.partial_cmp(&db).unwrap().then(a.cmp(&b))
+        });
+        ids.truncate(k);
+        ids
+    }
+
+    #[test]
+    fn kdtree_nearest_test() {
+        let points = random_points(1000, 1);
+        let tree = KdTree::from_points(&points);
+        let stored: Vec<Option<Point2<f64>>> = points.iter().copied().map(Some).collect();
+        assert_eq!(tree.len(), 1000);
+
+        for query in random_points(50, 2) {
+            let found: Vec<usize> = tree
+                .nearest_k(query, 5)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            assert_eq!(found, brute_nearest_k(&stored, query, 5));
+        }
+        assert_eq!(tree.nearest_k(p(0.5, 0.5), 0), Vec::new());
+        assert_eq!(tree.nearest_k(p(0.5, 0.5), 2000).len(), 1000);
+    }
+
+    #[test]
+    fn kdtree_range_test() {
+        let points = random_points(500, 3);
+        let tree = KdTree::from_points(&points);
+        let center = p(0.4, 0.6);
+        let expected: Vec<usize> = (0..points.len())
+            .filter(|&i| points[i].distance(center) <= 0.2)
+            .collect();
+        assert_eq!(tree.within_radius(center, 0.2), expected);
+
+        let bounds = BoundingBox::new(p(0.1, 0.2), p(0.3, 0.9));
+        let expected: Vec<usize> = (0..points.len())
+            .filter(|&i| bounds.contains_point(points[i]))
+            .collect();
+        assert_eq!(tree.within_box(&bounds), expected);
+    }
+
+    #[test]
+    fn kdtree_insert_remove_test() {
+        let points = random_points(400, 4);
+        let mut tree = KdTree::new();
+        let mut stored = Vec::new();
+        for &point in &points {
+            let id = tree.insert(point);
+            assert_eq!(id, stored.len());
+            stored.push(Some(point));
+        }
+        for id in (0..points.len()).step_by(3) {
+            assert!(tree.remove(id));
+            stored[id] = None;
+        }
+        assert!(!tree.remove(0));
+        assert!(!tree.remove(10_000));
+        assert_eq!(tree.point(0), None);
+        assert_eq!(tree.point(1), Some(points[1]));
+        assert_eq!(tree.len(), stored.iter().flatten().count());
+
+        for query in random_points(30, 5) {
+            let found: Vec<usize> = tree
+                .nearest_k(query, 3)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            assert_eq!(found, brute_nearest_k(&stored, query, 3));
+        }
+    }
+
+    #[test]
+    fn kdtree_duplicates_test() {
+        // Repeated and axis-aligned points must all be found.
+        let points = vec![p(1.0, 1.0); 20]
+            .into_iter()
+            .chain((0..20).map(|i| p(1.0, i as f64)))
+            .collect::<Vec<_>>();
+        let tree = KdTree::from_points(&points);
+        assert_eq!(tree.within_radius(p(1.0, 1.0), 0.0).len(), 21);
+        assert_eq!(
+            tree.within_box(&BoundingBox::new(p(1.0, 0.0), p(1.0, 19.0)))
+                .len(),
+            40
+        );
+        assert_eq!(tree.nearest_k(p(1.0, 1.0), 21).last().unwrap().1, 0.0);
+        assert!(KdTree::<f64>::new().nearest(p(0.0, 0.0)).is_none());
+    }
+}
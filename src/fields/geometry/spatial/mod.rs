@@ -0,0 +1,14 @@
+//! This module contains spatial indexes for fast proximity queries.
+//!
+//! It is part of the `geometry` module. The indexes answer nearest-neighbour,
+//! radius and rectangle range queries without comparing against every stored
+//! item. Items are identified by the `usize` id returned when they are added,
+//! which stays valid until the item is removed.
+//!
+//! # Modules
+//!
+//! - `kdtree`: Contains the `KdTree` point index.
+//! - `rtree`: Contains the `RTree` bounding box index with STR bulk loading.
+
+pub mod kdtree;
+pub mod rtree;
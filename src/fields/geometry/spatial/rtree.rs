@@ -0,0 +1,690 @@
+//! This module provides an R-tree for bounding box queries.
+//!
+//! It is part of the `spatial` module. An `RTree` stores bounding boxes, and
+//! points as boxes of zero size, in a balanced tree of nested boxes. `bulk_load`
+//! packs a known set of boxes with the Sort-Tile-Recursive (STR) method. The
+//! boxes are sorted into vertical slices by centre x and then packed into nodes
+//! by centre y, which gives nearly full nodes with little overlap. `insert` adds
+//! boxes one at a time, using Guttman's least-enlargement descent and quadratic
+//! split. `remove` deletes a box and reinserts the entries of any node left
+//! under-full.
+//!
+//! Queries return item ids. `bulk_load` gives box `i` the id `i`, and `insert`
+//! hands out the next unused id.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::spatial::rtree::RTree;
+//!
+//! let fences = vec![
+//!     BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)),
+//!     BoundingBox::new(Point2::new(1.0, 1.0), Point2::new(3.0, 3.0)),
+//!     BoundingBox::new(Point2::new(5.0, 5.0), Point2::new(6.0, 6.0)),
+//! ];
+//! let mut tree = RTree::bulk_load(&fences);
+//! let depot = tree.insert_point(Point2::new(4.0, 0.0));
+//!
+//! assert_eq!(tree.containing_point(Point2::new(1.5, 1.5)), vec![0, 1]);
+//! assert_eq!(tree.nearest(Point2::new(4.5, 0.5)), Some(depot));
+//! assert_eq!(tree.within_radius(Point2::new(4.0, 4.0), 1.5), vec![1, 2]);
+//!
+//! assert!(tree.remove(1));
+//! assert_eq!(tree.containing_point(Point2::new(1.5, 1.5)), vec![0]);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs an empty tree.
+//! - `bulk_load`: Builds a packed tree from a set of boxes with STR.
+//! - `len`: Gets the number of stored items.
+//! - `is_empty`: Checks whether the tree stores no items.
+//! - `get`: Gets the box stored under an id.
+//! - `insert`: Adds a box and returns its id.
+//! - `insert_point`: Adds a point and returns its id.
+//! - `remove`: Removes the item with an id.
+//! - `search`: Finds the stored boxes intersecting a box.
+//! - `containing_point`: Finds the stored boxes containing a point.
+//! - `within_radius`: Finds the stored boxes within a distance of a point.
+//! - `nearest`: Finds the stored box nearest to a point.
+//! - `nearest_k`: Finds the k stored boxes nearest to a point.
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::point::Point2;
+use num_traits::Float;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+// Node capacity and the fill below which a node is dissolved on removal.
+const MAX_ENTRIES: usize = 9;
+const MIN_ENTRIES: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct RTree<T> {
+    nodes: Vec<Node<T>>,
+    // Indices of nodes released by removals, reused before growing `nodes`.
+    free: Vec<usize>,
+    root: usize,
+    items: Vec<Option<BoundingBox<T>>>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    bounds: Option<BoundingBox<T>>,
+    leaf: bool,
+    // Item ids in a leaf, child node indices otherwise.
+    entries: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Node(usize),
+    Item(usize),
+}
+
+// A node or item ordered by its distance to the query point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance_squared: f64,
+    entry: Entry,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Nodes sort before items at the same distance, so tied items come out by id.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let rank = |entry: Entry| match entry {
+            Entry::Item(id) => (1, id),
+            Entry::Node(index) => (0, index),
+        };
+        self.distance_squared
+            .total_cmp(&other.distance_squared)
+            .then(rank(self.entry).cmp(&rank(other.entry)))
+    }
+}
+
+impl<T> RTree<T>
+where
+    T: Float,
+{
+    // Function to create an empty tree.
+    pub fn new() -> Self {
+        RTree {
+            nodes: vec![Node {
+                bounds: None,
+                leaf: true,
+                entries: Vec::new(),
+            }],
+            free: Vec::new(),
+            root: 0,
+            items: Vec::new(),
+            len: 0,
+        }
+    }
+
+    // Function to build a packed tree with STR, giving each box its index as id.
+    pub fn bulk_load(boxes: &[BoundingBox<T>]) -> Self {
+        let mut tree = RTree::new();
+        if boxes.is_empty() {
+            return tree;
+        }
+        tree.nodes.clear();
+        tree.items = boxes.iter().copied().map(Some).collect();
+        tree.len = boxes.len();
+        let mut level = tree.pack((0..boxes.len()).collect(), true);
+        while level.len() > 1 {
+            level = tree.pack(level, false);
+        }
+        tree.root = level[0];
+        tree
+    }
+
+    // Function to get the number of stored items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Function to check whether the tree stores no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Function to get the box stored under an id, or None if it was removed.
+    pub fn get(&self, id: usize) -> Option<BoundingBox<T>> {
+        *self.items.get(id)?
+    }
+
+    // Function to add a box and get its id.
+    pub fn insert(&mut self, bounds: BoundingBox<T>) -> usize {
+        let id = self.items.len();
+        self.items.push(Some(bounds));
+        self.len += 1;
+        self.insert_item(id);
+        id
+    }
+
+    // Function to add a point and get its id.
+    pub fn insert_point(&mut self, point: Point2<T>) -> usize {
+        self.insert(BoundingBox::from_point(point))
+    }
+
+    // Function to remove the item with an id, returning whether it was present.
+    pub fn remove(&mut self, id: usize) -> bool {
+        let Some(bounds) = self.items.get(id).copied().flatten() else {
+            return false;
+        };
+        let mut orphans = Vec::new();
+        if !self.remove_from(self.root, id, &bounds, &mut orphans) {
+            return false;
+        }
+        self.items[id] = None;
+        self.len -= 1;
+        // A root with a single child is replaced by the child.
+        while !self.nodes[self.root].leaf && self.nodes[self.root].entries.len() == 1 {
+            let old = self.root;
+            self.root = self.nodes[old].entries[0];
+            self.release(old);
+        }
+        if !self.nodes[self.root].leaf && self.nodes[self.root].entries.is_empty() {
+            self.nodes[self.root].leaf = true;
+        }
+        for orphan in orphans {
+            self.insert_item(orphan);
+        }
+        true
+    }
+
+    // Function to find the ids of the stored boxes that intersect a box, in id order.
+    pub fn search(&self, bounds: &BoundingBox<T>) -> Vec<usize> {
+        let mut ids = self.collect(
+            |node_bounds| node_bounds.intersects(bounds),
+            |item| item.intersects(bounds),
+        );
+        ids.sort_unstable();
+        ids
+    }
+
+    // Function to find the ids of the stored boxes that contain a point, in id order.
+    pub fn containing_point(&self, point: Point2<T>) -> Vec<usize> {
+        self.search(&BoundingBox::from_point(point))
+    }
+
+    // Function to find the ids of the stored boxes within a distance of a point, in id order.
+    pub fn within_radius(&self, center: Point2<T>, radius: T) -> Vec<usize> {
+        let radius_squared = radius * radius;
+        let near =
+            |bounds: &BoundingBox<T>| bounds.distance_squared_to_point(center) <= radius_squared;
+        let mut ids = self.collect(near, near);
+        ids.sort_unstable();
+        ids
+    }
+
+    // Function to find the id of the stored box nearest to a point.
+    pub fn nearest(&self, point: Point2<T>) -> Option<usize> {
+        self.nearest_k(point, 1).first().map(|&(id, _)| id)
+    }
+
+    // Function to find the k stored boxes nearest to a point.
+    //
+    // Returns pairs of id and distance, nearest first, where boxes containing the
+    // point are at distance zero. Ties are broken by id.
+    pub fn nearest_k(&self, point: Point2<T>, k: usize) -> Vec<(usize, T)> {
+        let mut found = Vec::with_capacity(k);
+        let mut queue = BinaryHeap::new();
+        if let Some(bounds) = self.nodes[self.root].bounds {
+            queue.push(Reverse(Candidate {
+                distance_squared: to_f64(bounds.distance_squared_to_point(point)),
+                entry: Entry::Node(self.root),
+            }));
+        }
+        // Best-first search: entries come off the queue in order of distance.
+        while let Some(Reverse(candidate)) = queue.pop() {
+            if found.len() == k {
+                break;
+            }
+            match candidate.entry {
+                Entry::Item(id) => {
+                    found.push((id, T::from(candidate.distance_squared.sqrt()).unwrap()));
+                }
+                Entry::Node(index) => {
+                    let node = &self.nodes[index];
+                    for &entry in &node.entries {
+                        let (bounds, entry) = if node.leaf {
+                            (self.items[entry].unwrap(), Entry::Item(entry))
+                        } else {
+                            (self.nodes[entry].bounds.unwrap(), Entry::Node(entry))
+                        };
+                        queue.push(Reverse(Candidate {
+                            distance_squared: to_f64(bounds.distance_squared_to_point(point)),
+                            entry,
+                        }));
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    // Ids of the items that pass `keep`, descending only into nodes that pass `visit`.
+    fn collect<V, K>(&self, visit: V, keep: K) -> Vec<usize>
+    where
+        V: Fn(&BoundingBox<T>) -> bool,
+        K: Fn(&BoundingBox<T>) -> bool,
+    {
+        let mut ids = Vec::new();
+        let mut stack = vec![self.root];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.is_some_and(|bounds| visit(&bounds)) {
+                continue;
+            }
+            if node.leaf {
+                ids.extend(
+                    node.entries
+                        .iter()
+                        .filter(|&&id| keep(&self.items[id].unwrap())),
+                );
+            } else {
+                stack.extend(&node.entries);
+            }
+        }
+        ids
+    }
+
+    fn insert_item(&mut self, id: usize) {
+        let bounds = self.items[id].unwrap();
+        if let Some(sibling) = self.insert_into(self.root, id, &bounds) {
+            let old = self.root;
+            self.root = self.allocate(false, vec![old, sibling]);
+        }
+    }
+
+    // Inserts an item below a node and returns the new sibling if the node split.
+    fn insert_into(&mut self, index: usize, id: usize, bounds: &BoundingBox<T>) -> Option<usize> {
+        if self.nodes[index].leaf {
+            self.nodes[index].entries.push(id);
+        } else {
+            let child = self.choose_subtree(index, bounds);
+            if let Some(sibling) = self.insert_into(child, id, bounds) {
+                self.nodes[index].entries.push(sibling);
+            }
+        }
+        self.refresh(index);
+        (self.nodes[index].entries.len() > MAX_ENTRIES).then(|| self.split(index))
+    }
+
+    // The child needing the least enlargement to cover the box, then the smallest.
+    fn choose_subtree(&self, index: usize, bounds: &BoundingBox<T>) -> usize {
+        *self.nodes[index]
+            .entries
+            .iter()
+            .min_by(|&&a, &&b| {
+                let cost = |child: usize| {
+                    let current = self.nodes[child].bounds.unwrap();
+                    let grown = current.union(bounds);
+                    (
+                        grown.area() - current.area(),
+                        margin(&grown) - margin(&current),
+                        current.area(),
+                    )
+                };
+                cost(a).partial_cmp(&cost(b)).unwrap()
+            })
+            .unwrap()
+    }
+
+    // Guttman's quadratic split. Returns the new node holding half of the entries.
+    fn split(&mut self, index: usize) -> usize {
+        let leaf = self.nodes[index].leaf;
+        let entries = std::mem::take(&mut self.nodes[index].entries);
+        let boxes: Vec<BoundingBox<T>> = entries
+            .iter()
+            .map(|&e| self.entry_bounds(leaf, e))
+            .collect();
+
+        // The seeds are the pair that would waste the most space together.
+        let waste = |i: usize, j: usize| {
+            let joined = boxes[i].union(&boxes[j]);
+            (
+                joined.area() - boxes[i].area() - boxes[j].area(),
+                margin(&joined) - margin(&boxes[i]) - margin(&boxes[j]),
+            )
+        };
+        let mut seeds = (0, 1);
+        for i in 0..boxes.len() {
+            for j in i + 1..boxes.len() {
+                if waste(i, j).partial_cmp(&waste(seeds.0, seeds.1)) == Some(Ordering::Greater) {
+                    seeds = (i, j);
+                }
+            }
+        }
+
+        let mut groups = [vec![seeds.0], vec![seeds.1]];
+        let mut covers = [boxes[seeds.0], boxes[seeds.1]];
+        let mut remaining: Vec<usize> = (0..boxes.len())
+            .filter(|&i| i != seeds.0 && i != seeds.1)
+            .collect();
+        while !remaining.is_empty() {
+            // A group that needs every remaining entry to reach the minimum takes them all.
+            if let Some(group) = (0..2).find(|&g| groups[g].len() + remaining.len() == MIN_ENTRIES)
+            {
+                for i in remaining.drain(..) {
+                    covers[group] = covers[group].union(&boxes[i]);
+                    groups[group].push(i);
+                }
+                break;
+            }
+            let growth = |g: usize, i: usize| covers[g].union(&boxes[i]).area() - covers[g].area();
+            // Place the entry with the strongest preference for one group first.
+            let position = (0..remaining.len())
+                .max_by(|&a, &b| {
+                    let preference = |i: usize| (growth(0, i) - growth(1, i)).abs();
+                    preference(remaining[a])
+                        .partial_cmp(&preference(remaining[b]))
+                        .unwrap()
+                })
+                .unwrap();
+            let i = remaining.swap_remove(position);
+            let key = |g: usize| (growth(g, i), covers[g].area(), groups[g].len());
+            let group = if key(0).partial_cmp(&key(1)) == Some(Ordering::Greater) {
+                1
+            } else {
+                0
+            };
+            covers[group] = covers[group].union(&boxes[i]);
+            groups[group].push(i);
+        }
+
+        let [first, second] =
+            groups.map(|group| group.into_iter().map(|i| entries[i]).collect::<Vec<_>>());
+        self.nodes[index].entries = first;
+        self.refresh(index);
+        self.allocate(leaf, second)
+    }
+
+    // Removes an item below a node, dissolving under-full nodes into `orphans`.
+    fn remove_from(
+        &mut self,
+        index: usize,
+        id: usize,
+        bounds: &BoundingBox<T>,
+        orphans: &mut Vec<usize>,
+    ) -> bool {
+        if self.nodes[index].leaf {
+            let Some(position) = self.nodes[index].entries.iter().position(|&e| e == id) else {
+                return false;
+            };
+            self.nodes[index].entries.remove(position);
+            self.refresh(index);
+            return true;
+        }
+        for position in 0..self.nodes[index].entries.len() {
+            let child = self.nodes[index].entries[position];
+            if !self.nodes[child].bounds.is_some_and(|b| b.contains(bounds)) {
+                continue;
+            }
+            if self.remove_from(child, id, bounds, orphans) {
+                if self.nodes[child].entries.len() < MIN_ENTRIES {
+                    self.nodes[index].entries.remove(position);
+                    self.dissolve(child, orphans);
+                }
+                self.refresh(index);
+                return true;
+            }
+        }
+        false
+    }
+
+    // Releases a subtree and collects its item ids.
+    fn dissolve(&mut self, index: usize, orphans: &mut Vec<usize>) {
+        let entries = std::mem::take(&mut self.nodes[index].entries);
+        if self.nodes[index].leaf {
+            orphans.extend(entries);
+        } else {
+            for child in entries {
+                self.dissolve(child, orphans);
+            }
+        }
+        self.release(index);
+    }
+
+    // Packs entries into nodes with STR and returns the new nodes.
+    fn pack(&mut self, mut entries: Vec<usize>, leaf: bool) -> Vec<usize> {
+        let center = |tree: &Self, entry: usize| tree.entry_bounds(leaf, entry).center();
+        let node_count = entries.len().div_ceil(MAX_ENTRIES);
+        let slices = (node_count as f64).sqrt().ceil() as usize;
+        let slice_size = slices * MAX_ENTRIES;
+        entries.sort_by(|&a, &b| center(self, a).x.partial_cmp(&center(self, b).x).unwrap());
+        let mut packed = Vec::with_capacity(node_count);
+        for slice in entries.chunks_mut(slice_size) {
+            slice.sort_by(|&a, &b| center(self, a).y.partial_cmp(&center(self, b).y).unwrap());
+            for group in slice.chunks(MAX_ENTRIES) {
+                packed.push(self.allocate(leaf, group.to_vec()));
+            }
+        }
+        packed
+    }
+
+    fn allocate(&mut self, leaf: bool, entries: Vec<usize>) -> usize {
+        let node = Node {
+            bounds: None,
+            leaf,
+            entries,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        self.refresh(index);
+        index
+    }
+
+    fn release(&mut self, index: usize) {
+        self.nodes[index].entries.clear();
+        self.nodes[index].bounds = None;
+        self.free.push(index);
+    }
+
+    // Recomputes the bounds of a node from its entries.
+    fn refresh(&mut self, index: usize) {
+        let leaf = self.nodes[index].leaf;
+        let bounds = self.nodes[index]
+            .entries
+            .iter()
+            .map(|&entry| self.entry_bounds(leaf, entry))
+            .reduce(|a, b| a.union(&b));
+        self.nodes[index].bounds = bounds;
+    }
+
+    fn entry_bounds(&self, leaf: bool, entry: usize) -> BoundingBox<T> {
+        if leaf {
+            self.items[entry].unwrap()
+        } else {
+            self.nodes[entry].bounds.unwrap()
+        }
+    }
+}
+
+impl<T> Default for RTree<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        RTree::new()
+    }
+}
+
+// Half the perimeter, which still separates boxes of zero area.
+fn margin<T: Float>(bounds: &BoundingBox<T>) -> T {
+    bounds.width() + bounds.height()
+}
+
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap()
+}
+
+#[cfg(test)]
+mod geometry_rtree_tests {
+    use super::*;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn random_boxes(count: usize, seed: u64) -> Vec<BoundingBox<f64>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count)
+            .map(|_| {
+                let corner = p(next(), next());
+                BoundingBox::new(
+                    corner,
+                    p(corner.x + 0.05 * next(), corner.y + 0.05 * next()),
+                )
+            })
+            .collect()
+    }
+
+    fn brute_search(boxes: &[Option<BoundingBox<f64>>], query: &BoundingBox<f64>) -> Vec<usize> {
+        (0..boxes.len())
+            .filter(|&i| boxes[i].is_some_and(|b| b.intersects(query)))
+            .collect()
+    }
+
+    fn brute_nearest_k(
+        boxes: &[Option<BoundingBox<f64>>],
+        point: Point2<f64>,
+        k: usize,
+    ) -> Vec<usize> {
+        let mut ids: Vec<usize> = (0..boxes.len()).filter(|&i| boxes[i].is_some()).collect();
+        ids.sort_by(|&a, &b| {
+            let distance = |i: usize| boxes[i].unwrap().distance_squared_to_point(point);
+            distance(a)
+                .partial_cmp(&distance(b))
+                .unwrap()
+                .then(a.cmp(&b))
+        });
+        ids.truncate(k);
+        ids
+    }
+
+    // Checks that every node covers its entries and every leaf is at the same depth.
+    fn assert_well_formed(tree: &RTree<f64>) {
+        let mut leaf_depths = Vec::new();
+        let mut count = 0;
+        let mut stack = vec![(tree.root, 0)];
+        while let Some((index, depth)) = stack.pop() {
+            let node = &tree.nodes[index];
+            assert!(node.entries.len() <= MAX_ENTRIES);
+            for &entry in &node.entries {
+                let bounds = tree.entry_bounds(node.leaf, entry);
+                assert!(node.bounds.unwrap().contains(&bounds));
+                if !node.leaf {
+                    stack.push((entry, depth + 1));
+                }
+            }
+            if node.leaf {
+                leaf_depths.push(depth);
+                count += node.entries.len();
+            }
+        }
+        assert_eq!(count, tree.len());
+        assert!(leaf_depths.iter().all(|&depth| depth == leaf_depths[0]));
+    }
+
+    #[test]
+    fn rtree_bulk_load_test() {
+        let boxes = random_boxes(2000, 1);
+        let tree = RTree::bulk_load(&boxes);
+        let stored: Vec<Option<BoundingBox<f64>>> = boxes.iter().copied().map(Some).collect();
+        assert_well_formed(&tree);
+        assert_eq!(tree.len(), 2000);
+
+        for query in random_boxes(30, 2) {
+            assert_eq!(tree.search(&query), brute_search(&stored, &query));
+        }
+        for point in random_boxes(30, 3).iter().map(|b| b.min()) {
+            let found: Vec<usize> = tree
+                .nearest_k(point, 4)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            assert_eq!(found, brute_nearest_k(&stored, point, 4));
+        }
+    }
+
+    #[test]
+    fn rtree_insert_remove_test() {
+        let boxes = random_boxes(600, 4);
+        let mut tree = RTree::new();
+        let mut stored = Vec::new();
+        for &bounds in &boxes {
+            assert_eq!(tree.insert(bounds), stored.len());
+            stored.push(Some(bounds));
+        }
+        assert_well_formed(&tree);
+
+        for id in (0..boxes.len()).filter(|id| id % 4 != 1) {
+            assert!(tree.remove(id));
+            stored[id] = None;
+        }
+        assert!(!tree.remove(0));
+        assert!(!tree.remove(5000));
+        assert_eq!(tree.get(0), None);
+        assert_eq!(tree.get(1), Some(boxes[1]));
+        assert_well_formed(&tree);
+
+        for query in random_boxes(30, 5) {
+            assert_eq!(tree.search(&query), brute_search(&stored, &query));
+        }
+
+        // Removing everything leaves an empty, usable tree.
+        for id in (0..boxes.len()).filter(|id| id % 4 == 1) {
+            assert!(tree.remove(id));
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(p(0.5, 0.5)), None);
+        let id = tree.insert_point(p(0.5, 0.5));
+        assert_eq!(tree.nearest(p(0.0, 0.0)), Some(id));
+    }
+
+    #[test]
+    fn rtree_points_test() {
+        // Collinear points have boxes of zero area.
+        let points: Vec<BoundingBox<f64>> = (0..200)
+            .map(|i| BoundingBox::from_point(p(i as f64, 0.0)))
+            .collect();
+        let mut tree = RTree::new();
+        for &bounds in &points {
+            tree.insert(bounds);
+        }
+        assert_well_formed(&tree);
+        assert_eq!(tree.within_radius(p(50.0, 1.0), 1.5), vec![49, 50, 51]);
+        assert_eq!(tree.containing_point(p(7.0, 0.0)), vec![7]);
+        assert_eq!(
+            tree.nearest_k(p(-3.0, 4.0), 2),
+            vec![(0, 5.0), (1, 32f64.sqrt())]
+        );
+    }
+}
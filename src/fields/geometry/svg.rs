@@ -0,0 +1,625 @@
+//! This module provides rendering of geometry to SVG.
+//!
+//! It is part of the `geometry` module. An `Svg` maps a rectangle of the plane,
+//! its view, onto an image of a given pixel width. The y axis is flipped so that
+//! it points up, as in the rest of the crate. Shapes are drawn in the order they
+//! are added, each with a `Style`. Grid lines, axes and text labels can be
+//! overlaid on them.
+//!
+//! Output is deterministic. Coordinates are rounded to hundredths of a pixel and
+//! written without trailing zeros, so the same drawing always gives the same
+//! string and can be compared in snapshot tests.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::bounding_box::BoundingBox;
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::svg::{Style, Svg};
+//!
+//! let view = BoundingBox::new(Point2::new(0.0, 0.0), Point2::new(4.0, 2.0));
+//! let mut svg = Svg::new(view, 200.0).unwrap();
+//! svg.circle(Point2::new(1.0, 1.0), 0.5, &Style::default());
+//! svg.label(Point2::new(3.0, 1.0), "A < B", &Style::default());
+//!
+//! assert_eq!(
+//!     svg.to_string(),
+//!     concat!(
+//!         "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\" height=\"100\" viewBox=\"0 0 200 100\">\n",
+//!         "  <circle cx=\"50\" cy=\"50\" r=\"25\" stroke=\"black\" stroke-width=\"1\" fill=\"none\"/>\n",
+//!         "  <text x=\"150\" y=\"50\" font-size=\"12\" fill=\"black\">A &lt; B</text>\n",
+//!         "</svg>\n",
+//!     )
+//! );
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a drawing of a view at a pixel width.
+//! - `width` and `height`: Get the size of the image in pixels.
+//! - `grid`: Draws grid lines at a regular spacing.
+//! - `axes`: Draws the x and y axes.
+//! - `point`: Draws a point as a dot of fixed pixel radius.
+//! - `segment`: Draws a line segment.
+//! - `polyline`: Draws an open path through points.
+//! - `circle`: Draws a circle.
+//! - `triangle`: Draws a triangle.
+//! - `polygon`: Draws a polygon.
+//! - `polygon_with_holes`: Draws a polygon with holes.
+//! - `hull`: Draws a convex hull given as vertex indices.
+//! - `bezier`: Draws a Bézier curve.
+//! - `bspline`: Draws a B-spline.
+//! - `voronoi`: Draws the cells of a Voronoi diagram.
+//! - `geometry`: Draws a `Geometry` read from WKT or GeoJSON.
+//! - `label`: Draws a text label.
+//! - `to_string`: Gets the SVG document (via `Display`).
+
+use crate::fields::geometry::bounding_box::BoundingBox;
+use crate::fields::geometry::curves::bezier::BezierCurve;
+use crate::fields::geometry::curves::bspline::BSpline;
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::io::geometry::Geometry;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+use crate::fields::geometry::voronoi::VoronoiDiagram;
+use core::fmt;
+use num_traits::Float;
+use std::fmt::{Formatter, Write};
+
+// Curves without a native SVG form are flattened to within this many pixels.
+const FLATTEN_TOLERANCE: f64 = 0.25;
+
+// How a shape is drawn. Colours are any SVG colour, such as "red" or "#336699".
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    pub stroke: Option<String>,
+    pub fill: Option<String>,
+    pub stroke_width: f64,
+    pub opacity: f64,
+    pub dash: Vec<f64>,
+    pub font_size: f64,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            stroke: Some("black".to_string()),
+            fill: None,
+            stroke_width: 1.0,
+            opacity: 1.0,
+            dash: Vec::new(),
+            font_size: 12.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Svg {
+    view: BoundingBox<f64>,
+    width: f64,
+    height: f64,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    // Function to create a drawing of `view`, `width` pixels wide.
+    //
+    // The height follows from the aspect ratio of the view.
+    pub fn new<T: Float>(view: BoundingBox<T>, width: f64) -> Result<Self, GeometryError> {
+        let view = BoundingBox::new(to_f64(view.min()), to_f64(view.max()));
+        if !(view.width() > 0.0 && view.height() > 0.0) {
+            return Err(GeometryError::new(
+                "The view must have a positive width and height",
+            ));
+        }
+        if !(width > 0.0 && width.is_finite()) {
+            return Err(GeometryError::new("The image width must be positive"));
+        }
+        Ok(Svg {
+            view,
+            width,
+            height: width * view.height() / view.width(),
+            elements: Vec::new(),
+        })
+    }
+
+    // Function to get the width of the image in pixels.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    // Function to get the height of the image in pixels.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    // Function to draw grid lines at every multiple of `spacing` inside the view.
+    pub fn grid(&mut self, spacing: f64, style: &Style) {
+        if spacing.is_nan() || spacing <= 0.0 {
+            return;
+        }
+        let (min, max) = (self.view.min(), self.view.max());
+        let mut path = String::new();
+        // Lines are placed at whole multiples, so rounding does not accumulate.
+        let multiples = |low: f64, high: f64| {
+            let first = (low / spacing).ceil() as i64;
+            let last = (high / spacing).floor() as i64;
+            (first..=last).map(move |k| k as f64 * spacing)
+        };
+        for x in multiples(min.x, max.x) {
+            self.move_line(&mut path, Point2::new(x, min.y), Point2::new(x, max.y));
+        }
+        for y in multiples(min.y, max.y) {
+            self.move_line(&mut path, Point2::new(min.x, y), Point2::new(max.x, y));
+        }
+        if !path.is_empty() {
+            self.path(path.trim_end(), style);
+        }
+    }
+
+    // Function to draw the x and y axes where they cross the view.
+    pub fn axes(&mut self, style: &Style) {
+        let (min, max) = (self.view.min(), self.view.max());
+        let mut path = String::new();
+        if min.y <= 0.0 && 0.0 <= max.y {
+            self.move_line(&mut path, Point2::new(min.x, 0.0), Point2::new(max.x, 0.0));
+        }
+        if min.x <= 0.0 && 0.0 <= max.x {
+            self.move_line(&mut path, Point2::new(0.0, min.y), Point2::new(0.0, max.y));
+        }
+        if !path.is_empty() {
+            self.path(path.trim_end(), style);
+        }
+    }
+
+    // Function to draw a point as a dot with a radius in pixels.
+    pub fn point<T: Float>(&mut self, point: Point2<T>, radius: f64, style: &Style) {
+        let (x, y) = self.project(to_f64(point));
+        self.elements.push(format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"{}/>",
+            number(x),
+            number(y),
+            number(radius),
+            attributes(style)
+        ));
+    }
+
+    // Function to draw a line segment.
+    pub fn segment<T: Float>(&mut self, a: Point2<T>, b: Point2<T>, style: &Style) {
+        self.polyline(&[a, b], style);
+    }
+
+    // Function to draw an open path through points.
+    pub fn polyline<T: Float>(&mut self, points: &[Point2<T>], style: &Style) {
+        let points = self.point_list(points);
+        self.elements.push(format!(
+            "<polyline points=\"{}\"{}/>",
+            points,
+            attributes(style)
+        ));
+    }
+
+    // Function to draw a circle.
+    pub fn circle<T: Float>(&mut self, center: Point2<T>, radius: T, style: &Style) {
+        let (x, y) = self.project(to_f64(center));
+        let radius = radius.to_f64().unwrap() * self.scale();
+        self.elements.push(format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"{}/>",
+            number(x),
+            number(y),
+            number(radius),
+            attributes(style)
+        ));
+    }
+
+    // Function to draw a triangle.
+    pub fn triangle<T: Float>(&mut self, a: Point2<T>, b: Point2<T>, c: Point2<T>, style: &Style) {
+        self.polygon(&Polygon::new(vec![a, b, c]), style);
+    }
+
+    // Function to draw a polygon.
+    pub fn polygon<T: Float>(&mut self, polygon: &Polygon<T>, style: &Style) {
+        let points = self.point_list(polygon.vertices());
+        self.elements.push(format!(
+            "<polygon points=\"{}\"{}/>",
+            points,
+            attributes(style)
+        ));
+    }
+
+    // Function to draw a polygon with holes, which are left unfilled.
+    pub fn polygon_with_holes<T: Float>(&mut self, polygon: &PolygonWithHoles<T>, style: &Style) {
+        let mut path = String::new();
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.holes()) {
+            for (index, &vertex) in ring.vertices().iter().enumerate() {
+                let command = if index == 0 { 'M' } else { 'L' };
+                self.command(&mut path, command, &[to_f64(vertex)]);
+            }
+            if !ring.is_empty() {
+                path.push_str("Z ");
+            }
+        }
+        self.elements.push(format!(
+            "<path d=\"{}\" fill-rule=\"evenodd\"{}/>",
+            path.trim_end(),
+            attributes(style)
+        ));
+    }
+
+    // Function to draw the convex hull of `points` given as indices, as returned by `convex_hull`.
+    pub fn hull<T: Float>(&mut self, points: &[Point2<T>], hull: &[usize], style: &Style) {
+        let vertices = hull.iter().map(|&index| points[index]).collect();
+        self.polygon(&Polygon::new(vertices), style);
+    }
+
+    // Function to draw a Bézier curve.
+    //
+    // Polynomial curves up to cubic use native SVG curves; others are flattened.
+    pub fn bezier<T: Float>(&mut self, curve: &BezierCurve<T>, style: &Style) {
+        let mut path = String::new();
+        self.command(&mut path, 'M', &[to_f64(curve.start())]);
+        self.append_bezier(&mut path, curve);
+        self.path(path.trim_end(), style);
+    }
+
+    // Function to draw a B-spline as a chain of Bézier pieces.
+    pub fn bspline<T: Float>(&mut self, spline: &BSpline<T>, style: &Style) {
+        let pieces = spline.to_bezier();
+        let Some(first) = pieces.first() else {
+            return;
+        };
+        let mut path = String::new();
+        self.command(&mut path, 'M', &[to_f64(first.start())]);
+        for piece in &pieces {
+            self.append_bezier(&mut path, piece);
+        }
+        self.path(path.trim_end(), style);
+    }
+
+    // Function to draw every cell of a Voronoi diagram.
+    pub fn voronoi<T: Float>(&mut self, diagram: &VoronoiDiagram<T>, style: &Style) {
+        for cell in diagram.cells().iter().filter(|cell| !cell.is_empty()) {
+            self.polygon(cell, style);
+        }
+    }
+
+    // Function to draw a geometry, with points as dots three pixels in radius.
+    pub fn geometry<T: Float>(&mut self, geometry: &Geometry<T>, style: &Style) {
+        match geometry {
+            Geometry::Point(point) => self.point(*point, 3.0, style),
+            Geometry::LineString(path) => self.polyline(path, style),
+            Geometry::Polygon(polygon) => self.polygon_with_holes(polygon, style),
+            Geometry::MultiPoint(points) => {
+                for &point in points {
+                    self.point(point, 3.0, style);
+                }
+            }
+            Geometry::MultiLineString(paths) => {
+                for path in paths {
+                    self.polyline(path, style);
+                }
+            }
+            Geometry::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    self.polygon_with_holes(polygon, style);
+                }
+            }
+            Geometry::GeometryCollection(parts) => {
+                for part in parts {
+                    self.geometry(part, style);
+                }
+            }
+        }
+    }
+
+    // Function to draw a text label with its baseline starting at a point.
+    //
+    // The text is filled with the fill colour, or the stroke colour if there is none.
+    pub fn label<T: Float>(&mut self, point: Point2<T>, text: &str, style: &Style) {
+        let (x, y) = self.project(to_f64(point));
+        let colour = style
+            .fill
+            .as_deref()
+            .or(style.stroke.as_deref())
+            .unwrap_or("black");
+        let mut element = format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\"",
+            number(x),
+            number(y),
+            number(style.font_size),
+            escape(colour)
+        );
+        if style.opacity != 1.0 {
+            write!(element, " opacity=\"{}\"", number(style.opacity)).unwrap();
+        }
+        write!(element, ">{}</text>", escape(text)).unwrap();
+        self.elements.push(element);
+    }
+
+    // Pixels per unit of the plane.
+    fn scale(&self) -> f64 {
+        self.width / self.view.width()
+    }
+
+    fn project(&self, point: Point2<f64>) -> (f64, f64) {
+        let scale = self.scale();
+        (
+            (point.x - self.view.min().x) * scale,
+            (self.view.max().y - point.y) * scale,
+        )
+    }
+
+    fn point_list<T: Float>(&self, points: &[Point2<T>]) -> String {
+        points
+            .iter()
+            .map(|&point| {
+                let (x, y) = self.project(to_f64(point));
+                format!("{},{}", number(x), number(y))
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // Appends a path command and its projected points.
+    fn command(&self, path: &mut String, command: char, points: &[Point2<f64>]) {
+        path.push(command);
+        for (index, &point) in points.iter().enumerate() {
+            let (x, y) = self.project(point);
+            let separator = if index == 0 { "" } else { " " };
+            write!(path, "{}{} {}", separator, number(x), number(y)).unwrap();
+        }
+        path.push(' ');
+    }
+
+    fn move_line(&self, path: &mut String, a: Point2<f64>, b: Point2<f64>) {
+        self.command(path, 'M', &[a]);
+        self.command(path, 'L', &[b]);
+    }
+
+    // Appends a Bézier curve that starts where the path currently ends.
+    fn append_bezier<T: Float>(&self, path: &mut String, curve: &BezierCurve<T>) {
+        let points: Vec<_> = curve.control_points().iter().map(|&p| to_f64(p)).collect();
+        match (curve.is_rational(), points.len()) {
+            (false, 2) => self.command(path, 'L', &points[1..]),
+            (false, 3) => self.command(path, 'Q', &points[1..]),
+            (false, 4) => self.command(path, 'C', &points[1..]),
+            _ => {
+                let tolerance = T::from(FLATTEN_TOLERANCE / self.scale()).unwrap();
+                let polyline = curve.flatten(tolerance);
+                for &point in &polyline[1..] {
+                    self.command(path, 'L', &[to_f64(point)]);
+                }
+            }
+        }
+    }
+
+    fn path(&mut self, d: &str, style: &Style) {
+        self.elements
+            .push(format!("<path d=\"{}\"{}/>", d, attributes(style)));
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (number(self.width), number(self.height));
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+            width, height, width, height
+        )?;
+        for element in &self.elements {
+            writeln!(f, "  {}", element)?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+fn to_f64<T: Float>(point: Point2<T>) -> Point2<f64> {
+    Point2::new(point.x.to_f64().unwrap(), point.y.to_f64().unwrap())
+}
+
+// Formats a number rounded to hundredths, without trailing zeros or a negative zero.
+fn number(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+fn attributes(style: &Style) -> String {
+    let mut text = String::new();
+    match &style.stroke {
+        Some(stroke) => write!(
+            text,
+            " stroke=\"{}\" stroke-width=\"{}\"",
+            escape(stroke),
+            number(style.stroke_width)
+        )
+        .unwrap(),
+        None => text.push_str(" stroke=\"none\""),
+    }
+    write!(
+        text,
+        " fill=\"{}\"",
+        escape(style.fill.as_deref().unwrap_or("none"))
+    )
+    .unwrap();
+    if style.opacity != 1.0 {
+        write!(text, " opacity=\"{}\"", number(style.opacity)).unwrap();
+    }
+    if !style.dash.is_empty() {
+        let dash: Vec<_> = style.dash.iter().map(|&length| number(length)).collect();
+        write!(text, " stroke-dasharray=\"{}\"", dash.join(" ")).unwrap();
+    }
+    text
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod geometry_svg_tests {
+    use super::*;
+    use crate::fields::geometry::convex_hull::monotone_chain;
+    use crate::fields::geometry::io::wkt::from_wkt;
+
+    fn view() -> BoundingBox<f64> {
+        BoundingBox::new(Point2::new(-1.0, -1.0), Point2::new(3.0, 1.0))
+    }
+
+    #[test]
+    fn svg_new_test() {
+        let svg = Svg::new(view(), 400.0).unwrap();
+
+        assert_eq!((svg.width(), svg.height()), (400.0, 200.0));
+        assert!(Svg::new(BoundingBox::from_point(Point2::new(0.0, 0.0)), 100.0).is_err());
+        assert!(Svg::new(view(), 0.0).is_err());
+        assert!(Svg::new(view(), f64::NAN).is_err());
+    }
+
+    #[test]
+    fn svg_snapshot_test() {
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        let light = Style {
+            stroke: Some("#ccc".to_string()),
+            stroke_width: 0.5,
+            dash: vec![2.0, 2.0],
+            ..Style::default()
+        };
+        let filled = Style {
+            stroke: None,
+            fill: Some("red".to_string()),
+            opacity: 0.5,
+            ..Style::default()
+        };
+        svg.grid(1.0, &light);
+        svg.axes(&Style::default());
+        svg.triangle(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            &filled,
+        );
+        svg.segment(
+            Point2::new(-1.0, -1.0),
+            Point2::new(1.0 / 3.0, 0.5),
+            &Style::default(),
+        );
+        svg.point(Point2::new(2.0, 0.5), 2.0, &filled);
+        svg.label(Point2::new(2.0, -0.5), "area = 0.5 & \"ok\"", &filled);
+
+        let expected = concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"400\" height=\"200\" viewBox=\"0 0 400 200\">\n",
+            "  <path d=\"M0 200 L0 0 M100 200 L100 0 M200 200 L200 0 M300 200 L300 0 M400 200 L400 0 ",
+            "M0 200 L400 200 M0 100 L400 100 M0 0 L400 0\" stroke=\"#ccc\" stroke-width=\"0.5\" fill=\"none\" stroke-dasharray=\"2 2\"/>\n",
+            "  <path d=\"M0 100 L400 100 M100 200 L100 0\" stroke=\"black\" stroke-width=\"1\" fill=\"none\"/>\n",
+            "  <polygon points=\"100,100 200,100 100,0\" stroke=\"none\" fill=\"red\" opacity=\"0.5\"/>\n",
+            "  <polyline points=\"0,200 133.33,50\" stroke=\"black\" stroke-width=\"1\" fill=\"none\"/>\n",
+            "  <circle cx=\"300\" cy=\"50\" r=\"2\" stroke=\"none\" fill=\"red\" opacity=\"0.5\"/>\n",
+            "  <text x=\"300\" y=\"150\" font-size=\"12\" fill=\"red\" opacity=\"0.5\">area = 0.5 &amp; &quot;ok&quot;</text>\n",
+            "</svg>\n",
+        );
+        assert_eq!(svg.to_string(), expected);
+        assert_eq!(svg.clone().to_string(), expected);
+    }
+
+    #[test]
+    fn svg_curve_test() {
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        let cubic = BezierCurve::cubic(
+            Point2::new(-1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, -1.0),
+            Point2::new(2.0, 0.0),
+        );
+        svg.bezier(&cubic, &Style::default());
+        assert!(svg
+            .to_string()
+            .contains("d=\"M0 100 C100 0 200 200 300 100\""));
+
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        let rational = BezierCurve::rational(
+            vec![
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(0.0, 1.0),
+            ],
+            vec![1.0, 0.5f64.sqrt(), 1.0],
+        )
+        .unwrap();
+        svg.bezier(&rational, &Style::default());
+        let text = svg.to_string();
+        assert!(text.contains("d=\"M200 100 L"));
+        assert!(text
+            .trim_end()
+            .ends_with("L100 0\" stroke=\"black\" stroke-width=\"1\" fill=\"none\"/>\n</svg>"));
+
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        let spline = BSpline::uniform(
+            2,
+            vec![
+                Point2::new(-1.0, 0.0),
+                Point2::new(0.0, 1.0),
+                Point2::new(1.0, -1.0),
+                Point2::new(2.0, 0.0),
+            ],
+        )
+        .unwrap();
+        svg.bspline(&spline, &Style::default());
+        assert_eq!(svg.to_string().matches('Q').count(), 2);
+    }
+
+    #[test]
+    fn svg_shapes_test() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 0.5),
+            Point2::new(2.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        svg.hull(&points, &monotone_chain(&points), &Style::default());
+        svg.circle(Point2::new(0.0, 0.0), 0.5, &Style::default());
+        svg.voronoi(
+            &VoronoiDiagram::new(&points, view().min(), view().max()),
+            &Style::default(),
+        );
+        let text = svg.to_string();
+
+        assert!(text.contains("<polygon points=\"100,100 300,100 300,0 100,0\""));
+        assert!(text.contains("<circle cx=\"100\" cy=\"100\" r=\"50\""));
+        assert_eq!(text.matches("<polygon").count(), 6);
+    }
+
+    #[test]
+    fn svg_geometry_test() {
+        let geometry = from_wkt::<f64>(
+            "GEOMETRYCOLLECTION (POLYGON ((0 0, 2 0, 2 1, 0 1, 0 0), (0.5 0.25, 1 0.25, 1 0.75, 0.5 0.25)), MULTIPOINT ((0 0), (1 1)), LINESTRING (-1 -1, 0 0))",
+        )
+        .unwrap();
+        let mut svg = Svg::new(view(), 400.0).unwrap();
+        svg.geometry(&geometry, &Style::default());
+        let text = svg.to_string();
+
+        assert!(text.contains(
+            "<path d=\"M100 100 L300 100 L300 0 L100 0 Z M150 75 L200 75 L200 25 Z\" fill-rule=\"evenodd\""
+        ));
+        assert_eq!(text.matches("<circle").count(), 2);
+        assert!(text.contains("<polyline points=\"0,200 100,100\""));
+    }
+}
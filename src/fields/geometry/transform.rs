@@ -0,0 +1,753 @@
+//! This module provides affine transformations in homogeneous coordinates.
+//!
+//! It is part of the `geometry` module. `Transform2` wraps a 3x3 matrix acting on
+//! 2D points and vectors, and `Transform3` wraps a 4x4 matrix acting on 3D points
+//! and vectors. The last row of each matrix is always `[0, ..., 0, 1]`, so
+//! points pick up the translation while vectors (displacements) do not.
+//!
+//! Transforms compose with `*` using the matrix convention: `a * b` applies `b`
+//! first and then `a`. `then` reads in application order instead, so
+//! `a.then(&b)` equals `b * a`. Applying a transform scales areas (2D) or volumes
+//! (3D) by the absolute value of its `determinant`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::Polygon;
+//! use crabmath::fields::geometry::transform::Transform2;
+//! use crabmath::fields::geometry::vector::Vector2;
+//! use std::f64::consts::FRAC_PI_2;
+//!
+//! let rotate = Transform2::rotation_about(Point2::new(1.0, 1.0), FRAC_PI_2);
+//! let moved = rotate.apply_point(Point2::new(2.0, 1.0));
+//!
+//! assert!((moved.x - 1.0).abs() < 1e-12);
+//! assert!((moved.y - 2.0).abs() < 1e-12);
+//!
+//! let square = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(1.0, 0.0),
+//!     Point2::new(1.0, 1.0),
+//!     Point2::new(0.0, 1.0),
+//! ]);
+//! let stretch = Transform2::scaling(2.0, 3.0).then(&Transform2::translation(Vector2::new(5.0, 0.0)));
+//!
+//! assert_eq!(stretch.apply_polygon(&square).area(), 6.0);
+//! assert_eq!(stretch.determinant(), 6.0);
+//! assert_eq!(stretch.inverse().unwrap().apply_point(Point2::new(7.0, 3.0)), Point2::new(1.0, 1.0));
+//! ```
+//!
+//! # Functions
+//!
+//! - `identity`: Constructs the identity transform.
+//! - `from_matrix`: Constructs a transform from a homogeneous matrix.
+//! - `matrix`: Gets the homogeneous matrix.
+//! - `translation`: Constructs a translation.
+//! - `rotation`: Constructs a rotation about the origin (2D) or an axis through the origin (3D).
+//! - `rotation_about`: Constructs a rotation about a point (2D) or an arbitrary axis (3D).
+//! - `rotation_x` / `rotation_y` / `rotation_z`: Constructs a rotation about a coordinate axis (3D).
+//! - `scaling`: Constructs a scaling about the origin.
+//! - `scaling_about`: Constructs a scaling about a point.
+//! - `shear`: Constructs a shear.
+//! - `reflection`: Constructs a reflection across a line (2D) or a plane (3D).
+//! - `then`: Composes with a transform applied afterwards.
+//! - `determinant`: Gets the determinant of the linear part.
+//! - `inverse`: Gets the inverse transform, if one exists.
+//! - `apply_point`: Transforms a point.
+//! - `apply_vector`: Transforms a vector, ignoring translation.
+//! - `apply_polygon`: Transforms every vertex of a polygon (2D).
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::point::{Point2, Point3};
+use crate::fields::geometry::polygon::Polygon;
+use crate::fields::geometry::vector::{Vector2, Vector3};
+use num_traits::Float;
+use std::ops::Mul;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2<T> {
+    matrix: [[T; 3]; 3],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3<T> {
+    matrix: [[T; 4]; 4],
+}
+
+impl<T> Transform2<T>
+where
+    T: Float,
+{
+    // Function to create the identity transform.
+    pub fn identity() -> Self {
+        Self::from_linear([[T::one(), T::zero()], [T::zero(), T::one()]])
+    }
+
+    // Function to create a transform from a 3x3 homogeneous matrix.
+    pub fn from_matrix(matrix: [[T; 3]; 3]) -> Result<Self, GeometryError> {
+        if matrix[2] != [T::zero(), T::zero(), T::one()] {
+            return Err(GeometryError::new(
+                "The last row of an affine matrix must be [0, 0, 1]",
+            ));
+        }
+        Ok(Transform2 { matrix })
+    }
+
+    // Function to get the 3x3 homogeneous matrix.
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        self.matrix
+    }
+
+    // Function to create a translation.
+    pub fn translation(offset: Vector2<T>) -> Self {
+        let mut transform = Self::identity();
+        transform.matrix[0][2] = offset.x;
+        transform.matrix[1][2] = offset.y;
+        transform
+    }
+
+    // Function to create a counter-clockwise rotation about the origin, in radians.
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_linear([[cos, -sin], [sin, cos]])
+    }
+
+    // Function to create a counter-clockwise rotation about `center`, in radians.
+    pub fn rotation_about(center: Point2<T>, angle: T) -> Self {
+        Self::about(center, Self::rotation(angle))
+    }
+
+    // Function to create a scaling about the origin.
+    pub fn scaling(sx: T, sy: T) -> Self {
+        Self::from_linear([[sx, T::zero()], [T::zero(), sy]])
+    }
+
+    // Function to create a scaling about `center`.
+    pub fn scaling_about(center: Point2<T>, sx: T, sy: T) -> Self {
+        Self::about(center, Self::scaling(sx, sy))
+    }
+
+    // Function to create a shear, mapping (x, y) to (x + shx * y, y + shy * x).
+    pub fn shear(shx: T, shy: T) -> Self {
+        Self::from_linear([[T::one(), shx], [shy, T::one()]])
+    }
+
+    // Function to create a reflection across the line through `point` along `direction`.
+    //
+    // Returns None when `direction` is the zero vector.
+    pub fn reflection(point: Point2<T>, direction: Vector2<T>) -> Option<Self> {
+        let d = direction.normalize()?;
+        let two = T::one() + T::one();
+        // Householder-style reflection: 2 d d^T - I.
+        let linear = [
+            [two * d.x * d.x - T::one(), two * d.x * d.y],
+            [two * d.x * d.y, two * d.y * d.y - T::one()],
+        ];
+        Some(Self::about(point, Self::from_linear(linear)))
+    }
+
+    // Function to compose with `next`, which is applied after `self`.
+    pub fn then(&self, next: &Self) -> Self {
+        *next * *self
+    }
+
+    // Function to get the determinant of the linear part (the area scale factor, signed).
+    pub fn determinant(&self) -> T {
+        let m = &self.matrix;
+        m[0][0] * m[1][1] - m[0][1] * m[1][0]
+    }
+
+    // Function to get the inverse transform, or None if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() || !det.is_finite() {
+            return None;
+        }
+        let m = &self.matrix;
+        let linear = [
+            [m[1][1] / det, -m[0][1] / det],
+            [-m[1][0] / det, m[0][0] / det],
+        ];
+        let mut inverse = Self::from_linear(linear);
+        let t = inverse.apply_vector(Vector2::new(m[0][2], m[1][2]));
+        inverse.matrix[0][2] = -t.x;
+        inverse.matrix[1][2] = -t.y;
+        Some(inverse)
+    }
+
+    // Function to transform a point.
+    pub fn apply_point(&self, point: Point2<T>) -> Point2<T> {
+        let m = &self.matrix;
+        Point2::new(
+            m[0][0] * point.x + m[0][1] * point.y + m[0][2],
+            m[1][0] * point.x + m[1][1] * point.y + m[1][2],
+        )
+    }
+
+    // Function to transform a vector, which ignores the translation.
+    pub fn apply_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        let m = &self.matrix;
+        Vector2::new(
+            m[0][0] * vector.x + m[0][1] * vector.y,
+            m[1][0] * vector.x + m[1][1] * vector.y,
+        )
+    }
+
+    // Function to transform every vertex of a polygon.
+    //
+    // The area of the result is the original area times `determinant().abs()`. A
+    // negative determinant (a reflection) also reverses the winding order.
+    pub fn apply_polygon(&self, polygon: &Polygon<T>) -> Polygon<T> {
+        Polygon::new(
+            polygon
+                .vertices()
+                .iter()
+                .map(|vertex| self.apply_point(*vertex))
+                .collect(),
+        )
+    }
+
+    fn from_linear(linear: [[T; 2]; 2]) -> Self {
+        Transform2 {
+            matrix: [
+                [linear[0][0], linear[0][1], T::zero()],
+                [linear[1][0], linear[1][1], T::zero()],
+                [T::zero(), T::zero(), T::one()],
+            ],
+        }
+    }
+
+    // Conjugates `transform` so that it acts about `center` instead of the origin.
+    fn about(center: Point2<T>, transform: Self) -> Self {
+        let offset = center.to_vector();
+        Self::translation(-offset)
+            .then(&transform)
+            .then(&Self::translation(offset))
+    }
+}
+
+impl<T> Transform3<T>
+where
+    T: Float,
+{
+    // Function to create the identity transform.
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::from_linear([[o, z, z], [z, o, z], [z, z, o]])
+    }
+
+    // Function to create a transform from a 4x4 homogeneous matrix.
+    pub fn from_matrix(matrix: [[T; 4]; 4]) -> Result<Self, GeometryError> {
+        if matrix[3] != [T::zero(), T::zero(), T::zero(), T::one()] {
+            return Err(GeometryError::new(
+                "The last row of an affine matrix must be [0, 0, 0, 1]",
+            ));
+        }
+        Ok(Transform3 { matrix })
+    }
+
+    // Function to get the 4x4 homogeneous matrix.
+    pub fn matrix(&self) -> [[T; 4]; 4] {
+        self.matrix
+    }
+
+    // Function to create a translation.
+    pub fn translation(offset: Vector3<T>) -> Self {
+        let mut transform = Self::identity();
+        transform.matrix[0][3] = offset.x;
+        transform.matrix[1][3] = offset.y;
+        transform.matrix[2][3] = offset.z;
+        transform
+    }
+
+    // Function to create a right-handed rotation about an axis through the origin, in radians.
+    //
+    // Returns None when `axis` is the zero vector.
+    pub fn rotation(axis: Vector3<T>, angle: T) -> Option<Self> {
+        let u = axis.normalize()?;
+        let (sin, cos) = angle.sin_cos();
+        let k = T::one() - cos;
+        // Rodrigues' rotation formula in matrix form.
+        Some(Self::from_linear([
+            [
+                cos + u.x * u.x * k,
+                u.x * u.y * k - u.z * sin,
+                u.x * u.z * k + u.y * sin,
+            ],
+            [
+                u.y * u.x * k + u.z * sin,
+                cos + u.y * u.y * k,
+                u.y * u.z * k - u.x * sin,
+            ],
+            [
+                u.z * u.x * k - u.y * sin,
+                u.z * u.y * k + u.x * sin,
+                cos + u.z * u.z * k,
+            ],
+        ]))
+    }
+
+    // Function to create a rotation about the axis through `point` along `axis`, in radians.
+    //
+    // Returns None when `axis` is the zero vector.
+    pub fn rotation_about(point: Point3<T>, axis: Vector3<T>, angle: T) -> Option<Self> {
+        Some(Self::about(point, Self::rotation(axis, angle)?))
+    }
+
+    // Function to create a rotation about the x axis, in radians.
+    pub fn rotation_x(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (T::one(), T::zero());
+        Self::from_linear([[o, z, z], [z, cos, -sin], [z, sin, cos]])
+    }
+
+    // Function to create a rotation about the y axis, in radians.
+    pub fn rotation_y(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (T::one(), T::zero());
+        Self::from_linear([[cos, z, sin], [z, o, z], [-sin, z, cos]])
+    }
+
+    // Function to create a rotation about the z axis, in radians.
+    pub fn rotation_z(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (T::one(), T::zero());
+        Self::from_linear([[cos, -sin, z], [sin, cos, z], [z, z, o]])
+    }
+
+    // Function to create a scaling about the origin.
+    pub fn scaling(sx: T, sy: T, sz: T) -> Self {
+        let z = T::zero();
+        Self::from_linear([[sx, z, z], [z, sy, z], [z, z, sz]])
+    }
+
+    // Function to create a scaling about `center`.
+    pub fn scaling_about(center: Point3<T>, sx: T, sy: T, sz: T) -> Self {
+        Self::about(center, Self::scaling(sx, sy, sz))
+    }
+
+    // Function to create a shear. Each factor `ab` adds `ab` times coordinate b to coordinate a,
+    // so x' = x + xy * y + xz * z, and so on.
+    pub fn shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        let o = T::one();
+        Self::from_linear([[o, xy, xz], [yx, o, yz], [zx, zy, o]])
+    }
+
+    // Function to create a reflection across the plane through `point` with normal `normal`.
+    //
+    // Returns None when `normal` is the zero vector.
+    pub fn reflection(point: Point3<T>, normal: Vector3<T>) -> Option<Self> {
+        let n = normal.normalize()?;
+        let two = T::one() + T::one();
+        let o = T::one();
+        // Householder reflection: I - 2 n n^T.
+        let linear = [
+            [o - two * n.x * n.x, -two * n.x * n.y, -two * n.x * n.z],
+            [-two * n.y * n.x, o - two * n.y * n.y, -two * n.y * n.z],
+            [-two * n.z * n.x, -two * n.z * n.y, o - two * n.z * n.z],
+        ];
+        Some(Self::about(point, Self::from_linear(linear)))
+    }
+
+    // Function to compose with `next`, which is applied after `self`.
+    pub fn then(&self, next: &Self) -> Self {
+        *next * *self
+    }
+
+    // Function to get the determinant of the linear part (the volume scale factor, signed).
+    pub fn determinant(&self) -> T {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    // Function to get the inverse transform, or None if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() || !det.is_finite() {
+            return None;
+        }
+        let m = &self.matrix;
+        // The inverse of the linear part is the adjugate divided by the determinant.
+        let linear = [
+            [
+                (m[1][1] * m[2][2] - m[1][2] * m[2][1]) / det,
+                (m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det,
+                (m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det,
+            ],
+            [
+                (m[1][2] * m[2][0] - m[1][0] * m[2][2]) / det,
+                (m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det,
+                (m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det,
+            ],
+            [
+                (m[1][0] * m[2][1] - m[1][1] * m[2][0]) / det,
+                (m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det,
+                (m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det,
+            ],
+        ];
+        let mut inverse = Self::from_linear(linear);
+        let t = inverse.apply_vector(Vector3::new(m[0][3], m[1][3], m[2][3]));
+        inverse.matrix[0][3] = -t.x;
+        inverse.matrix[1][3] = -t.y;
+        inverse.matrix[2][3] = -t.z;
+        Some(inverse)
+    }
+
+    // Function to transform a point.
+    pub fn apply_point(&self, point: Point3<T>) -> Point3<T> {
+        let m = &self.matrix;
+        Point3::new(
+            m[0][0] * point.x + m[0][1] * point.y + m[0][2] * point.z + m[0][3],
+            m[1][0] * point.x + m[1][1] * point.y + m[1][2] * point.z + m[1][3],
+            m[2][0] * point.x + m[2][1] * point.y + m[2][2] * point.z + m[2][3],
+        )
+    }
+
+    // Function to transform a vector, which ignores the translation.
+    pub fn apply_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let m = &self.matrix;
+        Vector3::new(
+            m[0][0] * vector.x + m[0][1] * vector.y + m[0][2] * vector.z,
+            m[1][0] * vector.x + m[1][1] * vector.y + m[1][2] * vector.z,
+            m[2][0] * vector.x + m[2][1] * vector.y + m[2][2] * vector.z,
+        )
+    }
+
+    fn from_linear(linear: [[T; 3]; 3]) -> Self {
+        let z = T::zero();
+        Transform3 {
+            matrix: [
+                [linear[0][0], linear[0][1], linear[0][2], z],
+                [linear[1][0], linear[1][1], linear[1][2], z],
+                [linear[2][0], linear[2][1], linear[2][2], z],
+                [z, z, z, T::one()],
+            ],
+        }
+    }
+
+    // Conjugates `transform` so that it acts about `center` instead of the origin.
+    fn about(center: Point3<T>, transform: Self) -> Self {
+        let offset = center.to_vector();
+        Self::translation(-offset)
+            .then(&transform)
+            .then(&Self::translation(offset))
+    }
+}
+
+impl<T: Float> Mul for Transform2<T> {
+    type Output = Self;
+
+    // Matrix product: the result applies `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut matrix = [[T::zero(); 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).fold(T::zero(), |sum, k| {
+                    sum + self.matrix[i][k] * rhs.matrix[k][j]
+                });
+            }
+        }
+        Transform2 { matrix }
+    }
+}
+
+impl<T: Float> Mul for Transform3<T> {
+    type Output = Self;
+
+    // Matrix product: the result applies `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut matrix = [[T::zero(); 4]; 4];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..4).fold(T::zero(), |sum, k| {
+                    sum + self.matrix[i][k] * rhs.matrix[k][j]
+                });
+            }
+        }
+        Transform3 { matrix }
+    }
+}
+
+#[cfg(test)]
+mod geometry_transform_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    const EPSILON: f64 = 1e-12;
+
+    fn assert_point2_close(a: Point2<f64>, b: Point2<f64>) {
+        assert!(a.distance(b) < EPSILON, "{} != {}", a, b);
+    }
+
+    fn assert_point3_close(a: Point3<f64>, b: Point3<f64>) {
+        assert!(a.distance(b) < EPSILON, "{} != {}", a, b);
+    }
+
+    fn assert_transform2_close(a: Transform2<f64>, b: Transform2<f64>) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((a.matrix()[i][j] - b.matrix()[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    fn assert_transform3_close(a: Transform3<f64>, b: Transform3<f64>) {
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((a.matrix()[i][j] - b.matrix()[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    fn unit_square() -> Polygon<f64> {
+        Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ])
+    }
+
+    #[test]
+    fn transform2_translation_test() {
+        let transform = Transform2::translation(Vector2::new(2.0, -1.0));
+
+        assert_eq!(
+            transform.apply_point(Point2::new(1.0, 1.0)),
+            Point2::new(3.0, 0.0)
+        );
+        assert_eq!(
+            transform.apply_vector(Vector2::new(1.0, 1.0)),
+            Vector2::new(1.0, 1.0)
+        );
+        assert_eq!(transform.determinant(), 1.0);
+    }
+
+    #[test]
+    fn transform2_rotation_test() {
+        let transform = Transform2::rotation(FRAC_PI_2);
+        assert_point2_close(
+            transform.apply_point(Point2::new(1.0, 0.0)),
+            Point2::new(0.0, 1.0),
+        );
+
+        let transform = Transform2::rotation_about(Point2::new(1.0, 1.0), PI);
+        assert_point2_close(
+            transform.apply_point(Point2::new(0.0, 0.0)),
+            Point2::new(2.0, 2.0),
+        );
+        assert_point2_close(
+            transform.apply_point(Point2::new(1.0, 1.0)),
+            Point2::new(1.0, 1.0),
+        );
+
+        let square = transform.apply_polygon(&unit_square());
+        assert!((square.area() - 1.0).abs() < EPSILON);
+        assert!(square.is_counter_clockwise());
+    }
+
+    #[test]
+    fn transform2_scaling_test() {
+        let transform = Transform2::scaling(2.0, 3.0);
+        assert_eq!(
+            transform.apply_point(Point2::new(1.0, 1.0)),
+            Point2::new(2.0, 3.0)
+        );
+        assert_eq!(transform.apply_polygon(&unit_square()).area(), 6.0);
+
+        let transform = Transform2::scaling_about(Point2::new(1.0, 1.0), 2.0, 2.0);
+        assert_eq!(
+            transform.apply_point(Point2::new(1.0, 1.0)),
+            Point2::new(1.0, 1.0)
+        );
+        assert_eq!(
+            transform.apply_point(Point2::new(2.0, 1.0)),
+            Point2::new(3.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn transform2_shear_test() {
+        let transform = Transform2::shear(1.0, 0.0);
+
+        assert_eq!(
+            transform.apply_point(Point2::new(0.0, 2.0)),
+            Point2::new(2.0, 2.0)
+        );
+        // A shear preserves area.
+        assert_eq!(transform.apply_polygon(&unit_square()).area(), 1.0);
+    }
+
+    #[test]
+    fn transform2_reflection_test() {
+        let transform =
+            Transform2::reflection(Point2::new(0.0, 1.0), Vector2::new(1.0, 0.0)).unwrap();
+
+        assert_point2_close(
+            transform.apply_point(Point2::new(3.0, 3.0)),
+            Point2::new(3.0, -1.0),
+        );
+        assert!((transform.determinant() + 1.0).abs() < EPSILON);
+
+        let square = transform.apply_polygon(&unit_square());
+        assert!((square.area() - 1.0).abs() < EPSILON);
+        assert!(!square.is_counter_clockwise());
+
+        let diagonal = Transform2::reflection(Point2::origin(), Vector2::new(1.0, 1.0)).unwrap();
+        assert_point2_close(
+            diagonal.apply_point(Point2::new(2.0, 0.0)),
+            Point2::new(0.0, 2.0),
+        );
+
+        assert!(Transform2::<f64>::reflection(Point2::origin(), Vector2::zero()).is_none());
+    }
+
+    #[test]
+    fn transform2_composition_test() {
+        let scale = Transform2::scaling(2.0, 2.0);
+        let translate = Transform2::translation(Vector2::new(1.0, 0.0));
+
+        let point = Point2::new(1.0, 1.0);
+        assert_eq!(
+            scale.then(&translate).apply_point(point),
+            Point2::new(3.0, 2.0)
+        );
+        assert_eq!(
+            translate.then(&scale).apply_point(point),
+            Point2::new(4.0, 2.0)
+        );
+        assert_eq!(translate * scale, scale.then(&translate));
+    }
+
+    #[test]
+    fn transform2_inverse_test() {
+        let transform = Transform2::rotation_about(Point2::new(3.0, -2.0), 0.7)
+            .then(&Transform2::shear(0.5, 0.25))
+            .then(&Transform2::scaling(2.0, -3.0));
+        let inverse = transform.inverse().unwrap();
+
+        assert_transform2_close(transform * inverse, Transform2::identity());
+        assert_transform2_close(inverse * transform, Transform2::identity());
+        assert!(Transform2::scaling(0.0, 1.0).inverse().is_none());
+    }
+
+    #[test]
+    fn transform2_from_matrix_test() {
+        let matrix = [[1.0, 0.0, 5.0], [0.0, 1.0, 6.0], [0.0, 0.0, 1.0]];
+        let transform = Transform2::from_matrix(matrix).unwrap();
+
+        assert_eq!(transform, Transform2::translation(Vector2::new(5.0, 6.0)));
+        assert_eq!(transform.matrix(), matrix);
+        assert!(
+            Transform2::from_matrix([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]).is_err()
+        );
+    }
+
+    #[test]
+    fn transform3_translation_test() {
+        let transform = Transform3::translation(Vector3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(
+            transform.apply_point(Point3::origin()),
+            Point3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            transform.apply_vector(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn transform3_rotation_test() {
+        let about_z = Transform3::rotation(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2).unwrap();
+        assert_transform3_close(about_z, Transform3::rotation_z(FRAC_PI_2));
+        assert_point3_close(
+            about_z.apply_point(Point3::new(1.0, 0.0, 0.0)),
+            Point3::new(0.0, 1.0, 0.0),
+        );
+
+        let about_x = Transform3::rotation_x(FRAC_PI_2);
+        assert_point3_close(
+            about_x.apply_point(Point3::new(0.0, 1.0, 0.0)),
+            Point3::new(0.0, 0.0, 1.0),
+        );
+
+        let about_y = Transform3::rotation_y(FRAC_PI_2);
+        assert_point3_close(
+            about_y.apply_point(Point3::new(0.0, 0.0, 1.0)),
+            Point3::new(1.0, 0.0, 0.0),
+        );
+
+        // A third of a turn about (1, 1, 1) cycles the coordinate axes.
+        let diagonal = Transform3::rotation(Vector3::new(1.0, 1.0, 1.0), 2.0 * PI / 3.0).unwrap();
+        assert_point3_close(
+            diagonal.apply_point(Point3::new(1.0, 0.0, 0.0)),
+            Point3::new(0.0, 1.0, 0.0),
+        );
+        assert!((diagonal.determinant() - 1.0).abs() < EPSILON);
+
+        let shifted =
+            Transform3::rotation_about(Point3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0), PI)
+                .unwrap();
+        assert_point3_close(
+            shifted.apply_point(Point3::origin()),
+            Point3::new(2.0, 2.0, 0.0),
+        );
+
+        assert!(Transform3::rotation(Vector3::zero(), 1.0).is_none());
+    }
+
+    #[test]
+    fn transform3_scaling_shear_test() {
+        let scale = Transform3::scaling(2.0, 3.0, 4.0);
+        assert_eq!(scale.determinant(), 24.0);
+
+        let scale = Transform3::scaling_about(Point3::new(1.0, 1.0, 1.0), 2.0, 2.0, 2.0);
+        assert_eq!(
+            scale.apply_point(Point3::new(2.0, 1.0, 1.0)),
+            Point3::new(3.0, 1.0, 1.0)
+        );
+
+        let shear = Transform3::shear(1.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+        assert_eq!(
+            shear.apply_point(Point3::new(0.0, 1.0, 0.0)),
+            Point3::new(1.0, 1.0, 2.0)
+        );
+        assert_eq!(shear.determinant(), 1.0);
+    }
+
+    #[test]
+    fn transform3_reflection_test() {
+        let transform =
+            Transform3::reflection(Point3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0))
+                .unwrap();
+
+        assert_point3_close(
+            transform.apply_point(Point3::new(1.0, 2.0, 3.0)),
+            Point3::new(1.0, 2.0, -1.0),
+        );
+        assert!((transform.determinant() + 1.0).abs() < EPSILON);
+        assert!(Transform3::<f64>::reflection(Point3::origin(), Vector3::zero()).is_none());
+    }
+
+    #[test]
+    fn transform3_inverse_test() {
+        let transform = Transform3::rotation_about(
+            Point3::new(1.0, -2.0, 0.5),
+            Vector3::new(1.0, 2.0, 3.0),
+            0.9,
+        )
+        .unwrap()
+        .then(&Transform3::shear(0.5, 0.0, 0.25, 0.0, 0.0, 1.0))
+        .then(&Transform3::scaling(2.0, -1.0, 0.5));
+        let inverse = transform.inverse().unwrap();
+
+        assert_transform3_close(transform * inverse, Transform3::identity());
+        assert_transform3_close(inverse * transform, Transform3::identity());
+        assert!(Transform3::scaling(1.0, 0.0, 1.0).inverse().is_none());
+        assert!(Transform3::from_matrix([[1.0; 4]; 4]).is_err());
+    }
+}
@@ -0,0 +1,684 @@
+//! This module provides triangulation of simple polygons with holes.
+//!
+//! It is part of the `geometry` module. Both triangulations return triangles as
+//! index triples wound counter-clockwise. The indices refer to the vertices of
+//! the polygon in the order returned by `triangulation_vertices`: the exterior
+//! ring first, followed by each hole in turn, each ring in its own order.
+//!
+//! `ear_clipping` is the fast option. Holes are first joined to the exterior by
+//! bridge edges, and ears are then cut from the resulting ring. The triangles
+//! can be thin. `constrained_delaunay` starts from the ear clipping result and
+//! flips interior edges until every triangle is locally Delaunay. Polygon edges
+//! are never flipped, which gives the constrained Delaunay triangulation and
+//! maximises the smallest angle among all triangulations of the polygon.
+//!
+//! Repeated consecutive vertices are skipped, and vertices lying on a straight
+//! edge are still used by the triangles. Rings with fewer than three distinct
+//! vertices or no area produce no triangles. The areas of the triangles add up
+//! to the area of the polygon. Holes may touch the exterior or each other at
+//! points or along edges, but rings that cross or overlap cannot be
+//! triangulated and return an error.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::polygon::{Polygon, PolygonWithHoles};
+//! use crabmath::fields::geometry::triangulation::{
+//!     constrained_delaunay, ear_clipping, triangulation_vertices,
+//! };
+//!
+//! let square = Polygon::new(vec![
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(3.0, 0.0),
+//!     Point2::new(3.0, 3.0),
+//!     Point2::new(0.0, 3.0),
+//! ]);
+//! let hole = Polygon::new(vec![
+//!     Point2::new(1.0, 1.0),
+//!     Point2::new(2.0, 1.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(1.0, 2.0),
+//! ]);
+//! let frame = PolygonWithHoles::new(square, vec![hole]);
+//!
+//! let vertices = triangulation_vertices(&frame);
+//! let triangles = ear_clipping(&frame).unwrap();
+//! let area: f64 = triangles
+//!     .iter()
+//!     .map(|&[a, b, c]| Polygon::new(vec![vertices[a], vertices[b], vertices[c]]).area())
+//!     .sum();
+//!
+//! assert_eq!(triangles.len(), 8);
+//! assert_eq!(area, 8.0);
+//! assert_eq!(constrained_delaunay(&frame).unwrap().len(), 8);
+//! ```
+//!
+//! # Functions
+//!
+//! - `triangulation_vertices`: Gets the vertices that triangle indices refer to.
+//! - `ear_clipping`: Triangulates a polygon with holes by ear clipping.
+//! - `constrained_delaunay`: Computes the constrained Delaunay triangulation of a polygon with holes.
+
+use crate::fields::geometry::error::GeometryError;
+use crate::fields::geometry::intersection::{segment_intersection, SegmentIntersection};
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::PolygonWithHoles;
+use crate::fields::geometry::predicates::{incircle, orient2d};
+use num_traits::Float;
+use std::collections::{HashMap, HashSet};
+
+// Function to get the vertices of a polygon with holes in triangulation index order.
+pub fn triangulation_vertices<T: Float>(polygon: &PolygonWithHoles<T>) -> Vec<Point2<T>> {
+    let mut vertices = polygon.exterior().vertices().to_vec();
+    for hole in polygon.holes() {
+        vertices.extend_from_slice(hole.vertices());
+    }
+    vertices
+}
+
+// Function to triangulate a polygon with holes by ear clipping.
+pub fn ear_clipping<T: Float>(
+    polygon: &PolygonWithHoles<T>,
+) -> Result<Vec<[usize; 3]>, GeometryError> {
+    let vertices = triangulation_vertices(polygon);
+    let (exterior, holes) = rings(polygon, &vertices);
+    if exterior.len() < 3 {
+        return Ok(Vec::new());
+    }
+    let ring = bridge_holes(&vertices, exterior, holes);
+    clip_ears(&vertices, ring)
+}
+
+// Function to compute the constrained Delaunay triangulation of a polygon with holes.
+pub fn constrained_delaunay<T: Float>(
+    polygon: &PolygonWithHoles<T>,
+) -> Result<Vec<[usize; 3]>, GeometryError> {
+    let vertices = triangulation_vertices(polygon);
+    let mut triangles = ear_clipping(polygon)?;
+    let (exterior, holes) = rings(polygon, &vertices);
+    let mut constraints = HashSet::new();
+    for ring in std::iter::once(&exterior).chain(&holes) {
+        for i in 0..ring.len() {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            constraints.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    // Directed edge to the triangle that has it on its boundary.
+    let mut owner: HashMap<(usize, usize), usize> = HashMap::new();
+    for (index, triangle) in triangles.iter().enumerate() {
+        for edge in triangle_edges(triangle) {
+            owner.insert(edge, index);
+        }
+    }
+
+    // Lawson's flip algorithm on the unconstrained edges.
+    let mut stack: Vec<(usize, usize)> = owner.keys().copied().collect();
+    while let Some((a, b)) = stack.pop() {
+        if constraints.contains(&(a.min(b), a.max(b))) {
+            continue;
+        }
+        let (Some(&first), Some(&second)) = (owner.get(&(a, b)), owner.get(&(b, a))) else {
+            continue;
+        };
+        let c = third(&triangles[first], a, b);
+        let d = third(&triangles[second], b, a);
+        let [pa, pb, pc, pd] = [a, b, c, d].map(|i| vertices[i]);
+        if incircle(pa, pb, pc, pd) <= 0f64
+            || orient2d(pc, pa, pd) <= 0f64
+            || orient2d(pd, pb, pc) <= 0f64
+        {
+            continue;
+        }
+        owner.remove(&(a, b));
+        owner.remove(&(b, a));
+        triangles[first] = [c, a, d];
+        triangles[second] = [d, b, c];
+        for (index, triangle) in [(first, triangles[first]), (second, triangles[second])] {
+            for edge in triangle_edges(&triangle) {
+                owner.insert(edge, index);
+            }
+        }
+        stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+    }
+    Ok(triangles)
+}
+
+fn triangle_edges(triangle: &[usize; 3]) -> [(usize, usize); 3] {
+    let [a, b, c] = *triangle;
+    [(a, b), (b, c), (c, a)]
+}
+
+fn third(triangle: &[usize; 3], a: usize, b: usize) -> usize {
+    *triangle.iter().find(|&&i| i != a && i != b).unwrap()
+}
+
+// Index rings for the exterior, wound counter-clockwise, and the holes, wound
+// clockwise, with repeated consecutive vertices removed. Degenerate holes are dropped.
+fn rings<T: Float>(
+    polygon: &PolygonWithHoles<T>,
+    vertices: &[Point2<T>],
+) -> (Vec<usize>, Vec<Vec<usize>>) {
+    let mut offset = 0;
+    let mut result = Vec::new();
+    for (position, ring) in std::iter::once(polygon.exterior())
+        .chain(polygon.holes())
+        .enumerate()
+    {
+        let mut indices: Vec<usize> = (offset..offset + ring.len()).collect();
+        offset += ring.len();
+        indices.dedup_by(|a, b| vertices[*a] == vertices[*b]);
+        while indices.len() > 1 && vertices[indices[0]] == vertices[*indices.last().unwrap()] {
+            indices.pop();
+        }
+        let area = signed_area(vertices, &indices);
+        let counter_clockwise = position == 0;
+        if (area > T::zero()) != counter_clockwise {
+            indices.reverse();
+        }
+        if indices.len() < 3 || area.is_zero() {
+            indices.clear();
+        }
+        result.push(indices);
+    }
+    let exterior = result.remove(0);
+    result.retain(|hole| !hole.is_empty());
+    (exterior, result)
+}
+
+fn signed_area<T: Float>(vertices: &[Point2<T>], ring: &[usize]) -> T {
+    let n = ring.len();
+    let twice_area = (0..n).fold(T::zero(), |sum, i| {
+        let (a, b) = (vertices[ring[i]], vertices[ring[(i + 1) % n]]);
+        sum + (a.x * b.y - b.x * a.y)
+    });
+    twice_area / (T::one() + T::one())
+}
+
+// Joins every hole to the outer ring with a pair of coincident bridge edges.
+//
+// Holes are handled from the rightmost one leftwards. Each is bridged from its
+// rightmost vertex to the nearest ring vertex that can see it, where seeing means
+// the bridge crosses no edge of the ring or of any hole and enters the ring vertex
+// through its interior angle.
+fn bridge_holes<T: Float>(
+    vertices: &[Point2<T>],
+    mut ring: Vec<usize>,
+    mut holes: Vec<Vec<usize>>,
+) -> Vec<usize> {
+    let rightmost = |hole: &Vec<usize>| {
+        (0..hole.len())
+            .max_by(|&i, &j| {
+                let (p, q) = (vertices[hole[i]], vertices[hole[j]]);
+                p.x.partial_cmp(&q.x)
+                    .unwrap()
+                    .then(p.y.partial_cmp(&q.y).unwrap())
+            })
+            .unwrap()
+    };
+    holes.sort_by(|a, b| {
+        let (p, q) = (vertices[a[rightmost(a)]], vertices[b[rightmost(b)]]);
+        q.x.partial_cmp(&p.x).unwrap()
+    });
+
+    for (k, hole) in holes.iter().enumerate() {
+        if let Some(merged) = splice_touching(vertices, &ring, hole) {
+            ring = merged;
+            continue;
+        }
+        let start = rightmost(hole);
+        let m = vertices[hole[start]];
+        let mut candidates: Vec<usize> = (0..ring.len()).collect();
+        candidates.sort_by(|&i, &j| {
+            let (di, dj) = (
+                m.distance_squared(vertices[ring[i]]),
+                m.distance_squared(vertices[ring[j]]),
+            );
+            di.partial_cmp(&dj).unwrap()
+        });
+        let visible = candidates.into_iter().find(|&i| {
+            let n = ring.len();
+            let v = vertices[ring[i]];
+            in_cone(
+                vertices[ring[(i + n - 1) % n]],
+                v,
+                vertices[ring[(i + 1) % n]],
+                m,
+            ) && !crosses(vertices, &ring, v, m)
+                && holes[k..]
+                    .iter()
+                    .all(|other| !crosses(vertices, other, v, m))
+        });
+        // Fall back to the nearest vertex if rounding hides every candidate.
+        let i = visible.unwrap_or(0);
+        let mut merged = ring[..=i].to_vec();
+        merged.extend(hole[start..].iter().chain(&hole[..=start]));
+        merged.extend_from_slice(&ring[i..]);
+        ring = merged;
+    }
+    ring
+}
+
+// Joins a hole that touches the ring at a point straight into the ring there.
+//
+// A bridge to such a hole could run along one of its edges, so instead the ring
+// walks around the hole when it reaches the shared point. The point is either a
+// ring vertex or lies on a ring edge, which is then split at it.
+fn splice_touching<T: Float>(
+    vertices: &[Point2<T>],
+    ring: &[usize],
+    hole: &[usize],
+) -> Option<Vec<usize>> {
+    let (n, m) = (ring.len(), hole.len());
+    for s in 0..m {
+        let h = vertices[hole[s]];
+        let (before, after) = (vertices[hole[(s + m - 1) % m]], vertices[hole[(s + 1) % m]]);
+        for i in 0..n {
+            let (a, b) = (vertices[ring[i]], vertices[ring[(i + 1) % n]]);
+            let (corner, inserted) = if a == h {
+                (vertices[ring[(i + n - 1) % n]], false)
+            } else if b != h && orient2d(a, b, h) == 0f64 && (h - a).dot(h - b) < T::zero() {
+                (a, true)
+            } else {
+                continue;
+            };
+            // The hole must leave the shared point into the ring's interior angle,
+            // though its edges may run along the ring's.
+            if !in_closed_cone(corner, h, b, before) || !in_closed_cone(corner, h, b, after) {
+                continue;
+            }
+            let mut merged = ring[..=i].to_vec();
+            if inserted {
+                merged.push(hole[s]);
+            }
+            merged.extend(hole[s + 1..].iter().chain(&hole[..=s]));
+            merged.extend_from_slice(&ring[i + 1..]);
+            return Some(merged);
+        }
+    }
+    None
+}
+
+// Whether the direction from b towards d lies strictly inside the interior angle abc
+// of a counter-clockwise ring.
+fn in_cone<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    if orient2d(a, b, c) >= 0f64 {
+        orient2d(a, b, d) > 0f64 && orient2d(b, c, d) > 0f64
+    } else {
+        orient2d(a, b, d) > 0f64 || orient2d(b, c, d) > 0f64
+    }
+}
+
+// Whether the direction from b towards d lies inside or on the boundary of the
+// interior angle abc of a counter-clockwise ring.
+fn in_closed_cone<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    if orient2d(a, b, c) >= 0f64 {
+        orient2d(a, b, d) >= 0f64 && orient2d(b, c, d) >= 0f64
+    } else {
+        orient2d(a, b, d) >= 0f64 || orient2d(b, c, d) >= 0f64
+    }
+}
+
+// Whether segment pq meets an edge of the ring anywhere other than at p or q.
+fn crosses<T: Float>(vertices: &[Point2<T>], ring: &[usize], p: Point2<T>, q: Point2<T>) -> bool {
+    let n = ring.len();
+    (0..n).any(|i| {
+        let (a, b) = (vertices[ring[i]], vertices[ring[(i + 1) % n]]);
+        if a == p || a == q || b == p || b == q {
+            return false;
+        }
+        segment_intersection(p, q, a, b) != SegmentIntersection::None
+    })
+}
+
+// Cuts ears from a counter-clockwise ring of vertex indices.
+fn clip_ears<T: Float>(
+    vertices: &[Point2<T>],
+    mut ring: Vec<usize>,
+) -> Result<Vec<[usize; 3]>, GeometryError> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut i = 0;
+    let mut attempts = 0;
+    remove_spikes(vertices, &mut ring);
+    while ring.len() > 3 {
+        let n = ring.len();
+        let (prev, next) = ((i + n - 1) % n, (i + 1) % n);
+        if is_ear(vertices, &ring, prev, i, next) {
+            triangles.push([ring[prev], ring[i], ring[next]]);
+            ring.remove(i);
+            remove_spikes(vertices, &mut ring);
+            attempts = 0;
+            if i >= ring.len() {
+                i = 0;
+            }
+            continue;
+        }
+        attempts += 1;
+        if attempts >= n {
+            // No ear is left, which happens when the remaining vertices are
+            // collinear or the rings cross. Drop a collinear vertex, never a reflex
+            // one, whose removal would cover outside area.
+            let flat = (0..n).find(|&j| {
+                let [a, b, c] = [(j + n - 1) % n, j, (j + 1) % n].map(|k| vertices[ring[k]]);
+                orient2d(a, b, c) == 0f64
+            });
+            match flat {
+                Some(j) => {
+                    ring.remove(j);
+                    attempts = 0;
+                    i = 0;
+                    continue;
+                }
+                None => {
+                    return Err(GeometryError::new(
+                        "Polygon rings cross or overlap and cannot be triangulated",
+                    ))
+                }
+            }
+        }
+        i = next;
+    }
+    if ring.len() == 3 {
+        let [a, b, c] = [ring[0], ring[1], ring[2]];
+        if orient2d(vertices[a], vertices[b], vertices[c]) > 0f64 {
+            triangles.push([a, b, c]);
+        }
+    }
+    Ok(triangles)
+}
+
+// Removes repeated consecutive positions and the tips of spikes where the ring
+// turns straight back on itself. Ear cuts next to a point where the ring touches
+// itself leave these behind, as do holes sharing part of an edge. Neither encloses
+// any area, but a spike would pass for an ear.
+fn remove_spikes<T: Float>(vertices: &[Point2<T>], ring: &mut Vec<usize>) {
+    let mut j = 0;
+    while ring.len() > 2 && j < ring.len() {
+        let n = ring.len();
+        let [a, b, c] = [(j + n - 1) % n, j, (j + 1) % n].map(|k| vertices[ring[k]]);
+        if a != b && (orient2d(a, b, c) != 0f64 || (a - b).dot(c - b) <= T::zero()) {
+            j += 1;
+            continue;
+        }
+        ring.remove(j);
+        j = j.saturating_sub(2);
+    }
+}
+
+// Whether the vertex at position i is a strictly convex corner whose triangle
+// contains no other ring vertex, including on its edges.
+fn is_ear<T: Float>(
+    vertices: &[Point2<T>],
+    ring: &[usize],
+    prev: usize,
+    i: usize,
+    next: usize,
+) -> bool {
+    let [a, b, c] = [ring[prev], ring[i], ring[next]].map(|k| vertices[k]);
+    if orient2d(a, b, c) <= 0f64 {
+        return false;
+    }
+    let n = ring.len();
+    ring.iter().enumerate().all(|(j, &k)| {
+        let p = vertices[k];
+        if j == prev || j == i || j == next {
+            return true;
+        }
+        // Where the ring touches itself, another visit to a corner must not send an
+        // edge into the triangle.
+        if p == a || p == b || p == c {
+            let (inward, outward) = (vertices[ring[(j + n - 1) % n]], vertices[ring[(j + 1) % n]]);
+            let enters = |d: Point2<T>| {
+                let [first, second] = if p == a {
+                    [orient2d(a, b, d), orient2d(c, a, d)]
+                } else if p == b {
+                    [orient2d(a, b, d), orient2d(b, c, d)]
+                } else {
+                    [orient2d(b, c, d), orient2d(c, a, d)]
+                };
+                first > 0f64 && second > 0f64
+            };
+            return !enters(inward) && !enters(outward);
+        }
+        orient2d(a, b, p) < 0f64 || orient2d(b, c, p) < 0f64 || orient2d(c, a, p) < 0f64
+    })
+}
+
+#[cfg(test)]
+mod geometry_triangulation_tests {
+    use super::*;
+    use crate::fields::geometry::containment::{point_in_polygon_with_holes, Location};
+    use crate::fields::geometry::polygon::Polygon;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+        Polygon::new(vec![p(x0, y0), p(x1, y0), p(x1, y1), p(x0, y1)])
+    }
+
+    // Checks orientation, total area and that every triangle lies in the polygon.
+    fn assert_valid(polygon: &PolygonWithHoles<f64>, triangles: &[[usize; 3]]) {
+        let vertices = triangulation_vertices(polygon);
+        let mut area = 0.0;
+        for &[a, b, c] in triangles {
+            let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+            let twice = orient2d(pa, pb, pc);
+            assert!(twice > 0.0);
+            area += twice / 2.0;
+            let centroid = p((pa.x + pb.x + pc.x) / 3.0, (pa.y + pb.y + pc.y) / 3.0);
+            assert_eq!(
+                point_in_polygon_with_holes(centroid, polygon),
+                Location::Inside
+            );
+        }
+        assert!((area - polygon.area()).abs() < 1e-9 * polygon.area().max(1.0));
+    }
+
+    // Checks that no unconstrained edge violates the empty circle property.
+    fn assert_delaunay(polygon: &PolygonWithHoles<f64>, triangles: &[[usize; 3]]) {
+        let vertices = triangulation_vertices(polygon);
+        let mut owner = HashMap::new();
+        for triangle in triangles {
+            for (a, b) in triangle_edges(triangle) {
+                owner.insert((a, b), *triangle);
+            }
+        }
+        for (&(a, b), triangle) in &owner {
+            if let Some(other) = owner.get(&(b, a)) {
+                let c = third(triangle, a, b);
+                let d = third(other, b, a);
+                let inside = incircle(vertices[a], vertices[b], vertices[c], vertices[d]);
+                let convex = orient2d(vertices[c], vertices[a], vertices[d]) > 0.0
+                    && orient2d(vertices[d], vertices[b], vertices[c]) > 0.0;
+                let a_to_b = polygon_edge(polygon, a, b);
+                assert!(a_to_b || !convex || inside <= 0.0);
+            }
+        }
+    }
+
+    fn polygon_edge(polygon: &PolygonWithHoles<f64>, a: usize, b: usize) -> bool {
+        let mut offset = 0;
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.holes()) {
+            let n = ring.len();
+            for i in 0..n {
+                let (u, v) = (offset + i, offset + (i + 1) % n);
+                if (u, v) == (a, b) || (v, u) == (a, b) {
+                    return true;
+                }
+            }
+            offset += n;
+        }
+        false
+    }
+
+    fn comb() -> Polygon<f64> {
+        Polygon::new(vec![
+            p(0.0, 0.0),
+            p(5.0, 0.0),
+            p(5.0, 3.0),
+            p(4.0, 3.0),
+            p(4.0, 1.0),
+            p(3.0, 1.0),
+            p(3.0, 3.0),
+            p(2.0, 3.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 3.0),
+            p(0.0, 3.0),
+        ])
+    }
+
+    #[test]
+    fn ear_clipping_simple_test() {
+        let polygon = PolygonWithHoles::from(comb());
+        let triangles = ear_clipping(&polygon).unwrap();
+        assert_eq!(triangles.len(), 10);
+        assert_valid(&polygon, &triangles);
+
+        // Clockwise input gives the same area.
+        let reversed = PolygonWithHoles::from(comb().reversed());
+        assert_valid(&reversed, &ear_clipping(&reversed).unwrap());
+    }
+
+    #[test]
+    fn ear_clipping_holes_test() {
+        let polygon = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 10.0, 6.0),
+            vec![
+                rectangle(1.0, 1.0, 3.0, 5.0),
+                rectangle(4.0, 1.0, 6.0, 5.0).reversed(),
+                Polygon::new(vec![p(7.0, 1.0), p(9.0, 3.0), p(7.0, 5.0)]),
+            ],
+        );
+        let triangles = ear_clipping(&polygon).unwrap();
+        // n + 2h - 2 triangles for n vertices and h holes.
+        assert_eq!(triangles.len(), 15 + 6 - 2);
+        assert_valid(&polygon, &triangles);
+    }
+
+    #[test]
+    fn ear_clipping_degenerate_test() {
+        let empty: PolygonWithHoles<f64> = PolygonWithHoles::from(Polygon::new(Vec::new()));
+        assert!(ear_clipping(&empty).unwrap().is_empty());
+
+        let line =
+            PolygonWithHoles::from(Polygon::new(vec![p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)]));
+        assert!(ear_clipping(&line).unwrap().is_empty());
+        assert!(constrained_delaunay(&line).unwrap().is_empty());
+
+        // Repeated vertices are skipped.
+        let repeated = PolygonWithHoles::from(Polygon::new(vec![
+            p(0.0, 0.0),
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(2.0, 2.0),
+            p(2.0, 2.0),
+            p(0.0, 2.0),
+            p(0.0, 0.0),
+        ]));
+        let triangles = ear_clipping(&repeated).unwrap();
+        assert_eq!(triangles.len(), 2);
+        assert_valid(&repeated, &triangles);
+    }
+
+    #[test]
+    fn ear_clipping_collinear_test() {
+        // A square with extra vertices along every edge still uses all of them.
+        let mut vertices = Vec::new();
+        for i in 0..4 {
+            vertices.push(p(i as f64, 0.0));
+        }
+        for i in 0..4 {
+            vertices.push(p(4.0, i as f64));
+        }
+        for i in 0..4 {
+            vertices.push(p(4.0 - i as f64, 4.0));
+        }
+        for i in 0..4 {
+            vertices.push(p(0.0, 4.0 - i as f64));
+        }
+        let polygon = PolygonWithHoles::from(Polygon::new(vertices));
+        let triangles = ear_clipping(&polygon).unwrap();
+        assert_eq!(triangles.len(), 14);
+        assert_valid(&polygon, &triangles);
+
+        let delaunay = constrained_delaunay(&polygon).unwrap();
+        assert_valid(&polygon, &delaunay);
+        assert_delaunay(&polygon, &delaunay);
+    }
+
+    #[test]
+    fn ear_clipping_touching_hole_test() {
+        // The hole touches the exterior edge, which leaves no ear for a while.
+        let polygon = PolygonWithHoles::new(
+            Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(3.0, 0.0), p(1.0, 4.0)]),
+            vec![Polygon::new(vec![p(1.0, 1.0), p(2.0, 0.0), p(1.0, 3.0)])],
+        );
+        assert_valid(&polygon, &ear_clipping(&polygon).unwrap());
+
+        // Holes touching each other and the exterior, at a vertex and along an edge.
+        let polygon = PolygonWithHoles::new(
+            Polygon::new(vec![
+                p(9.0, 1.0),
+                p(0.0, 8.0),
+                p(-7.0, 2.0),
+                p(-6.0, -5.0),
+                p(4.0, -7.0),
+            ]),
+            vec![
+                Polygon::new(vec![p(4.0, -7.0), p(2.0, -5.0), p(2.0, -2.0)]),
+                Polygon::new(vec![p(2.0, -5.0), p(3.0, -6.0), p(0.0, -6.0)]),
+            ],
+        );
+        assert_valid(&polygon, &ear_clipping(&polygon).unwrap());
+        let polygon = PolygonWithHoles::new(
+            Polygon::new(vec![p(5.0, 1.0), p(-3.0, 6.0), p(-9.0, -3.0), p(0.0, -6.0)]),
+            vec![
+                Polygon::new(vec![p(2.5, -2.5), p(0.0, -3.0), p(2.0, 1.0)]),
+                Polygon::new(vec![p(0.0, -3.0), p(-2.0, -3.0), p(1.0, -1.0)]),
+            ],
+        );
+        assert_valid(&polygon, &ear_clipping(&polygon).unwrap());
+        assert_valid(&polygon, &constrained_delaunay(&polygon).unwrap());
+    }
+
+    #[test]
+    fn ear_clipping_crossing_rings_test() {
+        // A hole sticking out of the exterior leaves no ear to cut.
+        let polygon = PolygonWithHoles::new(
+            rectangle(0.0, 0.0, 4.0, 4.0),
+            vec![rectangle(2.0, 1.0, 6.0, 3.0)],
+        );
+        assert!(ear_clipping(&polygon).is_err());
+        assert!(constrained_delaunay(&polygon).is_err());
+    }
+
+    #[test]
+    fn constrained_delaunay_test() {
+        // A fan of thin slivers from ear clipping becomes well shaped.
+        let circle: Vec<Point2<f64>> = (0..32)
+            .map(|i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / 32.0;
+                p(3.0 * angle.cos(), angle.sin())
+            })
+            .collect();
+        let polygon = PolygonWithHoles::from(Polygon::new(circle));
+        let triangles = constrained_delaunay(&polygon).unwrap();
+        assert_eq!(triangles.len(), 30);
+        assert_valid(&polygon, &triangles);
+        assert_delaunay(&polygon, &triangles);
+
+        let holes = PolygonWithHoles::new(comb(), vec![rectangle(0.25, 0.25, 4.75, 0.75)]);
+        let triangles = constrained_delaunay(&holes).unwrap();
+        assert_valid(&holes, &triangles);
+        assert_delaunay(&holes, &triangles);
+
+        let comb = PolygonWithHoles::from(comb());
+        let triangles = constrained_delaunay(&comb).unwrap();
+        assert_valid(&comb, &triangles);
+        assert_delaunay(&comb, &triangles);
+    }
+}
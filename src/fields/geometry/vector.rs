@@ -0,0 +1,516 @@
+//! This module provides two and three dimensional vector types.
+//!
+//! It is part of the `geometry` module. `Vector2` and `Vector3` are generic over
+//! their component type. Operations that only need ring arithmetic (addition,
+//! scaling, dot and cross products, projection, rejection and interpolation) are
+//! available for any `num_traits::Num` type, including `Fraction` for exact work.
+//! Operations that need a square root or trigonometry (norm, normalization,
+//! distance and angles) are available for `num_traits::Float` types.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::vector::{Vector2, Vector3};
+//! use crabmath::types::Fraction;
+//!
+//! let a = Vector2::new(3.0, 4.0);
+//! let b = Vector2::new(1.0, 0.0);
+//!
+//! assert_eq!(a + b, Vector2::new(4.0, 4.0));
+//! assert_eq!(a.dot(b), 3.0);
+//! assert_eq!(a.norm(), 5.0);
+//! assert_eq!(a.project_onto(b), Some(Vector2::new(3.0, 0.0)));
+//!
+//! let x = Vector3::new(1.0, 0.0, 0.0);
+//! let y = Vector3::new(0.0, 1.0, 0.0);
+//!
+//! assert_eq!(x.cross(y), Vector3::new(0.0, 0.0, 1.0));
+//! assert_eq!(x.angle_between(y), std::f64::consts::FRAC_PI_2);
+//!
+//! let half = Fraction::new(1, 2).unwrap();
+//! let third = Fraction::new(1, 3).unwrap();
+//! let exact = Vector2::new(half, third);
+//!
+//! assert_eq!(exact.dot(exact), Fraction::new(13, 36).unwrap());
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a vector from its components.
+//! - `zero`: Constructs the zero vector.
+//! - `dot`: Computes the dot product.
+//! - `cross`: Computes the cross product (a scalar in 2D, a vector in 3D).
+//! - `norm_squared`: Computes the squared length.
+//! - `scale`: Multiplies every component by a scalar.
+//! - `lerp`: Linearly interpolates towards another vector.
+//! - `project_onto`: Computes the projection onto another vector.
+//! - `reject_from`: Computes the component orthogonal to another vector.
+//! - `perpendicular`: Rotates a 2D vector by a quarter turn counter-clockwise.
+//! - `norm`: Computes the length (floating point only).
+//! - `normalize`: Computes the unit vector in the same direction (floating point only).
+//! - `distance`: Computes the distance between the tips of two vectors (floating point only).
+//! - `angle_between`: Computes the unsigned angle to another vector (floating point only).
+
+use core::fmt;
+use num_traits::{Float, Num};
+use std::fmt::Formatter;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector2<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new vector.
+    pub fn new(x: T, y: T) -> Self {
+        Vector2 { x, y }
+    }
+
+    // Function to create the zero vector.
+    pub fn zero() -> Self {
+        Vector2::new(T::zero(), T::zero())
+    }
+
+    // Function to get the dot product.
+    pub fn dot(&self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    // Function to get the 2D cross product (the z component of the 3D cross product).
+    pub fn cross(&self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    // Function to get the squared length.
+    pub fn norm_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    // Function to multiply every component by a scalar.
+    pub fn scale(&self, factor: T) -> Self {
+        Vector2::new(self.x * factor, self.y * factor)
+    }
+
+    // Function to linearly interpolate, returning `self` at t = 0 and `other` at t = 1.
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self).scale(t)
+    }
+
+    // Function to get the projection onto `other`, or None if `other` is the zero vector.
+    pub fn project_onto(&self, other: Self) -> Option<Self> {
+        let denominator = other.norm_squared();
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(other.scale(self.dot(other) / denominator))
+    }
+
+    // Function to get the component orthogonal to `other`, or None if `other` is the zero vector.
+    pub fn reject_from(&self, other: Self) -> Option<Self> {
+        self.project_onto(other)
+            .map(|projection| *self - projection)
+    }
+
+    // Function to rotate the vector a quarter turn counter-clockwise.
+    pub fn perpendicular(&self) -> Self {
+        Vector2::new(T::zero() - self.y, self.x)
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Float,
+{
+    // Function to get the length.
+    pub fn norm(&self) -> T {
+        self.x.hypot(self.y)
+    }
+
+    // Function to get the unit vector in the same direction, or None for the zero vector.
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        Some(*self / norm)
+    }
+
+    // Function to get the distance between the tips of two vectors.
+    pub fn distance(&self, other: Self) -> T {
+        (*self - other).norm()
+    }
+
+    // Function to get the unsigned angle to another vector in radians, in [0, pi].
+    pub fn angle_between(&self, other: Self) -> T {
+        // atan2 stays accurate for nearly parallel vectors where acos would not.
+        self.cross(other).abs().atan2(self.dot(other))
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new vector.
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    // Function to create the zero vector.
+    pub fn zero() -> Self {
+        Vector3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    // Function to get the dot product.
+    pub fn dot(&self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    // Function to get the cross product.
+    pub fn cross(&self, other: Self) -> Self {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    // Function to get the squared length.
+    pub fn norm_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    // Function to multiply every component by a scalar.
+    pub fn scale(&self, factor: T) -> Self {
+        Vector3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    // Function to linearly interpolate, returning `self` at t = 0 and `other` at t = 1.
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self).scale(t)
+    }
+
+    // Function to get the projection onto `other`, or None if `other` is the zero vector.
+    pub fn project_onto(&self, other: Self) -> Option<Self> {
+        let denominator = other.norm_squared();
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(other.scale(self.dot(other) / denominator))
+    }
+
+    // Function to get the component orthogonal to `other`, or None if `other` is the zero vector.
+    pub fn reject_from(&self, other: Self) -> Option<Self> {
+        self.project_onto(other)
+            .map(|projection| *self - projection)
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: Float,
+{
+    // Function to get the length.
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    // Function to get the unit vector in the same direction, or None for the zero vector.
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        Some(*self / norm)
+    }
+
+    // Function to get the distance between the tips of two vectors.
+    pub fn distance(&self, other: Self) -> T {
+        (*self - other).norm()
+    }
+
+    // Function to get the unsigned angle to another vector in radians, in [0, pi].
+    pub fn angle_between(&self, other: Self) -> T {
+        self.cross(other).norm().atan2(self.dot(other))
+    }
+}
+
+impl<T: Num + Copy> Add for Vector2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Num + Copy> Sub for Vector2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Num + Copy> Div<T> for Vector2<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vector2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Num + Copy> Add for Vector3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Num + Copy> Sub for Vector3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Num + Copy> Div<T> for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vector3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}, {}>", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod geometry_vector_tests {
+    use super::*;
+    use crate::types::Fraction;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn frac(numerator: isize, denominator: usize) -> Fraction {
+        Fraction::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn vector2_arithmetic_test() {
+        let a = Vector2::new(1.0, 2.0);
+        let b = Vector2::new(3.0, -1.0);
+
+        assert_eq!(a + b, Vector2::new(4.0, 1.0));
+        assert_eq!(a - b, Vector2::new(-2.0, 3.0));
+        assert_eq!(a * 2.0, Vector2::new(2.0, 4.0));
+        assert_eq!(a / 2.0, Vector2::new(0.5, 1.0));
+        assert_eq!(-a, Vector2::new(-1.0, -2.0));
+        assert_eq!(Vector2::<f64>::zero(), Vector2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn vector2_products_test() {
+        let a = Vector2::new(1, 2);
+        let b = Vector2::new(3, -1);
+
+        assert_eq!(a.dot(b), 1);
+        assert_eq!(a.cross(b), -7);
+        assert_eq!(b.cross(a), 7);
+        assert_eq!(a.norm_squared(), 5);
+        assert_eq!(a.perpendicular(), Vector2::new(-2, 1));
+    }
+
+    #[test]
+    fn vector2_norm_test() {
+        let a = Vector2::new(3.0, 4.0);
+
+        assert_eq!(a.norm(), 5.0);
+        assert_eq!(a.normalize(), Some(Vector2::new(0.6, 0.8)));
+        assert_eq!(Vector2::<f64>::zero().normalize(), None);
+        assert_eq!(a.distance(Vector2::new(0.0, 0.0)), 5.0);
+    }
+
+    #[test]
+    fn vector2_angle_between_test() {
+        let x = Vector2::new(1.0, 0.0);
+
+        assert!((x.angle_between(Vector2::new(1.0, 1.0)) - FRAC_PI_4).abs() < 1e-15);
+        assert!((x.angle_between(Vector2::new(0.0, -2.0)) - FRAC_PI_2).abs() < 1e-15);
+        assert!((x.angle_between(Vector2::new(-1.0, 0.0)) - PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn vector2_projection_test() {
+        let a = Vector2::new(2.0, 3.0);
+        let b = Vector2::new(4.0, 0.0);
+
+        assert_eq!(a.project_onto(b), Some(Vector2::new(2.0, 0.0)));
+        assert_eq!(a.reject_from(b), Some(Vector2::new(0.0, 3.0)));
+        assert_eq!(a.project_onto(Vector2::zero()), None);
+    }
+
+    #[test]
+    fn vector2_lerp_test() {
+        let a = Vector2::new(0.0, 0.0);
+        let b = Vector2::new(10.0, -4.0);
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.25), Vector2::new(2.5, -1.0));
+    }
+
+    #[test]
+    fn vector2_fraction_test() {
+        let a = Vector2::new(frac(1, 2), frac(1, 3));
+        let b = Vector2::new(frac(1, 1), frac(1, 1));
+
+        assert_eq!(a.dot(b), frac(5, 6));
+        assert_eq!(a.cross(b), frac(1, 6));
+
+        // The projection of (1/2, 1/3) onto (1, 1) is exactly (5/12, 5/12).
+        let projection = a.project_onto(b).unwrap();
+        assert_eq!(projection, Vector2::new(frac(5, 12), frac(5, 12)));
+        assert_eq!(
+            a.reject_from(b).unwrap(),
+            Vector2::new(frac(1, 12), frac(-1, 12))
+        );
+        assert_eq!(a.lerp(b, frac(1, 2)), Vector2::new(frac(3, 4), frac(2, 3)));
+    }
+
+    #[test]
+    fn vector3_arithmetic_test() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(-1.0, 0.5, 2.0);
+
+        assert_eq!(a + b, Vector3::new(0.0, 2.5, 5.0));
+        assert_eq!(a - b, Vector3::new(2.0, 1.5, 1.0));
+        assert_eq!(a * 2.0, Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(a / 2.0, Vector3::new(0.5, 1.0, 1.5));
+        assert_eq!(-a, Vector3::new(-1.0, -2.0, -3.0));
+    }
+
+    #[test]
+    fn vector3_products_test() {
+        let x = Vector3::new(1, 0, 0);
+        let y = Vector3::new(0, 1, 0);
+        let a = Vector3::new(1, 2, 3);
+        let b = Vector3::new(4, 5, 6);
+
+        assert_eq!(x.cross(y), Vector3::new(0, 0, 1));
+        assert_eq!(y.cross(x), Vector3::new(0, 0, -1));
+        assert_eq!(a.cross(b), Vector3::new(-3, 6, -3));
+        assert_eq!(a.dot(b), 32);
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!(a.norm_squared(), 14);
+    }
+
+    #[test]
+    fn vector3_norm_test() {
+        let a = Vector3::new(2.0, 3.0, 6.0);
+
+        assert_eq!(a.norm(), 7.0);
+        assert!((a.normalize().unwrap().norm() - 1.0).abs() < 1e-15);
+        assert_eq!(Vector3::<f64>::zero().normalize(), None);
+        assert_eq!(a.distance(Vector3::new(2.0, 3.0, 0.0)), 6.0);
+    }
+
+    #[test]
+    fn vector3_angle_between_test() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(x.angle_between(y), FRAC_PI_2);
+        assert!((x.angle_between(Vector3::new(1.0, 1.0, 0.0)) - FRAC_PI_4).abs() < 1e-15);
+        assert_eq!(x.angle_between(x), 0.0);
+    }
+
+    #[test]
+    fn vector3_projection_test() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let z = Vector3::new(0.0, 0.0, 2.0);
+
+        assert_eq!(a.project_onto(z), Some(Vector3::new(0.0, 0.0, 3.0)));
+        assert_eq!(a.reject_from(z), Some(Vector3::new(1.0, 2.0, 0.0)));
+        assert_eq!(a.project_onto(Vector3::zero()), None);
+        assert_eq!(a.lerp(z, 0.5), Vector3::new(0.5, 1.0, 2.5));
+    }
+
+    #[test]
+    fn vector3_fraction_test() {
+        let a = Vector3::new(frac(1, 2), frac(0, 1), frac(1, 1));
+        let b = Vector3::new(frac(1, 1), frac(1, 1), frac(0, 1));
+
+        assert_eq!(a.dot(b), frac(1, 2));
+        assert_eq!(
+            a.cross(b),
+            Vector3::new(frac(-1, 1), frac(1, 1), frac(1, 2))
+        );
+        assert_eq!(
+            a.project_onto(b).unwrap(),
+            Vector3::new(frac(1, 4), frac(1, 4), frac(0, 1))
+        );
+    }
+
+    #[test]
+    fn vector_display_test() {
+        assert_eq!(Vector2::new(1, 2).to_string(), "<1, 2>");
+        assert_eq!(
+            Vector3::new(frac(1, 2), frac(1, 1), frac(0, 1)).to_string(),
+            "<1/2, 1/1, 0/1>"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! This module provides Voronoi diagrams clipped to a bounding box.
+//!
+//! It is part of the `geometry` module. The Voronoi cell of a site is the region
+//! of points closer to it than to any other site. Cells are built from the dual
+//! `DelaunayTriangulation`. Each cell starts as the bounding box and is cut back
+//! by the perpendicular bisector between its site and each Delaunay neighbour.
+//! Clipping to the box keeps the cells of hull sites finite, so every cell is a
+//! convex `Polygon` wound counter-clockwise. Together the cells tile the box.
+//!
+//! Repeated sites share one cell. Collinear sites give parallel strips. A site
+//! whose cell misses the box entirely has an empty cell.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::point::Point2;
+//! use crabmath::fields::geometry::voronoi::VoronoiDiagram;
+//!
+//! let facilities = vec![Point2::new(1.0, 1.0), Point2::new(3.0, 1.0)];
+//! let diagram = VoronoiDiagram::new(&facilities, Point2::new(0.0, 0.0), Point2::new(4.0, 2.0));
+//!
+//! assert_eq!(diagram.cell(0).area(), 4.0);
+//! assert_eq!(diagram.cell(1).area(), 4.0);
+//! assert_eq!(diagram.locate(Point2::new(2.5, 0.5)), Some(1));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Builds the Voronoi diagram of a set of sites inside a bounding box.
+//! - `from_delaunay`: Builds the Voronoi diagram from an existing Delaunay triangulation.
+//! - `sites`: Gets the sites.
+//! - `cells`: Gets the cell of every site.
+//! - `cell`: Gets the cell of one site.
+//! - `locate`: Finds the site whose cell contains a point.
+//! - `triangulation`: Gets the dual Delaunay triangulation.
+
+use crate::fields::geometry::delaunay::DelaunayTriangulation;
+use crate::fields::geometry::point::Point2;
+use crate::fields::geometry::polygon::Polygon;
+use num_traits::Float;
+
+#[derive(Debug, Clone)]
+pub struct VoronoiDiagram<T> {
+    triangulation: DelaunayTriangulation<T>,
+    cells: Vec<Polygon<T>>,
+}
+
+impl<T> VoronoiDiagram<T>
+where
+    T: Float,
+{
+    // Function to build the Voronoi diagram of a set of sites inside the box from `min` to `max`.
+    pub fn new(sites: &[Point2<T>], min: Point2<T>, max: Point2<T>) -> Self {
+        VoronoiDiagram::from_delaunay(DelaunayTriangulation::new(sites), min, max)
+    }
+
+    // Function to build the Voronoi diagram dual to a Delaunay triangulation.
+    pub fn from_delaunay(
+        triangulation: DelaunayTriangulation<T>,
+        min: Point2<T>,
+        max: Point2<T>,
+    ) -> Self {
+        let bounds = vec![
+            min,
+            Point2::new(max.x, min.y),
+            max,
+            Point2::new(min.x, max.y),
+        ];
+        let sites = triangulation.points();
+        let cells = (0..sites.len())
+            .map(|i| {
+                let cell = triangulation
+                    .neighbors(i)
+                    .iter()
+                    .fold(bounds.clone(), |cell, &j| {
+                        clip_to_bisector(&cell, sites[i], sites[j])
+                    });
+                Polygon::new(cell)
+            })
+            .collect();
+        VoronoiDiagram {
+            triangulation,
+            cells,
+        }
+    }
+
+    // Function to get the sites.
+    pub fn sites(&self) -> &[Point2<T>] {
+        self.triangulation.points()
+    }
+
+    // Function to get the cell of every site, in site order.
+    pub fn cells(&self) -> &[Polygon<T>] {
+        &self.cells
+    }
+
+    // Function to get the cell of one site.
+    pub fn cell(&self, index: usize) -> &Polygon<T> {
+        &self.cells[index]
+    }
+
+    // Function to find the site whose cell contains a point, which is the nearest site.
+    pub fn locate(&self, point: Point2<T>) -> Option<usize> {
+        self.triangulation.nearest(point)
+    }
+
+    // Function to get the dual Delaunay triangulation.
+    pub fn triangulation(&self) -> &DelaunayTriangulation<T> {
+        &self.triangulation
+    }
+}
+
+// Keeps the part of a convex polygon that is at least as close to `site` as to `other`.
+fn clip_to_bisector<T: Float>(
+    polygon: &[Point2<T>],
+    site: Point2<T>,
+    other: Point2<T>,
+) -> Vec<Point2<T>> {
+    let normal = other - site;
+    let middle = site.midpoint(other);
+    let side = |point: Point2<T>| (point - middle).dot(normal);
+    let mut output = Vec::with_capacity(polygon.len() + 1);
+    for i in 0..polygon.len() {
+        let current = polygon[i];
+        let previous = polygon[(i + polygon.len() - 1) % polygon.len()];
+        let (s_previous, s_current) = (side(previous), side(current));
+        if (s_previous <= T::zero()) != (s_current <= T::zero()) {
+            let t = s_previous / (s_previous - s_current);
+            output.push(previous + (current - previous).scale(t));
+        }
+        if s_current <= T::zero() {
+            output.push(current);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod geometry_voronoi_tests {
+    use super::*;
+    use crate::fields::geometry::containment::{point_in_polygon_winding, Location};
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2::new(x, y)
+    }
+
+    fn random_points(count: usize, seed: u64) -> Vec<Point2<f64>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count).map(|_| p(next(), next())).collect()
+    }
+
+    #[test]
+    fn voronoi_tiles_box_test() {
+        let sites = random_points(150, 12);
+        let diagram = VoronoiDiagram::new(&sites, p(0.0, 0.0), p(1.0, 1.0));
+
+        let total: f64 = diagram.cells().iter().map(|cell| cell.area()).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        for (i, cell) in diagram.cells().iter().enumerate() {
+            assert!(cell.is_counter_clockwise());
+            assert_eq!(point_in_polygon_winding(sites[i], cell), Location::Inside);
+        }
+    }
+
+    #[test]
+    fn voronoi_locate_test() {
+        let sites = random_points(60, 8);
+        let diagram = VoronoiDiagram::new(&sites, p(0.0, 0.0), p(1.0, 1.0));
+        for query in random_points(40, 30) {
+            let index = diagram.locate(query).unwrap();
+            assert_ne!(
+                point_in_polygon_winding(query, diagram.cell(index)),
+                Location::Outside
+            );
+        }
+    }
+
+    #[test]
+    fn voronoi_degenerate_test() {
+        let bounds = (p(0.0, 0.0), p(6.0, 2.0));
+
+        // A single site owns the whole box.
+        let single = VoronoiDiagram::new(&[p(1.0, 1.0)], bounds.0, bounds.1);
+        assert_eq!(single.cell(0).area(), 12.0);
+
+        // Collinear sites split the box into strips.
+        let line = [p(1.0, 1.0), p(5.0, 1.0), p(3.0, 1.0), p(3.0, 1.0)];
+        let strips = VoronoiDiagram::new(&line, bounds.0, bounds.1);
+        assert_eq!(strips.cell(0).area(), 4.0);
+        assert_eq!(strips.cell(1).area(), 4.0);
+        assert_eq!(strips.cell(2).area(), 4.0);
+        assert_eq!(strips.cell(3), strips.cell(2));
+
+        // A site far outside the box has an empty cell.
+        let outside = VoronoiDiagram::new(&[p(1.0, 1.0), p(50.0, 1.0)], bounds.0, bounds.1);
+        assert!(outside.cell(1).is_empty());
+        assert_eq!(outside.cell(0).area(), 12.0);
+    }
+}
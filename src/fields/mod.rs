@@ -1 +1,3 @@
+pub mod geodesy;
 pub mod geometry;
+pub mod trigonometry;
@@ -0,0 +1,94 @@
+//! This module provides the circular trigonometric functions of an angle.
+//!
+//! It is part of the `trigonometry` module. The functions take an `Angle`, so
+//! the caller chooses the unit when building the angle. The reciprocal functions
+//! follow floating point division, so `csc` of a zero angle is infinite.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::trigonometry::circular;
+//! use crabmath::types::Angle;
+//!
+//! let angle = Angle::from_degrees(60.0f64);
+//!
+//! assert!((circular::sin(angle) - 3f64.sqrt() / 2.0).abs() < 1e-12);
+//! assert!((circular::cos(angle) - 0.5).abs() < 1e-12);
+//! assert!((circular::sec(angle) - 2.0).abs() < 1e-12);
+//! assert!((circular::cot(angle) - 1.0 / 3f64.sqrt()).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `sin`: Computes the sine.
+//! - `cos`: Computes the cosine.
+//! - `tan`: Computes the tangent.
+//! - `sec`: Computes the secant, the reciprocal of the cosine.
+//! - `csc`: Computes the cosecant, the reciprocal of the sine.
+//! - `cot`: Computes the cotangent, the reciprocal of the tangent.
+
+use crate::types::Angle;
+use num_traits::Float;
+
+// Function to get the sine of an angle.
+pub fn sin<T: Float>(angle: Angle<T>) -> T {
+    angle.sin()
+}
+
+// Function to get the cosine of an angle.
+pub fn cos<T: Float>(angle: Angle<T>) -> T {
+    angle.cos()
+}
+
+// Function to get the tangent of an angle.
+pub fn tan<T: Float>(angle: Angle<T>) -> T {
+    angle.tan()
+}
+
+// Function to get the secant of an angle.
+pub fn sec<T: Float>(angle: Angle<T>) -> T {
+    angle.cos().recip()
+}
+
+// Function to get the cosecant of an angle.
+pub fn csc<T: Float>(angle: Angle<T>) -> T {
+    angle.sin().recip()
+}
+
+// Function to get the cotangent of an angle.
+//
+// This is cos / sin rather than 1 / tan, so it is exactly zero at a right angle.
+pub fn cot<T: Float>(angle: Angle<T>) -> T {
+    let (sin, cos) = angle.sin_cos();
+    cos / sin
+}
+
+#[cfg(test)]
+mod trigonometry_circular_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn sin_cos_tan_test() {
+        let angle = Angle::from_degrees(30.0);
+
+        assert_close(sin(angle), 0.5);
+        assert_close(cos(angle), 3f64.sqrt() / 2.0);
+        assert_close(tan(angle), 1.0 / 3f64.sqrt());
+        assert_close(sin(Angle::from_degrees(-90.0)), -1.0);
+    }
+
+    #[test]
+    fn reciprocal_test() {
+        let angle = Angle::from_degrees(45.0);
+
+        assert_close(sec(angle), 2f64.sqrt());
+        assert_close(csc(angle), 2f64.sqrt());
+        assert_close(cot(angle), 1.0);
+        assert_eq!(cot(Angle::<f64>::zero()), f64::INFINITY);
+        assert!(cot(Angle::from_degrees(90.0)).abs() < 1e-16);
+    }
+}
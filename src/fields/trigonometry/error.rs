@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct TrigonometryError {
+    details: String,
+}
+
+impl TrigonometryError {
+    pub fn new(msg: &str) -> TrigonometryError {
+        TrigonometryError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TrigonometryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for TrigonometryError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
@@ -0,0 +1,199 @@
+//! This module provides exact values of the circular functions at special angles.
+//!
+//! At multiples of 30° and 45° the sine, cosine and tangent are a rational number
+//! times a square root, such as sin 30° = 1/2 or cos 45° = √2/2. An `ExactValue`
+//! holds that rational coefficient as a `Fraction` along with the number under the
+//! root. Angles within about 1e-9° of a special angle count as that angle, so
+//! values built from radians still match. Other angles, and the tangent of a right
+//! angle, have no exact value and give `None`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::trigonometry::exact::{exact_cos, exact_sin, exact_tan};
+//! use crabmath::types::{Angle, Fraction};
+//!
+//! let sin = exact_sin(Angle::from_degrees(30.0f64)).unwrap();
+//! assert_eq!(sin.coefficient, Fraction::new(1, 2).unwrap());
+//! assert_eq!(sin.radicand, 1);
+//!
+//! let cos = exact_cos(Angle::from_degrees(135.0f64)).unwrap();
+//! assert_eq!(cos.to_string(), "-√2/2");
+//! assert!((cos.to_f64() + 0.5f64.sqrt()).abs() < 1e-15);
+//!
+//! assert_eq!(exact_tan(Angle::from_degrees(-60.0f64)).unwrap().to_string(), "-√3");
+//! assert!(exact_tan(Angle::from_degrees(90.0f64)).is_none());
+//! assert!(exact_sin(Angle::from_degrees(10.0f64)).is_none());
+//! ```
+//!
+//! # Functions
+//!
+//! - `exact_sin`: Gets the exact sine of a special angle.
+//! - `exact_cos`: Gets the exact cosine of a special angle.
+//! - `exact_tan`: Gets the exact tangent of a special angle.
+//! - `to_f64`: Gets the floating point value of an `ExactValue`.
+//! - `to_string`: Gets the `ExactValue` in radical notation (via `Display`).
+
+use crate::types::{Angle, Fraction};
+use core::fmt;
+use num_traits::{Float, FloatConst};
+use std::fmt::Formatter;
+
+// How far, in degrees, an angle may be from a special angle and still count as it.
+const TOLERANCE_DEGREES: f64 = 1e-9;
+
+// A value of the form coefficient · √radicand, with radicand free of square factors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExactValue {
+    pub coefficient: Fraction,
+    pub radicand: usize,
+}
+
+impl ExactValue {
+    fn new(numerator: isize, denominator: usize, radicand: usize) -> Self {
+        ExactValue {
+            coefficient: Fraction::new(numerator, denominator).unwrap(),
+            radicand,
+        }
+    }
+
+    // Function to get the floating point value.
+    pub fn to_f64(&self) -> f64 {
+        self.coefficient.to_decimal() * (self.radicand as f64).sqrt()
+    }
+}
+
+impl fmt::Display for ExactValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let numerator = self.coefficient.numerator;
+        let denominator = self.coefficient.denominator;
+        if numerator == 0 {
+            return write!(f, "0");
+        }
+        if numerator < 0 {
+            write!(f, "-")?;
+        }
+        let magnitude = numerator.unsigned_abs();
+        match (magnitude, self.radicand) {
+            (_, 1) => write!(f, "{}", magnitude)?,
+            (1, radicand) => write!(f, "√{}", radicand)?,
+            (magnitude, radicand) => write!(f, "{}√{}", magnitude, radicand)?,
+        }
+        if denominator != 1 {
+            write!(f, "/{}", denominator)?;
+        }
+        Ok(())
+    }
+}
+
+// Function to get the exact sine of a multiple of 30° or 45°.
+pub fn exact_sin<T: Float + FloatConst>(angle: Angle<T>) -> Option<ExactValue> {
+    special_degrees(angle).map(sin_degrees)
+}
+
+// Function to get the exact cosine of a multiple of 30° or 45°.
+pub fn exact_cos<T: Float + FloatConst>(angle: Angle<T>) -> Option<ExactValue> {
+    special_degrees(angle).map(|degrees| sin_degrees((degrees + 90) % 360))
+}
+
+// Function to get the exact tangent of a multiple of 30° or 45°, other than a right angle.
+pub fn exact_tan<T: Float + FloatConst>(angle: Angle<T>) -> Option<ExactValue> {
+    let degrees = special_degrees(angle)?;
+    let reference = degrees % 180;
+    let sign = if reference > 90 { -1 } else { 1 };
+    match reference.min(180 - reference) {
+        0 => Some(ExactValue::new(0, 1, 1)),
+        30 => Some(ExactValue::new(sign, 3, 3)),
+        45 => Some(ExactValue::new(sign, 1, 1)),
+        60 => Some(ExactValue::new(sign, 1, 3)),
+        _ => None,
+    }
+}
+
+// Rounds a normalized angle to whole degrees in [0, 360), if it is a multiple of 30° or 45°.
+fn special_degrees<T: Float + FloatConst>(angle: Angle<T>) -> Option<usize> {
+    let degrees = angle.normalized().degrees().to_f64()?;
+    let rounded = degrees.round();
+    if (degrees - rounded).abs() > TOLERANCE_DEGREES {
+        return None;
+    }
+    // An angle just below a full turn rounds up to 360°.
+    let degrees = rounded as usize % 360;
+    (degrees.is_multiple_of(30) || degrees.is_multiple_of(45)).then_some(degrees)
+}
+
+fn sin_degrees(degrees: usize) -> ExactValue {
+    let sign = if degrees > 180 { -1 } else { 1 };
+    let reference = degrees % 180;
+    match reference.min(180 - reference) {
+        0 => ExactValue::new(0, 1, 1),
+        30 => ExactValue::new(sign, 2, 1),
+        45 => ExactValue::new(sign, 2, 2),
+        60 => ExactValue::new(sign, 2, 3),
+        _ => ExactValue::new(sign, 1, 1),
+    }
+}
+
+#[cfg(test)]
+mod trigonometry_exact_tests {
+    use super::*;
+
+    #[test]
+    fn exact_sin_test() {
+        let sin = |degrees: f64| exact_sin(Angle::from_degrees(degrees)).unwrap();
+
+        assert_eq!(sin(0.0), ExactValue::new(0, 1, 1));
+        assert_eq!(sin(30.0), ExactValue::new(1, 2, 1));
+        assert_eq!(sin(45.0), ExactValue::new(1, 2, 2));
+        assert_eq!(sin(120.0), ExactValue::new(1, 2, 3));
+        assert_eq!(sin(180.0), ExactValue::new(0, 1, 1));
+        assert_eq!(sin(270.0), ExactValue::new(-1, 1, 1));
+        assert_eq!(sin(-30.0), ExactValue::new(-1, 2, 1));
+        assert_eq!(sin(750.0), ExactValue::new(1, 2, 1));
+        assert!(exact_sin(Angle::from_degrees(15.0)).is_none());
+        assert!(exact_sin(Angle::from_degrees(30.001)).is_none());
+    }
+
+    #[test]
+    fn exact_cos_tan_test() {
+        let cos = |degrees: f64| exact_cos(Angle::from_degrees(degrees)).unwrap();
+        let tan = |degrees: f64| exact_tan(Angle::from_degrees(degrees)).unwrap();
+
+        assert_eq!(cos(0.0), ExactValue::new(1, 1, 1));
+        assert_eq!(cos(60.0), ExactValue::new(1, 2, 1));
+        assert_eq!(cos(150.0), ExactValue::new(-1, 2, 3));
+        assert_eq!(cos(315.0), ExactValue::new(1, 2, 2));
+        assert_eq!(tan(30.0), ExactValue::new(1, 3, 3));
+        assert_eq!(tan(135.0), ExactValue::new(-1, 1, 1));
+        assert_eq!(tan(240.0), ExactValue::new(1, 1, 3));
+        assert!(exact_tan(Angle::from_degrees(90.0)).is_none());
+        assert!(exact_tan(Angle::from_degrees(-90.0)).is_none());
+    }
+
+    #[test]
+    fn exact_matches_float_test() {
+        for step in 0..24 {
+            let angle = Angle::from_radians(step as f64 * std::f64::consts::PI / 12.0);
+            for (exact, value) in [
+                (exact_sin(angle), angle.sin()),
+                (exact_cos(angle), angle.cos()),
+                (exact_tan(angle), angle.tan()),
+            ] {
+                if let Some(exact) = exact {
+                    assert!((exact.to_f64() - value).abs() < 1e-12);
+                }
+            }
+        }
+        assert!(exact_sin(Angle::from_radians(std::f64::consts::FRAC_PI_6)).is_some());
+    }
+
+    #[test]
+    fn exact_to_string_test() {
+        assert_eq!(ExactValue::new(1, 2, 1).to_string(), "1/2");
+        assert_eq!(ExactValue::new(-1, 1, 1).to_string(), "-1");
+        assert_eq!(ExactValue::new(0, 1, 1).to_string(), "0");
+        assert_eq!(ExactValue::new(1, 2, 3).to_string(), "√3/2");
+        assert_eq!(ExactValue::new(-1, 3, 3).to_string(), "-√3/3");
+        assert_eq!(ExactValue::new(2, 1, 2).to_string(), "2√2");
+    }
+}
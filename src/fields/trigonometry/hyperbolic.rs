@@ -0,0 +1,162 @@
+//! This module provides the hyperbolic functions and their inverses.
+//!
+//! It is part of the `trigonometry` module. Hyperbolic functions take a plain
+//! number rather than an `Angle`, since their argument is not an angle of
+//! rotation. Forward functions follow floating point arithmetic, so `coth` and
+//! `csch` of zero are infinite. Inverse functions give a `TrigonometryError`
+//! outside their domain.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::trigonometry::hyperbolic;
+//!
+//! let x = 0.75f64;
+//! let identity = hyperbolic::cosh(x).powi(2) - hyperbolic::sinh(x).powi(2);
+//!
+//! assert!((identity - 1.0).abs() < 1e-12);
+//! assert!((hyperbolic::atanh(hyperbolic::tanh(x)).unwrap() - x).abs() < 1e-12);
+//! assert!(hyperbolic::acosh(0.5f64).is_err());
+//! ```
+//!
+//! # Functions
+//!
+//! - `sinh`, `cosh` and `tanh`: Compute the hyperbolic sine, cosine and tangent.
+//! - `sech`, `csch` and `coth`: Compute the hyperbolic secant, cosecant and cotangent.
+//! - `asinh`, `acosh` and `atanh`: Compute the inverse hyperbolic sine, cosine and tangent.
+//! - `asech`, `acsch` and `acoth`: Compute the inverse hyperbolic secant, cosecant and cotangent.
+
+use crate::fields::trigonometry::error::TrigonometryError;
+use num_traits::Float;
+
+// Function to get the hyperbolic sine.
+pub fn sinh<T: Float>(x: T) -> T {
+    x.sinh()
+}
+
+// Function to get the hyperbolic cosine.
+pub fn cosh<T: Float>(x: T) -> T {
+    x.cosh()
+}
+
+// Function to get the hyperbolic tangent.
+pub fn tanh<T: Float>(x: T) -> T {
+    x.tanh()
+}
+
+// Function to get the hyperbolic secant.
+pub fn sech<T: Float>(x: T) -> T {
+    x.cosh().recip()
+}
+
+// Function to get the hyperbolic cosecant.
+pub fn csch<T: Float>(x: T) -> T {
+    x.sinh().recip()
+}
+
+// Function to get the hyperbolic cotangent.
+pub fn coth<T: Float>(x: T) -> T {
+    x.tanh().recip()
+}
+
+// Function to get the inverse hyperbolic sine.
+pub fn asinh<T: Float>(x: T) -> T {
+    x.asinh()
+}
+
+// Function to get the non-negative inverse hyperbolic cosine, for x >= 1.
+pub fn acosh<T: Float>(x: T) -> Result<T, TrigonometryError> {
+    if x >= T::one() {
+        Ok(x.acosh())
+    } else {
+        Err(TrigonometryError::new(
+            "The argument of acosh must be at least 1",
+        ))
+    }
+}
+
+// Function to get the inverse hyperbolic tangent, for x in (-1, 1).
+pub fn atanh<T: Float>(x: T) -> Result<T, TrigonometryError> {
+    if x.abs() < T::one() {
+        Ok(x.atanh())
+    } else {
+        Err(TrigonometryError::new(
+            "The argument of atanh must lie in (-1, 1)",
+        ))
+    }
+}
+
+// Function to get the non-negative inverse hyperbolic secant, for x in (0, 1].
+pub fn asech<T: Float>(x: T) -> Result<T, TrigonometryError> {
+    if x > T::zero() && x <= T::one() {
+        Ok(x.recip().acosh())
+    } else {
+        Err(TrigonometryError::new(
+            "The argument of asech must lie in (0, 1]",
+        ))
+    }
+}
+
+// Function to get the inverse hyperbolic cosecant, for x other than 0.
+pub fn acsch<T: Float>(x: T) -> Result<T, TrigonometryError> {
+    if x != T::zero() && !x.is_nan() {
+        Ok(x.recip().asinh())
+    } else {
+        Err(TrigonometryError::new(
+            "The argument of acsch must not be 0",
+        ))
+    }
+}
+
+// Function to get the inverse hyperbolic cotangent, for |x| > 1.
+pub fn acoth<T: Float>(x: T) -> Result<T, TrigonometryError> {
+    if x.abs() > T::one() {
+        Ok(x.recip().atanh())
+    } else {
+        Err(TrigonometryError::new(
+            "The argument of acoth must have an absolute value greater than 1",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod trigonometry_hyperbolic_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn hyperbolic_test() {
+        let x = 1.0f64;
+        let e = std::f64::consts::E;
+
+        assert_close(sinh(x), (e - 1.0 / e) / 2.0);
+        assert_close(cosh(x), (e + 1.0 / e) / 2.0);
+        assert_close(tanh(x), sinh(x) / cosh(x));
+        assert_close(sech(x), 1.0 / cosh(x));
+        assert_close(csch(x), 1.0 / sinh(x));
+        assert_close(coth(x), 1.0 / tanh(x));
+        assert_eq!(coth(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn inverse_hyperbolic_test() {
+        for x in [0.25f64, 0.5, 2.0] {
+            assert_close(asinh(sinh(x)), x);
+            assert_close(acosh(cosh(x)).unwrap(), x);
+            assert_close(asech(sech(x)).unwrap(), x);
+            assert_close(acsch(csch(-x)).unwrap(), -x);
+            assert_close(acoth(coth(x)).unwrap(), x);
+        }
+        assert_close(atanh(tanh(0.5)).unwrap(), 0.5);
+
+        assert!(acosh(0.9).is_err());
+        assert!(atanh(1.0).is_err());
+        assert!(asech(0.0).is_err());
+        assert!(asech(1.5).is_err());
+        assert!(acsch(0.0).is_err());
+        assert!(acoth(-1.0).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! This module provides the inverse circular trigonometric functions.
+//!
+//! It is part of the `trigonometry` module. The functions return an `Angle` on
+//! the principal branch:
+//!
+//! - `asin` and `acsc` give angles in [−π/2, π/2].
+//! - `acos` and `asec` give angles in [0, π].
+//! - `atan` gives angles in (−π/2, π/2).
+//! - `acot` gives angles in (0, π).
+//! - `atan2` gives angles in (−π, π].
+//!
+//! Inputs outside a function's domain give a `TrigonometryError`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::trigonometry::inverse;
+//!
+//! let angle = inverse::asin(0.5f64).unwrap();
+//! assert!((angle.degrees() - 30.0).abs() < 1e-12);
+//!
+//! let heading = inverse::atan2(-1.0f64, -1.0);
+//! assert!((heading.degrees() + 135.0).abs() < 1e-12);
+//!
+//! assert!(inverse::acos(2.0f64).is_err());
+//! ```
+//!
+//! # Functions
+//!
+//! - `asin`: Computes the arcsine.
+//! - `acos`: Computes the arccosine.
+//! - `atan`: Computes the arctangent.
+//! - `atan2`: Computes the angle of the point (x, y) from the positive x axis.
+//! - `asec`: Computes the arcsecant.
+//! - `acsc`: Computes the arccosecant.
+//! - `acot`: Computes the arccotangent.
+
+use crate::fields::trigonometry::error::TrigonometryError;
+use crate::types::Angle;
+use num_traits::{Float, FloatConst};
+
+// Function to get the angle whose sine is x, for x in [-1, 1].
+pub fn asin<T: Float>(x: T) -> Result<Angle<T>, TrigonometryError> {
+    check_unit_interval(x, "asin")?;
+    Ok(Angle::from_radians(x.asin()))
+}
+
+// Function to get the angle whose cosine is x, for x in [-1, 1].
+pub fn acos<T: Float>(x: T) -> Result<Angle<T>, TrigonometryError> {
+    check_unit_interval(x, "acos")?;
+    Ok(Angle::from_radians(x.acos()))
+}
+
+// Function to get the angle whose tangent is x.
+pub fn atan<T: Float>(x: T) -> Angle<T> {
+    Angle::from_radians(x.atan())
+}
+
+// Function to get the angle from the positive x axis to the point (x, y).
+pub fn atan2<T: Float>(y: T, x: T) -> Angle<T> {
+    Angle::from_radians(y.atan2(x))
+}
+
+// Function to get the angle whose secant is x, for |x| >= 1.
+pub fn asec<T: Float>(x: T) -> Result<Angle<T>, TrigonometryError> {
+    check_outside_unit_interval(x, "asec")?;
+    Ok(Angle::from_radians(x.recip().acos()))
+}
+
+// Function to get the angle whose cosecant is x, for |x| >= 1.
+pub fn acsc<T: Float>(x: T) -> Result<Angle<T>, TrigonometryError> {
+    check_outside_unit_interval(x, "acsc")?;
+    Ok(Angle::from_radians(x.recip().asin()))
+}
+
+// Function to get the angle in (0, π) whose cotangent is x.
+pub fn acot<T: Float + FloatConst>(x: T) -> Angle<T> {
+    Angle::from_radians(T::FRAC_PI_2() - x.atan())
+}
+
+fn check_unit_interval<T: Float>(x: T, name: &str) -> Result<(), TrigonometryError> {
+    if x.abs() <= T::one() {
+        Ok(())
+    } else {
+        Err(TrigonometryError::new(&format!(
+            "The argument of {} must lie in [-1, 1]",
+            name
+        )))
+    }
+}
+
+fn check_outside_unit_interval<T: Float>(x: T, name: &str) -> Result<(), TrigonometryError> {
+    if x.abs() >= T::one() {
+        Ok(())
+    } else {
+        Err(TrigonometryError::new(&format!(
+            "The argument of {} must have an absolute value of at least 1",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod trigonometry_inverse_tests {
+    use super::*;
+
+    fn assert_degrees(angle: Angle<f64>, degrees: f64) {
+        assert!(
+            (angle.degrees() - degrees).abs() < 1e-9,
+            "{} != {}",
+            angle.degrees(),
+            degrees
+        );
+    }
+
+    #[test]
+    fn asin_acos_atan_test() {
+        assert_degrees(asin(-1.0).unwrap(), -90.0);
+        assert_degrees(acos(-1.0).unwrap(), 180.0);
+        assert_degrees(acos(0.5).unwrap(), 60.0);
+        assert_degrees(atan(1.0), 45.0);
+        assert!(asin(1.5).is_err());
+        assert!(acos(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn atan2_test() {
+        assert_degrees(atan2(1.0, 0.0), 90.0);
+        assert_degrees(atan2(0.0, -1.0), 180.0);
+        assert_degrees(atan2(-1.0, 1.0), -45.0);
+    }
+
+    #[test]
+    fn reciprocal_inverse_test() {
+        assert_degrees(asec(2.0).unwrap(), 60.0);
+        assert_degrees(asec(-1.0).unwrap(), 180.0);
+        assert_degrees(acsc(-2.0).unwrap(), -30.0);
+        assert_degrees(acot(1.0), 45.0);
+        assert_degrees(acot(-1.0), 135.0);
+        assert_degrees(acot(0.0), 90.0);
+        assert!(asec(0.5).is_err());
+        assert!(acsc(0.0).is_err());
+    }
+}
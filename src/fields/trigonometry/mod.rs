@@ -0,0 +1,20 @@
+//! This module contains trigonometric functions.
+//!
+//! It includes the circular functions of an `Angle` and their reciprocals, the
+//! inverse functions returning an `Angle`, the hyperbolic functions and their
+//! inverses, and exact values at special angles. Inverse functions report inputs
+//! outside their domain as a `TrigonometryError`.
+//!
+//! # Modules
+//!
+//! - `circular`: Contains the sine, cosine and tangent of an angle and their reciprocals.
+//! - `error`: Contains the `TrigonometryError` type.
+//! - `exact`: Contains exact values of the circular functions at special angles.
+//! - `hyperbolic`: Contains the hyperbolic functions and their inverses.
+//! - `inverse`: Contains the inverse circular functions.
+
+pub mod circular;
+pub mod error;
+pub mod exact;
+pub mod hyperbolic;
+pub mod inverse;
@@ -0,0 +1,220 @@
+//! `Angle` is a struct representing a plane angle
+//!
+//! An `Angle` stores its size in radians and converts to and from degrees,
+//! gradians and turns, so code can work in whichever unit suits it without
+//! hard-coding conversion factors. Angles can be normalized to one full turn,
+//! either [0, 2π) or (−π, π], and are added, subtracted and scaled like numbers.
+//! Degrees-minutes-seconds values are handled by the `dms` module, and the
+//! `trigonometry` field builds on this type.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::Angle;
+//!
+//! let right = Angle::from_degrees(90.0);
+//! let turn = Angle::from_turns(1.0);
+//! let obtuse = Angle::from_gradians(150.0);
+//!
+//! assert_eq!(right, Angle::right());
+//! assert_eq!(turn.degrees(), 360.0);
+//! assert!((obtuse.degrees() - 135.0f64).abs() < 1e-12);
+//! assert!((right.sin() - 1.0f64).abs() < 1e-12);
+//!
+//! let wrapped = Angle::from_degrees(-90.0).normalized();
+//! assert!((wrapped.degrees() - 270.0f64).abs() < 1e-12);
+//! let signed = Angle::from_degrees(270.0).normalized_signed();
+//! assert!((signed.degrees() + 90.0f64).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_radians`, `from_degrees`, `from_gradians` and `from_turns`: Construct an angle in a unit.
+//! - `zero`, `right`, `straight` and `full`: Construct the angles of 0, 1/4, 1/2 and 1 turn.
+//! - `radians`, `degrees`, `gradians` and `turns`: Get the size in a unit.
+//! - `normalized`: Wraps the angle into [0, 2π).
+//! - `normalized_signed`: Wraps the angle into (−π, π].
+//! - `sin`, `cos`, `tan` and `sin_cos`: Evaluate the trigonometric functions.
+
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Angle<T> {
+    radians: T,
+}
+
+impl<T> Angle<T>
+where
+    T: Float,
+{
+    // Function to create an angle from its size in radians.
+    pub fn from_radians(radians: T) -> Self {
+        Angle { radians }
+    }
+
+    // Function to create an angle from its size in degrees.
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle::from_radians(degrees.to_radians())
+    }
+
+    // Function to create the zero angle.
+    pub fn zero() -> Self {
+        Angle::from_radians(T::zero())
+    }
+
+    // Function to get the size in radians.
+    pub fn radians(&self) -> T {
+        self.radians
+    }
+
+    // Function to get the size in degrees.
+    pub fn degrees(&self) -> T {
+        self.radians.to_degrees()
+    }
+
+    // Function to get the sine.
+    pub fn sin(&self) -> T {
+        self.radians.sin()
+    }
+
+    // Function to get the cosine.
+    pub fn cos(&self) -> T {
+        self.radians.cos()
+    }
+
+    // Function to get the tangent.
+    pub fn tan(&self) -> T {
+        self.radians.tan()
+    }
+
+    // Function to get the sine and cosine together.
+    pub fn sin_cos(&self) -> (T, T) {
+        self.radians.sin_cos()
+    }
+}
+
+impl<T> Angle<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to create an angle from its size in gradians, 400 to the turn.
+    pub fn from_gradians(gradians: T) -> Self {
+        Angle::from_radians(gradians * T::PI() / T::from(200).unwrap())
+    }
+
+    // Function to create an angle from its size in turns.
+    pub fn from_turns(turns: T) -> Self {
+        Angle::from_radians(turns * T::TAU())
+    }
+
+    // Function to create the right angle, a quarter turn.
+    pub fn right() -> Self {
+        Angle::from_radians(T::FRAC_PI_2())
+    }
+
+    // Function to create the straight angle, a half turn.
+    pub fn straight() -> Self {
+        Angle::from_radians(T::PI())
+    }
+
+    // Function to create the full angle, one turn.
+    pub fn full() -> Self {
+        Angle::from_radians(T::TAU())
+    }
+
+    // Function to get the size in gradians.
+    pub fn gradians(&self) -> T {
+        self.radians * T::from(200).unwrap() / T::PI()
+    }
+
+    // Function to get the size in turns.
+    pub fn turns(&self) -> T {
+        self.radians / T::TAU()
+    }
+
+    // Function to get the equivalent angle in [0, 2π).
+    pub fn normalized(&self) -> Self {
+        let tau = T::TAU();
+        let mut radians = self.radians % tau;
+        if radians < T::zero() {
+            radians = radians + tau;
+        }
+        // A tiny negative remainder can round up to a full turn.
+        if radians >= tau {
+            radians = T::zero();
+        }
+        Angle::from_radians(radians)
+    }
+
+    // Function to get the equivalent angle in (−π, π].
+    pub fn normalized_signed(&self) -> Self {
+        let radians = self.normalized().radians;
+        if radians > T::PI() {
+            Angle::from_radians(radians - T::TAU())
+        } else {
+            Angle::from_radians(radians)
+        }
+    }
+}
+
+#[cfg(test)]
+mod angle_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn angle_units_test() {
+        let angle = Angle::from_degrees(180.0);
+
+        assert_eq!(angle.radians(), PI);
+        assert_eq!(angle.degrees(), 180.0);
+        assert_close(angle.gradians(), 200.0);
+        assert_close(angle.turns(), 0.5);
+
+        assert_eq!(Angle::from_radians(FRAC_PI_2), Angle::right());
+        assert_eq!(Angle::from_gradians(200.0), Angle::straight());
+        assert_eq!(Angle::from_turns(1.0), Angle::full());
+        assert_eq!(Angle::<f64>::zero(), Angle::default());
+        assert!(Angle::<f64>::right() < Angle::straight());
+    }
+
+    #[test]
+    fn angle_normalized_test() {
+        assert_close(Angle::from_degrees(450.0).normalized().degrees(), 90.0);
+        assert_close(Angle::from_degrees(-30.0).normalized().degrees(), 330.0);
+        assert_eq!(Angle::from_turns(-3.0).normalized(), Angle::zero());
+        assert_eq!(Angle::<f64>::full().normalized(), Angle::zero());
+        assert_eq!(Angle::from_radians(-1e-20).normalized(), Angle::zero());
+
+        assert_close(
+            Angle::from_degrees(190.0).normalized_signed().degrees(),
+            -170.0,
+        );
+        assert_close(
+            Angle::from_degrees(-190.0).normalized_signed().degrees(),
+            170.0,
+        );
+        assert_eq!(
+            Angle::<f64>::straight().normalized_signed(),
+            Angle::straight()
+        );
+        assert_eq!(
+            (-Angle::<f64>::straight()).normalized_signed(),
+            Angle::straight()
+        );
+    }
+
+    #[test]
+    fn angle_trigonometry_test() {
+        let angle = Angle::from_degrees(60.0);
+
+        assert_close(angle.sin(), 3f64.sqrt() / 2.0);
+        assert_close(angle.cos(), 0.5);
+        assert_close(angle.tan(), 3f64.sqrt());
+        assert_eq!(angle.sin_cos(), (angle.sin(), angle.cos()));
+    }
+}
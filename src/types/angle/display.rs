@@ -0,0 +1,97 @@
+use crate::types::angle::dms::Dms;
+use crate::types::Angle;
+use core::fmt;
+use num_traits::Float;
+use std::fmt::Formatter;
+
+// Angles display in degrees, honouring a requested precision.
+impl<T: Float + fmt::Display> fmt::Display for Angle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}°", precision, self.degrees()),
+            None => write!(f, "{}°", self.degrees()),
+        }
+    }
+}
+
+// A requested precision applies to the seconds, and seconds that round up to 60
+// carry into the minutes and degrees.
+impl<T: Float + fmt::Display> fmt::Display for Dms<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        match f.precision() {
+            Some(precision) => {
+                let sixty = T::from(60).unwrap();
+                let (mut degrees, mut minutes, mut seconds) =
+                    (self.degrees, self.minutes, self.seconds);
+                let rounded = format!("{:.*}", precision, seconds);
+                if rounded.parse::<f64>().is_ok_and(|rounded| rounded >= 60.0) {
+                    seconds = T::zero();
+                    minutes = minutes + T::one();
+                    if minutes >= sixty {
+                        minutes = T::zero();
+                        degrees = degrees + T::one();
+                    }
+                }
+                write!(
+                    f,
+                    "{}{}°{}'{:.*}\"",
+                    sign, degrees, minutes, precision, seconds
+                )
+            }
+            None => write!(
+                f,
+                "{}{}°{}'{}\"",
+                sign, self.degrees, self.minutes, self.seconds
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod angle_display_tests {
+    use super::*;
+
+    #[test]
+    fn angle_to_string_test() {
+        let angle = Angle::from_degrees(45.0);
+
+        assert_eq!(angle.to_string(), "45°");
+        assert_eq!(format!("{:.2}", Angle::from_degrees(12.3456)), "12.35°");
+    }
+
+    #[test]
+    fn dms_to_string_test() {
+        let dms = Angle::from_dms(-12.0, 30.0, 36.0).to_dms();
+
+        assert_eq!(format!("{:.1}", dms), "-12°30'36.0\"");
+        assert_eq!(
+            format!("{:.1}", dms)
+                .parse::<Angle<f64>>()
+                .unwrap()
+                .to_dms()
+                .minutes,
+            30.0
+        );
+    }
+
+    #[test]
+    fn dms_to_string_carry_test() {
+        assert_eq!(
+            Angle::from_dms(1.0, 1.0, 0.0).to_dms().to_string(),
+            "1°1'0\""
+        );
+        assert_eq!(
+            format!("{:.1}", Angle::from_dms(1.0, 0.0, 59.97).to_dms()),
+            "1°1'0.0\""
+        );
+        assert_eq!(
+            format!("{:.0}", Angle::from_dms(-1.0, 59.0, 59.6).to_dms()),
+            "-2°0'0\""
+        );
+        assert_eq!(
+            format!("{:.2}", Angle::from_dms(1.0, 0.0, 59.97).to_dms()),
+            "1°0'59.97\""
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Degrees-minutes-seconds values and parsing for `Angle`.
+//!
+//! A `Dms` splits an angle into whole degrees, whole minutes (1/60 degree) and
+//! seconds (1/60 minute), as used for bearings and geographic coordinates. Its
+//! `Display` gives the usual `12°30'15.5"` notation.
+//!
+//! Angles parse from a number followed by a unit (`deg` or `°`, `rad`, `grad`
+//! or `gon`, and `turn` or `tr`) or from degrees-minutes-seconds notation with
+//! `°`, `'` or `′`, and `"` or `″` marks. A trailing `N` or `E` keeps the sign
+//! and `S` or `W` negates it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::Angle;
+//!
+//! let bearing = Angle::from_dms(12.0, 30.0, 36.0);
+//! let dms = bearing.to_dms();
+//! assert_eq!((dms.degrees, dms.minutes), (12.0, 30.0));
+//! assert!((dms.seconds - 36.0f64).abs() < 1e-9);
+//!
+//! let latitude: Angle<f64> = "33°52'4.8\" S".parse().unwrap();
+//! assert!((latitude.degrees() + 33.868).abs() < 1e-12);
+//!
+//! let half: Angle<f64> = "0.5 turn".parse().unwrap();
+//! assert_eq!(half.degrees(), 180.0);
+//! ```
+//!
+//! # Functions
+//!
+//! - `from_dms`: Constructs an angle from degrees, minutes and seconds.
+//! - `to_dms`: Splits an angle into degrees, minutes and seconds.
+//! - `from_str`: Parses an angle with a unit or in degrees-minutes-seconds notation (via `FromStr`).
+
+use crate::types::angle::error::AngleError;
+use crate::types::Angle;
+use num_traits::{Float, FloatConst};
+use std::str::FromStr;
+
+// How far, in units of the last place of the total, `to_dms` lets a total of
+// seconds sit from a whole second and still snap to it. Reaching seconds takes
+// a few roundings (the degree/radian conversion and two multiplications by
+// 60), each off by at most half a unit, so 8 units covers them with margin.
+// For f64 this is under 3e-9" even at 360°, far below any printed precision.
+const DMS_SNAP_ULPS: u32 = 8;
+
+// Builds an angle from a number in one unit.
+type Constructor<T> = fn(T) -> Angle<T>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dms<T> {
+    pub negative: bool,
+    pub degrees: T,
+    pub minutes: T,
+    pub seconds: T,
+}
+
+impl<T> Angle<T>
+where
+    T: Float,
+{
+    // Function to create an angle from degrees, minutes and seconds.
+    //
+    // The sign of `degrees` applies to the whole angle, including a negative zero, so
+    // -0° 30' is half a degree below zero. Minutes and seconds are taken as magnitudes.
+    pub fn from_dms(degrees: T, minutes: T, seconds: T) -> Self {
+        let sixty = T::from(60).unwrap();
+        let magnitude = degrees.abs() + minutes.abs() / sixty + seconds.abs() / (sixty * sixty);
+        if degrees.is_sign_negative() {
+            Angle::from_degrees(-magnitude)
+        } else {
+            Angle::from_degrees(magnitude)
+        }
+    }
+
+    // Function to split the angle into whole degrees, whole minutes and seconds.
+    //
+    // A total within rounding error of a whole second is snapped to it first, so
+    // 1° 1' comes back as 1° 1' 0" rather than 1° 0' 59.9999...".
+    pub fn to_dms(&self) -> Dms<T> {
+        let sixty = T::from(60).unwrap();
+        let degrees = self.degrees();
+        let mut seconds = degrees.abs() * sixty * sixty;
+        let tolerance = seconds.max(T::one()) * T::epsilon() * T::from(DMS_SNAP_ULPS).unwrap();
+        if (seconds - seconds.round()).abs() <= tolerance {
+            seconds = seconds.round();
+        }
+        let whole_degrees = (seconds / (sixty * sixty)).floor();
+        seconds = seconds - whole_degrees * sixty * sixty;
+        let whole_minutes = (seconds / sixty).floor();
+        Dms {
+            negative: degrees < T::zero(),
+            degrees: whole_degrees,
+            minutes: whole_minutes,
+            seconds: (seconds - whole_minutes * sixty).max(T::zero()),
+        }
+    }
+}
+
+impl<T> FromStr for Angle<T>
+where
+    T: Float + FloatConst,
+{
+    type Err = AngleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (text, hemisphere_negative) = split_hemisphere(s.trim());
+
+        // "grad" is checked before "rad", since it ends with it.
+        let units: [(&str, Constructor<T>); 6] = [
+            ("deg", Angle::from_degrees),
+            ("grad", Angle::from_gradians),
+            ("gon", Angle::from_gradians),
+            ("rad", Angle::from_radians),
+            ("turn", Angle::from_turns),
+            ("tr", Angle::from_turns),
+        ];
+        let angle = units
+            .iter()
+            .find_map(|&(unit, construct)| {
+                let number = text.strip_suffix(unit)?;
+                Some(parse_number(number).map(construct))
+            })
+            .unwrap_or_else(|| parse_dms(text))?;
+
+        Ok(if hemisphere_negative { -angle } else { angle })
+    }
+}
+
+// Removes a trailing compass direction, returning whether it points south or west.
+fn split_hemisphere(text: &str) -> (&str, bool) {
+    let Some(last) = text.chars().last() else {
+        return (text, false);
+    };
+    let rest = &text[..text.len() - last.len_utf8()];
+    // A direction must follow a space or a mark, so "1e" stays a malformed number.
+    let separated = rest.ends_with(|c: char| c.is_whitespace() || "°'′\"″".contains(c));
+    match last.to_ascii_uppercase() {
+        'N' | 'E' if separated => (rest.trim_end(), false),
+        'S' | 'W' if separated => (rest.trim_end(), true),
+        _ => (text, false),
+    }
+}
+
+fn parse_number<T: Float>(text: &str) -> Result<T, AngleError> {
+    let value: f64 = text
+        .trim()
+        .parse()
+        .map_err(|_| AngleError::new(&format!("Invalid angle value \"{}\"", text.trim())))?;
+    Ok(T::from(value).unwrap())
+}
+
+// Parses degrees, minutes and seconds, each followed by its mark and in that order.
+fn parse_dms<T: Float>(text: &str) -> Result<Angle<T>, AngleError> {
+    let mut parts = [None; 3];
+    let mut rest = text.trim();
+    let mut next_slot = 0;
+    while !rest.is_empty() {
+        let (index, mark) = rest
+            .char_indices()
+            .find(|&(_, c)| "°'′\"″".contains(c))
+            .ok_or_else(|| AngleError::new(&format!("Missing angle unit in \"{}\"", text)))?;
+        let slot = match mark {
+            '°' => 0,
+            '\'' | '′' => 1,
+            _ => 2,
+        };
+        if slot < next_slot {
+            return Err(AngleError::new(&format!(
+                "Degrees, minutes and seconds are out of order in \"{}\"",
+                text
+            )));
+        }
+        parts[slot] = Some(parse_number::<T>(&rest[..index])?);
+        next_slot = slot + 1;
+        rest = rest[index + mark.len_utf8()..].trim_start();
+    }
+    let [degrees, minutes, seconds] = parts;
+    let degrees =
+        degrees.ok_or_else(|| AngleError::new(&format!("Missing degrees in \"{}\"", text)))?;
+    // Only the degrees may carry a sign.
+    let minutes = minutes.unwrap_or(T::zero());
+    let seconds = seconds.unwrap_or(T::zero());
+    if minutes < T::zero() || seconds < T::zero() {
+        return Err(AngleError::new(&format!(
+            "Only the degrees of \"{}\" may be negative",
+            text
+        )));
+    }
+    Ok(Angle::from_dms(degrees, minutes, seconds))
+}
+
+#[cfg(test)]
+mod angle_dms_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn angle_dms_test() {
+        let angle = Angle::from_dms(10.0, 15.0, 45.0);
+        assert_close(angle.degrees(), 10.2625);
+
+        let dms = angle.to_dms();
+        assert!(!dms.negative);
+        assert_eq!(dms.degrees, 10.0);
+        assert_eq!(dms.minutes, 15.0);
+        assert_close(dms.seconds, 45.0);
+
+        let angle = Angle::from_dms(-0.0, 30.0, 0.0);
+        assert_close(angle.degrees(), -0.5);
+        let dms = angle.to_dms();
+        assert!(dms.negative);
+        assert_eq!((dms.degrees, dms.minutes), (0.0, 30.0));
+    }
+
+    #[test]
+    fn angle_dms_whole_minutes_test() {
+        for degrees in 0..90 {
+            for minutes in 0..60 {
+                let dms = Angle::from_dms(degrees as f64, minutes as f64, 0.0).to_dms();
+                assert_eq!(
+                    (dms.degrees, dms.minutes, dms.seconds),
+                    (degrees as f64, minutes as f64, 0.0),
+                    "{}° {}'",
+                    degrees,
+                    minutes
+                );
+            }
+        }
+        let dms = Angle::from_dms(-1.0, 1.0, 0.0).to_dms();
+        assert!(dms.negative);
+        assert_eq!((dms.degrees, dms.minutes, dms.seconds), (1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn angle_from_str_test() {
+        let parse = |s: &str| s.parse::<Angle<f64>>().unwrap();
+
+        assert_close(parse("45deg").degrees(), 45.0);
+        assert_close(parse("-45 °").degrees(), -45.0);
+        assert_close(parse("1.5rad").radians(), 1.5);
+        assert_close(parse("100 grad").degrees(), 90.0);
+        assert_close(parse("100gon").degrees(), 90.0);
+        assert_close(parse("0.25 turn").degrees(), 90.0);
+        assert_close(parse("1tr").degrees(), 360.0);
+        assert_close(parse("12°30'").degrees(), 12.5);
+        assert_close(parse("12° 30′ 36″").degrees(), 12.51);
+        assert_close(parse("-12°30'36\"").degrees(), -12.51);
+        assert_close(parse("151°12'30\" E").degrees(), 151.208_333_333_333_33);
+        assert_close(parse("33°52'4.8\"S").degrees(), -33.868);
+        assert_close(parse("2 rad W").radians(), -2.0);
+
+        assert!("45".parse::<Angle<f64>>().is_err());
+        assert!("abc deg".parse::<Angle<f64>>().is_err());
+        assert!("30'12°".parse::<Angle<f64>>().is_err());
+        assert!("30'".parse::<Angle<f64>>().is_err());
+        assert!("12°-30'".parse::<Angle<f64>>().is_err());
+        assert!("".parse::<Angle<f64>>().is_err());
+    }
+}
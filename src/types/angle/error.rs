@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct AngleError {
+    details: String,
+}
+
+impl AngleError {
+    pub fn new(msg: &str) -> AngleError {
+        AngleError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AngleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for AngleError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod angle;
+pub mod display;
+pub mod dms;
+pub mod error;
+pub mod ops;
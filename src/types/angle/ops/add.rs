@@ -0,0 +1,32 @@
+use crate::types::Angle;
+use num_traits::Float;
+
+use std::ops::{Add, AddAssign};
+
+impl<T: Float> Add for Angle<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Angle::from_radians(self.radians() + rhs.radians())
+    }
+}
+
+impl<T: Float> AddAssign for Angle<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod angle_add_tests {
+    use super::*;
+
+    #[test]
+    fn angle_add_test() {
+        let mut angle = Angle::from_radians(0.5) + Angle::from_radians(0.25);
+        assert_eq!(angle.radians(), 0.75);
+
+        angle += Angle::from_radians(1.0);
+        assert_eq!(angle.radians(), 1.75);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::types::Angle;
+use num_traits::Float;
+
+use std::ops::{Div, DivAssign};
+
+impl<T: Float> Div<T> for Angle<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Angle::from_radians(self.radians() / rhs)
+    }
+}
+
+impl<T: Float> DivAssign<T> for Angle<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod angle_div_tests {
+    use super::*;
+
+    #[test]
+    fn angle_div_test() {
+        let mut angle = Angle::from_radians(3.0) / 2.0;
+        assert_eq!(angle.radians(), 1.5);
+
+        angle /= 3.0;
+        assert_eq!(angle.radians(), 0.5);
+    }
+}
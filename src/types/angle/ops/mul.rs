@@ -0,0 +1,32 @@
+use crate::types::Angle;
+use num_traits::Float;
+
+use std::ops::{Mul, MulAssign};
+
+impl<T: Float> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Angle::from_radians(self.radians() * rhs)
+    }
+}
+
+impl<T: Float> MulAssign<T> for Angle<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod angle_mul_tests {
+    use super::*;
+
+    #[test]
+    fn angle_mul_test() {
+        let mut angle = Angle::from_radians(0.5) * 3.0;
+        assert_eq!(angle.radians(), 1.5);
+
+        angle *= 2.0;
+        assert_eq!(angle.radians(), 3.0);
+    }
+}
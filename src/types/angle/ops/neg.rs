@@ -0,0 +1,24 @@
+use crate::types::Angle;
+use num_traits::Float;
+
+use std::ops::Neg;
+
+impl<T: Float> Neg for Angle<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Angle::from_radians(-self.radians())
+    }
+}
+
+#[cfg(test)]
+mod angle_neg_tests {
+    use super::*;
+
+    #[test]
+    fn angle_neg_test() {
+        let angle = -Angle::from_radians(0.5);
+
+        assert_eq!(angle.radians(), -0.5);
+    }
+}
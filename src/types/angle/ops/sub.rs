@@ -0,0 +1,32 @@
+use crate::types::Angle;
+use num_traits::Float;
+
+use std::ops::{Sub, SubAssign};
+
+impl<T: Float> Sub for Angle<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Angle::from_radians(self.radians() - rhs.radians())
+    }
+}
+
+impl<T: Float> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod angle_sub_tests {
+    use super::*;
+
+    #[test]
+    fn angle_sub_test() {
+        let mut angle = Angle::from_radians(0.5) - Angle::from_radians(0.75);
+        assert_eq!(angle.radians(), -0.25);
+
+        angle -= Angle::from_radians(1.0);
+        assert_eq!(angle.radians(), -1.25);
+    }
+}
@@ -0,0 +1,574 @@
+//! `Complex` is a struct representing a complex number
+//!
+//! A `Complex` consists of a real part and an imaginary part. Arithmetic only
+//! needs the parts to be numbers, so `Complex<Fraction>` gives exact Gaussian
+//! rationals. With floating-point parts the polar form, exponential, logarithm,
+//! powers, roots and the trigonometric and hyperbolic functions are available.
+//!
+//! The multi-valued functions return their principal value. Their branch cuts
+//! follow the usual conventions (as in C99 and most numerical libraries):
+//!
+//! - `ln`, `sqrt` and `powc` are cut along the negative real axis, with the
+//!   argument in (−π, π] and `sqrt` having a non-negative real part.
+//! - `asin` and `acos` are cut along the real axis outside [−1, 1], `atanh`
+//!   likewise, and `acosh` along the real axis left of 1.
+//! - `atan` and `asinh` are cut along the imaginary axis outside [−i, i].
+//!
+//! On a cut the sign of a zero imaginary (or real) part selects the side, so
+//! `asin(2 + 0i)` and `asin(2 − 0i)` are complex conjugates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::{Complex, Fraction};
+//!
+//! let z = Complex::new(3.0, 4.0);
+//! let w: Complex<f64> = "1-2i".parse().unwrap();
+//!
+//! assert_eq!(z * w, Complex::new(11.0, -2.0));
+//! assert_eq!(z.conj(), Complex::new(3.0, -4.0));
+//! assert_eq!(z.modulus(), 5.0);
+//! assert_eq!(z.to_string(), "3+4i");
+//!
+//! // Euler's identity, up to rounding.
+//! let euler = Complex::new(0.0, std::f64::consts::PI).exp() + Complex::new(1.0, 0.0);
+//! assert!(euler.modulus() < 1e-15);
+//! assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+//!
+//! // Gaussian rationals divide exactly.
+//! let half = Fraction::new(1, 2).unwrap();
+//! let q = Complex::new(half, half) / Complex::new(half, -half);
+//! assert_eq!(q, Complex::new(Fraction::new(0, 1).unwrap(), Fraction::new(1, 1).unwrap()));
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a new complex number from its parts.
+//! - `i`: Constructs the imaginary unit.
+//! - `conj`: Gets the complex conjugate.
+//! - `norm_sqr`: Gets the squared modulus.
+//! - `scale` and `unscale`: Multiply or divide both parts by a number.
+//! - `inv`: Gets the reciprocal.
+//! - `powu` and `powi`: Raise to an integer power by repeated squaring.
+//! - `from_polar` and `to_polar`: Convert to and from the polar form.
+//! - `modulus` and `arg`: Get the modulus and the argument.
+//! - `exp`, `ln` and `log`: Compute the exponential and logarithms.
+//! - `sqrt`, `cbrt`, `powf` and `powc`: Compute roots and powers.
+//! - `sin`, `cos`, `tan`, `asin`, `acos` and `atan`: Compute the trigonometric functions.
+//! - `sinh`, `cosh`, `tanh`, `asinh`, `acosh` and `atanh`: Compute the hyperbolic functions.
+//! - `is_finite` and `is_nan`: Check the parts for infinities and NaN.
+
+use crate::types::Angle;
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new Complex instance.
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+
+    // Function to get the imaginary unit.
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+
+    // Function to get the complex conjugate.
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, T::zero() - self.im)
+    }
+
+    // Function to get the squared modulus, re² + im².
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    // Function to multiply both parts by a number.
+    pub fn scale(&self, factor: T) -> Self {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+
+    // Function to divide both parts by a number.
+    pub fn unscale(&self, divisor: T) -> Self {
+        Complex::new(self.re / divisor, self.im / divisor)
+    }
+
+    // Function to get the reciprocal, 1 / z.
+    //
+    // Uses the complex division, so floating-point parts do not overflow through |z|².
+    pub fn inv(&self) -> Self
+    where
+        T: 'static,
+    {
+        Complex::new(T::one(), T::zero()) / *self
+    }
+
+    // Function to raise to a non-negative integer power.
+    pub fn powu(&self, exponent: u32) -> Self {
+        let mut result = Complex::new(T::one(), T::zero());
+        let mut base = *self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // Function to raise to an integer power; negative powers use the reciprocal.
+    pub fn powi(&self, exponent: i32) -> Self
+    where
+        T: 'static,
+    {
+        if exponent < 0 {
+            self.inv().powu(exponent.unsigned_abs())
+        } else {
+            self.powu(exponent as u32)
+        }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Float,
+{
+    // Function to create a complex number from its modulus and argument.
+    pub fn from_polar(modulus: T, arg: Angle<T>) -> Self {
+        let (sin, cos) = arg.sin_cos();
+        Complex::new(modulus * cos, modulus * sin)
+    }
+
+    // Function to get the modulus and the argument.
+    pub fn to_polar(&self) -> (T, Angle<T>) {
+        (self.modulus(), self.arg())
+    }
+
+    // Function to get the modulus |z|, computed without overflow.
+    pub fn modulus(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    // Function to get the principal argument, in (−π, π].
+    pub fn arg(&self) -> Angle<T> {
+        Angle::from_radians(self.im.atan2(self.re))
+    }
+
+    // Function to get the exponential e^z.
+    pub fn exp(&self) -> Self {
+        // On the real axis the polar form would give ∞·sin 0 = NaN once e^x overflows.
+        if self.im == T::zero() {
+            return Complex::new(self.re.exp(), self.im);
+        }
+        Complex::from_polar(self.re.exp(), Angle::from_radians(self.im))
+    }
+
+    // Function to get the principal natural logarithm.
+    pub fn ln(&self) -> Self {
+        Complex::new(self.modulus().ln(), self.arg().radians())
+    }
+
+    // Function to get the principal logarithm to a real base.
+    pub fn log(&self, base: T) -> Self {
+        self.ln().unscale(base.ln())
+    }
+
+    // Function to get the principal square root, with a non-negative real part.
+    pub fn sqrt(&self) -> Self {
+        if self.re == T::zero() && self.im == T::zero() {
+            return Complex::new(T::zero(), self.im);
+        }
+        let two = T::one() + T::one();
+        // Taking the root of the larger of |z| ± re avoids cancellation.
+        let t = ((self.modulus() + self.re.abs()) / two).sqrt();
+        if self.re >= T::zero() {
+            Complex::new(t, self.im / (two * t))
+        } else {
+            Complex::new(self.im.abs() / (two * t), t.copysign(self.im))
+        }
+    }
+
+    // Function to get the principal cube root.
+    pub fn cbrt(&self) -> Self {
+        let three = T::one() + T::one() + T::one();
+        Complex::from_polar(
+            self.modulus().cbrt(),
+            Angle::from_radians(self.arg().radians() / three),
+        )
+    }
+
+    // Function to raise to a real power, using the principal argument.
+    pub fn powf(&self, exponent: T) -> Self {
+        if self.re == T::zero() && self.im == T::zero() {
+            return *self;
+        }
+        Complex::from_polar(
+            self.modulus().powf(exponent),
+            Angle::from_radians(self.arg().radians() * exponent),
+        )
+    }
+
+    // Function to raise to a complex power, e^(w·ln z).
+    //
+    // Zero raised to a power with positive real part is zero.
+    pub fn powc(&self, exponent: Complex<T>) -> Self {
+        if self.re == T::zero() && self.im == T::zero() {
+            if exponent.re > T::zero() {
+                return Complex::new(T::zero(), T::zero());
+            }
+            if exponent.re == T::zero() && exponent.im == T::zero() {
+                return Complex::new(T::one(), T::zero());
+            }
+        }
+        (exponent * self.ln()).exp()
+    }
+
+    // Function to get the sine.
+    pub fn sin(&self) -> Self {
+        // On the imaginary axis sin(iy) = i·sinh y, even once cosh y overflows.
+        if self.re == T::zero() {
+            return Complex::new(self.re, self.im.sinh());
+        }
+        Complex::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    // Function to get the cosine.
+    pub fn cos(&self) -> Self {
+        // On the imaginary axis cos(iy) = cosh y, with the zero signed as −sin x·sinh y.
+        if self.re == T::zero() {
+            return Complex::new(self.im.cosh(), -self.re * self.im.signum());
+        }
+        Complex::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    // Function to get the tangent.
+    pub fn tan(&self) -> Self {
+        // tan z = −i·tanh(iz)
+        let w = self.times_i().tanh();
+        Complex::new(w.im, -w.re)
+    }
+
+    // Function to get the hyperbolic sine.
+    pub fn sinh(&self) -> Self {
+        // On the real axis the imaginary part stays a zero of the input's sign,
+        // rather than cosh x·sin 0 = NaN once cosh x overflows.
+        if self.im == T::zero() {
+            return Complex::new(self.re.sinh(), self.im);
+        }
+        Complex::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
+    }
+
+    // Function to get the hyperbolic cosine.
+    pub fn cosh(&self) -> Self {
+        // On the real axis the imaginary part is a zero signed as sinh x·sin y.
+        if self.im == T::zero() {
+            return Complex::new(self.re.cosh(), self.im * self.re.signum());
+        }
+        Complex::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
+
+    // Function to get the hyperbolic tangent.
+    pub fn tanh(&self) -> Self {
+        let two = T::one() + T::one();
+        let (re, im) = (self.re * two, self.im * two);
+        // Once cosh 2x overflows, tanh z is ±1 up to a correctly signed tiny
+        // imaginary part of about 2·sin(2y)·e^(−2|x|).
+        if re.abs() > T::max_value().ln() {
+            return Complex::new(T::one().copysign(re), two * im.sin() * (-re.abs()).exp());
+        }
+        // tanh z = (sinh 2x + i sin 2y)/(cosh 2x + cos 2y), divided through by cosh 2x.
+        let sech = re.cosh().recip();
+        let denominator = T::one() + im.cos() * sech;
+        Complex::new(re.tanh() / denominator, im.sin() * sech / denominator)
+    }
+
+    // Function to get the principal arcsine.
+    //
+    // Uses Kahan's formulas in terms of √(1 − z) and √(1 + z), which keep full
+    // accuracy near ±1 and respect signed zeros on the cuts.
+    pub fn asin(&self) -> Self {
+        let (s1, s2) = (self.one_minus().sqrt(), self.one_plus().sqrt());
+        Complex::new(
+            self.re.atan2(s1.re * s2.re - s1.im * s2.im),
+            (s1.re * s2.im - s1.im * s2.re).asinh(),
+        )
+    }
+
+    // Function to get the principal arccosine.
+    pub fn acos(&self) -> Self {
+        let (s1, s2) = (self.one_minus().sqrt(), self.one_plus().sqrt());
+        let two = T::one() + T::one();
+        Complex::new(
+            two * s1.re.atan2(s2.re),
+            (s2.re * s1.im - s2.im * s1.re).asinh(),
+        )
+    }
+
+    // Function to get the principal arctangent, −i·atanh(iz).
+    pub fn atan(&self) -> Self {
+        let w = self.times_i().atanh();
+        Complex::new(w.im, -w.re)
+    }
+
+    // Function to get the principal inverse hyperbolic sine, −i·asin(iz).
+    pub fn asinh(&self) -> Self {
+        let w = self.times_i().asin();
+        Complex::new(w.im, -w.re)
+    }
+
+    // Function to get the principal inverse hyperbolic cosine.
+    pub fn acosh(&self) -> Self {
+        let s1 = Complex::new(self.re - T::one(), self.im).sqrt();
+        let s2 = self.one_plus().sqrt();
+        let two = T::one() + T::one();
+        Complex::new(
+            (s1.re * s2.re + s1.im * s2.im).asinh(),
+            two * s1.im.atan2(s2.re),
+        )
+    }
+
+    // Function to get the principal inverse hyperbolic tangent, (ln(1 + z) − ln(1 − z)) / 2.
+    pub fn atanh(&self) -> Self {
+        (self.one_plus().ln() - self.one_minus().ln()).unscale(T::one() + T::one())
+    }
+
+    // Function to check whether both parts are finite.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    // Function to check whether either part is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    // 1 + z and 1 − z and i·z are formed part by part, so that the sign of a zero
+    // part survives and selects the side of a branch cut.
+    fn one_plus(&self) -> Self {
+        Complex::new(T::one() + self.re, self.im)
+    }
+
+    fn one_minus(&self) -> Self {
+        Complex::new(T::one() - self.re, -self.im)
+    }
+
+    fn times_i(&self) -> Self {
+        Complex::new(-self.im, self.re)
+    }
+}
+
+#[cfg(test)]
+mod complex_tests {
+    use super::*;
+    use crate::types::Fraction;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_close(a: Complex<f64>, b: Complex<f64>) {
+        assert!((a - b).modulus() < 1e-12, "{} != {}", a, b);
+    }
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn complex_new_test() {
+        let z = Complex::new(3, -4);
+
+        assert_eq!(z.re, 3);
+        assert_eq!(z.im, -4);
+        assert_eq!(z.conj(), Complex::new(3, 4));
+        assert_eq!(z.norm_sqr(), 25);
+        assert_eq!(z.scale(2), Complex::new(6, -8));
+        assert_eq!(Complex::<i32>::i().powu(2), Complex::new(-1, 0));
+        assert_eq!(Complex::<i32>::default(), Complex::new(0, 0));
+    }
+
+    #[test]
+    fn complex_powers_test() {
+        let z = c(1.0, 1.0);
+
+        assert_eq!(z.powu(4), c(-4.0, 0.0));
+        assert_eq!(z.powi(-2), c(0.0, -0.5));
+        assert_eq!(z.powi(0), c(1.0, 0.0));
+        assert_eq!(z.inv(), c(0.5, -0.5));
+        assert_close(z.powf(4.0), c(-4.0, 0.0));
+        assert_close(z.powc(c(2.0, 0.0)), c(0.0, 2.0));
+        // i^i = e^(−π/2) is real.
+        assert_close(Complex::i().powc(Complex::i()), c((-FRAC_PI_2).exp(), 0.0));
+        assert_eq!(c(0.0, 0.0).powc(c(2.0, 1.0)), c(0.0, 0.0));
+        assert_eq!(c(0.0, 0.0).powc(c(0.0, 0.0)), c(1.0, 0.0));
+    }
+
+    #[test]
+    fn complex_polar_test() {
+        let z = Complex::from_polar(2.0, Angle::from_radians(FRAC_PI_4));
+        assert_close(z, c(2f64.sqrt(), 2f64.sqrt()));
+
+        let (modulus, arg) = c(-1.0, 0.0).to_polar();
+        assert_eq!(modulus, 1.0);
+        assert_eq!(arg.radians(), PI);
+        assert_eq!(c(-1.0, -0.0).arg().radians(), -PI);
+        assert_eq!(c(3e300, 4e300).modulus(), 5e300);
+    }
+
+    #[test]
+    fn complex_exp_ln_test() {
+        let z = c(0.5, -2.0);
+        assert_close(z.ln().exp(), z);
+        assert_close(z.exp().ln(), z);
+        assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+        assert_close(c(-1.0, -0.0).ln(), c(0.0, -PI));
+        assert_close(c(100.0, 0.0).log(10.0), c(2.0, 0.0));
+    }
+
+    #[test]
+    fn complex_sqrt_test() {
+        assert_eq!(c(3.0, 4.0).sqrt(), c(2.0, 1.0));
+        assert_eq!(c(-3.0, 4.0).sqrt(), c(1.0, 2.0));
+        assert_eq!(c(-3.0, -4.0).sqrt(), c(1.0, -2.0));
+        // The sign of zero picks the side of the cut.
+        assert_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+        assert_eq!(c(-4.0, -0.0).sqrt(), c(0.0, -2.0));
+        assert_eq!(c(0.0, 0.0).sqrt(), c(0.0, 0.0));
+        // No cancellation for tiny imaginary parts.
+        assert_eq!(c(1.0, 1e-20).sqrt(), c(1.0, 5e-21));
+
+        let z = c(-8.0, 0.0).cbrt();
+        assert_close(z, c(1.0, 3f64.sqrt()));
+        assert_close(z.powu(3), c(-8.0, 0.0));
+    }
+
+    #[test]
+    fn complex_trigonometry_test() {
+        let z = c(0.7, -1.3);
+        let one = c(1.0, 0.0);
+
+        assert_close(z.sin() * z.sin() + z.cos() * z.cos(), one);
+        assert_close(z.tan(), z.sin() / z.cos());
+        assert_close(z.cosh() * z.cosh() - z.sinh() * z.sinh(), one);
+        assert_close(z.tanh(), z.sinh() / z.cosh());
+        assert_close(c(0.0, 1.0).sin(), c(0.0, 1f64.sinh()));
+
+        assert_close(z.asin().sin(), z);
+        assert_close(z.acos().cos(), z);
+        assert_close(z.atan().tan(), z);
+        assert_close(z.asinh().sinh(), z);
+        assert_close(z.acosh().cosh(), z);
+        assert_close(z.atanh().tanh(), z);
+        assert_close(z.asin() + z.acos(), c(FRAC_PI_2, 0.0));
+    }
+
+    #[test]
+    fn complex_tan_tanh_large_test() {
+        let z = Complex::new(1.0, 1000.0).tan();
+        assert_eq!(z, Complex::new(0.0, 1.0));
+        assert!(z.re.is_sign_positive());
+        let z = Complex::new(1.0, -1000.0).tan();
+        assert_eq!(z, Complex::new(0.0, -1.0));
+
+        let z = Complex::new(1000.0, 1.0).tanh();
+        assert_eq!(z, Complex::new(1.0, 0.0));
+        assert!(z.im.is_sign_positive());
+        let z = Complex::new(-1000.0, -1.0).tanh();
+        assert_eq!(z, Complex::new(-1.0, 0.0));
+        assert!(z.im.is_sign_negative());
+
+        // Just below the overflow threshold the tiny part is still representable.
+        let z = Complex::new(350.0, 0.25).tanh();
+        assert_eq!(z.re, 1.0);
+        assert!(z.im > 0.0 && z.im < 1e-300);
+
+        // Moderate arguments agree with sinh / cosh.
+        for w in [
+            Complex::new(0.5, 0.3),
+            Complex::new(-2.0, 1.2),
+            Complex::new(0.1, -3.0),
+        ] {
+            assert!((w.tanh() - w.sinh() / w.cosh()).modulus() < 1e-14);
+            assert!((w.tan() - w.sin() / w.cos()).modulus() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn complex_axis_overflow_test() {
+        // Overflowing results on an axis keep a signed zero instead of NaN.
+        let z = Complex::new(1000.0, 0.0).exp();
+        assert_eq!(z, Complex::new(f64::INFINITY, 0.0));
+        assert!(z.im.is_sign_positive());
+        let z = Complex::new(1000.0, -0.0).exp();
+        assert!(z.re.is_infinite() && z.im == 0.0 && z.im.is_sign_negative());
+
+        let z = Complex::new(-1000.0, 0.0).sinh();
+        assert_eq!(z, Complex::new(f64::NEG_INFINITY, 0.0));
+        assert!(z.im.is_sign_positive());
+        let z = Complex::new(1000.0, -0.0).cosh();
+        assert!(z.re.is_infinite() && z.im == 0.0 && z.im.is_sign_negative());
+        let z = Complex::new(-1000.0, 0.0).cosh();
+        assert!(z.re.is_infinite() && z.im == 0.0 && z.im.is_sign_negative());
+
+        let z = Complex::new(0.0, 1000.0).sin();
+        assert_eq!(z, Complex::new(0.0, f64::INFINITY));
+        assert!(z.re.is_sign_positive());
+        let z = Complex::new(0.0, 1000.0).cos();
+        assert!(z.re.is_infinite() && z.im == 0.0 && z.im.is_sign_negative());
+
+        // Off the axes the parts are unchanged.
+        let w = Complex::new(0.5, 0.3);
+        assert!((w.sinh() - (w.exp() - (-w).exp()).unscale(2.0)).modulus() < 1e-15);
+        assert!((w.cosh() - (w.exp() + (-w).exp()).unscale(2.0)).modulus() < 1e-15);
+    }
+
+    #[test]
+    fn complex_branch_cuts_test() {
+        // Principal values on the real axis agree with the real functions.
+        assert_close(c(0.5, 0.0).asin(), c(0.5f64.asin(), 0.0));
+        assert_close(c(0.5, 0.0).acos(), c(0.5f64.acos(), 0.0));
+        assert_close(c(2.0, 0.0).acosh(), c(2f64.acosh(), 0.0));
+        assert_close(c(0.5, 0.0).atanh(), c(0.5f64.atanh(), 0.0));
+
+        // Across a cut the values jump, and each side is reached with a signed zero.
+        let above = c(2.0, 0.0).asin();
+        let below = c(2.0, -0.0).asin();
+        assert_close(above, c(FRAC_PI_2, (2.0 + 3f64.sqrt()).ln()));
+        assert_close(below, above.conj());
+        assert_close(c(0.0, 2.0).atan(), c(FRAC_PI_2, (3f64).ln() / 2.0));
+        assert_close(c(-0.0, 2.0).atan(), c(-FRAC_PI_2, (3f64).ln() / 2.0));
+        assert_close(c(0.0, 2.0).asinh(), c((2.0 + 3f64.sqrt()).ln(), FRAC_PI_2));
+        assert_close(c(-2.0, 0.0).acosh(), c((2.0 + 3f64.sqrt()).ln(), PI));
+    }
+
+    #[test]
+    fn complex_gaussian_rational_test() {
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        let z = Complex::new(f(1, 2), f(-1, 3));
+
+        assert_eq!(z.conj(), Complex::new(f(1, 2), f(1, 3)));
+        assert_eq!(z.norm_sqr(), f(13, 36));
+        assert_eq!(z * z.inv(), Complex::new(f(1, 1), f(0, 1)));
+        assert_eq!(z.powi(-1), Complex::new(f(18, 13), f(12, 13)));
+        assert_eq!(z.powu(2), Complex::new(f(5, 36), f(-1, 3)));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::types::Complex;
+use core::fmt;
+use std::fmt::Formatter;
+
+// Complex numbers display as "3+4i" or "3-4i", honouring a requested precision.
+// The sign is taken from the formatted imaginary part, so -0 displays as "-0i".
+impl<T: fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (re, im) = match f.precision() {
+            Some(precision) => (
+                format!("{:.*}", precision, self.re),
+                format!("{:.*}", precision, self.im),
+            ),
+            None => (self.re.to_string(), self.im.to_string()),
+        };
+        match im.strip_prefix('-') {
+            Some(magnitude) => write!(f, "{}-{}i", re, magnitude),
+            None => write!(f, "{}+{}i", re, im),
+        }
+    }
+}
+
+#[cfg(test)]
+mod complex_display_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_display_test() {
+        assert_eq!(Complex::new(3, 4).to_string(), "3+4i");
+        assert_eq!(Complex::new(3.5, -4.0).to_string(), "3.5-4i");
+        assert_eq!(Complex::new(-0.0, -0.0).to_string(), "-0-0i");
+        assert_eq!(format!("{:.2}", Complex::new(1.0, 1.0 / 3.0)), "1.00+0.33i");
+
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        assert_eq!(Complex::new(f(1, 2), f(-3, 4)).to_string(), "1/2-3/4i");
+    }
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct ComplexError {
+    details: String,
+}
+
+impl ComplexError {
+    pub fn new(msg: &str) -> ComplexError {
+        ComplexError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for ComplexError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
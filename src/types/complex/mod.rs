@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod complex;
+pub mod display;
+pub mod error;
+pub mod num;
+pub mod ops;
@@ -0,0 +1,160 @@
+//! Implementations of the `num_traits` numeric traits and parsing for `Complex`.
+//!
+//! With these in place a `Complex` satisfies `num_traits::Num` whenever its parts
+//! do, so it can be used where the library accepts a generic number. Strings are
+//! parsed in the form written by `Display`: a real part, an imaginary part ending
+//! in `i`, or both joined by `+` or `-`, with optional spaces. A bare `i` stands
+//! for a coefficient of one. Each part is parsed with the `from_str_radix` of the
+//! part type, so fractions such as "1/2-3/4i" parse into `Complex<Fraction>`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::{Complex, Fraction};
+//! use num_traits::{Num, One, Zero};
+//!
+//! let parsed: Complex<f64> = "3 - 4.5i".parse().unwrap();
+//! let unit: Complex<f64> = "-i".parse().unwrap();
+//! let rational: Complex<Fraction> = "1/2+3/4i".parse().unwrap();
+//! let hex = Complex::<i64>::from_str_radix("ff+10i", 16).unwrap();
+//!
+//! assert_eq!(parsed, Complex::new(3.0, -4.5));
+//! assert_eq!(unit, Complex::new(0.0, -1.0));
+//! assert_eq!(rational.im, Fraction::new(3, 4).unwrap());
+//! assert_eq!(hex, Complex::new(255, 16));
+//! assert!(Complex::<f64>::zero().is_zero());
+//! assert_eq!(Complex::<f64>::one(), Complex::new(1.0, 0.0));
+//! ```
+
+use crate::types::complex::error::ComplexError;
+use crate::types::Complex;
+use num_traits::{Num, One, Zero};
+use std::str::FromStr;
+
+impl<T: Num + Copy> Zero for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Num + Copy> One for Complex<T> {
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T: Num + Copy + 'static> Num for Complex<T> {
+    type FromStrRadixErr = ComplexError;
+
+    // Parses "a", "bi", "a+bi" or "a-bi".
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let text: String = str.chars().filter(|c| !c.is_whitespace()).collect();
+        if text.is_empty() {
+            return Err(ComplexError::new("Empty complex number"));
+        }
+        // The parts are joined at the last sign that does not start the text, an
+        // exponent or a denominator.
+        let split = text.char_indices().rev().find(|&(index, c)| {
+            let previous = text[..index].chars().last();
+            (c == '+' || c == '-')
+                && index > 0
+                && previous != Some('/')
+                && !(radix <= 10 && matches!(previous, Some('e') | Some('E')))
+        });
+        let (real, imaginary) = match split {
+            Some((index, _)) => (&text[..index], Some(&text[index..])),
+            None if text.ends_with('i') => ("", Some(text.as_str())),
+            None => (text.as_str(), None),
+        };
+
+        let re = if real.is_empty() {
+            T::zero()
+        } else {
+            T::from_str_radix(real, radix).map_err(|_| ComplexError::new("Invalid real part"))?
+        };
+        let im = match imaginary {
+            None => T::zero(),
+            Some(imaginary) => {
+                let coefficient = imaginary
+                    .strip_suffix('i')
+                    .ok_or_else(|| ComplexError::new("Imaginary part must end in i"))?;
+                match coefficient {
+                    "" | "+" => T::one(),
+                    "-" => T::zero() - T::one(),
+                    _ => T::from_str_radix(coefficient.trim_start_matches('+'), radix)
+                        .map_err(|_| ComplexError::new("Invalid imaginary part"))?,
+                }
+            }
+        };
+        Ok(Complex::new(re, im))
+    }
+}
+
+impl<T: Num + Copy + 'static> FromStr for Complex<T> {
+    type Err = ComplexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Complex::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod complex_num_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_zero_one_test() {
+        let zero = Complex::<f64>::zero();
+        let one = Complex::<f64>::one();
+
+        assert!(zero.is_zero());
+        assert!(!one.is_zero());
+        assert!(!Complex::new(0.0, 1.0).is_zero());
+        assert_eq!(one * Complex::new(2.0, 3.0), Complex::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn complex_from_str_test() {
+        let parse = |s: &str| s.parse::<Complex<f64>>().unwrap();
+
+        assert_eq!(parse("3+4i"), Complex::new(3.0, 4.0));
+        assert_eq!(parse(" 3 - 4i "), Complex::new(3.0, -4.0));
+        assert_eq!(parse("-2.5i"), Complex::new(0.0, -2.5));
+        assert_eq!(parse("+i"), Complex::new(0.0, 1.0));
+        assert_eq!(parse("-1-i"), Complex::new(-1.0, -1.0));
+        assert_eq!(parse("7"), Complex::new(7.0, 0.0));
+        assert_eq!(parse("-7"), Complex::new(-7.0, 0.0));
+        assert_eq!(parse("1e-3-2E+2i"), Complex::new(0.001, -200.0));
+
+        assert!("".parse::<Complex<f64>>().is_err());
+        assert!("3+4".parse::<Complex<f64>>().is_err());
+        assert!("3+4j".parse::<Complex<f64>>().is_err());
+        assert!("x+2i".parse::<Complex<f64>>().is_err());
+    }
+
+    #[test]
+    fn complex_from_str_fraction_test() {
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        let z: Complex<Fraction> = "1/-2-3/4i".parse().unwrap();
+
+        assert_eq!(z, Complex::new(f(-1, 2), f(-3, 4)));
+        assert_eq!(z.to_string().parse::<Complex<Fraction>>().unwrap(), z);
+        assert!("1/0+i".parse::<Complex<Fraction>>().is_err());
+    }
+
+    #[test]
+    fn complex_display_round_trip_test() {
+        for z in [
+            Complex::new(1.5, -0.25),
+            Complex::new(-3.0, 1e-5),
+            Complex::new(0.0, 0.0),
+        ] {
+            assert_eq!(z.to_string().parse::<Complex<f64>>().unwrap(), z);
+        }
+    }
+}
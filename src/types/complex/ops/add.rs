@@ -0,0 +1,56 @@
+use crate::types::Complex;
+use num_traits::Num;
+
+use std::ops::{Add, AddAssign};
+
+impl<T: Num + Copy> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Num + Copy> Add<T> for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Complex::new(self.re + rhs, self.im)
+    }
+}
+
+impl<T: Num + Copy> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Num + Copy> AddAssign<T> for Complex<T> {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod complex_add_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_add_test() {
+        let mut z = Complex::new(1.0, 2.0) + Complex::new(3.0, -5.0);
+        assert_eq!(z, Complex::new(4.0, -3.0));
+
+        z += 1.5;
+        assert_eq!(z, Complex::new(5.5, -3.0));
+        z += Complex::new(0.5, 3.0);
+        assert_eq!(z, Complex::new(6.0, 0.0));
+
+        let half = Fraction::new(1, 2).unwrap();
+        let sum = Complex::new(half, half) + Complex::new(half, Fraction::new(1, 4).unwrap());
+        assert_eq!(
+            sum,
+            Complex::new(Fraction::new(1, 1).unwrap(), Fraction::new(3, 4).unwrap())
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::types::Complex;
+use num_traits::{Float, Num};
+
+use std::any::Any;
+use std::ops::{Div, DivAssign};
+
+impl<T: Num + Copy + 'static> Div for Complex<T> {
+    type Output = Self;
+
+    // (a + bi)/(c + di) = ((ac + bd) + (bc − ad)i)/(c² + d²)
+    //
+    // The formula is exact for types such as `Fraction` and the integers, but with
+    // floating-point parts c² + d² overflows or underflows long before the quotient
+    // does, so `f32` and `f64` parts use Smith's algorithm instead.
+    fn div(self, rhs: Self) -> Self::Output {
+        if let Some(quotient) = float_quotient(&self, &rhs) {
+            return quotient;
+        }
+        let denominator = rhs.norm_sqr();
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+impl<T: Num + Copy> Div<T> for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.unscale(rhs)
+    }
+}
+
+impl<T: Num + Copy + 'static> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Num + Copy> DivAssign<T> for Complex<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+// Function to divide with Smith's algorithm when the parts are `f32` or `f64`.
+//
+// Returns None for any other part type, which then uses the exact formula.
+fn float_quotient<T: Copy + 'static>(lhs: &Complex<T>, rhs: &Complex<T>) -> Option<Complex<T>> {
+    fn cast<T: Copy + 'static, U: Copy + 'static>(z: &Complex<T>) -> Option<Complex<U>> {
+        (z as &dyn Any).downcast_ref::<Complex<U>>().copied()
+    }
+    if let (Some(lhs), Some(rhs)) = (cast::<T, f64>(lhs), cast::<T, f64>(rhs)) {
+        return cast(&smith_div(lhs, rhs));
+    }
+    if let (Some(lhs), Some(rhs)) = (cast::<T, f32>(lhs), cast::<T, f32>(rhs)) {
+        return cast(&smith_div(lhs, rhs));
+    }
+    None
+}
+
+// Function to divide by first dividing through by the larger part of the divisor.
+//
+// This never forms c² + d², so the result is finite whenever the quotient is.
+fn smith_div<F: Float>(lhs: Complex<F>, rhs: Complex<F>) -> Complex<F> {
+    if rhs.re.abs() >= rhs.im.abs() {
+        let ratio = rhs.im / rhs.re;
+        let denominator = rhs.re + rhs.im * ratio;
+        Complex::new(
+            (lhs.re + lhs.im * ratio) / denominator,
+            (lhs.im - lhs.re * ratio) / denominator,
+        )
+    } else {
+        let ratio = rhs.re / rhs.im;
+        let denominator = rhs.re * ratio + rhs.im;
+        Complex::new(
+            (lhs.re * ratio + lhs.im) / denominator,
+            (lhs.im * ratio - lhs.re) / denominator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod complex_div_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_div_test() {
+        let mut z = Complex::new(4.0, 3.0) / Complex::new(2.0, -1.0);
+        assert_eq!(z, Complex::new(1.0, 2.0));
+
+        z /= 2.0;
+        assert_eq!(z, Complex::new(0.5, 1.0));
+        z /= Complex::new(0.0, 1.0);
+        assert_eq!(z, Complex::new(1.0, -0.5));
+        assert!((Complex::new(1.0, 0.0) / Complex::new(0.0, 0.0)).is_nan());
+
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        let quotient = Complex::new(f(2, 1), f(-5, 6)) / Complex::new(f(2, 1), f(-3, 1));
+        assert_eq!(quotient, Complex::new(f(1, 2), f(1, 3)));
+    }
+
+    #[test]
+    fn complex_div_extreme_test() {
+        // |rhs|² overflows or underflows, but the quotients are ordinary numbers.
+        let huge = Complex::new(1e300, 1e300);
+        assert_eq!(huge / huge, Complex::new(1.0, 0.0));
+        assert_eq!(huge.inv(), Complex::new(5e-301, -5e-301));
+        let tiny = Complex::new(1e-300, -1e-300);
+        assert_eq!(Complex::new(2e-300, 0.0) / tiny, Complex::new(1.0, 1.0));
+        let inverse = tiny.inv();
+        assert!((inverse - Complex::new(5e299, 5e299)).modulus() < 1e-15 * inverse.modulus());
+        let quotient = Complex::new(1e30f32, 0.0) / Complex::new(0.0, 1e30f32);
+        assert_eq!(quotient, Complex::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn complex_div_gaussian_chain_test() {
+        // Simplifying after every step keeps a chain of operations in lowest terms.
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        let simplify = |mut z: Complex<Fraction>| {
+            z.re.simplify();
+            z.im.simplify();
+            z
+        };
+        let a = Complex::new(f(1, 3), f(2, 5));
+        let b = Complex::new(f(3, 7), f(-1, 11));
+
+        let quotient = simplify(a / b);
+        assert_eq!(quotient.re.numerator, 3157);
+        assert_eq!(quotient.re.denominator, 5690);
+        assert_eq!(quotient.im.numerator, 17941);
+        assert_eq!(quotient.im.denominator, 17070);
+
+        let mut z = a;
+        for _ in 0..2 {
+            z = simplify(simplify(simplify(z * b) / a) + b);
+        }
+        assert_eq!(z.re.numerator, 227841);
+        assert_eq!(z.re.denominator, 361669);
+        assert_eq!(z.im.numerator, -289379);
+        assert_eq!(z.im.denominator, 361669);
+
+        for _ in 0..3 {
+            z = simplify(simplify(simplify(z * b) / a) + b);
+        }
+        assert_eq!(
+            z,
+            Complex::new(
+                f(-961450566003, 69531916621783),
+                f(-5382880990743967, 37477703059141037)
+            )
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::types::Complex;
+use num_traits::Num;
+
+use std::ops::{Mul, MulAssign};
+
+impl<T: Num + Copy> Mul for Complex<T> {
+    type Output = Self;
+
+    // (a + bi)(c + di) = (ac − bd) + (ad + bc)i
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Num + Copy> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Num + Copy> MulAssign<T> for Complex<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod complex_mul_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_mul_test() {
+        let mut z = Complex::new(1.0, 2.0) * Complex::new(3.0, -1.0);
+        assert_eq!(z, Complex::new(5.0, 5.0));
+
+        z *= 2.0;
+        assert_eq!(z, Complex::new(10.0, 10.0));
+        z *= Complex::new(0.0, 1.0);
+        assert_eq!(z, Complex::new(-10.0, 10.0));
+        assert_eq!(Complex::<i32>::i() * Complex::i(), Complex::new(-1, 0));
+
+        let f = |n, d| Fraction::new(n, d).unwrap();
+        let product = Complex::new(f(1, 2), f(1, 3)) * Complex::new(f(2, 1), f(-3, 1));
+        assert_eq!(product, Complex::new(f(2, 1), f(-5, 6)));
+    }
+}
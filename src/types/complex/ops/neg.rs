@@ -0,0 +1,27 @@
+use crate::types::Complex;
+use num_traits::Num;
+
+use std::ops::Neg;
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod complex_neg_tests {
+    use super::*;
+    use crate::types::Fraction;
+
+    #[test]
+    fn complex_neg_test() {
+        assert_eq!(-Complex::new(1.0, -2.0), Complex::new(-1.0, 2.0));
+
+        let z = -Complex::new(Fraction::new(1, 2).unwrap(), Fraction::new(-1, 3).unwrap());
+        assert_eq!(z.re, Fraction::new(-1, 2).unwrap());
+        assert_eq!(z.im, Fraction::new(1, 3).unwrap());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::types::Complex;
+use num_traits::Num;
+
+use std::ops::{Rem, RemAssign};
+
+impl<T: Num + Copy + 'static> Rem for Complex<T> {
+    type Output = Self;
+
+    // The remainder after dividing by a Gaussian quotient truncated towards zero,
+    // so that z = rhs·q + z % rhs.
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self / rhs;
+        let truncated = Complex::new(
+            quotient.re - quotient.re % T::one(),
+            quotient.im - quotient.im % T::one(),
+        );
+        self - rhs * truncated
+    }
+}
+
+impl<T: Num + Copy> Rem<T> for Complex<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Complex::new(self.re % rhs, self.im % rhs)
+    }
+}
+
+impl<T: Num + Copy + 'static> RemAssign for Complex<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl<T: Num + Copy> RemAssign<T> for Complex<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
+    }
+}
+
+#[cfg(test)]
+mod complex_rem_tests {
+    use super::*;
+
+    #[test]
+    fn complex_rem_test() {
+        let z = Complex::new(7, 5);
+        let w = Complex::new(2, 1);
+        let remainder = z % w;
+        assert_eq!(remainder, Complex::new(1, 2));
+        assert_eq!(w * (z / w) + remainder, z);
+
+        let mut x = Complex::new(7.5, -3.25);
+        x %= 2.0;
+        assert_eq!(x, Complex::new(1.5, -1.25));
+        x %= Complex::new(1.0, 0.0);
+        assert_eq!(x, Complex::new(0.5, -0.25));
+    }
+}
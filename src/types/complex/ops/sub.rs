@@ -0,0 +1,49 @@
+use crate::types::Complex;
+use num_traits::Num;
+
+use std::ops::{Sub, SubAssign};
+
+impl<T: Num + Copy> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Num + Copy> Sub<T> for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Complex::new(self.re - rhs, self.im)
+    }
+}
+
+impl<T: Num + Copy> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Num + Copy> SubAssign<T> for Complex<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod complex_sub_tests {
+    use super::*;
+
+    #[test]
+    fn complex_sub_test() {
+        let mut z = Complex::new(1.0, 2.0) - Complex::new(3.0, -5.0);
+        assert_eq!(z, Complex::new(-2.0, 7.0));
+
+        z -= 1.0;
+        assert_eq!(z, Complex::new(-3.0, 7.0));
+        z -= Complex::new(-3.0, 7.0);
+        assert_eq!(z, Complex::new(0.0, 0.0));
+        assert_eq!(Complex::new(4, 1) - 4, Complex::new(0, 1));
+    }
+}
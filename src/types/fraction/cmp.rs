@@ -0,0 +1,67 @@
+use crate::types::Fraction;
+
+use std::cmp::Ordering;
+
+// Fractions compare by value, so 1/2 and 2/4 are equal even when unsimplified.
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are always positive, so cross-multiplying keeps the order.
+        // Widening to i128 keeps the products from overflowing.
+        let lhs = self.numerator as i128 * other.denominator as i128;
+        let rhs = other.numerator as i128 * self.denominator as i128;
+        lhs.cmp(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod fraction_cmp_tests {
+    use super::*;
+
+    #[test]
+    fn fraction_eq_test() {
+        let frac1 = Fraction::new(1, 2).unwrap();
+        let frac2 = Fraction::new(2, 4).unwrap();
+        let frac3 = Fraction::new(-1, 2).unwrap();
+
+        assert_eq!(frac1, frac2);
+        assert_ne!(frac1, frac3);
+    }
+
+    #[test]
+    fn fraction_eq_by_value_test() {
+        // Equality is by value, not by the stored parts.
+        let frac1 = Fraction::new(2, 4).unwrap();
+        let frac2 = Fraction::new(1, 2).unwrap();
+
+        assert_ne!(frac1.numerator, frac2.numerator);
+        assert_ne!(frac1.denominator, frac2.denominator);
+        assert_eq!(frac1, frac2);
+        assert_eq!(Fraction::new(0, 3).unwrap(), Fraction::new(0, 7).unwrap());
+        assert_eq!(Fraction::new(-3, 6).unwrap(), Fraction::new(-1, 2).unwrap());
+    }
+
+    #[test]
+    fn fraction_cmp_test() {
+        let frac1 = Fraction::new(1, 3).unwrap();
+        let frac2 = Fraction::new(1, 2).unwrap();
+        let frac3 = Fraction::new(-3, 4).unwrap();
+
+        assert!(frac1 < frac2);
+        assert!(frac3 < frac1);
+        assert_eq!(frac2.max(frac1), frac2);
+    }
+}
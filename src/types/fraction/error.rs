@@ -25,4 +25,4 @@ impl error::Error for FractionError {
     fn description(&self) -> &str {
         &self.details
     }
-}
\ No newline at end of file
+}
@@ -3,6 +3,20 @@
 //! A `Fraction` consists of a numerator and a denominator, and
 //! provides various methods to perform operations.
 //!
+//! `new` keeps the parts as given and the arithmetic operators do not simplify
+//! their results, so `1/2 + 1/2` has the parts `2/2`; call `simplify` to reduce
+//! them.
+//!
+//! # Panics
+//!
+//! The operators panic with "Fraction arithmetic overflowed", in release builds
+//! too, when a part of the result does not fit in `isize`/`usize`, and dividing
+//! by zero panics. The `num_traits` `checked_add`, `checked_sub`, `checked_mul`,
+//! `checked_div` and `checked_rem` variants never panic: they return the result
+//! in lowest terms, or `None` if it does not fit or the divisor is zero.
+//!
+//! Fractions compare by value, so `1/2 == 2/4` even though their parts differ.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -37,11 +51,11 @@
 //! - `reciprocal`: Finds the fractions reciprocal.
 //! - `simplify`: Simplifies the fraction structure.
 //! - `to_decimal`: Gets the fraction structure's decimal value.
-//! - `to_string`: Gets the fraction structure's string value.
+//! - `to_string`: Gets the fraction structure's string value (via `Display`).
 
 use crate::types::fraction::error::FractionError;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub struct Fraction {
     pub numerator: isize,
     pub denominator: usize,
@@ -88,7 +102,7 @@ impl Fraction {
             self.denominator as isize
         };
 
-        let denominator = self.numerator.abs() as usize;
+        let denominator = self.numerator.unsigned_abs();
 
         Fraction {
             numerator,
@@ -98,18 +112,18 @@ impl Fraction {
 
     // Function to simplify the fraction by dividing numerator and denominator by their GCD.
     pub fn simplify(&mut self) {
-        let gcd = Fraction::gcd(self.numerator.abs() as usize, self.denominator);
+        let gcd = Fraction::gcd(self.numerator.unsigned_abs(), self.denominator);
         // If the numerator is negative, keep it negative after simplification.
         if self.numerator < 0 {
             // Find GCD without negative sign and reassign numerator.
-            let numerator_abs = self.numerator.abs() as usize / gcd;
+            let numerator_abs = self.numerator.unsigned_abs() / gcd;
             self.numerator = -(numerator_abs as isize);
         } else {
             // Otherwise, simply divide the numerator by the GCD.
-            self.numerator = self.numerator / gcd as isize;
+            self.numerator /= gcd as isize;
         }
         // Simplify the denominator by dividing the GCD.
-        self.denominator = self.denominator / gcd;
+        self.denominator /= gcd;
     }
 
     // Function to convert the fraction to a decimal (integer division).
@@ -117,9 +131,42 @@ impl Fraction {
         self.numerator as f64 / self.denominator as f64
     }
 
-    // Function to convert the fraction into a string.
-    pub fn to_string(&self) -> String {
-        format!("{}/{}", self.numerator, self.denominator)
+    // Function to find the least common denominator of two denominators, along with
+    // the factors that scale each fraction to it, or None if they do not fit.
+    pub(crate) fn common_denominator(a: usize, b: usize) -> Option<(usize, isize, isize)> {
+        let gcd = Fraction::gcd(a, b);
+        let common_denominator = (a / gcd).checked_mul(b)?;
+        let scale_a = isize::try_from(b / gcd).ok()?;
+        let scale_b = isize::try_from(a / gcd).ok()?;
+        Some((common_denominator, scale_a, scale_b))
+    }
+
+    // Function to build a fraction in lowest terms from a wide numerator and denominator.
+    //
+    // Backs the checked arithmetic: a part that already overflowed (None), a zero
+    // denominator or a simplified fraction that does not fit gives None.
+    pub(crate) fn checked_from_wide(
+        numerator: Option<i128>,
+        denominator: Option<i128>,
+    ) -> Option<Self> {
+        let (numerator, denominator) = (numerator?, denominator?);
+        if denominator == 0 {
+            return None;
+        }
+        let (mut a, mut b) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let magnitude = numerator.unsigned_abs() / a;
+        let numerator = if (numerator < 0) != (denominator < 0) {
+            0i128.checked_sub_unsigned(magnitude)?
+        } else {
+            i128::try_from(magnitude).ok()?
+        };
+        Some(Fraction {
+            numerator: isize::try_from(numerator).ok()?,
+            denominator: usize::try_from(denominator.unsigned_abs() / a).ok()?,
+        })
     }
 }
 
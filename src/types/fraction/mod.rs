@@ -1,4 +1,7 @@
-pub mod fraction;
-pub mod ops;
+pub mod cmp;
 pub mod display;
 pub mod error;
+#[allow(clippy::module_inception)]
+pub mod fraction;
+pub mod num;
+pub mod ops;
@@ -0,0 +1,258 @@
+//! Implementations of the `num_traits` numeric traits for `Fraction`.
+//!
+//! With these in place a `Fraction` satisfies `num_traits::Num`, so it can be
+//! used anywhere the library accepts a generic number, for example as the
+//! coordinate type of the geometry vectors for exact arithmetic.
+//!
+//! The checked operations return their result in lowest terms, or `None` when
+//! it does not fit in `isize`/`usize` or the divisor is zero, where the plain
+//! operators would overflow or panic.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::Fraction;
+//! use num_traits::{CheckedAdd, CheckedMul, Num, One, Zero};
+//!
+//! let zero = Fraction::zero();
+//! let one = Fraction::one();
+//! let parsed: Fraction = "3/4".parse().unwrap();
+//! let hex = Fraction::from_str_radix("-a/10", 16).unwrap();
+//!
+//! assert!(zero.is_zero());
+//! assert_eq!(one, Fraction::new(1, 1).unwrap());
+//! assert_eq!(parsed, Fraction::new(3, 4).unwrap());
+//! assert_eq!(hex, Fraction::new(-10, 16).unwrap());
+//!
+//! let half = Fraction::new(1, 2).unwrap();
+//! let sum = half.checked_add(&half).unwrap();
+//! let huge = Fraction::new(isize::MAX, 1).unwrap();
+//!
+//! assert_eq!((sum.numerator, sum.denominator), (1, 1));
+//! assert!(huge.checked_mul(&huge).is_none());
+//! ```
+
+use crate::types::fraction::error::FractionError;
+use crate::types::Fraction;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Num, One, Signed, ToPrimitive, Zero,
+};
+use std::str::FromStr;
+
+impl Zero for Fraction {
+    fn zero() -> Self {
+        Fraction::new(0, 1).unwrap()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+}
+
+impl One for Fraction {
+    fn one() -> Self {
+        Fraction::new(1, 1).unwrap()
+    }
+}
+
+impl Num for Fraction {
+    type FromStrRadixErr = FractionError;
+
+    // Parses either "numerator/denominator" or a bare integer numerator.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (numerator, denominator) = match str.split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (str.trim(), "1"),
+        };
+        let numerator = isize::from_str_radix(numerator, radix)
+            .map_err(|_| FractionError::new("Invalid numerator"))?;
+        let denominator = isize::from_str_radix(denominator, radix)
+            .map_err(|_| FractionError::new("Invalid denominator"))?;
+        // Move a negative denominator's sign onto the numerator.
+        let numerator = if denominator < 0 {
+            -numerator
+        } else {
+            numerator
+        };
+        Fraction::new(numerator, denominator.unsigned_abs())
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = FractionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fraction::from_str_radix(s, 10)
+    }
+}
+
+impl Signed for Fraction {
+    fn abs(&self) -> Self {
+        Fraction::new(self.numerator.abs(), self.denominator).unwrap()
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Fraction::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Fraction::new(self.numerator.signum(), 1).unwrap()
+    }
+
+    fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+}
+
+impl ToPrimitive for Fraction {
+    fn to_i64(&self) -> Option<i64> {
+        (self.numerator / self.denominator as isize).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self.numerator / self.denominator as isize).to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_decimal())
+    }
+}
+
+impl CheckedAdd for Fraction {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        // a/b + c/d = (a * d + c * b) / (b * d), widened to i128 before reducing.
+        let (b, d) = (self.denominator as i128, v.denominator as i128);
+        let numerator = (self.numerator as i128 * d).checked_add(v.numerator as i128 * b);
+        Fraction::checked_from_wide(numerator, b.checked_mul(d))
+    }
+}
+
+impl CheckedSub for Fraction {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let (b, d) = (self.denominator as i128, v.denominator as i128);
+        let numerator = (self.numerator as i128 * d).checked_sub(v.numerator as i128 * b);
+        Fraction::checked_from_wide(numerator, b.checked_mul(d))
+    }
+}
+
+impl CheckedMul for Fraction {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        let numerator = self.numerator as i128 * v.numerator as i128;
+        let denominator = (self.denominator as i128).checked_mul(v.denominator as i128);
+        Fraction::checked_from_wide(Some(numerator), denominator)
+    }
+}
+
+impl CheckedDiv for Fraction {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        let numerator = self.numerator as i128 * v.denominator as i128;
+        let denominator = self.denominator as i128 * v.numerator as i128;
+        Fraction::checked_from_wide(Some(numerator), Some(denominator))
+    }
+}
+
+impl CheckedRem for Fraction {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        // a/b % c/d = ((a * d) % (c * b)) / (b * d), keeping the sign of the dividend.
+        let dividend = self.numerator as i128 * v.denominator as i128;
+        let divisor = v.numerator as i128 * self.denominator as i128;
+        let denominator = (self.denominator as i128).checked_mul(v.denominator as i128);
+        Fraction::checked_from_wide(dividend.checked_rem(divisor), denominator)
+    }
+}
+
+#[cfg(test)]
+mod fraction_num_tests {
+    use super::*;
+
+    #[test]
+    fn fraction_zero_one_test() {
+        let zero = Fraction::zero();
+        let one = Fraction::one();
+
+        assert!(zero.is_zero());
+        assert!(!one.is_zero());
+        assert_eq!(one.numerator, 1);
+        assert_eq!(one.denominator, 1);
+    }
+
+    #[test]
+    fn fraction_from_str_radix_test() {
+        let frac = Fraction::from_str_radix("3/4", 10).unwrap();
+        assert_eq!(frac.numerator, 3);
+        assert_eq!(frac.denominator, 4);
+
+        let frac = Fraction::from_str_radix("5", 10).unwrap();
+        assert_eq!(frac, Fraction::new(5, 1).unwrap());
+
+        let frac = Fraction::from_str_radix("1/-2", 10).unwrap();
+        assert_eq!(frac.numerator, -1);
+        assert_eq!(frac.denominator, 2);
+
+        assert!(Fraction::from_str_radix("1/0", 10).is_err());
+        assert!(Fraction::from_str_radix("x/2", 10).is_err());
+    }
+
+    #[test]
+    fn fraction_signed_test() {
+        let frac = Fraction::new(-3, 4).unwrap();
+
+        assert_eq!(frac.abs(), Fraction::new(3, 4).unwrap());
+        assert_eq!(frac.signum(), Fraction::new(-1, 1).unwrap());
+        assert!(frac.is_negative());
+    }
+
+    #[test]
+    fn fraction_to_primitive_test() {
+        let frac = Fraction::new(7, 2).unwrap();
+
+        assert_eq!(frac.to_i64(), Some(3));
+        assert_eq!(frac.to_f64(), Some(3.5));
+    }
+
+    #[test]
+    fn fraction_checked_test() {
+        let half = Fraction::new(1, 2).unwrap();
+        let third = Fraction::new(-1, 3).unwrap();
+
+        let sum = half.checked_add(&half).unwrap();
+        assert_eq!((sum.numerator, sum.denominator), (1, 1));
+        let difference = half.checked_sub(&third).unwrap();
+        assert_eq!((difference.numerator, difference.denominator), (5, 6));
+        let product = half.checked_mul(&third).unwrap();
+        assert_eq!((product.numerator, product.denominator), (-1, 6));
+        let quotient = Fraction::new(3, 4).unwrap().checked_div(&third).unwrap();
+        assert_eq!((quotient.numerator, quotient.denominator), (-9, 4));
+        let remainder = Fraction::new(7, 4).unwrap().checked_rem(&half).unwrap();
+        assert_eq!((remainder.numerator, remainder.denominator), (1, 4));
+    }
+
+    #[test]
+    fn fraction_checked_overflow_test() {
+        let huge = Fraction::new(isize::MAX, 1).unwrap();
+        let tiny = Fraction::new(1, usize::MAX).unwrap();
+
+        assert!(huge.checked_add(&huge).is_none());
+        assert!(huge.checked_mul(&huge).is_none());
+        assert!(tiny.checked_mul(&tiny).is_none());
+        assert!(huge.checked_div(&Fraction::zero()).is_none());
+        assert!(huge.checked_rem(&Fraction::zero()).is_none());
+
+        // Coprime denominators near 10^9 give an exact sum near the limit of usize.
+        let frac1 = Fraction::new(1, 999_999_937).unwrap();
+        let frac2 = Fraction::new(-1, 999_999_929).unwrap();
+        let sum = frac1.checked_add(&frac2).unwrap();
+        assert_eq!(
+            (sum.numerator, sum.denominator),
+            (-8, 999_999_866_000_004_473)
+        );
+    }
+}
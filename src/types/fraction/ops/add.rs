@@ -6,10 +6,15 @@ impl Add for Fraction {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let common_denominator = Fraction::lcm(self.denominator, rhs.denominator);
-        let numerator1 = self.numerator * (common_denominator / self.denominator) as isize;
-        let numerator2 = rhs.numerator * (common_denominator / rhs.denominator) as isize;
-        let result_numerator = numerator1 + numerator2;
+        let (common_denominator, scale1, scale2) =
+            Fraction::common_denominator(self.denominator, rhs.denominator)
+                .expect("Fraction arithmetic overflowed");
+        let numerator1 = self.numerator.checked_mul(scale1);
+        let numerator2 = rhs.numerator.checked_mul(scale2);
+        let result_numerator = numerator1
+            .zip(numerator2)
+            .and_then(|(numerator1, numerator2)| numerator1.checked_add(numerator2))
+            .expect("Fraction arithmetic overflowed");
         Fraction::new(result_numerator, common_denominator).unwrap()
     }
 }
@@ -6,13 +6,20 @@ impl Div for Fraction {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let numerator = self.numerator * rhs.denominator as isize;
-        let denominator = self.denominator * rhs.numerator.abs() as usize;
-        let mut result = Fraction::new(numerator, denominator).unwrap();
-        if rhs.numerator < 0 {
-            result.numerator = -result.numerator;
+        let numerator = isize::try_from(rhs.denominator)
+            .ok()
+            .and_then(|denominator| self.numerator.checked_mul(denominator));
+        // Move the divisor's sign onto the numerator.
+        let numerator = if rhs.numerator < 0 {
+            numerator.and_then(isize::checked_neg)
+        } else {
+            numerator
+        };
+        let denominator = self.denominator.checked_mul(rhs.numerator.unsigned_abs());
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) => Fraction::new(numerator, denominator).unwrap(),
+            _ => panic!("Fraction arithmetic overflowed"),
         }
-        result
     }
 }
 
@@ -1,4 +1,6 @@
 mod add;
 mod div;
 mod mul;
+mod neg;
+mod rem;
 mod sub;
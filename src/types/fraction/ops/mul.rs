@@ -6,9 +6,12 @@ impl Mul for Fraction {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let numerator = self.numerator * rhs.numerator;
-        let denominator = self.denominator * rhs.denominator;
-        Fraction::new(numerator, denominator).unwrap()
+        let numerator = self.numerator.checked_mul(rhs.numerator);
+        let denominator = self.denominator.checked_mul(rhs.denominator);
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) => Fraction::new(numerator, denominator).unwrap(),
+            _ => panic!("Fraction arithmetic overflowed"),
+        }
     }
 }
 
@@ -25,4 +28,11 @@ mod fraction_mul_tests {
         assert_eq!(frac3.numerator, 1);
         assert_eq!(frac3.denominator, 4);
     }
+
+    #[test]
+    #[should_panic(expected = "Fraction arithmetic overflowed")]
+    fn fraction_mul_overflow_test() {
+        let frac = Fraction::new(isize::MAX, 1).unwrap();
+        let _ = frac * frac;
+    }
 }
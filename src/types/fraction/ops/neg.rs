@@ -0,0 +1,29 @@
+use crate::types::Fraction;
+
+use std::ops::Neg;
+
+impl Neg for Fraction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let numerator = self
+            .numerator
+            .checked_neg()
+            .expect("Fraction arithmetic overflowed");
+        Fraction::new(numerator, self.denominator).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod fraction_neg_tests {
+    use super::*;
+
+    #[test]
+    fn fraction_neg_test() {
+        let frac1 = Fraction::new(1, 2).unwrap();
+        let frac2 = -frac1;
+
+        assert_eq!(frac2.numerator, -1);
+        assert_eq!(frac2.denominator, 2);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::types::Fraction;
+
+use std::ops::Rem;
+
+impl Rem for Fraction {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        // a/b % c/d = ((a * d) % (c * b)) / (b * d), keeping the sign of the dividend.
+        let scaled = |numerator: isize, denominator: usize| {
+            isize::try_from(denominator)
+                .ok()
+                .and_then(|denominator| numerator.checked_mul(denominator))
+        };
+        let dividend = scaled(self.numerator, rhs.denominator);
+        let divisor = scaled(rhs.numerator, self.denominator);
+        let denominator = self.denominator.checked_mul(rhs.denominator);
+        match (dividend, divisor, denominator) {
+            (Some(dividend), Some(divisor), Some(denominator)) => {
+                Fraction::new(dividend % divisor, denominator).unwrap()
+            }
+            _ => panic!("Fraction arithmetic overflowed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod fraction_rem_tests {
+    use super::*;
+
+    #[test]
+    fn fraction_rem_test() {
+        let frac1 = Fraction::new(7, 4).unwrap();
+        let frac2 = Fraction::new(1, 2).unwrap();
+        let frac3 = frac1 % frac2;
+
+        assert_eq!(frac3.numerator, 2);
+        assert_eq!(frac3.denominator, 8);
+    }
+}
@@ -1,3 +1,13 @@
+pub mod angle;
+pub mod complex;
 pub mod fraction;
+pub mod quantity;
+pub mod quaternion;
 
-pub use crate::types::fraction::fraction::Fraction;
\ No newline at end of file
+pub use crate::types::angle::angle::Angle;
+pub use crate::types::complex::complex::Complex;
+pub use crate::types::fraction::fraction::Fraction;
+pub use crate::types::quantity::area::Area;
+pub use crate::types::quantity::length::Length;
+pub use crate::types::quantity::volume::Volume;
+pub use crate::types::quaternion::quaternion::Quaternion;
@@ -0,0 +1,108 @@
+//! `Area` is a struct representing a surface area with a unit
+//!
+//! An `Area` stores its size in square metres and converts to and from any
+//! `AreaUnit`. It is the product of two `Length`s, and dividing it by a length
+//! gives a length back.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::quantity::unit::{AreaUnit, LengthUnit};
+//! use crabmath::types::{Area, Length};
+//!
+//! let field = Area::new(2.5, AreaUnit::Acre);
+//! let frontage = Length::new(330.0, LengthUnit::Foot);
+//! let depth = field / frontage;
+//!
+//! assert!((depth.to(LengthUnit::Foot) - 330.0f64).abs() < 1e-9);
+//! assert!((field.to(AreaUnit::Hectare) - 1.0117141056).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs an area from a value in a unit.
+//! - `from_square_meters`: Constructs an area from a value in square metres.
+//! - `zero`: Constructs the zero area.
+//! - `square_meters`: Gets the size in square metres.
+//! - `to`: Gets the size in a unit.
+//! - `abs`: Gets the magnitude of the area.
+//! - `sqrt`: Gets the side of a square with this area.
+
+use crate::types::quantity::length::Length;
+use crate::types::quantity::unit::AreaUnit;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Area<T> {
+    square_meters: T,
+}
+
+impl<T> Area<T>
+where
+    T: Float,
+{
+    // Function to create an area from a value in a unit.
+    pub fn new(value: T, unit: AreaUnit) -> Self {
+        Area::from_square_meters(value * T::from(unit.factor()).unwrap())
+    }
+
+    // Function to create an area from its size in square metres.
+    pub fn from_square_meters(square_meters: T) -> Self {
+        Area { square_meters }
+    }
+
+    // Function to create the zero area.
+    pub fn zero() -> Self {
+        Area::from_square_meters(T::zero())
+    }
+
+    // Function to get the size in square metres.
+    pub fn square_meters(&self) -> T {
+        self.square_meters
+    }
+
+    // Function to get the size in a unit.
+    pub fn to(&self, unit: AreaUnit) -> T {
+        self.square_meters / T::from(unit.factor()).unwrap()
+    }
+
+    // Function to get the magnitude of the area.
+    pub fn abs(&self) -> Self {
+        Area::from_square_meters(self.square_meters.abs())
+    }
+
+    // Function to get the side of a square with this area.
+    pub fn sqrt(&self) -> Length<T> {
+        Length::from_meters(self.square_meters.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod area_tests {
+    use super::*;
+    use crate::types::quantity::unit::LengthUnit;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn area_conversion_test() {
+        let area = Area::new(1.0, AreaUnit::Square(LengthUnit::Mile));
+
+        assert_close(area.to(AreaUnit::Acre), 640.0);
+        assert_close(area.to(AreaUnit::Hectare), 258.9988110336);
+        assert_close(Area::new(1.0, AreaUnit::Hectare).square_meters(), 10000.0);
+        assert_close(
+            Area::new(9.0, AreaUnit::Square(LengthUnit::Foot))
+                .to(AreaUnit::Square(LengthUnit::Yard)),
+            1.0,
+        );
+        assert_close(area.sqrt().to(LengthUnit::Mile), 1.0);
+        assert_eq!(
+            Area::from_square_meters(-2.0).abs(),
+            Area::from_square_meters(2.0)
+        );
+        assert_eq!(Area::<f64>::zero(), Area::default());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::types::quantity::unit::{AreaUnit, LengthUnit, VolumeUnit};
+use crate::types::{Area, Length, Volume};
+use core::fmt;
+use num_traits::Float;
+use std::fmt::Formatter;
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            LengthUnit::Millimeter => "mm",
+            LengthUnit::Centimeter => "cm",
+            LengthUnit::Meter => "m",
+            LengthUnit::Kilometer => "km",
+            LengthUnit::Inch => "in",
+            LengthUnit::Foot => "ft",
+            LengthUnit::Yard => "yd",
+            LengthUnit::Mile => "mi",
+            LengthUnit::NauticalMile => "nmi",
+            LengthUnit::UsSurveyFoot => "ftUS",
+            LengthUnit::UsSurveyMile => "miUS",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+impl fmt::Display for AreaUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AreaUnit::Square(unit) => write!(f, "{}²", unit),
+            AreaUnit::Hectare => write!(f, "ha"),
+            AreaUnit::Acre => write!(f, "ac"),
+            AreaUnit::UsSurveyAcre => write!(f, "acUS"),
+        }
+    }
+}
+
+impl fmt::Display for VolumeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VolumeUnit::Cubic(unit) => write!(f, "{}³", unit),
+            VolumeUnit::Milliliter => write!(f, "mL"),
+            VolumeUnit::Liter => write!(f, "L"),
+            VolumeUnit::UsFluidOunce => write!(f, "fl oz"),
+            VolumeUnit::UsGallon => write!(f, "gal"),
+            VolumeUnit::ImperialFluidOunce => write!(f, "imp fl oz"),
+            VolumeUnit::ImperialGallon => write!(f, "imp gal"),
+        }
+    }
+}
+
+// Quantities display in SI base units, honouring a requested precision.
+fn write_quantity<T: fmt::Display>(f: &mut Formatter<'_>, value: T, symbol: &str) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*} {}", precision, value, symbol),
+        None => write!(f, "{} {}", value, symbol),
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Length<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_quantity(f, self.meters(), "m")
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Area<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_quantity(f, self.square_meters(), "m²")
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Volume<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_quantity(f, self.cubic_meters(), "m³")
+    }
+}
+
+#[cfg(test)]
+mod quantity_display_tests {
+    use super::*;
+
+    #[test]
+    fn unit_to_string_test() {
+        assert_eq!(LengthUnit::UsSurveyFoot.to_string(), "ftUS");
+        assert_eq!(AreaUnit::Square(LengthUnit::Kilometer).to_string(), "km²");
+        assert_eq!(AreaUnit::Hectare.to_string(), "ha");
+        assert_eq!(VolumeUnit::Cubic(LengthUnit::Inch).to_string(), "in³");
+        assert_eq!(VolumeUnit::Liter.to_string(), "L");
+    }
+
+    #[test]
+    fn quantity_to_string_test() {
+        assert_eq!(Length::from_meters(1.5).to_string(), "1.5 m");
+        assert_eq!(
+            format!("{:.2}", Area::from_square_meters(2.0 / 3.0)),
+            "0.67 m²"
+        );
+        assert_eq!(Volume::from_cubic_meters(8.0).to_string(), "8 m³");
+    }
+}
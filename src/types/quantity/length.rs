@@ -0,0 +1,100 @@
+//! `Length` is a struct representing a distance with a unit
+//!
+//! A `Length` stores its size in metres and converts to and from any
+//! `LengthUnit`, so feet and metres can be mixed without hand-written factors.
+//! Multiplying two lengths gives an `Area` and multiplying by an area gives a
+//! `Volume`, so the dimension of a result is checked by the compiler.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::quantity::unit::{AreaUnit, LengthUnit};
+//! use crabmath::types::Length;
+//!
+//! let plank = Length::new(6.0, LengthUnit::Foot);
+//! let offcut = Length::new(30.0, LengthUnit::Centimeter);
+//! let remaining = plank - offcut;
+//!
+//! assert!((remaining.meters() - 1.5288f64).abs() < 1e-12);
+//! assert!((remaining.to(LengthUnit::Inch) - 60.18897637795276).abs() < 1e-9);
+//!
+//! let area = plank * offcut;
+//! assert!((area.to(AreaUnit::Square(LengthUnit::Meter)) - 0.54864).abs() < 1e-12);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a length from a value in a unit.
+//! - `from_meters`: Constructs a length from a value in metres.
+//! - `zero`: Constructs the zero length.
+//! - `meters`: Gets the size in metres.
+//! - `to`: Gets the size in a unit.
+//! - `abs`: Gets the magnitude of the length.
+
+use crate::types::quantity::unit::LengthUnit;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Length<T> {
+    meters: T,
+}
+
+impl<T> Length<T>
+where
+    T: Float,
+{
+    // Function to create a length from a value in a unit.
+    pub fn new(value: T, unit: LengthUnit) -> Self {
+        Length::from_meters(value * T::from(unit.factor()).unwrap())
+    }
+
+    // Function to create a length from its size in metres.
+    pub fn from_meters(meters: T) -> Self {
+        Length { meters }
+    }
+
+    // Function to create the zero length.
+    pub fn zero() -> Self {
+        Length::from_meters(T::zero())
+    }
+
+    // Function to get the size in metres.
+    pub fn meters(&self) -> T {
+        self.meters
+    }
+
+    // Function to get the size in a unit.
+    pub fn to(&self, unit: LengthUnit) -> T {
+        self.meters / T::from(unit.factor()).unwrap()
+    }
+
+    // Function to get the magnitude of the length.
+    pub fn abs(&self) -> Self {
+        Length::from_meters(self.meters.abs())
+    }
+}
+
+#[cfg(test)]
+mod length_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn length_conversion_test() {
+        let mile = Length::new(1.0, LengthUnit::Mile);
+
+        assert_close(mile.meters(), 1609.344);
+        assert_close(mile.to(LengthUnit::Foot), 5280.0);
+        assert_close(mile.to(LengthUnit::Kilometer), 1.609344);
+        assert_close(mile.to(LengthUnit::UsSurveyFoot), 5279.98944);
+        assert_close(
+            Length::new(1.0, LengthUnit::NauticalMile).to(LengthUnit::Meter),
+            1852.0,
+        );
+        assert_eq!(Length::<f64>::zero(), Length::default());
+        assert_eq!(Length::from_meters(-2.0).abs(), Length::from_meters(2.0));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod area;
+pub mod display;
+pub mod length;
+pub mod ops;
+pub mod unit;
+pub mod volume;
@@ -0,0 +1,57 @@
+use crate::types::{Area, Length, Volume};
+use num_traits::Float;
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+// Quantities add only to quantities of the same dimension.
+macro_rules! impl_add {
+    ($quantity:ident, $getter:ident, $constructor:ident) => {
+        impl<T: Float> Add for $quantity<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $quantity::$constructor(self.$getter() + rhs.$getter())
+            }
+        }
+
+        impl<T: Float> AddAssign for $quantity<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Float> Sum for $quantity<T> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($quantity::zero(), |total, value| total + value)
+            }
+        }
+    };
+}
+
+impl_add!(Length, meters, from_meters);
+impl_add!(Area, square_meters, from_square_meters);
+impl_add!(Volume, cubic_meters, from_cubic_meters);
+
+#[cfg(test)]
+mod quantity_add_tests {
+    use super::*;
+    use crate::types::quantity::unit::LengthUnit;
+
+    #[test]
+    fn quantity_add_test() {
+        let mut length =
+            Length::new(1.0, LengthUnit::Meter) + Length::new(50.0, LengthUnit::Centimeter);
+        assert_eq!(length.meters(), 1.5);
+
+        length += Length::from_meters(0.5);
+        assert_eq!(length.meters(), 2.0);
+
+        let total: Area<f64> = (1..=3).map(|i| Area::from_square_meters(i as f64)).sum();
+        assert_eq!(total.square_meters(), 6.0);
+        assert_eq!(
+            (Volume::from_cubic_meters(1.0) + Volume::from_cubic_meters(2.0)).cubic_meters(),
+            3.0
+        );
+    }
+}
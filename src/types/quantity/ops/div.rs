@@ -0,0 +1,103 @@
+use crate::types::{Area, Length, Volume};
+use num_traits::Float;
+
+use std::ops::{Div, DivAssign};
+
+// Dividing by a plain number keeps the dimension, while dividing two quantities
+// of the same dimension gives a plain ratio.
+macro_rules! impl_div_scalar {
+    ($quantity:ident, $getter:ident, $constructor:ident) => {
+        impl<T: Float> Div<T> for $quantity<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $quantity::$constructor(self.$getter() / rhs)
+            }
+        }
+
+        impl<T: Float> DivAssign<T> for $quantity<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Float> Div for $quantity<T> {
+            type Output = T;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.$getter() / rhs.$getter()
+            }
+        }
+    };
+}
+
+impl_div_scalar!(Length, meters, from_meters);
+impl_div_scalar!(Area, square_meters, from_square_meters);
+impl_div_scalar!(Volume, cubic_meters, from_cubic_meters);
+
+impl<T: Float> Div<Length<T>> for Area<T> {
+    type Output = Length<T>;
+
+    fn div(self, rhs: Length<T>) -> Self::Output {
+        Length::from_meters(self.square_meters() / rhs.meters())
+    }
+}
+
+impl<T: Float> Div<Length<T>> for Volume<T> {
+    type Output = Area<T>;
+
+    fn div(self, rhs: Length<T>) -> Self::Output {
+        Area::from_square_meters(self.cubic_meters() / rhs.meters())
+    }
+}
+
+impl<T: Float> Div<Area<T>> for Volume<T> {
+    type Output = Length<T>;
+
+    fn div(self, rhs: Area<T>) -> Self::Output {
+        Length::from_meters(self.cubic_meters() / rhs.square_meters())
+    }
+}
+
+#[cfg(test)]
+mod quantity_div_tests {
+    use super::*;
+    use crate::types::quantity::unit::LengthUnit;
+
+    #[test]
+    fn quantity_div_scalar_test() {
+        let mut length = Length::from_meters(3.0) / 2.0;
+        assert_eq!(length.meters(), 1.5);
+
+        length /= 3.0;
+        assert_eq!(length.meters(), 0.5);
+        assert_eq!((Area::from_square_meters(3.0) / 2.0).square_meters(), 1.5);
+        assert_eq!((Volume::from_cubic_meters(3.0) / 2.0).cubic_meters(), 1.5);
+    }
+
+    #[test]
+    fn quantity_div_ratio_test() {
+        let ratio = Length::new(1.0, LengthUnit::Mile) / Length::new(1.0, LengthUnit::Foot);
+
+        assert!((ratio - 5280.0).abs() < 1e-9);
+        assert_eq!(
+            Area::from_square_meters(6.0) / Area::from_square_meters(2.0),
+            3.0
+        );
+        assert_eq!(
+            Volume::from_cubic_meters(6.0) / Volume::from_cubic_meters(2.0),
+            3.0
+        );
+    }
+
+    #[test]
+    fn quantity_div_dimension_test() {
+        let volume = Volume::from_cubic_meters(24.0);
+        let area = Area::from_square_meters(6.0);
+        let length = Length::from_meters(4.0);
+
+        assert_eq!((area / length).meters(), 1.5);
+        assert_eq!((volume / length).square_meters(), 6.0);
+        assert_eq!((volume / area).meters(), 4.0);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::types::{Area, Length, Volume};
+use num_traits::Float;
+
+use std::ops::{Mul, MulAssign};
+
+// Scaling by a plain number keeps the dimension.
+macro_rules! impl_mul_scalar {
+    ($quantity:ident, $getter:ident, $constructor:ident) => {
+        impl<T: Float> Mul<T> for $quantity<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $quantity::$constructor(self.$getter() * rhs)
+            }
+        }
+
+        impl<T: Float> MulAssign<T> for $quantity<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_mul_scalar!(Length, meters, from_meters);
+impl_mul_scalar!(Area, square_meters, from_square_meters);
+impl_mul_scalar!(Volume, cubic_meters, from_cubic_meters);
+
+impl<T: Float> Mul for Length<T> {
+    type Output = Area<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Area::from_square_meters(self.meters() * rhs.meters())
+    }
+}
+
+impl<T: Float> Mul<Area<T>> for Length<T> {
+    type Output = Volume<T>;
+
+    fn mul(self, rhs: Area<T>) -> Self::Output {
+        Volume::from_cubic_meters(self.meters() * rhs.square_meters())
+    }
+}
+
+impl<T: Float> Mul<Length<T>> for Area<T> {
+    type Output = Volume<T>;
+
+    fn mul(self, rhs: Length<T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+#[cfg(test)]
+mod quantity_mul_tests {
+    use super::*;
+    use crate::types::quantity::unit::{AreaUnit, LengthUnit, VolumeUnit};
+
+    #[test]
+    fn quantity_mul_scalar_test() {
+        let mut length = Length::from_meters(1.5) * 2.0;
+        assert_eq!(length.meters(), 3.0);
+
+        length *= 2.0;
+        assert_eq!(length.meters(), 6.0);
+        assert_eq!((Area::from_square_meters(1.5) * 2.0).square_meters(), 3.0);
+        assert_eq!((Volume::from_cubic_meters(1.5) * 2.0).cubic_meters(), 3.0);
+    }
+
+    #[test]
+    fn quantity_mul_dimension_test() {
+        let foot = Length::new(1.0, LengthUnit::Foot);
+        let area = foot * Length::new(12.0, LengthUnit::Inch);
+        let volume = area * Length::new(1.0, LengthUnit::Yard);
+
+        assert!((area.to(AreaUnit::Square(LengthUnit::Foot)) - 1.0).abs() < 1e-12);
+        assert!((volume.to(VolumeUnit::Cubic(LengthUnit::Foot)) - 3.0).abs() < 1e-12);
+        assert_eq!(foot * area, area * foot);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::types::{Area, Length, Volume};
+use num_traits::Float;
+
+use std::ops::Neg;
+
+macro_rules! impl_neg {
+    ($quantity:ident, $getter:ident, $constructor:ident) => {
+        impl<T: Float> Neg for $quantity<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $quantity::$constructor(-self.$getter())
+            }
+        }
+    };
+}
+
+impl_neg!(Length, meters, from_meters);
+impl_neg!(Area, square_meters, from_square_meters);
+impl_neg!(Volume, cubic_meters, from_cubic_meters);
+
+#[cfg(test)]
+mod quantity_neg_tests {
+    use super::*;
+
+    #[test]
+    fn quantity_neg_test() {
+        assert_eq!((-Length::from_meters(2.0)).meters(), -2.0);
+        assert_eq!((-Area::from_square_meters(2.0)).square_meters(), -2.0);
+        assert_eq!((-Volume::from_cubic_meters(-2.0)).cubic_meters(), 2.0);
+    }
+}
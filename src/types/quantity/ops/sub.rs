@@ -0,0 +1,49 @@
+use crate::types::{Area, Length, Volume};
+use num_traits::Float;
+
+use std::ops::{Sub, SubAssign};
+
+macro_rules! impl_sub {
+    ($quantity:ident, $getter:ident, $constructor:ident) => {
+        impl<T: Float> Sub for $quantity<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $quantity::$constructor(self.$getter() - rhs.$getter())
+            }
+        }
+
+        impl<T: Float> SubAssign for $quantity<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_sub!(Length, meters, from_meters);
+impl_sub!(Area, square_meters, from_square_meters);
+impl_sub!(Volume, cubic_meters, from_cubic_meters);
+
+#[cfg(test)]
+mod quantity_sub_tests {
+    use super::*;
+
+    #[test]
+    fn quantity_sub_test() {
+        let mut length = Length::from_meters(3.0) - Length::from_meters(1.0);
+        assert_eq!(length.meters(), 2.0);
+
+        length -= Length::from_meters(2.5);
+        assert_eq!(length.meters(), -0.5);
+
+        assert_eq!(
+            (Area::from_square_meters(3.0) - Area::from_square_meters(1.0)).square_meters(),
+            2.0
+        );
+        assert_eq!(
+            (Volume::from_cubic_meters(3.0) - Volume::from_cubic_meters(1.0)).cubic_meters(),
+            2.0
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Units of length, area and volume.
+//!
+//! Each unit knows its size in the SI base unit of its dimension: metres, square
+//! metres or cubic metres. The factors are exact by definition. The international
+//! foot is exactly 0.3048 m, while the US survey foot is 1200/3937 m, so a survey
+//! mile is about 3.2 mm longer than an international one. Area and volume units
+//! include the square or cube of every length unit, along with the customary units
+//! of each dimension.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::quantity::unit::{AreaUnit, LengthUnit, VolumeUnit};
+//!
+//! assert_eq!(LengthUnit::Foot.factor(), 0.3048);
+//! assert_eq!(AreaUnit::Square(LengthUnit::Kilometer).factor(), 1e6);
+//! assert_eq!(VolumeUnit::Liter.factor(), 0.001);
+//! assert_eq!(AreaUnit::Square(LengthUnit::Foot).to_string(), "ft²");
+//! ```
+//!
+//! # Functions
+//!
+//! - `factor`: Gets the size of the unit in the SI base unit of its dimension.
+//! - `to_string`: Gets the unit symbol (via `Display`).
+
+// Metres per US survey foot, exactly 1200/3937 (a metre is 3937/1200 survey feet).
+const US_SURVEY_FOOT: f64 = 1200.0 / 3937.0;
+const US_GALLON: f64 = 3.785411784e-3;
+const IMPERIAL_GALLON: f64 = 4.54609e-3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    Millimeter,
+    Centimeter,
+    Meter,
+    Kilometer,
+    Inch,
+    Foot,
+    Yard,
+    Mile,
+    NauticalMile,
+    UsSurveyFoot,
+    UsSurveyMile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AreaUnit {
+    Square(LengthUnit),
+    Hectare,
+    Acre,
+    UsSurveyAcre,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VolumeUnit {
+    Cubic(LengthUnit),
+    Milliliter,
+    Liter,
+    UsFluidOunce,
+    UsGallon,
+    ImperialFluidOunce,
+    ImperialGallon,
+}
+
+impl LengthUnit {
+    // Function to get the length of the unit in metres.
+    pub fn factor(&self) -> f64 {
+        match self {
+            LengthUnit::Millimeter => 1e-3,
+            LengthUnit::Centimeter => 1e-2,
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Kilometer => 1e3,
+            LengthUnit::Inch => 0.0254,
+            LengthUnit::Foot => 0.3048,
+            LengthUnit::Yard => 0.9144,
+            LengthUnit::Mile => 1609.344,
+            LengthUnit::NauticalMile => 1852.0,
+            LengthUnit::UsSurveyFoot => US_SURVEY_FOOT,
+            LengthUnit::UsSurveyMile => 5280.0 * US_SURVEY_FOOT,
+        }
+    }
+}
+
+impl AreaUnit {
+    // Function to get the area of the unit in square metres.
+    pub fn factor(&self) -> f64 {
+        match self {
+            AreaUnit::Square(unit) => unit.factor() * unit.factor(),
+            AreaUnit::Hectare => 1e4,
+            // An acre is 43,560 square feet of the matching kind.
+            AreaUnit::Acre => 43560.0 * 0.3048 * 0.3048,
+            AreaUnit::UsSurveyAcre => 43560.0 * US_SURVEY_FOOT * US_SURVEY_FOOT,
+        }
+    }
+}
+
+impl VolumeUnit {
+    // Function to get the volume of the unit in cubic metres.
+    pub fn factor(&self) -> f64 {
+        match self {
+            VolumeUnit::Cubic(unit) => unit.factor() * unit.factor() * unit.factor(),
+            VolumeUnit::Milliliter => 1e-6,
+            VolumeUnit::Liter => 1e-3,
+            VolumeUnit::UsFluidOunce => US_GALLON / 128.0,
+            VolumeUnit::UsGallon => US_GALLON,
+            VolumeUnit::ImperialFluidOunce => IMPERIAL_GALLON / 160.0,
+            VolumeUnit::ImperialGallon => IMPERIAL_GALLON,
+        }
+    }
+}
+
+#[cfg(test)]
+mod quantity_unit_tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-12 * b.abs(), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn length_unit_factor_test() {
+        assert_close(12.0 * LengthUnit::Inch.factor(), LengthUnit::Foot.factor());
+        assert_close(3.0 * LengthUnit::Foot.factor(), LengthUnit::Yard.factor());
+        assert_close(
+            1760.0 * LengthUnit::Yard.factor(),
+            LengthUnit::Mile.factor(),
+        );
+        assert_close(LengthUnit::UsSurveyMile.factor(), 1609.347218694437);
+        assert!(LengthUnit::UsSurveyFoot.factor() > LengthUnit::Foot.factor());
+    }
+
+    #[test]
+    fn area_volume_unit_factor_test() {
+        assert_close(AreaUnit::Acre.factor(), 4046.8564224);
+        assert_close(AreaUnit::UsSurveyAcre.factor(), 4046.872609874252);
+        assert_close(
+            640.0 * AreaUnit::Acre.factor(),
+            AreaUnit::Square(LengthUnit::Mile).factor(),
+        );
+        assert_close(
+            VolumeUnit::Cubic(LengthUnit::Centimeter).factor(),
+            VolumeUnit::Milliliter.factor(),
+        );
+        assert_close(
+            VolumeUnit::UsGallon.factor(),
+            231.0 * VolumeUnit::Cubic(LengthUnit::Inch).factor(),
+        );
+        assert_close(VolumeUnit::ImperialFluidOunce.factor(), 28.4130625e-6);
+    }
+}
@@ -0,0 +1,115 @@
+//! `Volume` is a struct representing a volume with a unit
+//!
+//! A `Volume` stores its size in cubic metres and converts to and from any
+//! `VolumeUnit`. It is the product of a `Length` and an `Area`, and dividing it
+//! by one of them gives the other.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::types::quantity::unit::{LengthUnit, VolumeUnit};
+//! use crabmath::types::{Length, Volume};
+//!
+//! let tank = Volume::new(500.0, VolumeUnit::UsGallon);
+//! let footprint = Length::new(1.0, LengthUnit::Meter) * Length::new(1.0, LengthUnit::Meter);
+//! let depth = tank / footprint;
+//!
+//! assert!((tank.to(VolumeUnit::Liter) - 1892.705892f64).abs() < 1e-9);
+//! assert!((depth.to(LengthUnit::Centimeter) - 189.2705892).abs() < 1e-9);
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a volume from a value in a unit.
+//! - `from_cubic_meters`: Constructs a volume from a value in cubic metres.
+//! - `zero`: Constructs the zero volume.
+//! - `cubic_meters`: Gets the size in cubic metres.
+//! - `to`: Gets the size in a unit.
+//! - `abs`: Gets the magnitude of the volume.
+//! - `cbrt`: Gets the side of a cube with this volume.
+
+use crate::types::quantity::length::Length;
+use crate::types::quantity::unit::VolumeUnit;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Volume<T> {
+    cubic_meters: T,
+}
+
+impl<T> Volume<T>
+where
+    T: Float,
+{
+    // Function to create a volume from a value in a unit.
+    pub fn new(value: T, unit: VolumeUnit) -> Self {
+        Volume::from_cubic_meters(value * T::from(unit.factor()).unwrap())
+    }
+
+    // Function to create a volume from its size in cubic metres.
+    pub fn from_cubic_meters(cubic_meters: T) -> Self {
+        Volume { cubic_meters }
+    }
+
+    // Function to create the zero volume.
+    pub fn zero() -> Self {
+        Volume::from_cubic_meters(T::zero())
+    }
+
+    // Function to get the size in cubic metres.
+    pub fn cubic_meters(&self) -> T {
+        self.cubic_meters
+    }
+
+    // Function to get the size in a unit.
+    pub fn to(&self, unit: VolumeUnit) -> T {
+        self.cubic_meters / T::from(unit.factor()).unwrap()
+    }
+
+    // Function to get the magnitude of the volume.
+    pub fn abs(&self) -> Self {
+        Volume::from_cubic_meters(self.cubic_meters.abs())
+    }
+
+    // Function to get the side of a cube with this volume.
+    pub fn cbrt(&self) -> Length<T> {
+        Length::from_meters(self.cubic_meters.cbrt())
+    }
+}
+
+#[cfg(test)]
+mod volume_tests {
+    use super::*;
+    use crate::types::quantity::unit::LengthUnit;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn volume_conversion_test() {
+        let volume = Volume::new(1.0, VolumeUnit::Cubic(LengthUnit::Meter));
+
+        assert_close(volume.to(VolumeUnit::Liter), 1000.0);
+        assert_close(volume.to(VolumeUnit::ImperialGallon), 219.969248299088);
+        assert_close(
+            Volume::new(128.0, VolumeUnit::UsFluidOunce).to(VolumeUnit::UsGallon),
+            1.0,
+        );
+        assert_close(
+            Volume::new(160.0, VolumeUnit::ImperialFluidOunce).to(VolumeUnit::ImperialGallon),
+            1.0,
+        );
+        assert_close(
+            Volume::new(27.0, VolumeUnit::Cubic(LengthUnit::Foot))
+                .cbrt()
+                .to(LengthUnit::Yard),
+            1.0,
+        );
+        assert_eq!(
+            Volume::from_cubic_meters(-2.0).abs(),
+            Volume::from_cubic_meters(2.0)
+        );
+        assert_eq!(Volume::<f64>::zero(), Volume::default());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::types::Quaternion;
+use core::fmt;
+use std::fmt::Formatter;
+
+// Quaternions display as "1+2i-3j+4k", honouring a requested precision.
+// The sign of each imaginary part is taken from its formatted value.
+impl<T: fmt::Display> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        let format = |value: &T| match precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        };
+        write!(f, "{}", format(&self.w))?;
+        for (value, unit) in [(&self.x, 'i'), (&self.y, 'j'), (&self.z, 'k')] {
+            let text = format(value);
+            match text.strip_prefix('-') {
+                Some(magnitude) => write!(f, "-{}{}", magnitude, unit)?,
+                None => write!(f, "+{}{}", text, unit)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quaternion_display_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_display_test() {
+        assert_eq!(Quaternion::new(1, 2, -3, 4).to_string(), "1+2i-3j+4k");
+        assert_eq!(
+            Quaternion::new(0.5, 0.0, -0.0, -1.5).to_string(),
+            "0.5+0i-0j-1.5k"
+        );
+        assert_eq!(
+            format!("{:.2}", Quaternion::new(1.0, 1.0 / 3.0, -2.0, 0.0)),
+            "1.00+0.33i-2.00j+0.00k"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+use std::error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct QuaternionError {
+    details: String,
+}
+
+impl QuaternionError {
+    pub fn new(msg: &str) -> QuaternionError {
+        QuaternionError {
+            details: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for QuaternionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl error::Error for QuaternionError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
@@ -0,0 +1,311 @@
+//! Conversions between quaternions and Euler angles.
+//!
+//! Euler angles describe a rotation as three successive rotations about
+//! coordinate axes. There are twelve axis sequences: six Tait–Bryan sequences
+//! that use every axis once (such as `Zyx` for yaw, pitch and roll) and six
+//! proper Euler sequences that repeat the first axis (such as `Zxz`).
+//!
+//! The angles are intrinsic: each rotation turns about an axis of the body as
+//! already rotated by the previous ones. An extrinsic rotation about the fixed
+//! axes equals the intrinsic rotation with the sequence and the angles in reverse
+//! order, so extrinsic `Xyz` by (a, b, c) is intrinsic `Zyx` by (c, b, a); see
+//! `EulerOrder::reversed`.
+//!
+//! `to_euler` gives the first and third angles in (−π, π] and the middle angle in
+//! [−π/2, π/2] for Tait–Bryan sequences or [0, π] for proper Euler sequences. At
+//! gimbal lock, where the first and third axes line up, only their combination is
+//! determined; the third angle is then reported as zero.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::vector::Vector3;
+//! use crabmath::types::quaternion::euler::EulerOrder;
+//! use crabmath::types::{Angle, Quaternion};
+//!
+//! let yaw = Angle::from_degrees(30.0f64);
+//! let pitch = Angle::from_degrees(-20.0);
+//! let roll = Angle::from_degrees(10.0);
+//! let attitude = Quaternion::from_euler(EulerOrder::Zyx, [yaw, pitch, roll]);
+//!
+//! let angles = attitude.to_euler(EulerOrder::Zyx);
+//! assert!((angles[0].degrees() - 30.0).abs() < 1e-12);
+//! assert!((angles[1].degrees() + 20.0).abs() < 1e-12);
+//! assert!((angles[2].degrees() - 10.0).abs() < 1e-12);
+//!
+//! // A pure yaw turns the x axis towards the y axis.
+//! let heading = Quaternion::from_euler(EulerOrder::Zyx, [Angle::from_degrees(90.0), Angle::zero(), Angle::zero()]);
+//! let forward = heading.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+//! assert!((forward - Vector3::new(0.0, 1.0, 0.0)).norm() < 1e-15);
+//! ```
+//!
+//! # Functions
+//!
+//! - `EulerOrder::axes`: Gets the indices of the three rotation axes.
+//! - `EulerOrder::is_proper`: Checks whether the sequence repeats its first axis.
+//! - `EulerOrder::reversed`: Gets the sequence with the axes in reverse order.
+//! - `from_euler`: Constructs the rotation by three intrinsic Euler angles.
+//! - `to_euler`: Gets the intrinsic Euler angles of a rotation.
+
+use crate::types::{Angle, Quaternion};
+use num_traits::{Float, FloatConst};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    Xyx,
+    Xzx,
+    Yxy,
+    Yzy,
+    Zxz,
+    Zyz,
+}
+
+impl EulerOrder {
+    // Function to get the axes in rotation order, with x, y and z as 0, 1 and 2.
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::Xyz => [0, 1, 2],
+            EulerOrder::Xzy => [0, 2, 1],
+            EulerOrder::Yxz => [1, 0, 2],
+            EulerOrder::Yzx => [1, 2, 0],
+            EulerOrder::Zxy => [2, 0, 1],
+            EulerOrder::Zyx => [2, 1, 0],
+            EulerOrder::Xyx => [0, 1, 0],
+            EulerOrder::Xzx => [0, 2, 0],
+            EulerOrder::Yxy => [1, 0, 1],
+            EulerOrder::Yzy => [1, 2, 1],
+            EulerOrder::Zxz => [2, 0, 2],
+            EulerOrder::Zyz => [2, 1, 2],
+        }
+    }
+
+    // Function to check whether this is a proper Euler sequence, repeating its first axis.
+    pub fn is_proper(&self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+
+    // Function to get the sequence with the axes in reverse order.
+    pub fn reversed(&self) -> EulerOrder {
+        match self {
+            EulerOrder::Xyz => EulerOrder::Zyx,
+            EulerOrder::Xzy => EulerOrder::Yzx,
+            EulerOrder::Yxz => EulerOrder::Zxy,
+            EulerOrder::Yzx => EulerOrder::Xzy,
+            EulerOrder::Zxy => EulerOrder::Yxz,
+            EulerOrder::Zyx => EulerOrder::Xyz,
+            proper => *proper,
+        }
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float + FloatConst,
+{
+    // Function to create the rotation by intrinsic Euler angles in the given order.
+    pub fn from_euler(order: EulerOrder, angles: [Angle<T>; 3]) -> Self {
+        order
+            .axes()
+            .iter()
+            .zip(angles)
+            .map(|(&axis, angle)| Quaternion::about_axis(axis, angle.radians()))
+            .fold(Quaternion::identity(), |product, factor| product * factor)
+    }
+
+    // Function to get the intrinsic Euler angles in the given order.
+    //
+    // Follows Bernardes and Viollet, "Quaternion to Euler angles conversion: a
+    // direct, general and computationally efficient method" (2022), which reads
+    // the angles off a permutation of the components instead of a matrix.
+    pub fn to_euler(&self, order: EulerOrder) -> [Angle<T>; 3] {
+        let unit = self.normalize().unwrap_or_else(Quaternion::identity);
+        let parts = [unit.x, unit.y, unit.z];
+        // The method is stated for extrinsic sequences; the intrinsic sequence is
+        // the extrinsic one reversed, with the angles reversed too.
+        let [k, j, i] = order.axes();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        // Whether (i, j, k) is an even (+1) or odd (−1) permutation.
+        let sign = if (j + 3 - i) % 3 == 1 {
+            T::one()
+        } else {
+            -T::one()
+        };
+
+        let (a, b, c, d) = if proper {
+            (unit.w, parts[i], parts[j], parts[k] * sign)
+        } else {
+            (
+                unit.w - parts[j],
+                parts[i] + parts[k] * sign,
+                parts[j] + unit.w,
+                parts[k] * sign - parts[i],
+            )
+        };
+
+        let two = T::one() + T::one();
+        let mut middle = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_difference = d.atan2(c);
+        let tolerance = T::epsilon().sqrt();
+        let locked = middle.abs() <= tolerance || (middle - T::PI()).abs() <= tolerance;
+        let (mut first, mut third) = (half_sum - half_difference, half_sum + half_difference);
+        if !proper {
+            third = third * sign;
+            middle = middle - T::FRAC_PI_2();
+        }
+        // The extrinsic angles come out in the reverse of the intrinsic order.
+        std::mem::swap(&mut first, &mut third);
+        if locked {
+            // Only the combined turn about the aligned axes is known; put all of it
+            // into the first angle, which is what remains after the middle one.
+            let axes = order.axes();
+            let rest = unit * Quaternion::about_axis(axes[1], middle).conj();
+            first = two * [rest.x, rest.y, rest.z][axes[0]].atan2(rest.w);
+            third = T::zero();
+        }
+        [first, middle, third].map(|radians| Angle::from_radians(radians).normalized_signed())
+    }
+
+    // Function to create the rotation about a coordinate axis, with x, y and z as 0, 1 and 2.
+    fn about_axis(axis: usize, radians: T) -> Self {
+        let (sin, cos) = (radians / (T::one() + T::one())).sin_cos();
+        let mut parts = [T::zero(); 3];
+        parts[axis] = sin;
+        Quaternion::new(cos, parts[0], parts[1], parts[2])
+    }
+}
+
+#[cfg(test)]
+mod quaternion_euler_tests {
+    use super::*;
+    use crate::fields::geometry::vector::Vector3;
+
+    const ORDERS: [EulerOrder; 12] = [
+        EulerOrder::Xyz,
+        EulerOrder::Xzy,
+        EulerOrder::Yxz,
+        EulerOrder::Yzx,
+        EulerOrder::Zxy,
+        EulerOrder::Zyx,
+        EulerOrder::Xyx,
+        EulerOrder::Xzx,
+        EulerOrder::Yxy,
+        EulerOrder::Yzy,
+        EulerOrder::Zxz,
+        EulerOrder::Zyz,
+    ];
+
+    fn degrees(angles: [f64; 3]) -> [Angle<f64>; 3] {
+        angles.map(Angle::from_degrees)
+    }
+
+    fn assert_same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) {
+        assert!(a.angle_to(&b).radians() < 1e-7, "{a} != {b}");
+    }
+
+    #[test]
+    fn euler_order_test() {
+        assert_eq!(EulerOrder::Zyx.axes(), [2, 1, 0]);
+        assert!(EulerOrder::Zxz.is_proper());
+        assert!(!EulerOrder::Xyz.is_proper());
+        for order in ORDERS {
+            let mut axes = order.axes();
+            axes.reverse();
+            assert_eq!(order.reversed().axes(), axes);
+        }
+    }
+
+    #[test]
+    fn from_euler_test() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let z = Vector3::new(0.0, 0.0, 1.0);
+
+        // Intrinsic: after turning 90° about z, the second rotation is about the
+        // body x axis, which now points along the fixed y axis.
+        let q = Quaternion::from_euler(EulerOrder::Zxy, degrees([90.0, 90.0, 0.0]));
+        assert!((q.rotate_vector(z) - x).norm() < 1e-12);
+
+        for order in ORDERS {
+            let angles = degrees([40.0, -25.0, 75.0]);
+            let [a, b, c] = order.axes();
+            let expected = Quaternion::from_axis_angle(axis(a), angles[0]).unwrap()
+                * Quaternion::from_axis_angle(axis(b), angles[1]).unwrap()
+                * Quaternion::from_axis_angle(axis(c), angles[2]).unwrap();
+            assert_same_rotation(Quaternion::from_euler(order, angles), expected);
+
+            // Extrinsic rotations are intrinsic ones with everything reversed.
+            let mut fixed = Vector3::new(0.3, -0.7, 1.1);
+            for (index, angle) in order.axes().iter().zip(angles) {
+                fixed = Quaternion::from_axis_angle(axis(*index), angle)
+                    .unwrap()
+                    .rotate_vector(fixed);
+            }
+            let reversed =
+                Quaternion::from_euler(order.reversed(), [angles[2], angles[1], angles[0]]);
+            assert!((reversed.rotate_vector(Vector3::new(0.3, -0.7, 1.1)) - fixed).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn to_euler_test() {
+        for order in ORDERS {
+            let sets: [[f64; 3]; 4] = if order.is_proper() {
+                [
+                    [40.0, 25.0, 75.0],
+                    [-170.0, 160.0, -5.0],
+                    [10.0, 1.0, 179.0],
+                    [0.0, 90.0, 0.0],
+                ]
+            } else {
+                [
+                    [40.0, -25.0, 75.0],
+                    [-170.0, 80.0, -5.0],
+                    [10.0, 1.0, 179.0],
+                    [0.0, -89.0, 0.0],
+                ]
+            };
+            for set in sets {
+                let q = Quaternion::from_euler(order, degrees(set));
+                let angles = q.to_euler(order);
+                for (angle, expected) in angles.iter().zip(set) {
+                    assert!(
+                        (angle.degrees() - expected).abs() < 1e-9,
+                        "{order:?}: {set:?} gave {angles:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_euler_gimbal_lock_test() {
+        for order in ORDERS {
+            let middle = if order.is_proper() {
+                [0.0, 180.0]
+            } else {
+                [90.0, -90.0]
+            };
+            for m in middle {
+                let q = Quaternion::from_euler(order, degrees([30.0, m, 20.0]));
+                let angles = q.to_euler(order);
+                assert_eq!(angles[2].radians(), 0.0);
+                assert!((angles[1].degrees() - m).abs() < 1e-6);
+                assert_same_rotation(Quaternion::from_euler(order, angles), q);
+            }
+        }
+    }
+
+    fn axis(index: usize) -> Vector3<f64> {
+        let mut parts = [0.0; 3];
+        parts[index] = 1.0;
+        Vector3::new(parts[0], parts[1], parts[2])
+    }
+}
@@ -0,0 +1,6 @@
+pub mod display;
+pub mod error;
+pub mod euler;
+pub mod ops;
+#[allow(clippy::module_inception)]
+pub mod quaternion;
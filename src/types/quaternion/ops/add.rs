@@ -0,0 +1,37 @@
+use crate::types::Quaternion;
+use num_traits::Num;
+
+use std::ops::{Add, AddAssign};
+
+impl<T: Num + Copy> Add for Quaternion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl<T: Num + Copy> AddAssign for Quaternion<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod quaternion_add_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_add_test() {
+        let mut q = Quaternion::new(1, 2, 3, 4) + Quaternion::new(4, -3, 2, -1);
+        assert_eq!(q, Quaternion::new(5, -1, 5, 3));
+
+        q += Quaternion::new(-5, 1, -5, -3);
+        assert_eq!(q, Quaternion::new(0, 0, 0, 0));
+    }
+}
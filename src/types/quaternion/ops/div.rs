@@ -0,0 +1,34 @@
+use crate::types::Quaternion;
+use num_traits::Num;
+
+use std::ops::{Div, DivAssign};
+
+// Only division by a scalar is provided: quaternion products do not commute, so
+// q / p is ambiguous. Multiply by `inverse` on the side that is meant instead.
+impl<T: Num + Copy> Div<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.unscale(rhs)
+    }
+}
+
+impl<T: Num + Copy> DivAssign<T> for Quaternion<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod quaternion_div_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_div_test() {
+        let mut q = Quaternion::new(2.0, -4.0, 6.0, 1.0) / 2.0;
+        assert_eq!(q, Quaternion::new(1.0, -2.0, 3.0, 0.5));
+
+        q /= 0.5;
+        assert_eq!(q, Quaternion::new(2.0, -4.0, 6.0, 1.0));
+    }
+}
@@ -0,0 +1,5 @@
+mod add;
+mod div;
+mod mul;
+mod neg;
+mod sub;
@@ -0,0 +1,64 @@
+use crate::types::Quaternion;
+use num_traits::Num;
+
+use std::ops::{Mul, MulAssign};
+
+impl<T: Num + Copy> Mul for Quaternion<T> {
+    type Output = Self;
+
+    // The Hamilton product, with i² = j² = k² = ijk = −1.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Num + Copy> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Num + Copy> MulAssign<T> for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod quaternion_mul_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_mul_test() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let k = Quaternion::new(0, 0, 0, 1);
+        let minus_one = Quaternion::new(-1, 0, 0, 0);
+
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(k * k, minus_one);
+        assert_eq!(i * j * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, k * -1);
+
+        let mut q = Quaternion::new(1, 2, 3, 4);
+        q *= Quaternion::new(5, 6, 7, 8);
+        assert_eq!(q, Quaternion::new(-60, 12, 30, 24));
+        q *= 2;
+        assert_eq!(q, Quaternion::new(-120, 24, 60, 48));
+    }
+}
@@ -0,0 +1,25 @@
+use crate::types::Quaternion;
+use num_traits::Num;
+
+use std::ops::Neg;
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod quaternion_neg_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_neg_test() {
+        assert_eq!(
+            -Quaternion::new(1.0, -2.0, 0.5, 0.0),
+            Quaternion::new(-1.0, 2.0, -0.5, -0.0)
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::types::Quaternion;
+use num_traits::Num;
+
+use std::ops::{Sub, SubAssign};
+
+impl<T: Num + Copy> Sub for Quaternion<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl<T: Num + Copy> SubAssign for Quaternion<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod quaternion_sub_tests {
+    use super::*;
+
+    #[test]
+    fn quaternion_sub_test() {
+        let mut q = Quaternion::new(1.0, 2.0, 3.0, 4.0) - Quaternion::new(4.0, -3.0, 2.0, -1.0);
+        assert_eq!(q, Quaternion::new(-3.0, 5.0, 1.0, 5.0));
+
+        q -= q;
+        assert_eq!(q, Quaternion::new(0.0, 0.0, 0.0, 0.0));
+    }
+}
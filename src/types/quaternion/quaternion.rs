@@ -0,0 +1,495 @@
+//! `Quaternion` is a struct representing a quaternion `w + xi + yj + zk`
+//!
+//! A `Quaternion` consists of a scalar part `w` and a vector part `(x, y, z)`.
+//! Quaternions multiply with the Hamilton product, which is associative but not
+//! commutative. Unit quaternions represent rotations in space without the gimbal
+//! lock of Euler angles: the rotation by an angle θ about a unit axis `u` is
+//! `cos(θ/2) + sin(θ/2)·u`, and `q` and `−q` describe the same rotation.
+//!
+//! Products compose rotations with the matrix convention, so `a * b` rotates by
+//! `b` first and then by `a`. The rotation functions accept quaternions of any
+//! non-zero length and rotate by their normalized form.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crabmath::fields::geometry::vector::Vector3;
+//! use crabmath::types::{Angle, Quaternion};
+//!
+//! let quarter = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0f64), Angle::from_degrees(90.0)).unwrap();
+//! let turned = quarter.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+//! assert!((turned - Vector3::new(0.0, 1.0, 0.0)).norm() < 1e-15);
+//!
+//! // Two quarter turns make a half turn.
+//! let (axis, angle) = (quarter * quarter).to_axis_angle();
+//! assert_eq!(axis, Vector3::new(0.0, 0.0, 1.0));
+//! assert!((angle.degrees() - 180.0).abs() < 1e-12);
+//!
+//! // Halfway between no rotation and the quarter turn is an eighth turn.
+//! let eighth = Quaternion::identity().slerp(&quarter, 0.5);
+//! assert!((eighth.to_axis_angle().1.degrees() - 45.0).abs() < 1e-12);
+//!
+//! let q = Quaternion::new(1.0, 1.0, -1.0, 3.0);
+//! assert_eq!(q * q.inverse().unwrap(), Quaternion::identity());
+//! assert_eq!(q.to_string(), "1+1i-1j+3k");
+//! ```
+//!
+//! # Functions
+//!
+//! - `new`: Constructs a quaternion from its four components.
+//! - `identity`: Constructs the quaternion one, the rotation that does nothing.
+//! - `from_parts`: Constructs a quaternion from a scalar and a vector part.
+//! - `scalar` and `vector`: Get the scalar and the vector part.
+//! - `conj`: Gets the conjugate.
+//! - `dot` and `norm_sqr`: Get the four-dimensional dot product and squared norm.
+//! - `scale` and `unscale`: Multiply or divide every component by a number.
+//! - `norm` and `normalize`: Get the norm or the unit quaternion.
+//! - `inverse`: Gets the multiplicative inverse, if one exists.
+//! - `from_axis_angle` and `to_axis_angle`: Convert to and from a rotation about an axis.
+//! - `from_rotation_matrix` and `to_rotation_matrix`: Convert to and from a 3x3 rotation matrix.
+//! - `rotate_vector` and `rotate_point`: Rotate a vector or a point about the origin.
+//! - `angle_to`: Gets the angle of the rotation between two orientations.
+//! - `nlerp` and `slerp`: Interpolate between two orientations.
+
+use crate::fields::geometry::point::Point3;
+use crate::fields::geometry::vector::Vector3;
+use crate::types::quaternion::error::QuaternionError;
+use crate::types::Angle;
+use num_traits::{Float, Num};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T>
+where
+    T: Num + Copy,
+{
+    // Function to create a new quaternion w + xi + yj + zk.
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    // Function to create the identity rotation.
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    // Function to create a quaternion from its scalar and vector parts.
+    pub fn from_parts(scalar: T, vector: Vector3<T>) -> Self {
+        Quaternion::new(scalar, vector.x, vector.y, vector.z)
+    }
+
+    // Function to get the scalar part.
+    pub fn scalar(&self) -> T {
+        self.w
+    }
+
+    // Function to get the vector part.
+    pub fn vector(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    // Function to get the conjugate, which negates the vector part.
+    pub fn conj(&self) -> Self {
+        Quaternion::new(
+            self.w,
+            T::zero() - self.x,
+            T::zero() - self.y,
+            T::zero() - self.z,
+        )
+    }
+
+    // Function to get the dot product of the components.
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    // Function to get the squared norm.
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    // Function to multiply every component by a number.
+    pub fn scale(&self, factor: T) -> Self {
+        Quaternion::new(
+            self.w * factor,
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+        )
+    }
+
+    // Function to divide every component by a number.
+    pub fn unscale(&self, divisor: T) -> Self {
+        Quaternion::new(
+            self.w / divisor,
+            self.x / divisor,
+            self.y / divisor,
+            self.z / divisor,
+        )
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    // Function to get the norm.
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    // Function to get the unit quaternion in the same direction, if there is one.
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == T::zero() || !norm.is_finite() {
+            return None;
+        }
+        Some(self.unscale(norm))
+    }
+
+    // Function to get the inverse, or None for the zero quaternion.
+    //
+    // For a unit quaternion the inverse is the conjugate, the opposite rotation.
+    pub fn inverse(&self) -> Option<Self> {
+        let norm_sqr = self.norm_sqr();
+        if norm_sqr == T::zero() || !norm_sqr.is_finite() {
+            return None;
+        }
+        Some(self.conj().unscale(norm_sqr))
+    }
+
+    // Function to create the rotation by an angle about an axis.
+    //
+    // The rotation is counter-clockwise when looking against the axis.
+    pub fn from_axis_angle(axis: Vector3<T>, angle: Angle<T>) -> Result<Self, QuaternionError> {
+        let axis = axis
+            .normalize()
+            .ok_or_else(|| QuaternionError::new("Rotation axis must be finite and not zero"))?;
+        let (sin, cos) = (angle.radians() / (T::one() + T::one())).sin_cos();
+        Ok(Quaternion::from_parts(cos, axis * sin))
+    }
+
+    // Function to get the rotation axis and the angle in [0, π].
+    //
+    // The identity has no axis; the x axis is returned for it.
+    pub fn to_axis_angle(&self) -> (Vector3<T>, Angle<T>) {
+        let unit = self.normalize().unwrap_or_else(Quaternion::identity);
+        let unit = if unit.w < T::zero() {
+            unit.scale(-T::one())
+        } else {
+            unit
+        };
+        let vector = unit.vector();
+        let sin = vector.norm();
+        let angle = Angle::from_radians((T::one() + T::one()) * sin.atan2(unit.w));
+        if sin == T::zero() {
+            (Vector3::new(T::one(), T::zero(), T::zero()), angle)
+        } else {
+            (vector / sin, angle)
+        }
+    }
+
+    // Function to create the rotation given by a 3x3 rotation matrix.
+    //
+    // The matrix acts on column vectors and must be orthonormal with determinant 1,
+    // up to a small tolerance. Follows Shepperd's method, dividing by the largest
+    // of the four candidate components for stability.
+    pub fn from_rotation_matrix(matrix: [[T; 3]; 3]) -> Result<Self, QuaternionError> {
+        let m = matrix;
+        let tolerance = T::epsilon().sqrt();
+        for i in 0..3 {
+            for j in 0..3 {
+                let product = m[i][0] * m[j][0] + m[i][1] * m[j][1] + m[i][2] * m[j][2];
+                let expected = if i == j { T::one() } else { T::zero() };
+                if (product - expected).abs() > tolerance || product.is_nan() {
+                    return Err(QuaternionError::new("Matrix is not orthonormal"));
+                }
+            }
+        }
+        let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        if determinant < T::zero() {
+            return Err(QuaternionError::new(
+                "Matrix is a reflection, not a rotation",
+            ));
+        }
+
+        let one = T::one();
+        let two = one + one;
+        let four = two + two;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                s / four,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Quaternion::new(
+                (m[2][1] - m[1][2]) / s,
+                s / four,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Quaternion::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / four,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Quaternion::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / four,
+            )
+        };
+        q.normalize()
+            .ok_or_else(|| QuaternionError::new("Matrix is not a rotation"))
+    }
+
+    // Function to get the 3x3 rotation matrix, acting on column vectors.
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let one = T::one();
+        let s = (one + one) / self.norm_sqr();
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        [
+            [
+                one - s * (y * y + z * z),
+                s * (x * y - w * z),
+                s * (x * z + w * y),
+            ],
+            [
+                s * (x * y + w * z),
+                one - s * (x * x + z * z),
+                s * (y * z - w * x),
+            ],
+            [
+                s * (x * z - w * y),
+                s * (y * z + w * x),
+                one - s * (x * x + y * y),
+            ],
+        ]
+    }
+
+    // Function to rotate a vector, computing q·v·q⁻¹ without forming the products.
+    pub fn rotate_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let axis = self.vector();
+        let twice = axis.cross(vector) * (T::one() + T::one());
+        vector + (twice * self.w + axis.cross(twice)) / self.norm_sqr()
+    }
+
+    // Function to rotate a point about the origin.
+    pub fn rotate_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::from(self.rotate_vector(point.to_vector()))
+    }
+
+    // Function to get the angle in [0, π] of the rotation taking one orientation to the other.
+    pub fn angle_to(&self, other: &Self) -> Angle<T> {
+        let difference = self.conj() * *other;
+        let sin = difference.vector().norm();
+        Angle::from_radians((T::one() + T::one()) * sin.atan2(difference.w.abs()))
+    }
+
+    // Function to interpolate linearly and normalize the result.
+    //
+    // Cheaper than slerp and follows the same path, but not at constant speed.
+    // Takes the shorter way round, like slerp.
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = self.nearer(other);
+        let blended = self.scale(T::one() - t) + other.scale(t);
+        blended.normalize().unwrap_or(blended)
+    }
+
+    // Function to interpolate along the great arc at constant angular speed.
+    //
+    // Both quaternions should be unit quaternions. Of q and −q the one nearer to
+    // self is used, so the interpolation takes the shorter way round.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let other = self.nearer(other);
+        // The angle between the two as 4-vectors, accurate even when they are close.
+        let theta = (T::one() + T::one()) * (*self - other).norm().atan2((*self + other).norm());
+        let sin = theta.sin();
+        if sin <= T::epsilon() {
+            return self.nlerp(&other, t);
+        }
+        self.scale(((T::one() - t) * theta).sin() / sin) + other.scale((t * theta).sin() / sin)
+    }
+
+    // Function to pick whichever of other and −other lies on the same side as self.
+    fn nearer(&self, other: &Self) -> Self {
+        if self.dot(other) < T::zero() {
+            other.scale(-T::one())
+        } else {
+            *other
+        }
+    }
+}
+
+#[cfg(test)]
+mod quaternion_tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_vector_close(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!((a - b).norm() < 1e-12, "{a} != {b}");
+    }
+
+    fn assert_same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) {
+        assert!(a.angle_to(&b).radians() < 1e-7, "{a} != {b}");
+    }
+
+    #[test]
+    fn quaternion_new_test() {
+        let q = Quaternion::from_parts(1, Vector3::new(2, 3, 4));
+        assert_eq!(q, Quaternion::new(1, 2, 3, 4));
+        assert_eq!(q.scalar(), 1);
+        assert_eq!(q.vector(), Vector3::new(2, 3, 4));
+        assert_eq!(q.conj(), Quaternion::new(1, -2, -3, -4));
+        assert_eq!(q.norm_sqr(), 30);
+        assert_eq!(q.scale(2).unscale(2), q);
+        assert_eq!(Quaternion::<i32>::identity(), Quaternion::new(1, 0, 0, 0));
+    }
+
+    #[test]
+    fn quaternion_norm_inverse_test() {
+        let q = Quaternion::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(q.norm(), 2.0);
+        assert_eq!(q.normalize().unwrap(), Quaternion::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(
+            q.inverse().unwrap(),
+            Quaternion::new(0.25, -0.25, -0.25, -0.25)
+        );
+        assert_eq!(q * q.inverse().unwrap(), Quaternion::identity());
+
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        assert!(zero.normalize().is_none());
+        assert!(zero.inverse().is_none());
+    }
+
+    #[test]
+    fn quaternion_axis_angle_test() {
+        let axis = Vector3::new(1.0, 2.0, 2.0);
+        let q = Quaternion::from_axis_angle(axis, Angle::from_radians(1.0)).unwrap();
+        assert!((q.norm() - 1.0).abs() < 1e-15);
+
+        let (back, angle) = q.to_axis_angle();
+        assert_vector_close(back, axis / 3.0);
+        assert!((angle.radians() - 1.0).abs() < 1e-12);
+
+        // The negated quaternion is the same rotation, reported the same way.
+        let (back, angle) = q.scale(-2.0).to_axis_angle();
+        assert_vector_close(back, axis / 3.0);
+        assert!((angle.radians() - 1.0).abs() < 1e-12);
+
+        // Beyond a half turn the axis flips instead.
+        let long = Quaternion::from_axis_angle(axis, Angle::from_radians(4.0)).unwrap();
+        let (back, angle) = long.to_axis_angle();
+        assert_vector_close(back, -axis / 3.0);
+        assert!((angle.radians() - (2.0 * PI - 4.0)).abs() < 1e-12);
+
+        let (back, angle) = Quaternion::<f64>::identity().to_axis_angle();
+        assert_eq!(back, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(angle.radians(), 0.0);
+
+        assert!(Quaternion::from_axis_angle(Vector3::zero(), Angle::from_radians(1.0)).is_err());
+    }
+
+    #[test]
+    fn quaternion_rotation_test() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        let about_z = Quaternion::from_axis_angle(z, Angle::from_radians(FRAC_PI_2)).unwrap();
+        let about_x = Quaternion::from_axis_angle(x, Angle::from_radians(FRAC_PI_2)).unwrap();
+
+        assert_vector_close(about_z.rotate_vector(x), y);
+        assert_vector_close(about_x.rotate_vector(y), z);
+        // a * b applies b first.
+        assert_vector_close((about_x * about_z).rotate_vector(x), z);
+        assert_vector_close((about_z * about_x).rotate_vector(x), y);
+
+        // The length of the quaternion does not matter.
+        assert_vector_close(about_z.scale(3.0).rotate_vector(x), y);
+
+        let point = about_z.rotate_point(Point3::new(2.0, 0.0, 5.0));
+        assert!(point.distance(Point3::new(0.0, 2.0, 5.0)) < 1e-12);
+    }
+
+    #[test]
+    fn quaternion_rotation_matrix_test() {
+        let q = Quaternion::from_axis_angle(Vector3::new(-1.0, 0.5, 2.0), Angle::from_radians(2.5))
+            .unwrap();
+        let m = q.to_rotation_matrix();
+        let v = Vector3::new(0.3, -1.2, 2.0);
+        let by_matrix = Vector3::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        );
+        assert_vector_close(by_matrix, q.rotate_vector(v));
+        assert_same_rotation(Quaternion::from_rotation_matrix(m).unwrap(), q);
+
+        // Every branch of Shepperd's method, including half turns with trace −1.
+        for axis in [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, 1.0, 0.0),
+        ] {
+            for radians in [0.0, 1.0, 3.0, PI] {
+                let q = Quaternion::from_axis_angle(axis, Angle::from_radians(radians)).unwrap();
+                let back = Quaternion::from_rotation_matrix(q.to_rotation_matrix()).unwrap();
+                assert_same_rotation(back, q);
+            }
+        }
+
+        let mirror = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        let skewed = [[1.0, 0.1, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert!(Quaternion::from_rotation_matrix(mirror).is_err());
+        assert!(Quaternion::from_rotation_matrix(skewed).is_err());
+    }
+
+    #[test]
+    fn quaternion_interpolation_test() {
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        let start = Quaternion::identity();
+        let end = Quaternion::from_axis_angle(z, Angle::from_radians(2.0)).unwrap();
+
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            let expected = Quaternion::from_axis_angle(z, Angle::from_radians(2.0 * t)).unwrap();
+            assert_same_rotation(start.slerp(&end, t), expected);
+        }
+        // nlerp follows the same path, so its midpoint agrees by symmetry.
+        assert_same_rotation(
+            start.nlerp(&end, 0.5),
+            Quaternion::from_axis_angle(z, Angle::from_radians(1.0)).unwrap(),
+        );
+        assert!((start.nlerp(&end, 0.3).norm() - 1.0).abs() < 1e-15);
+
+        // The shorter way round is taken even when the end point is negated.
+        let negated = end.scale(-1.0);
+        assert_same_rotation(
+            start.slerp(&negated, 0.5),
+            Quaternion::from_axis_angle(z, Angle::from_radians(1.0)).unwrap(),
+        );
+        assert_eq!(start.slerp(&start, 0.5), start);
+
+        assert!((start.angle_to(&end).radians() - 2.0).abs() < 1e-12);
+        assert!((start.angle_to(&negated).radians() - 2.0).abs() < 1e-12);
+    }
+}